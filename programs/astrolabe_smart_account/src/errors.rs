@@ -84,7 +84,7 @@ pub enum SmartAccountError {
     InvalidInstructionArgs,
     #[msg("Final message buffer hash doesnt match the expected hash")]
     FinalBufferHashMismatch,
-    #[msg("Final buffer size cannot exceed 4000 bytes")]
+    #[msg("Final buffer size cannot exceed 9216 bytes")]
     FinalBufferSizeExceeded,
     #[msg("Final buffer size mismatch")]
     FinalBufferSizeMismatch,
@@ -104,4 +104,96 @@ pub enum SmartAccountError {
     TimeLockNotZero,
     #[msg("Feature not implemented")]
     NotImplemented,
+    #[msg("Spending limit amount must be greater than 0")]
+    SpendingLimitInvalidAmount,
+    #[msg("Spending limit exceeded")]
+    SpendingLimitExceeded,
+    #[msg("Spending limit is expired")]
+    SpendingLimitExpired,
+    #[msg("Signer is not a restricted signer with the required permission")]
+    UnauthorizedRestrictedSigner,
+    #[msg("Restricted signer with the EmergencyExit permission must have an exit destination")]
+    MissingExitDestination,
+    #[msg("Smart account cannot be archived before `archivable_after`")]
+    ArchivalCooldownNotElapsed,
+    #[msg("Supplied settings do not match the archived settings hash")]
+    ArchivedSettingsHashMismatch,
+    #[msg("Invalid compressed secp256r1 public key")]
+    InvalidPasskeyPublicKey,
+    #[msg("No secp256r1 signature by the passkey found in the transaction")]
+    MissingPasskeySignature,
+    #[msg("Invalid WebAuthn authenticator data")]
+    InvalidWebAuthnAuthenticatorData,
+    #[msg("Invalid WebAuthn client data")]
+    InvalidWebAuthnClientData,
+    #[msg("Session key is expired")]
+    SessionKeyExpired,
+    #[msg("Session key is not allowed to perform this action")]
+    SessionKeyOutOfScope,
+    #[msg("Session key lamports budget exceeded")]
+    SessionKeyBudgetExceeded,
+    #[msg("Recovery threshold must not exceed the number of guardians")]
+    InvalidRecoveryThreshold,
+    #[msg("Recovery is disabled for this smart account")]
+    RecoveryDisabled,
+    #[msg("Recovery has not been approved by enough guardians")]
+    RecoveryNotApproved,
+    #[msg("Recovery veto period has not elapsed")]
+    RecoveryDelayNotElapsed,
+    #[msg("Guardian has already approved the recovery")]
+    RecoveryAlreadyApproved,
+    #[msg("Vault policy account is required for this smart account")]
+    MissingVaultPolicy,
+    #[msg("Transaction has not been approved by enough signers of the vault policy")]
+    VaultPolicyThresholdNotReached,
+    #[msg("Voting deadline must be in the future")]
+    InvalidVotingDeadline,
+    #[msg("Proposal voting deadline or execution window has passed")]
+    ProposalExpired,
+    #[msg("Proposal has not expired")]
+    ProposalNotExpired,
+    #[msg("Execution window must be longer than the time lock")]
+    InvalidExecutionWindow,
+    #[msg("Found multiple time locks for the same target")]
+    DuplicateTargetTimeLock,
+    #[msg("Target has no time lock")]
+    MissingTargetTimeLock,
+    #[msg("Signer rotation is disabled for this smart account")]
+    SignerRotationDisabled,
+    #[msg("Signer rotation delay has not elapsed yet")]
+    SignerRotationDelayNotElapsed,
+    #[msg("Program instruction is not allowed by the program filters of the smart account")]
+    ProgramNotAllowed,
+    #[msg("Found multiple program filters for the same instructions")]
+    DuplicateProgramFilter,
+    #[msg("Program filter not found")]
+    MissingProgramFilter,
+    #[msg("Program filter discriminator is too long")]
+    InvalidProgramFilter,
+    #[msg("Transaction buffer has not timed out, gone stale or lost its creator")]
+    TransactionBufferNotAbandoned,
+    #[msg("Referral fee can't exceed 10000 basis points")]
+    InvalidReferralFee,
+    #[msg("Referrer account doesn't match the referrer of the smart account")]
+    InvalidReferrer,
+    #[msg("Creator is not on the smart account creation allowlist")]
+    CreatorNotAllowlisted,
+    #[msg("Creator is already on the smart account creation allowlist")]
+    CreatorAlreadyAllowlisted,
+    #[msg("Creator allowlist page is full")]
+    CreatorAllowlistPageFull,
+    #[msg("Smart account creation must be cosigned by the program config authority")]
+    CreationNotCosigned,
+    #[msg("Program config transfer was proposed by a previous authority")]
+    ProgramConfigTransferStale,
+    #[msg("Program config transfer is for another parameter")]
+    ProgramConfigTransferKindMismatch,
+    #[msg("Transaction account can't grow by more than 10240 bytes within an instruction")]
+    TransactionSizeExceeded,
+    #[msg("Arithmetic overflow")]
+    Overflow,
+    #[msg("Settings account is already in the current layout")]
+    SettingsAlreadyMigrated,
+    #[msg("Settings are too large to be restored once archived")]
+    SettingsTooLargeToArchive,
 }
@@ -1,7 +1,13 @@
 use anchor_lang::prelude::*;
 
-use crate::{state::SettingsAction, Settings, SmartAccountCompiledInstruction};
-
+use crate::{
+    state::{
+        BatchTransactionOutcome, ProgramConfigTransferKind, Proposal, ProposalStatus,
+        SettingsAction, SmartAccountTransactionMessage, VaultPolicy,
+        MAX_PROGRAM_FILTER_DISCRIMINATOR_LEN,
+    },
+    Settings, SmartAccountCompiledInstruction,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreateSmartAccountEvent {
@@ -18,13 +24,12 @@ pub struct SynchronousTransactionEvent {
     pub instruction_accounts: Vec<Pubkey>,
 }
 
-
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct SynchronousSettingsTransactionEvent {
     pub settings_pubkey: Pubkey,
     pub signers: Vec<Pubkey>,
     pub settings: Settings,
-    pub changes: Vec<SettingsAction>
+    pub changes: Vec<SettingsAction>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -32,7 +37,7 @@ pub struct AuthoritySettingsEvent {
     pub settings: Settings,
     pub settings_pubkey: Pubkey,
     pub authority: Pubkey,
-    pub change: SettingsAction
+    pub change: SettingsAction,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -40,5 +45,218 @@ pub struct AuthorityChangeEvent {
     pub settings: Settings,
     pub settings_pubkey: Pubkey,
     pub authority: Pubkey,
-    pub new_authority: Option<Pubkey>
-}
\ No newline at end of file
+    pub new_authority: Option<Pubkey>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UseSpendingLimitEvent {
+    pub settings_pubkey: Pubkey,
+    pub spending_limit_pubkey: Pubkey,
+    pub signer: Pubkey,
+    pub destination: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub remaining_amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EmergencyExitEvent {
+    pub settings_pubkey: Pubkey,
+    pub restricted_signer: Pubkey,
+    pub account_index: u8,
+    pub smart_account: Pubkey,
+    pub exit_destination: Pubkey,
+    pub lamports: u64,
+    pub token_transfers: Vec<EmergencyExitTokenTransfer>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EmergencyExitTokenTransfer {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ArchiveSettingsEvent {
+    pub settings_pubkey: Pubkey,
+    pub archival_authority: Pubkey,
+    pub archived_settings_pubkey: Pubkey,
+    pub settings_hash: [u8; 32],
+    /// The archived settings, the preimage of `settings_hash` needed to unarchive them.
+    pub settings: Settings,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UnarchiveSettingsEvent {
+    pub settings_pubkey: Pubkey,
+    pub archived_settings_pubkey: Pubkey,
+    pub settings: Settings,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TransactionEvent {
+    pub event_type: TransactionEventType,
+    pub settings_pubkey: Pubkey,
+    pub transaction_pubkey: Pubkey,
+    /// Index of the transaction, or of the batch for batches and their transactions.
+    pub transaction_index: u64,
+    pub signer: Option<Pubkey>,
+    pub memo: Option<String>,
+    pub transaction_content: TransactionContent,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub enum TransactionEventType {
+    Create,
+    Execute,
+    Skip,
+    /// The transaction account is closed, along with its proposal unless it's a batch transaction.
+    Close,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub enum TransactionContent {
+    Transaction {
+        account_index: u8,
+        instructions: Vec<InstructionSummary>,
+    },
+    SettingsTransaction {
+        actions: Vec<SettingsAction>,
+    },
+    Batch {
+        account_index: u8,
+        size: u32,
+    },
+    BatchTransaction {
+        batch_transaction_index: u32,
+        outcome: BatchTransactionOutcome,
+        instructions: Vec<InstructionSummary>,
+    },
+}
+
+/// What an instruction of a transaction message calls, without its full data.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InstructionSummary {
+    pub program_id: Pubkey,
+    pub num_accounts: u8,
+    /// The leading bytes of the instruction data, as matched by the program filters.
+    pub discriminator: Vec<u8>,
+}
+
+impl InstructionSummary {
+    pub fn from_message(message: &SmartAccountTransactionMessage) -> Vec<Self> {
+        message
+            .instructions
+            .iter()
+            .map(|instruction| {
+                let discriminator_len = instruction
+                    .data
+                    .len()
+                    .min(MAX_PROGRAM_FILTER_DISCRIMINATOR_LEN);
+                Self {
+                    program_id: message
+                        .account_keys
+                        .get(usize::from(instruction.program_id_index))
+                        .copied()
+                        .unwrap_or_default(),
+                    num_accounts: u8::try_from(instruction.account_indexes.len())
+                        .unwrap_or(u8::MAX),
+                    discriminator: instruction.data[..discriminator_len].to_vec(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposalEvent {
+    pub event_type: ProposalEventType,
+    pub settings_pubkey: Pubkey,
+    pub proposal_pubkey: Pubkey,
+    pub transaction_index: u64,
+    pub signer: Option<Pubkey>,
+    pub memo: Option<String>,
+    /// The status of the proposal after the event.
+    pub status: ProposalStatus,
+    pub tally: VoteTally,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub enum ProposalEventType {
+    Create,
+    Activate,
+    Approve,
+    Reject,
+    Cancel,
+    Expire,
+    Execute,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VoteTally {
+    pub approvals: u16,
+    pub rejections: u16,
+    pub cancellations: u16,
+    /// Voting weights only count the signers allowed by the `VaultPolicy` of the smart account, if any.
+    pub approval_weight: u32,
+    pub rejection_weight: u32,
+    pub cancellation_weight: u32,
+    /// Voting weight needed to approve or cancel the proposal,
+    /// the `VaultPolicy` threshold if the smart account of the transaction has one.
+    pub threshold: u16,
+    /// Voting weight needed to reject the proposal.
+    pub cutoff: u32,
+}
+
+impl ProposalEvent {
+    /// `vault_policy` is the policy of the smart account the transaction is for, if any.
+    pub fn new(
+        event_type: ProposalEventType,
+        settings: &Account<Settings>,
+        proposal: &Account<Proposal>,
+        vault_policy: Option<&VaultPolicy>,
+        signer: Option<Pubkey>,
+        memo: Option<String>,
+    ) -> Self {
+        let weight = |keys: &[Pubkey]| {
+            u32::try_from(Proposal::voting_weight(settings, vault_policy, keys)).unwrap_or(u32::MAX)
+        };
+        Self {
+            event_type,
+            settings_pubkey: settings.key(),
+            proposal_pubkey: proposal.key(),
+            transaction_index: proposal.transaction_index,
+            signer,
+            memo,
+            status: proposal.status.clone(),
+            tally: VoteTally {
+                approvals: u16::try_from(proposal.approved.len()).unwrap_or(u16::MAX),
+                rejections: u16::try_from(proposal.rejected.len()).unwrap_or(u16::MAX),
+                cancellations: u16::try_from(proposal.cancelled.len()).unwrap_or(u16::MAX),
+                approval_weight: weight(&proposal.approved),
+                rejection_weight: weight(&proposal.rejected),
+                cancellation_weight: weight(&proposal.cancelled),
+                threshold: Proposal::threshold(settings, vault_policy),
+                cutoff: u32::try_from(Proposal::cutoff(settings, vault_policy)).unwrap_or(u32::MAX),
+            },
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProgramConfigEvent {
+    pub event_type: ProgramConfigEventType,
+    pub program_config_pubkey: Pubkey,
+    pub transfer_kind: ProgramConfigTransferKind,
+    /// The `authority` or `treasury` at the time of the event.
+    pub old_key: Pubkey,
+    pub new_key: Pubkey,
+    pub signer: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub enum ProgramConfigEventType {
+    ProposeTransfer,
+    AcceptTransfer,
+    CancelTransfer,
+}
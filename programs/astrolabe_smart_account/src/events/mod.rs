@@ -7,13 +7,41 @@ use crate::LogEventArgs;
 pub mod account_events;
 pub use account_events::*;
 
+/// Version of the event envelope and of the event layouts it carries. Bumped
+/// whenever an existing layout changes in a way older decoders can't read;
+/// new events and fields appended to existing events don't bump it.
+pub const EVENT_SCHEMA_VERSION: u8 = 1;
+
+/// What is actually logged through `log_event`. The payload is length-prefixed
+/// so decoders can skip events whose `event_type` they don't know about.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SmartAccountEventEnvelope {
+    pub version: u8,
+    /// The `SmartAccountEvent` variant index of the payload.
+    pub event_type: u8,
+    /// The smart account settings, or the program config for program config events.
+    pub settings_pubkey: Pubkey,
+    pub slot: u64,
+    pub timestamp: i64,
+    /// The Borsh-serialized event, without the `SmartAccountEvent` variant tag.
+    pub payload: Vec<u8>,
+}
+
+/// Variants are only ever appended, as their index is the envelope `event_type`.
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub enum SmartAccountEvent {
     CreateSmartAccountEvent(CreateSmartAccountEvent),
     SynchronousTransactionEvent(SynchronousTransactionEvent),
     SynchronousSettingsTransactionEvent(SynchronousSettingsTransactionEvent),
     AuthoritySettingsEvent(AuthoritySettingsEvent),
-    AuthorityChangeEvent(AuthorityChangeEvent)
+    AuthorityChangeEvent(AuthorityChangeEvent),
+    UseSpendingLimitEvent(UseSpendingLimitEvent),
+    EmergencyExitEvent(EmergencyExitEvent),
+    ArchiveSettingsEvent(ArchiveSettingsEvent),
+    UnarchiveSettingsEvent(UnarchiveSettingsEvent),
+    TransactionEvent(TransactionEvent),
+    ProposalEvent(ProposalEvent),
+    ProgramConfigEvent(ProgramConfigEvent),
 }
 
 pub struct LogAuthorityInfo<'info> {
@@ -23,6 +51,39 @@ pub struct LogAuthorityInfo<'info> {
     pub program: AccountInfo<'info>,
 }
 impl SmartAccountEvent {
+    /// The smart account the event is about, or the program config for program config events.
+    pub fn settings_pubkey(&self) -> Pubkey {
+        match self {
+            Self::CreateSmartAccountEvent(event) => event.new_settings_pubkey,
+            Self::SynchronousTransactionEvent(event) => event.settings_pubkey,
+            Self::SynchronousSettingsTransactionEvent(event) => event.settings_pubkey,
+            Self::AuthoritySettingsEvent(event) => event.settings_pubkey,
+            Self::AuthorityChangeEvent(event) => event.settings_pubkey,
+            Self::UseSpendingLimitEvent(event) => event.settings_pubkey,
+            Self::EmergencyExitEvent(event) => event.settings_pubkey,
+            Self::ArchiveSettingsEvent(event) => event.settings_pubkey,
+            Self::UnarchiveSettingsEvent(event) => event.settings_pubkey,
+            Self::TransactionEvent(event) => event.settings_pubkey,
+            Self::ProposalEvent(event) => event.settings_pubkey,
+            Self::ProgramConfigEvent(event) => event.program_config_pubkey,
+        }
+    }
+
+    pub fn to_envelope(&self) -> Result<SmartAccountEventEnvelope> {
+        let clock = Clock::get()?;
+        // Borsh writes the variant index as a single leading byte.
+        let mut payload = AnchorSerialize::try_to_vec(self)?;
+        let event_type = payload.remove(0);
+        Ok(SmartAccountEventEnvelope {
+            version: EVENT_SCHEMA_VERSION,
+            event_type,
+            settings_pubkey: self.settings_pubkey(),
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+            payload,
+        })
+    }
+
     pub fn log<'info>(&self, authority_info: &LogAuthorityInfo<'info>) -> Result<()> {
         let mut signer_seeds: Vec<&[u8]> = authority_info
             .authority_seeds
@@ -35,7 +96,7 @@ impl SmartAccountEvent {
         let data = LogEventArgs {
             account_seeds: authority_info.authority_seeds.clone(),
             bump: authority_info.bump,
-            event: AnchorSerialize::try_to_vec(self)?,
+            event: AnchorSerialize::try_to_vec(&self.to_envelope()?)?,
         };
         let mut instruction_data =
             Vec::with_capacity(8 + 4 + authority_info.authority_seeds.len() + 4 + data.event.len());
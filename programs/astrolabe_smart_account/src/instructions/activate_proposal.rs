@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -26,6 +28,16 @@ pub struct ActivateProposal<'info> {
         bump = proposal.bump,
     )]
     pub proposal: Account<'info, Proposal>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    /// CHECK: Checked by `VaultPolicy::load_for_proposal`.
+    pub transaction: Option<AccountInfo<'info>>,
+
+    /// The policy of the smart account the transaction is for, required if it has one.
+    pub vault_policy: Option<Account<'info, VaultPolicy>>,
 }
 
 impl ActivateProposal<'_> {
@@ -64,10 +76,37 @@ impl ActivateProposal<'_> {
     /// Update status of a multisig proposal from `Draft` to `Active`.
     #[access_control(ctx.accounts.validate())]
     pub fn activate_proposal(ctx: Context<Self>) -> Result<()> {
-        ctx.accounts.proposal.status = ProposalStatus::Active {
+        let settings = &ctx.accounts.settings;
+        let proposal = &mut ctx.accounts.proposal;
+
+        proposal.status = ProposalStatus::Active {
             timestamp: Clock::get()?.unix_timestamp,
         };
 
+        // Log the event
+        let vault_policy = VaultPolicy::load_for_proposal(
+            settings,
+            &settings.key(),
+            proposal,
+            ctx.accounts.transaction.as_ref(),
+            ctx.accounts.vault_policy.as_deref(),
+        )?;
+        let event = ProposalEvent::new(
+            ProposalEventType::Activate,
+            settings,
+            proposal,
+            vault_policy,
+            Some(ctx.accounts.signer.key()),
+            None,
+        );
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)?;
+
         Ok(())
     }
 }
@@ -47,6 +47,124 @@ pub struct SetArchivalAuthorityArgs {
     pub memo: Option<String>,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetRentCollectorArgs {
+    pub new_rent_collector: Option<Pubkey>,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetRecoveryConfigArgs {
+    pub new_recovery_threshold: u16,
+    pub new_recovery_delay: u32,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetExecutionWindowArgs {
+    pub new_execution_window: u32,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetTargetTimeLockArgs {
+    pub target: TimeLockTarget,
+    pub time_lock: u32,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveTargetTimeLockArgs {
+    pub target: TimeLockTarget,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddRestrictedSignerArgs {
+    pub new_restricted_signer: RestrictedSmartAccountSigner,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveRestrictedSignerArgs {
+    pub old_restricted_signer: Pubkey,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ChangeSignerPermissionsArgs {
+    pub signer: Pubkey,
+    pub new_permissions: Permissions,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RotateSignerArgs {
+    pub old_signer: Pubkey,
+    pub new_signer: Pubkey,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetSignerRotationDelayArgs {
+    pub new_signer_rotation_delay: Option<u32>,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetProgramFilterModeArgs {
+    pub mode: ProgramFilterMode,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddProgramFilterArgs {
+    pub filter: ProgramFilter,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveProgramFilterArgs {
+    pub filter: ProgramFilter,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetVaultPolicyArgs {
+    /// The index of the smart account that the policy is for.
+    pub account_index: u8,
+    /// How much voting weight of approvals from `signers` a transaction for the smart account needs to be executed.
+    pub threshold: u16,
+    /// How many seconds must pass between transaction voting settlement and execution.
+    pub time_lock: u32,
+    /// Signers of the settings that can create, approve and execute transactions for the smart account.
+    /// If empty, all the signers of the settings can.
+    pub signers: Vec<Pubkey>,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveVaultPolicyArgs {
+    /// The index of the smart account to remove the policy of.
+    pub account_index: u8,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
 #[derive(Accounts)]
 pub struct ExecuteSettingsTransactionAsAuthority<'info> {
     #[account(
@@ -70,7 +188,7 @@ pub struct ExecuteSettingsTransactionAsAuthority<'info> {
     pub program: Program<'info, AstrolabeSmartAccount>,
 }
 
-impl ExecuteSettingsTransactionAsAuthority<'_> {
+impl<'info> ExecuteSettingsTransactionAsAuthority<'info> {
     fn validate(&self) -> Result<()> {
         require_keys_eq!(
             self.settings_authority.key(),
@@ -99,10 +217,10 @@ impl ExecuteSettingsTransactionAsAuthority<'_> {
 
         settings.add_signer(new_signer.clone());
 
-        // Make sure the settings account can fit the newly set rent_collector.
+        // Make sure the settings account can fit the newly added signer.
         Settings::realloc_if_needed(
             settings.to_account_info(),
-            settings.signers.len(),
+            settings,
             ctx.accounts
                 .fee_payer
                 .as_ref()
@@ -274,40 +392,647 @@ impl ExecuteSettingsTransactionAsAuthority<'_> {
         Ok(())
     }
 
-    /// Set the settings `archival_authority` and reallocate space if necessary.
+    /// Set the settings `archival_authority`.
     ///
     /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
     ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
     #[access_control(ctx.accounts.validate())]
     pub fn set_archival_authority(
         ctx: Context<Self>,
-        _args: SetArchivalAuthorityArgs,
+        args: SetArchivalAuthorityArgs,
+    ) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+
+        // No reallocation is needed, `Settings::size` always accounts for the `archival_authority` key.
+        settings.archival_authority = args.new_archival_authority;
+
+        // We don't need to invalidate prior transactions here because changing
+        // `archival_authority` doesn't affect the consensus parameters of the settings.
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: (**settings).clone(),
+            settings_pubkey: settings.key(),
+            authority: ctx.accounts.settings_authority.key(),
+            change: SettingsAction::SetArchivalAuthority {
+                new_archival_authority: args.new_archival_authority,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+
+    /// Set the settings `rent_collector`.
+    ///
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_rent_collector(ctx: Context<Self>, args: SetRentCollectorArgs) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+
+        // No reallocation is needed, `Settings::size` always accounts for the `rent_collector` key.
+        settings.rent_collector = args.new_rent_collector;
+
+        // We don't need to invalidate prior transactions here because changing
+        // `rent_collector` doesn't affect the consensus parameters of the settings.
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: (**settings).clone(),
+            settings_pubkey: settings.key(),
+            authority: ctx.accounts.settings_authority.key(),
+            change: SettingsAction::SetRentCollector {
+                new_rent_collector: args.new_rent_collector,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+
+    /// Set the settings `recovery_threshold` and `recovery_delay`.
+    ///
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_recovery_config(ctx: Context<Self>, args: SetRecoveryConfigArgs) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+
+        settings.recovery_threshold = args.new_recovery_threshold;
+        settings.recovery_delay = args.new_recovery_delay;
+
+        // We don't need to invalidate prior transactions here because the recovery
+        // config doesn't affect the consensus parameters of the settings.
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: (**settings).clone(),
+            settings_pubkey: settings.key(),
+            authority: ctx.accounts.settings_authority.key(),
+            change: SettingsAction::SetRecoveryConfig {
+                new_recovery_threshold: args.new_recovery_threshold,
+                new_recovery_delay: args.new_recovery_delay,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+
+    /// Set the settings `execution_window`.
+    ///
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_execution_window(ctx: Context<Self>, args: SetExecutionWindowArgs) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+
+        settings.execution_window = args.new_execution_window;
+
+        // We don't need to invalidate prior transactions here because the execution
+        // window doesn't affect the consensus parameters of the settings.
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: (**settings).clone(),
+            settings_pubkey: settings.key(),
+            authority: ctx.accounts.settings_authority.key(),
+            change: SettingsAction::SetExecutionWindow {
+                new_execution_window: args.new_execution_window,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+
+    /// Set the time lock of a program or settings action kind and reallocate space if necessary.
+    ///
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_target_time_lock(ctx: Context<Self>, args: SetTargetTimeLockArgs) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+
+        settings.set_target_time_lock(args.target, args.time_lock);
+
+        // Make sure the settings account can fit the newly added target time lock.
+        Settings::realloc_if_needed(
+            settings.to_account_info(),
+            settings,
+            ctx.accounts
+                .fee_payer
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+            ctx.accounts
+                .system_program
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
+        settings.invalidate_prior_transactions();
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: (**settings).clone(),
+            settings_pubkey: settings.key(),
+            authority: ctx.accounts.settings_authority.key(),
+            change: SettingsAction::SetTargetTimeLock {
+                target: args.target,
+                time_lock: args.time_lock,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+
+    /// Remove the time lock of a program or settings action kind.
+    ///
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn remove_target_time_lock(
+        ctx: Context<Self>,
+        args: RemoveTargetTimeLockArgs,
     ) -> Result<()> {
-        // Marked as NotImplemented until archival feature is implemented.
-        return err!(SmartAccountError::NotImplemented);
-        // let settings = &mut ctx.accounts.settings;
-
-        // settings.archival_authority = args.new_archival_authority;
-
-        // // Make sure the settings account can fit the newly set rent_collector.
-        // Settings::realloc_if_needed(
-        //     settings.to_account_info(),
-        //     settings.signers.len(),
-        //     ctx.accounts
-        //         .fee_payer
-        //         .as_ref()
-        //         .map(ToAccountInfo::to_account_info),
-        //     ctx.accounts
-        //         .system_program
-        //         .as_ref()
-        //         .map(ToAccountInfo::to_account_info),
-        // )?;
-
-        // // We don't need to invalidate prior transactions here because changing
-        // // `rent_collector` doesn't affect the consensus parameters of the settings.
-
-        // settings.invariant()?;
-
-        // Ok(())
+        let settings = &mut ctx.accounts.settings;
+
+        settings.remove_target_time_lock(args.target)?;
+
+        settings.invalidate_prior_transactions();
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: (**settings).clone(),
+            settings_pubkey: settings.key(),
+            authority: ctx.accounts.settings_authority.key(),
+            change: SettingsAction::RemoveTargetTimeLock {
+                target: args.target,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+
+    /// Add a restricted signer to the settings and reallocate space if necessary.
+    ///
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn add_restricted_signer(ctx: Context<Self>, args: AddRestrictedSignerArgs) -> Result<()> {
+        let AddRestrictedSignerArgs {
+            new_restricted_signer,
+            ..
+        } = args;
+
+        let settings = &mut ctx.accounts.settings;
+
+        // Make sure that the new restricted signer is not already in the settings.
+        require!(
+            settings
+                .is_restricted_signer(new_restricted_signer.key)
+                .is_none(),
+            SmartAccountError::DuplicateSigner
+        );
+
+        settings.add_restricted_signer(new_restricted_signer.clone());
+
+        // Make sure the settings account can fit the newly added restricted signer.
+        Settings::realloc_if_needed(
+            settings.to_account_info(),
+            settings,
+            ctx.accounts
+                .fee_payer
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+            ctx.accounts
+                .system_program
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
+        // We don't need to invalidate prior transactions here because restricted
+        // signers don't take part in the voting consensus of the settings.
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: (**settings).clone(),
+            settings_pubkey: settings.key(),
+            authority: ctx.accounts.settings_authority.key(),
+            change: SettingsAction::AddRestrictedSigner {
+                new_restricted_signer,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+
+    /// Remove a restricted signer from the settings.
+    ///
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn remove_restricted_signer(
+        ctx: Context<Self>,
+        args: RemoveRestrictedSignerArgs,
+    ) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+
+        settings.remove_restricted_signer(args.old_restricted_signer)?;
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: (**settings).clone(),
+            settings_pubkey: settings.key(),
+            authority: ctx.accounts.settings_authority.key(),
+            change: SettingsAction::RemoveRestrictedSigner {
+                old_restricted_signer: args.old_restricted_signer,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+
+    /// Change the `permissions` of an existing signer.
+    ///
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn change_signer_permissions(
+        ctx: Context<Self>,
+        args: ChangeSignerPermissionsArgs,
+    ) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+
+        settings.change_signer_permissions(args.signer, args.new_permissions)?;
+
+        settings.invalidate_prior_transactions();
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: (**settings).clone(),
+            settings_pubkey: settings.key(),
+            authority: ctx.accounts.settings_authority.key(),
+            change: SettingsAction::ChangeSignerPermissions {
+                signer: args.signer,
+                new_permissions: args.new_permissions,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+
+    /// Replace the key of a signer, keeping its permissions and weight.
+    ///
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn rotate_signer(ctx: Context<Self>, args: RotateSignerArgs) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+
+        settings.rotate_signer(args.old_signer, args.new_signer)?;
+
+        settings.invalidate_prior_transactions();
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: (**settings).clone(),
+            settings_pubkey: settings.key(),
+            authority: ctx.accounts.settings_authority.key(),
+            change: SettingsAction::RotateSigner {
+                old_signer: args.old_signer,
+                new_signer: args.new_signer,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+
+    /// Set the settings `signer_rotation_delay`.
+    ///
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_signer_rotation_delay(
+        ctx: Context<Self>,
+        args: SetSignerRotationDelayArgs,
+    ) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+
+        settings.signer_rotation_delay = args.new_signer_rotation_delay;
+
+        // We don't need to invalidate prior transactions here because the signer
+        // rotation delay doesn't affect the consensus parameters of the settings.
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: (**settings).clone(),
+            settings_pubkey: settings.key(),
+            authority: ctx.accounts.settings_authority.key(),
+            change: SettingsAction::SetSignerRotationDelay {
+                new_signer_rotation_delay: args.new_signer_rotation_delay,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+
+    /// Set the settings `program_filter_mode`.
+    ///
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_program_filter_mode(
+        ctx: Context<Self>,
+        args: SetProgramFilterModeArgs,
+    ) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+
+        settings.program_filter_mode = args.mode;
+
+        // We don't need to invalidate prior transactions here because the program
+        // filters are checked when executing the transactions.
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: (**settings).clone(),
+            settings_pubkey: settings.key(),
+            authority: ctx.accounts.settings_authority.key(),
+            change: SettingsAction::SetProgramFilterMode { mode: args.mode },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+
+    /// Add a filter to the settings `program_filters`.
+    ///
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn add_program_filter(ctx: Context<Self>, args: AddProgramFilterArgs) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+
+        settings.add_program_filter(args.filter.clone())?;
+
+        // Make sure the settings account can fit the newly added program filter.
+        Settings::realloc_if_needed(
+            settings.to_account_info(),
+            settings,
+            ctx.accounts
+                .fee_payer
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+            ctx.accounts
+                .system_program
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
+        // We don't need to invalidate prior transactions here because the program
+        // filters are checked when executing the transactions.
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: (**settings).clone(),
+            settings_pubkey: settings.key(),
+            authority: ctx.accounts.settings_authority.key(),
+            change: SettingsAction::AddProgramFilter {
+                filter: args.filter,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+
+    /// Remove a filter from the settings `program_filters`.
+    ///
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn remove_program_filter(ctx: Context<Self>, args: RemoveProgramFilterArgs) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+
+        settings.remove_program_filter(&args.filter)?;
+
+        // We don't need to invalidate prior transactions here because the program
+        // filters are checked when executing the transactions.
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: (**settings).clone(),
+            settings_pubkey: settings.key(),
+            authority: ctx.accounts.settings_authority.key(),
+            change: SettingsAction::RemoveProgramFilter {
+                filter: args.filter,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+
+    /// Create or update the `VaultPolicy` of one of the smart accounts.
+    /// `remaining_accounts` must include the `VaultPolicy` account, and `fee_payer` and `system_program`
+    /// must be present to pay for its creation or reallocation.
+    ///
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_vault_policy(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        args: SetVaultPolicyArgs,
+    ) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+        let settings_key = settings.key();
+
+        let change = SettingsAction::SetVaultPolicy {
+            account_index: args.account_index,
+            threshold: args.threshold,
+            time_lock: args.time_lock,
+            signers: args.signers,
+        };
+
+        settings.modify_with_action(
+            &settings_key,
+            &change,
+            &Rent::get()?,
+            ctx.accounts.fee_payer.clone(),
+            &ctx.accounts.system_program,
+            ctx.remaining_accounts,
+            ctx.program_id,
+        )?;
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: (**settings).clone(),
+            settings_pubkey: settings_key,
+            authority: ctx.accounts.settings_authority.key(),
+            change,
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+
+    /// Remove the `VaultPolicy` of one of the smart accounts.
+    /// `remaining_accounts` must include the `VaultPolicy` account, and the settings `rent_collector`
+    /// to receive its rent. `fee_payer` receives it instead if the settings have no `rent_collector`.
+    ///
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn remove_vault_policy(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        args: RemoveVaultPolicyArgs,
+    ) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+        let settings_key = settings.key();
+
+        let change = SettingsAction::RemoveVaultPolicy {
+            account_index: args.account_index,
+        };
+
+        settings.modify_with_action(
+            &settings_key,
+            &change,
+            &Rent::get()?,
+            ctx.accounts.fee_payer.clone(),
+            &ctx.accounts.system_program,
+            ctx.remaining_accounts,
+            ctx.program_id,
+        )?;
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: (**settings).clone(),
+            settings_pubkey: settings_key,
+            authority: ctx.accounts.settings_authority.key(),
+            change,
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
     }
 }
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
 use crate::state::*;
 use crate::TransactionMessage;
 
@@ -71,6 +73,8 @@ pub struct AddTransactionToBatch<'info> {
     pub fee_payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
 }
 
 impl AddTransactionToBatch<'_> {
@@ -112,6 +116,7 @@ impl AddTransactionToBatch<'_> {
     /// Add a transaction to the batch.
     #[access_control(ctx.accounts.validate())]
     pub fn add_transaction_to_batch(ctx: Context<Self>, args: AddTransactionToBatchArgs) -> Result<()> {
+        let settings = &ctx.accounts.settings;
         let batch = &mut ctx.accounts.batch;
         let transaction = &mut ctx.accounts.transaction;
         let fee_payer = &mut ctx.accounts.fee_payer;
@@ -137,10 +142,15 @@ impl AddTransactionToBatch<'_> {
             .collect();
 
         transaction.bump = ctx.bumps.transaction;
-        transaction.rent_collector = fee_payer.key();
+        transaction.rent_collector = settings.rent_collector.unwrap_or(fee_payer.key());
         transaction.ephemeral_signer_bumps = ephemeral_signer_bumps;
         transaction.message = transaction_message.try_into()?; // stubbed
 
+        // The batch is time locked for the longest time lock of its transactions.
+        batch.time_lock = batch
+            .time_lock
+            .max(settings.message_time_lock(&transaction.message));
+
         // Increment the batch size.
         batch.size = batch.size.checked_add(1).expect("overflow");
 
@@ -148,6 +158,28 @@ impl AddTransactionToBatch<'_> {
         msg!("batch index: {}", batch.index);
         msg!("batch size: {}", batch.size);
 
+        // Log the event
+        let event = TransactionEvent {
+            event_type: TransactionEventType::Create,
+            settings_pubkey: settings.key(),
+            transaction_pubkey: transaction.key(),
+            transaction_index: batch.index,
+            signer: Some(ctx.accounts.signer.key()),
+            memo: None,
+            transaction_content: TransactionContent::BatchTransaction {
+                batch_transaction_index: batch.size,
+                outcome: batch.transaction_outcome(batch.size),
+                instructions: InstructionSummary::from_message(&transaction.message),
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+
         Ok(())
     }
 }
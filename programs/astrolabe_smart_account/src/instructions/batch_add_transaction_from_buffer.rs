@@ -0,0 +1,122 @@
+use crate::errors::*;
+use crate::instructions::*;
+use crate::state::*;
+use crate::utils::realloc;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use anchor_lang::solana_program::hash::hash;
+
+#[derive(Accounts)]
+pub struct AddTransactionToBatchFromBuffer<'info> {
+    // The context needed for the AddTransactionToBatch instruction
+    pub add_transaction: AddTransactionToBatch<'info>,
+
+    #[account(
+        mut,
+        close = from_buffer_creator,
+        // Only the creator can turn the buffer into a batch transaction and reclaim
+        // the rent
+        constraint = transaction_buffer.creator == from_buffer_creator.key() @ SmartAccountError::Unauthorized,
+        seeds = [
+            SEED_PREFIX,
+            add_transaction.settings.key().as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            from_buffer_creator.key().as_ref(),
+            &transaction_buffer.buffer_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub transaction_buffer: Box<Account<'info, TransactionBuffer>>,
+
+    // Anchor doesn't allow us to use the signer inside of
+    // add_transaction, so we just re-pass it here with the same constraint
+    #[account(
+        mut,
+        address = add_transaction.signer.key(),
+    )]
+    pub from_buffer_creator: Signer<'info>,
+}
+
+impl<'info> AddTransactionToBatchFromBuffer<'info> {
+    pub fn validate(&self, args: &AddTransactionToBatchArgs) -> Result<()> {
+        let transaction_buffer_account = &self.transaction_buffer;
+
+        // Accept either an empty args.transaction_message, required for buffers
+        // that don't fit in a Solana transaction, or a populated one that matches
+        // the buffer.
+        if !args.transaction_message.is_empty() {
+            // Size must match the declared final size
+            require_eq!(
+                args.transaction_message.len(),
+                transaction_buffer_account.final_buffer_size as usize,
+                SmartAccountError::InvalidInstructionArgs
+            );
+
+            // Accept either (a) all zeros of correct length, or (b) bytes matching the buffer hash
+            let is_all_zero = args.transaction_message.iter().all(|b| *b == 0);
+            if !is_all_zero {
+                let args_hash = hash(&args.transaction_message);
+                require!(
+                    args_hash.to_bytes() == transaction_buffer_account.final_buffer_hash,
+                    SmartAccountError::InvalidInstructionArgs
+                );
+            }
+        }
+
+        // Validate that the final hash matches the buffer
+        transaction_buffer_account.validate_hash()?;
+
+        // Validate that the final size is correct
+        transaction_buffer_account.validate_size()?;
+        Ok(())
+    }
+
+    /// Add a transaction to the batch from a completed transaction buffer account.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn add_transaction_to_batch_from_buffer(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        args: AddTransactionToBatchArgs,
+    ) -> Result<()> {
+        let transaction_buffer = &ctx.accounts.transaction_buffer;
+
+        // The batch transaction account was initialized with an empty transaction message,
+        // reallocate it to fit the message of the buffer.
+        let new_len =
+            BatchTransaction::size(args.ephemeral_signers, transaction_buffer.buffer.as_slice())?;
+
+        // The batch transaction account is created by this instruction, so it can only be grown up to the realloc limit.
+        require!(
+            new_len <= MAX_PERMITTED_DATA_INCREASE,
+            SmartAccountError::TransactionSizeExceeded
+        );
+        realloc(
+            &ctx.accounts.add_transaction.transaction.to_account_info(),
+            new_len,
+            Some(ctx.accounts.add_transaction.fee_payer.to_account_info()),
+            Some(
+                ctx.accounts
+                    .add_transaction
+                    .system_program
+                    .to_account_info(),
+            ),
+        )?;
+
+        // Create the args for the `add_transaction_to_batch` instruction
+        let add_args = AddTransactionToBatchArgs {
+            ephemeral_signers: args.ephemeral_signers,
+            transaction_message: transaction_buffer.buffer.clone(),
+        };
+        // Create the context for the `add_transaction_to_batch` instruction
+        let context = Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.add_transaction,
+            ctx.remaining_accounts,
+            ctx.bumps.add_transaction,
+        );
+
+        // Call the `add_transaction_to_batch` instruction
+        AddTransactionToBatch::add_transaction_to_batch(context, add_args)?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
 use crate::state::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -22,7 +24,7 @@ pub struct CreateBatch<'info> {
     #[account(
         init,
         payer = fee_payer,
-        space = 8 + Batch::INIT_SPACE,
+        space = Batch::size(0),
         seeds = [
             SEED_PREFIX,
             settings.key().as_ref(),
@@ -41,12 +43,20 @@ pub struct CreateBatch<'info> {
     pub fee_payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    pub vault_policy: Option<Account<'info, VaultPolicy>>,
 }
 
 impl CreateBatch<'_> {
-    fn validate(&self) -> Result<()> {
+    fn validate(&self, args: &CreateBatchArgs) -> Result<()> {
         let Self {
-            settings, creator, ..
+            settings,
+            creator,
+            vault_policy,
+            ..
         } = self;
 
         // creator
@@ -59,11 +69,24 @@ impl CreateBatch<'_> {
             SmartAccountError::Unauthorized
         );
 
+        // vault_policy
+        if let Some(vault_policy) = VaultPolicy::load(
+            settings,
+            &settings.key(),
+            args.account_index,
+            vault_policy.as_deref(),
+        )? {
+            require!(
+                vault_policy.is_signer(creator.key()),
+                SmartAccountError::Unauthorized
+            );
+        }
+
         Ok(())
     }
 
     /// Create a new batch.
-    #[access_control(ctx.accounts.validate())]
+    #[access_control(ctx.accounts.validate(&args))]
     pub fn create_batch(ctx: Context<Self>, args: CreateBatchArgs) -> Result<()> {
         let settings = &mut ctx.accounts.settings;
         let creator = &mut ctx.accounts.creator;
@@ -85,13 +108,15 @@ impl CreateBatch<'_> {
 
         batch.settings = settings_key;
         batch.creator = creator.key();
-        batch.rent_collector = fee_payer.key();
+        batch.rent_collector = settings.rent_collector.unwrap_or(fee_payer.key());
         batch.index = index;
         batch.bump = ctx.bumps.batch;
         batch.account_index = args.account_index;
         batch.account_bump = smart_account_bump;
         batch.size = 0;
         batch.executed_transaction_index = 0;
+        batch.time_lock = 0;
+        batch.skipped_transaction_indexes = vec![];
 
         batch.invariant()?;
 
@@ -103,6 +128,27 @@ impl CreateBatch<'_> {
         // Logs for indexing.
         msg!("batch index: {}", index);
 
+        // Log the event
+        let event = TransactionEvent {
+            event_type: TransactionEventType::Create,
+            settings_pubkey: settings_key,
+            transaction_pubkey: batch.key(),
+            transaction_index: index,
+            signer: Some(creator.key()),
+            memo: args.memo,
+            transaction_content: TransactionContent::Batch {
+                account_index: batch.account_index,
+                size: batch.size,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+
         Ok(())
     }
 }
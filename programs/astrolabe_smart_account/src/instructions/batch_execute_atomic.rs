@@ -0,0 +1,284 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct ExecuteBatchAtomic<'info> {
+    /// Settings account this batch belongs to.
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Account<'info, Settings>,
+
+    /// Signer of the settings.
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// The proposal account associated with the batch.
+    /// Its status is set to `Executed` once all the transactions of the batch are executed.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_TRANSACTION,
+            &batch.index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_TRANSACTION,
+            &batch.index.to_le_bytes(),
+        ],
+        bump = batch.bump,
+    )]
+    pub batch: Account<'info, Batch>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+
+    /// The policy of the smart account the batch is for, required if it has one.
+    pub vault_policy: Option<Account<'info, VaultPolicy>>,
+    //
+    // `remaining_accounts` must include the following accounts for each batch transaction
+    // not executed yet, in the order of the batch:
+    // 1. The `BatchTransaction` account.
+    // 2. AddressLookupTable accounts in the order they appear in `message.address_table_lookups`.
+    // 3. Accounts in the order they appear in `message.account_keys`.
+    // 4. Accounts in the order they appear in `message.address_table_lookups`.
+}
+
+impl<'info> ExecuteBatchAtomic<'info> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            settings,
+            signer,
+            proposal,
+            batch,
+            vault_policy,
+            ..
+        } = self;
+
+        let vault_policy = VaultPolicy::load(
+            settings,
+            &settings.key(),
+            batch.account_index,
+            vault_policy.as_deref(),
+        )?;
+
+        // `signer`
+        require!(
+            settings.is_signer(signer.key()).is_some(),
+            SmartAccountError::NotASigner
+        );
+        require!(
+            settings.signer_has_permission(signer.key(), Permission::Execute),
+            SmartAccountError::Unauthorized
+        );
+        if let Some(vault_policy) = vault_policy {
+            require!(
+                vault_policy.is_signer(signer.key()),
+                SmartAccountError::Unauthorized
+            );
+        }
+
+        // `proposal`
+        // Approved proposals can only be executed within the execution window of the settings.
+        require!(
+            !proposal.has_expired(settings.execution_window, Clock::get()?.unix_timestamp),
+            SmartAccountError::ProposalExpired
+        );
+        match proposal.status {
+            ProposalStatus::Approved { timestamp } => match vault_policy {
+                // The vault policy replaces the settings time lock.
+                // The time lock of the programs the batch touches applies on top of either.
+                Some(vault_policy) => vault_policy.validate_execution(
+                    settings,
+                    &proposal.approved,
+                    timestamp,
+                    batch.time_lock,
+                )?,
+                None => require!(
+                    Clock::get()?.unix_timestamp - timestamp
+                        >= i64::from(settings.time_lock.max(batch.time_lock)),
+                    SmartAccountError::TimeLockNotReleased
+                ),
+            },
+            _ => return err!(SmartAccountError::InvalidProposalStatus),
+        };
+        // Stale batch transaction proposals CAN be executed if they were approved
+        // before becoming stale, hence no check for staleness here.
+
+        // `batch` is validated by its seeds.
+
+        Ok(())
+    }
+
+    /// Execute all the remaining transactions of the batch within one instruction,
+    /// so either all of them succeed or none of them do.
+    ///
+    /// NOTE: All the transactions must fit the compute budget and the account limits of one Solana transaction,
+    ///       bigger batches should be executed with `execute_batch_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn execute_batch_atomic(ctx: Context<'_, '_, 'info, 'info, Self>) -> Result<()> {
+        let settings = &ctx.accounts.settings;
+        let proposal = &mut ctx.accounts.proposal;
+        let batch = &mut ctx.accounts.batch;
+
+        let settings_key = settings.key();
+        let batch_key = batch.key();
+        let fee_payer_info = ctx.accounts.fee_payer.to_account_info();
+
+        let smart_account_seeds = &[
+            SEED_PREFIX,
+            settings_key.as_ref(),
+            SEED_SMART_ACCOUNT,
+            &batch.account_index.to_le_bytes(),
+            &[batch.account_bump],
+        ];
+        let smart_account_pubkey =
+            Pubkey::create_program_address(smart_account_seeds, ctx.program_id).unwrap();
+
+        let protected_accounts = &[proposal.key(), batch_key];
+
+        let signer = ctx.accounts.signer.key();
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+
+        let mut remaining_accounts = ctx.remaining_accounts;
+
+        while batch.executed_transaction_index < batch.size {
+            let transaction_index = batch.executed_transaction_index.checked_add(1).unwrap();
+
+            let (transaction_info, message_infos) = remaining_accounts
+                .split_first()
+                .ok_or(SmartAccountError::InvalidNumberOfAccounts)?;
+
+            let transaction = Account::<BatchTransaction>::try_from(transaction_info)?;
+            // The batch transaction must be the next one to execute.
+            let transaction_pda = Pubkey::create_program_address(
+                &[
+                    SEED_PREFIX,
+                    settings_key.as_ref(),
+                    SEED_TRANSACTION,
+                    &batch.index.to_le_bytes(),
+                    SEED_BATCH_TRANSACTION,
+                    &transaction_index.to_le_bytes(),
+                    &[transaction.bump],
+                ],
+                ctx.program_id,
+            )
+            .map_err(|_| SmartAccountError::InvalidAccount)?;
+            require_keys_eq!(
+                transaction_pda,
+                transaction_info.key(),
+                SmartAccountError::InvalidAccount
+            );
+            let transaction = transaction.into_inner();
+
+            let transaction_message = transaction.message;
+            let num_lookups = transaction_message.address_table_lookups.len();
+            let instructions = InstructionSummary::from_message(&transaction_message);
+            // The fee payer is passed in the accounts of the instruction rather than the accounts of the message.
+            let has_fee_payer = transaction_message
+                .account_keys
+                .contains(fee_payer_info.key);
+            let num_message_accounts =
+                transaction_message.num_all_account_keys() - usize::from(has_fee_payer);
+
+            let num_transaction_accounts = num_lookups + num_message_accounts;
+            require!(
+                message_infos.len() >= num_transaction_accounts,
+                SmartAccountError::InvalidNumberOfAccounts
+            );
+            let (transaction_infos, next_remaining_accounts) =
+                message_infos.split_at(num_transaction_accounts);
+            let (address_lookup_table_account_infos, message_account_infos) =
+                transaction_infos.split_at(num_lookups);
+
+            let (ephemeral_signer_keys, ephemeral_signer_seeds) =
+                derive_ephemeral_signers(batch_key, &transaction.ephemeral_signer_bumps);
+
+            let executable_message = ExecutableTransactionMessage::new_validated(
+                transaction_message,
+                message_account_infos,
+                address_lookup_table_account_infos,
+                &smart_account_pubkey,
+                &ephemeral_signer_keys,
+                Some(&fee_payer_info),
+            )?;
+
+            // Execute the transaction message instructions one-by-one.
+            executable_message.execute_message(
+                smart_account_seeds,
+                &ephemeral_signer_seeds,
+                protected_accounts,
+                settings,
+            )?;
+
+            batch.executed_transaction_index = transaction_index;
+            remaining_accounts = next_remaining_accounts;
+
+            // Log the event
+            let event = TransactionEvent {
+                event_type: TransactionEventType::Execute,
+                settings_pubkey: settings_key,
+                transaction_pubkey: transaction_info.key(),
+                transaction_index: batch.index,
+                signer: Some(signer),
+                memo: None,
+                transaction_content: TransactionContent::BatchTransaction {
+                    batch_transaction_index: transaction_index,
+                    outcome: batch.transaction_outcome(transaction_index),
+                    instructions,
+                },
+            };
+            SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+        }
+
+        // All the remaining accounts must belong to the executed transactions.
+        require!(
+            remaining_accounts.is_empty(),
+            SmartAccountError::InvalidNumberOfAccounts
+        );
+
+        // All the transactions of the batch were executed.
+        proposal.status = ProposalStatus::Executed {
+            timestamp: Clock::get()?.unix_timestamp,
+        };
+
+        batch.invariant()?;
+
+        // Log the event
+        let event = ProposalEvent::new(
+            ProposalEventType::Execute,
+            settings,
+            proposal,
+            ctx.accounts.vault_policy.as_deref(),
+            Some(signer),
+            None,
+        );
+        SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)?;
+
+        Ok(())
+    }
+}
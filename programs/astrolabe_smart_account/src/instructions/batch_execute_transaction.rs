@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
 use crate::state::*;
 use crate::utils::*;
 
@@ -61,6 +63,11 @@ pub struct ExecuteBatchTransaction<'info> {
         bump = transaction.bump,
     )]
     pub transaction: Account<'info, BatchTransaction>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+
+    /// The policy of the smart account the batch is for, required if it has one.
+    pub vault_policy: Option<Account<'info, VaultPolicy>>,
     //
     // `remaining_accounts` must include the following accounts in the exact order:
     // 1. AddressLookupTable accounts in the order they appear in `message.address_table_lookups`.
@@ -74,9 +81,18 @@ impl<'info> ExecuteBatchTransaction<'info> {
             settings,
             signer,
             proposal,
+            batch,
+            vault_policy,
             ..
         } = self;
 
+        let vault_policy = VaultPolicy::load(
+            settings,
+            &settings.key(),
+            batch.account_index,
+            vault_policy.as_deref(),
+        )?;
+
         // `signer`
         require!(
             settings.is_signer(signer.key()).is_some(),
@@ -86,15 +102,35 @@ impl<'info> ExecuteBatchTransaction<'info> {
             settings.signer_has_permission(signer.key(), Permission::Execute),
             SmartAccountError::Unauthorized
         );
+        if let Some(vault_policy) = vault_policy {
+            require!(
+                vault_policy.is_signer(signer.key()),
+                SmartAccountError::Unauthorized
+            );
+        }
 
         // `proposal`
+        // Approved proposals can only be executed within the execution window of the settings.
+        require!(
+            !proposal.has_expired(settings.execution_window, Clock::get()?.unix_timestamp),
+            SmartAccountError::ProposalExpired
+        );
         match proposal.status {
-            ProposalStatus::Approved { timestamp } => {
-                require!(
-                    Clock::get()?.unix_timestamp - timestamp >= i64::from(settings.time_lock),
+            ProposalStatus::Approved { timestamp } => match vault_policy {
+                // The vault policy replaces the settings time lock.
+                // The time lock of the programs the batch touches applies on top of either.
+                Some(vault_policy) => vault_policy.validate_execution(
+                    settings,
+                    &proposal.approved,
+                    timestamp,
+                    batch.time_lock,
+                )?,
+                None => require!(
+                    Clock::get()?.unix_timestamp - timestamp
+                        >= i64::from(settings.time_lock.max(batch.time_lock)),
                     SmartAccountError::TimeLockNotReleased
-                );
-            }
+                ),
+            },
             _ => return err!(SmartAccountError::InvalidProposalStatus),
         };
         // Stale batch transaction proposals CAN be executed if they were approved
@@ -132,7 +168,8 @@ impl<'info> ExecuteBatchTransaction<'info> {
         ];
 
         let transaction_message = transaction.message;
-        let num_lookups = 0; // stubbed
+        let num_lookups = transaction_message.address_table_lookups.len();
+        let instructions = InstructionSummary::from_message(&transaction_message);
 
         let message_account_infos = ctx
             .remaining_accounts
@@ -171,6 +208,7 @@ impl<'info> ExecuteBatchTransaction<'info> {
             smart_account_seeds,
             &ephemeral_signer_seeds,
             protected_accounts,
+            settings,
         )?;
 
         // Increment the executed transaction index.
@@ -188,6 +226,40 @@ impl<'info> ExecuteBatchTransaction<'info> {
 
         batch.invariant()?;
 
+        // Log the events
+        let signer = ctx.accounts.signer.key();
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        let event = TransactionEvent {
+            event_type: TransactionEventType::Execute,
+            settings_pubkey: settings_key,
+            transaction_pubkey: ctx.accounts.transaction.key(),
+            transaction_index: batch.index,
+            signer: Some(signer),
+            memo: None,
+            transaction_content: TransactionContent::BatchTransaction {
+                batch_transaction_index: batch.executed_transaction_index,
+                outcome: batch.transaction_outcome(batch.executed_transaction_index),
+                instructions,
+            },
+        };
+        SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+        if batch.executed_transaction_index == batch.size {
+            let event = ProposalEvent::new(
+                ProposalEventType::Execute,
+                settings,
+                proposal,
+                ctx.accounts.vault_policy.as_deref(),
+                Some(signer),
+                None,
+            );
+            SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SkipBatchTransactionArgs {
+    /// The number of signers voting to skip the transaction, to reach the threshold.
+    pub num_signers: u8,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct SkipBatchTransaction<'info> {
+    /// Settings account this batch belongs to.
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Account<'info, Settings>,
+
+    /// The proposal account associated with the batch.
+    /// If the skipped transaction is the last in the batch, the `proposal` status will be set to `Executed`.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_TRANSACTION,
+            &batch.index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_TRANSACTION,
+            &batch.index.to_le_bytes(),
+        ],
+        bump = batch.bump,
+    )]
+    pub batch: Account<'info, Batch>,
+
+    /// The account that pays for the reallocation of the `batch` to record the skipped transaction.
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+
+    /// The policy of the smart account the batch is for, required if it has one.
+    pub vault_policy: Option<Account<'info, VaultPolicy>>,
+    // `remaining_accounts` must include the following accounts in the exact order:
+    // 1. The amount of signers specified in `num_signers`
+    //    Passkey signers are passed as their writable `Passkey` accounts.
+    // 2. The instructions sysvar if any of the signers is a passkey
+    // 3. The `SessionKey` accounts of any signers that are session keys
+}
+
+impl SkipBatchTransaction<'_> {
+    fn validate(
+        &self,
+        args: &SkipBatchTransactionArgs,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        let Self {
+            settings,
+            proposal,
+            batch,
+            vault_policy,
+            ..
+        } = self;
+
+        let vault_policy = VaultPolicy::load(
+            settings,
+            &settings.key(),
+            batch.account_index,
+            vault_policy.as_deref(),
+        )?;
+
+        // `proposal`
+        // Only approved batches that can still be executed have transactions to skip.
+        require!(
+            !proposal.has_expired(settings.execution_window, Clock::get()?.unix_timestamp),
+            SmartAccountError::ProposalExpired
+        );
+        require!(
+            matches!(proposal.status, ProposalStatus::Approved { .. }),
+            SmartAccountError::InvalidProposalStatus
+        );
+
+        // `batch`
+        require!(
+            batch.executed_transaction_index < batch.size,
+            SmartAccountError::InvalidTransactionIndex
+        );
+
+        // Skipping doesn't execute anything, so the voting signers don't need to wait for the time lock,
+        // but they must reach the threshold on their own.
+        let (_, voting_weight) = validate_consensus_signers(
+            settings,
+            args.num_signers,
+            remaining_accounts,
+            vault_policy,
+        )?;
+        let threshold = vault_policy.map_or(settings.threshold, |v| v.threshold);
+        require!(
+            voting_weight >= usize::from(threshold),
+            SmartAccountError::InsufficientVotePermissions
+        );
+
+        Ok(())
+    }
+
+    /// Skip the next transaction of the batch instead of executing it, so the later ones can be executed.
+    /// The signers must vote for the skip with enough voting weight to reach the threshold.
+    #[access_control(ctx.accounts.validate(&args, &ctx.remaining_accounts))]
+    pub fn skip_batch_transaction(
+        ctx: Context<Self>,
+        args: SkipBatchTransactionArgs,
+    ) -> Result<()> {
+        let settings = &ctx.accounts.settings;
+        let proposal = &mut ctx.accounts.proposal;
+        let batch = &mut ctx.accounts.batch;
+
+        let transaction_index = batch.executed_transaction_index.checked_add(1).unwrap();
+
+        batch.skipped_transaction_indexes.push(transaction_index);
+        batch.executed_transaction_index = transaction_index;
+
+        // Make sure the batch can fit the skipped transaction index.
+        realloc(
+            &batch.to_account_info(),
+            Batch::size(batch.skipped_transaction_indexes.len()),
+            Some(ctx.accounts.fee_payer.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+
+        // If this is the last transaction in the batch, set the proposal status to `Executed`.
+        if batch.executed_transaction_index == batch.size {
+            proposal.status = ProposalStatus::Executed {
+                timestamp: Clock::get()?.unix_timestamp,
+            };
+        }
+
+        batch.invariant()?;
+
+        // Log the events
+        let settings_key = settings.key();
+        let (transaction_pubkey, _) = Pubkey::find_program_address(
+            &[
+                SEED_PREFIX,
+                settings_key.as_ref(),
+                SEED_TRANSACTION,
+                &batch.index.to_le_bytes(),
+                SEED_BATCH_TRANSACTION,
+                &transaction_index.to_le_bytes(),
+            ],
+            ctx.program_id,
+        );
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        let event = TransactionEvent {
+            event_type: TransactionEventType::Skip,
+            settings_pubkey: settings_key,
+            transaction_pubkey,
+            transaction_index: batch.index,
+            signer: None,
+            memo: args.memo,
+            // The skipped transaction isn't passed to the instruction.
+            transaction_content: TransactionContent::BatchTransaction {
+                batch_transaction_index: transaction_index,
+                outcome: batch.transaction_outcome(transaction_index),
+                instructions: vec![],
+            },
+        };
+        SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+        if batch.executed_transaction_index == batch.size {
+            let event = ProposalEvent::new(
+                ProposalEventType::Execute,
+                settings,
+                proposal,
+                ctx.accounts.vault_policy.as_deref(),
+                None,
+                None,
+            );
+            SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)?;
+        }
+
+        Ok(())
+    }
+}
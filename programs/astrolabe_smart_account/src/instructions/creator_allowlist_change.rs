@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SmartAccountError;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreatorAllowlistChangeArgs {
+    pub creator: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct ChangeCreatorAllowlist<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_PROGRAM_CONFIG],
+        bump,
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_CREATOR_ALLOWLIST, &creator_allowlist.page.to_le_bytes()],
+        bump = creator_allowlist.bump,
+    )]
+    pub creator_allowlist: Account<'info, CreatorAllowlistPage>,
+
+    pub authority: Signer<'info>,
+}
+
+impl ChangeCreatorAllowlist<'_> {
+    fn validate(&self) -> Result<()> {
+        // authority
+        require_keys_eq!(
+            self.program_config.authority,
+            self.authority.key(),
+            SmartAccountError::Unauthorized
+        );
+
+        Ok(())
+    }
+
+    /// Allow `creator` to create smart accounts when the creation mode is `Allowlist`.
+    #[access_control(ctx.accounts.validate())]
+    pub fn add_creator(ctx: Context<Self>, args: CreatorAllowlistChangeArgs) -> Result<()> {
+        ctx.accounts.creator_allowlist.add_creator(args.creator)
+    }
+
+    /// Remove `creator` from the allowlist page.
+    #[access_control(ctx.accounts.validate())]
+    pub fn remove_creator(ctx: Context<Self>, args: CreatorAllowlistChangeArgs) -> Result<()> {
+        ctx.accounts.creator_allowlist.remove_creator(&args.creator)
+    }
+}
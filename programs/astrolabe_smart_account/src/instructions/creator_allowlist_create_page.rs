@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SmartAccountError;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateCreatorAllowlistPageArgs {
+    /// The index of the new page.
+    pub page: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args: CreateCreatorAllowlistPageArgs)]
+pub struct CreateCreatorAllowlistPage<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_PROGRAM_CONFIG],
+        bump,
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = CreatorAllowlistPage::size(),
+        seeds = [SEED_PREFIX, SEED_CREATOR_ALLOWLIST, &args.page.to_le_bytes()],
+        bump
+    )]
+    pub creator_allowlist: Account<'info, CreatorAllowlistPage>,
+
+    pub authority: Signer<'info>,
+
+    /// The payer for the page account rent.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl CreateCreatorAllowlistPage<'_> {
+    fn validate(&self) -> Result<()> {
+        // authority
+        require_keys_eq!(
+            self.program_config.authority,
+            self.authority.key(),
+            SmartAccountError::Unauthorized
+        );
+
+        Ok(())
+    }
+
+    /// Create an empty page of the smart account creation allowlist.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_creator_allowlist_page(
+        ctx: Context<Self>,
+        args: CreateCreatorAllowlistPageArgs,
+    ) -> Result<()> {
+        let creator_allowlist = &mut ctx.accounts.creator_allowlist;
+
+        creator_allowlist.page = args.page;
+        creator_allowlist.bump = ctx.bumps.creator_allowlist;
+        creator_allowlist.creators = vec![];
+
+        Ok(())
+    }
+}
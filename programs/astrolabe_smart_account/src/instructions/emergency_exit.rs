@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::{
+    errors::*, program::AstrolabeSmartAccount, state::*, EmergencyExitEvent,
+    EmergencyExitTokenTransfer, LogAuthorityInfo, SmartAccountEvent,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EmergencyExitArgs {
+    /// The index of the smart account to sweep the funds from.
+    pub account_index: u8,
+    /// Memo used for indexing.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: EmergencyExitArgs)]
+pub struct RestrictedEmergencyExit<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    /// Restricted signer with the `EmergencyExit` permission.
+    pub restricted_signer: Signer<'info>,
+
+    /// Smart account to sweep the funds from.
+    /// CHECK: All the required checks are done by checking the seeds.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SMART_ACCOUNT,
+            &args.account_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub smart_account: AccountInfo<'info>,
+
+    /// The exit destination pre-registered for `restricted_signer`.
+    /// CHECK: We do the checks in `RestrictedEmergencyExit::validate`.
+    #[account(mut)]
+    pub exit_destination: AccountInfo<'info>,
+
+    /// In case any SPL token balances are swept.
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+    // `remaining_accounts` must contain a `[mint, smart_account_token_account, exit_destination_token_account]`
+    // triplet for every SPL token balance to sweep.
+}
+
+impl RestrictedEmergencyExit<'_> {
+    fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        let Self {
+            settings,
+            restricted_signer,
+            exit_destination,
+            token_program,
+            ..
+        } = self;
+
+        // restricted_signer
+        require!(
+            settings.restricted_signer_has_permission(
+                restricted_signer.key(),
+                RestrictedPermission::EmergencyExit
+            ),
+            SmartAccountError::UnauthorizedRestrictedSigner
+        );
+
+        // exit_destination
+        // `is_restricted_signer` is `Some` as the permission check above passed.
+        let restricted_signer_index = settings
+            .is_restricted_signer(restricted_signer.key())
+            .unwrap();
+        require_keys_eq!(
+            exit_destination.key(),
+            settings.restricted_signers[restricted_signer_index].exit_destination,
+            SmartAccountError::InvalidDestination
+        );
+
+        // remaining_accounts
+        require!(
+            remaining_accounts.len() % 3 == 0,
+            SmartAccountError::InvalidNumberOfAccounts
+        );
+        if !remaining_accounts.is_empty() {
+            require!(token_program.is_some(), SmartAccountError::MissingAccount);
+        }
+
+        Ok(())
+    }
+
+    /// Sweep all SOL and the given SPL token balances of a smart account
+    /// to the exit destination pre-registered for the restricted signer.
+    #[access_control(ctx.accounts.validate(ctx.remaining_accounts))]
+    pub fn emergency_exit<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RestrictedEmergencyExit<'info>>,
+        args: EmergencyExitArgs,
+    ) -> Result<()> {
+        let smart_account = &ctx.accounts.smart_account;
+        let exit_destination = &ctx.accounts.exit_destination;
+
+        let settings_key = ctx.accounts.settings.key();
+        let smart_account_signer_seeds: &[&[u8]] = &[
+            SEED_PREFIX,
+            settings_key.as_ref(),
+            SEED_SMART_ACCOUNT,
+            &args.account_index.to_le_bytes(),
+            &[ctx.bumps.smart_account],
+        ];
+
+        // Sweep the SPL token balances.
+        let mut token_transfers = Vec::with_capacity(ctx.remaining_accounts.len() / 3);
+        for accounts in ctx.remaining_accounts.chunks(3) {
+            let mint = Account::<Mint>::try_from(&accounts[0])?;
+            let smart_account_token_account = Account::<TokenAccount>::try_from(&accounts[1])?;
+            let exit_destination_token_account = Account::<TokenAccount>::try_from(&accounts[2])?;
+
+            require_keys_eq!(
+                smart_account_token_account.mint,
+                mint.key(),
+                SmartAccountError::InvalidMint
+            );
+            require_keys_eq!(
+                smart_account_token_account.owner,
+                smart_account.key(),
+                SmartAccountError::InvalidAccount
+            );
+            require_keys_eq!(
+                exit_destination_token_account.mint,
+                mint.key(),
+                SmartAccountError::InvalidMint
+            );
+            require_keys_eq!(
+                exit_destination_token_account.owner,
+                exit_destination.key(),
+                SmartAccountError::InvalidDestination
+            );
+
+            let amount = smart_account_token_account.amount;
+            if amount == 0 {
+                continue;
+            }
+
+            // Checked in `validate`.
+            let token_program = ctx.accounts.token_program.as_ref().unwrap();
+
+            token::transfer_checked(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token::TransferChecked {
+                        from: accounts[1].clone(),
+                        mint: accounts[0].clone(),
+                        to: accounts[2].clone(),
+                        authority: smart_account.clone(),
+                    },
+                    &[smart_account_signer_seeds],
+                ),
+                amount,
+                mint.decimals,
+            )?;
+
+            token_transfers.push(EmergencyExitTokenTransfer {
+                mint: mint.key(),
+                amount,
+            });
+        }
+
+        // Sweep the SOL balance.
+        let lamports = smart_account.lamports();
+        if lamports > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: smart_account.clone(),
+                        to: exit_destination.clone(),
+                    },
+                    &[smart_account_signer_seeds],
+                ),
+                lamports,
+            )?;
+        }
+
+        // Log the event
+        let event = EmergencyExitEvent {
+            settings_pubkey: settings_key,
+            restricted_signer: ctx.accounts.restricted_signer.key(),
+            account_index: args.account_index,
+            smart_account: smart_account.key(),
+            exit_destination: exit_destination.key(),
+            lamports,
+            token_transfers,
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: ctx.accounts.settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(ctx.accounts.settings.seed),
+            bump: ctx.accounts.settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::EmergencyExitEvent(event).log(&log_authority_info)?;
+
+        Ok(())
+    }
+}
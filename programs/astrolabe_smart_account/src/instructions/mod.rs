@@ -1,21 +1,46 @@
 pub use activate_proposal::*;
 pub use batch_add_transaction::*;
+pub use batch_add_transaction_from_buffer::*;
 pub use batch_create::*;
+pub use batch_execute_atomic::*;
 pub use batch_execute_transaction::*;
+pub use batch_skip_transaction::*;
+pub use creator_allowlist_change::*;
+pub use creator_allowlist_create_page::*;
 pub use proposal_create::*;
+pub use proposal_expire::*;
 pub use settings_transaction_create::*;
+pub use settings_transaction_create_from_buffer::*;
 pub use smart_account_create::*;
 pub use transaction_create::*;
 pub use settings_transaction_execute::*;
 pub use transaction_execute::*;
+pub use passkey_create::*;
 pub use program_config_init::*;
 pub use authority_settings_transaction_execute::*;
 pub use program_config_change::*;
+pub use program_config_transfer_accept::*;
+pub use program_config_transfer_cancel::*;
+pub use program_config_transfer_propose::*;
 pub use proposal_vote::*;
-pub use restricted_transaction_create::*;
+pub use recovery_approve::*;
+pub use recovery_create::*;
+pub use recovery_finalize::*;
+pub use recovery_veto::*;
+pub use emergency_exit::*;
+pub use session_key_create::*;
+pub use session_key_revoke::*;
+pub use settings_archive::*;
+pub use settings_migrate::*;
 pub use settings_transaction_sync::*;
+pub use settings_unarchive::*;
+pub use signer_rotation_cancel::*;
+pub use signer_rotation_finalize::*;
+pub use signer_rotation_request::*;
+pub use spending_limit_use::*;
 pub use transaction_close::*;
 pub use transaction_buffer_close::*;
+pub use transaction_buffer_close_abandoned::*;
 pub use transaction_buffer_create::*;
 pub use transaction_buffer_extend::*;
 pub use transaction_create_from_buffer::*;
@@ -24,22 +49,47 @@ pub use log_event::*;
 
 mod activate_proposal;
 mod batch_add_transaction;
+mod batch_add_transaction_from_buffer;
 mod batch_create;
+mod batch_execute_atomic;
 mod batch_execute_transaction;
+mod batch_skip_transaction;
+mod creator_allowlist_change;
+mod creator_allowlist_create_page;
 mod proposal_create;
+mod proposal_expire;
 mod settings_transaction_create;
+mod settings_transaction_create_from_buffer;
 mod smart_account_create;
 mod transaction_create;
 mod settings_transaction_execute;
 mod transaction_execute;
+mod passkey_create;
 mod program_config_init;
 mod authority_settings_transaction_execute;
 mod program_config_change;
+mod program_config_transfer_accept;
+mod program_config_transfer_cancel;
+mod program_config_transfer_propose;
 mod proposal_vote;
-mod restricted_transaction_create;
+mod recovery_approve;
+mod recovery_create;
+mod recovery_finalize;
+mod recovery_veto;
+mod emergency_exit;
+mod session_key_create;
+mod session_key_revoke;
+mod settings_archive;
+mod settings_migrate;
 mod settings_transaction_sync;
+mod settings_unarchive;
+mod signer_rotation_cancel;
+mod signer_rotation_finalize;
+mod signer_rotation_request;
+mod spending_limit_use;
 mod transaction_close;
 mod transaction_buffer_close;
+mod transaction_buffer_close_abandoned;
 mod transaction_buffer_create;
 mod transaction_buffer_extend;
 mod transaction_create_from_buffer;
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreatePasskeyArgs {
+    /// Compressed secp256r1 public key of the passkey.
+    pub public_key: [u8; 33],
+    /// SHA-256 hash of the WebAuthn relying party ID the passkey is registered for.
+    pub rp_id_hash: [u8; 32],
+}
+
+#[derive(Accounts)]
+#[instruction(args: CreatePasskeyArgs)]
+pub struct CreatePasskey<'info> {
+    #[account(
+        init,
+        payer = fee_payer,
+        space = 8 + Passkey::INIT_SPACE,
+        seeds = [
+            SEED_PREFIX,
+            SEED_PASSKEY,
+            &args.public_key[..1],
+            &args.public_key[1..],
+            args.rp_id_hash.as_ref(),
+        ],
+        bump
+    )]
+    pub passkey: Account<'info, Passkey>,
+
+    /// The payer for the passkey account rent.
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl CreatePasskey<'_> {
+    fn validate(&self, args: &CreatePasskeyArgs) -> Result<()> {
+        // public_key
+        require!(
+            matches!(args.public_key[0], 0x02 | 0x03),
+            SmartAccountError::InvalidPasskeyPublicKey
+        );
+
+        Ok(())
+    }
+
+    /// Create a `Passkey` account that can be added to smart accounts as a signer.
+    /// The account is fully determined by its seeds, so anyone can create it.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn create_passkey(ctx: Context<Self>, args: CreatePasskeyArgs) -> Result<()> {
+        let passkey = &mut ctx.accounts.passkey;
+
+        passkey.public_key = args.public_key;
+        passkey.rp_id_hash = args.rp_id_hash;
+        passkey.nonce = 0;
+        passkey.bump = ctx.bumps.passkey;
+
+        Ok(())
+    }
+}
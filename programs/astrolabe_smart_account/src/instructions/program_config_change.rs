@@ -4,18 +4,24 @@ use crate::errors::SmartAccountError;
 use crate::state::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
-pub struct ProgramConfigSetAuthorityArgs {
-    pub new_authority: Pubkey,
+pub struct ProgramConfigSetSmartAccountCreationFeeArgs {
+    pub new_smart_account_creation_fee: u64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
-pub struct ProgramConfigSetSmartAccountCreationFeeArgs {
-    pub new_smart_account_creation_fee: u64,
+pub struct ProgramConfigSetCreationTokenFeeArgs {
+    pub new_creation_fee_mint: Pubkey,
+    pub new_smart_account_creation_token_fee: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProgramConfigSetReferralFeeArgs {
+    pub new_referral_fee_bps: u16,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
-pub struct ProgramConfigSetTreasuryArgs {
-    pub new_treasury: Pubkey,
+pub struct ProgramConfigSetCreationModeArgs {
+    pub new_smart_account_creation_mode: SmartAccountCreationMode,
 }
 
 #[derive(Accounts)]
@@ -48,13 +54,13 @@ impl ProgramConfig<'_> {
     }
 
     #[access_control(ctx.accounts.validate())]
-    pub fn set_authority(
+    pub fn set_smart_account_creation_fee(
         ctx: Context<Self>,
-        args: ProgramConfigSetAuthorityArgs,
+        args: ProgramConfigSetSmartAccountCreationFeeArgs,
     ) -> Result<()> {
         let program_config = &mut ctx.accounts.program_config;
 
-        program_config.authority = args.new_authority;
+        program_config.smart_account_creation_fee = args.new_smart_account_creation_fee;
 
         program_config.invariant()?;
 
@@ -62,13 +68,28 @@ impl ProgramConfig<'_> {
     }
 
     #[access_control(ctx.accounts.validate())]
-    pub fn set_smart_account_creation_fee(
+    pub fn set_creation_token_fee(
         ctx: Context<Self>,
-        args: ProgramConfigSetSmartAccountCreationFeeArgs,
+        args: ProgramConfigSetCreationTokenFeeArgs,
     ) -> Result<()> {
         let program_config = &mut ctx.accounts.program_config;
 
-        program_config.smart_account_creation_fee = args.new_smart_account_creation_fee;
+        program_config.creation_fee_mint = args.new_creation_fee_mint;
+        program_config.smart_account_creation_token_fee = args.new_smart_account_creation_token_fee;
+
+        program_config.invariant()?;
+
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_referral_fee(
+        ctx: Context<Self>,
+        args: ProgramConfigSetReferralFeeArgs,
+    ) -> Result<()> {
+        let program_config = &mut ctx.accounts.program_config;
+
+        program_config.referral_fee_bps = args.new_referral_fee_bps;
 
         program_config.invariant()?;
 
@@ -76,13 +97,13 @@ impl ProgramConfig<'_> {
     }
 
     #[access_control(ctx.accounts.validate())]
-    pub fn set_treasury(
+    pub fn set_creation_mode(
         ctx: Context<Self>,
-        args: ProgramConfigSetTreasuryArgs,
+        args: ProgramConfigSetCreationModeArgs,
     ) -> Result<()> {
         let program_config = &mut ctx.accounts.program_config;
 
-        program_config.treasury = args.new_treasury;
+        program_config.smart_account_creation_mode = args.new_smart_account_creation_mode;
 
         program_config.invariant()?;
 
@@ -54,6 +54,10 @@ impl InitProgramConfig<'_> {
         program_config.authority = args.authority;
         program_config.smart_account_creation_fee = args.smart_account_creation_fee;
         program_config.treasury = args.treasury;
+        program_config.creation_fee_mint = Pubkey::default();
+        program_config.smart_account_creation_token_fee = 0;
+        program_config.referral_fee_bps = 0;
+        program_config.smart_account_creation_mode = SmartAccountCreationMode::Open;
         program_config.smart_account_index = 0;
 
         program_config.invariant()?;
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SmartAccountError;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct AcceptProgramConfigTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_PROGRAM_CONFIG],
+        bump,
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_PROGRAM_CONFIG, SEED_PROGRAM_CONFIG_TRANSFER],
+        bump = program_config_transfer.bump,
+        close = rent_collector,
+    )]
+    pub program_config_transfer: Account<'info, ProgramConfigTransfer>,
+
+    /// The proposed `authority` or `treasury`.
+    #[account(
+        address = program_config_transfer.new_key @ SmartAccountError::Unauthorized,
+    )]
+    pub new_key: Signer<'info>,
+
+    /// The rent collector.
+    /// CHECK: We only need to validate the address.
+    #[account(
+        mut,
+        address = program_config_transfer.rent_collector @ SmartAccountError::InvalidRentCollector,
+    )]
+    pub rent_collector: AccountInfo<'info>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+}
+
+impl AcceptProgramConfigTransfer<'_> {
+    fn validate(&self, kind: ProgramConfigTransferKind) -> Result<()> {
+        let Self {
+            program_config,
+            program_config_transfer,
+            ..
+        } = self;
+
+        // program_config_transfer
+        require!(
+            program_config_transfer.kind == kind,
+            SmartAccountError::ProgramConfigTransferKindMismatch
+        );
+        require_keys_eq!(
+            program_config_transfer.proposed_by,
+            program_config.authority,
+            SmartAccountError::ProgramConfigTransferStale
+        );
+
+        Ok(())
+    }
+
+    /// Accept the transfer of the program config `authority` as the proposed authority.
+    #[access_control(ctx.accounts.validate(ProgramConfigTransferKind::Authority))]
+    pub fn accept_authority(ctx: Context<Self>) -> Result<()> {
+        let old_authority = ctx.accounts.program_config.authority;
+        ctx.accounts.program_config.authority = ctx.accounts.new_key.key();
+        Self::accept(ctx, ProgramConfigTransferKind::Authority, old_authority)
+    }
+
+    /// Accept the transfer of the program config `treasury` as the proposed treasury.
+    #[access_control(ctx.accounts.validate(ProgramConfigTransferKind::Treasury))]
+    pub fn accept_treasury(ctx: Context<Self>) -> Result<()> {
+        let old_treasury = ctx.accounts.program_config.treasury;
+        ctx.accounts.program_config.treasury = ctx.accounts.new_key.key();
+        Self::accept(ctx, ProgramConfigTransferKind::Treasury, old_treasury)
+    }
+
+    fn accept(ctx: Context<Self>, kind: ProgramConfigTransferKind, old_key: Pubkey) -> Result<()> {
+        ctx.accounts.program_config.invariant()?;
+
+        // Anchor will close the `program_config_transfer` account for us.
+        let event = ProgramConfigEvent {
+            event_type: ProgramConfigEventType::AcceptTransfer,
+            program_config_pubkey: ctx.accounts.program_config.key(),
+            transfer_kind: kind,
+            old_key,
+            new_key: ctx.accounts.new_key.key(),
+            signer: ctx.accounts.new_key.key(),
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: ctx.accounts.program_config.to_account_info(),
+            authority_seeds: get_program_config_signer_seeds(),
+            bump: ctx.bumps.program_config,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::ProgramConfigEvent(event).log(&log_authority_info)?;
+
+        Ok(())
+    }
+}
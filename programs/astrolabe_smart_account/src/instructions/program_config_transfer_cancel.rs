@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SmartAccountError;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CancelProgramConfigTransfer<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_PROGRAM_CONFIG],
+        bump,
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_PROGRAM_CONFIG, SEED_PROGRAM_CONFIG_TRANSFER],
+        bump = program_config_transfer.bump,
+        close = rent_collector,
+    )]
+    pub program_config_transfer: Account<'info, ProgramConfigTransfer>,
+
+    /// The program config authority, or the proposed key declining the transfer.
+    pub signer: Signer<'info>,
+
+    /// The rent collector.
+    /// CHECK: We only need to validate the address.
+    #[account(
+        mut,
+        address = program_config_transfer.rent_collector @ SmartAccountError::InvalidRentCollector,
+    )]
+    pub rent_collector: AccountInfo<'info>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+}
+
+impl CancelProgramConfigTransfer<'_> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            program_config,
+            program_config_transfer,
+            signer,
+            ..
+        } = self;
+
+        // signer
+        require!(
+            signer.key() == program_config.authority
+                || signer.key() == program_config_transfer.new_key,
+            SmartAccountError::Unauthorized
+        );
+
+        Ok(())
+    }
+
+    /// Cancel the pending program config transfer. The transfer account is closed,
+    /// so the transfer can no longer be accepted.
+    #[access_control(ctx.accounts.validate())]
+    pub fn cancel_program_config_transfer(ctx: Context<Self>) -> Result<()> {
+        let program_config = &ctx.accounts.program_config;
+        let program_config_transfer = &ctx.accounts.program_config_transfer;
+
+        // Anchor will close the `program_config_transfer` account for us.
+        let event = ProgramConfigEvent {
+            event_type: ProgramConfigEventType::CancelTransfer,
+            program_config_pubkey: program_config.key(),
+            transfer_kind: program_config_transfer.kind,
+            old_key: match program_config_transfer.kind {
+                ProgramConfigTransferKind::Authority => program_config.authority,
+                ProgramConfigTransferKind::Treasury => program_config.treasury,
+            },
+            new_key: program_config_transfer.new_key,
+            signer: ctx.accounts.signer.key(),
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: program_config.to_account_info(),
+            authority_seeds: get_program_config_signer_seeds(),
+            bump: ctx.bumps.program_config,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::ProgramConfigEvent(event).log(&log_authority_info)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SmartAccountError;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposeProgramConfigAuthorityArgs {
+    pub new_authority: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposeProgramConfigTreasuryArgs {
+    pub new_treasury: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct ProposeProgramConfigTransfer<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_PROGRAM_CONFIG],
+        bump,
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + ProgramConfigTransfer::INIT_SPACE,
+        seeds = [SEED_PREFIX, SEED_PROGRAM_CONFIG, SEED_PROGRAM_CONFIG_TRANSFER],
+        bump
+    )]
+    pub program_config_transfer: Account<'info, ProgramConfigTransfer>,
+
+    pub authority: Signer<'info>,
+
+    /// The payer for the program config transfer account rent.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub program: Program<'info, AstrolabeSmartAccount>,
+}
+
+impl ProposeProgramConfigTransfer<'_> {
+    fn validate(&self, new_key: Pubkey) -> Result<()> {
+        // authority
+        require_keys_eq!(
+            self.program_config.authority,
+            self.authority.key(),
+            SmartAccountError::Unauthorized
+        );
+
+        // new_key
+        require_keys_neq!(
+            new_key,
+            Pubkey::default(),
+            SmartAccountError::InvalidAccount
+        );
+
+        Ok(())
+    }
+
+    /// Propose a new program config `authority`, which takes effect once the new authority accepts it.
+    #[access_control(ctx.accounts.validate(args.new_authority))]
+    pub fn propose_authority(
+        ctx: Context<Self>,
+        args: ProposeProgramConfigAuthorityArgs,
+    ) -> Result<()> {
+        let old_authority = ctx.accounts.program_config.authority;
+        Self::propose(
+            ctx,
+            ProgramConfigTransferKind::Authority,
+            old_authority,
+            args.new_authority,
+        )
+    }
+
+    /// Propose a new program config `treasury`, which takes effect once the new treasury accepts it.
+    #[access_control(ctx.accounts.validate(args.new_treasury))]
+    pub fn propose_treasury(
+        ctx: Context<Self>,
+        args: ProposeProgramConfigTreasuryArgs,
+    ) -> Result<()> {
+        let old_treasury = ctx.accounts.program_config.treasury;
+        Self::propose(
+            ctx,
+            ProgramConfigTransferKind::Treasury,
+            old_treasury,
+            args.new_treasury,
+        )
+    }
+
+    fn propose(
+        ctx: Context<Self>,
+        kind: ProgramConfigTransferKind,
+        old_key: Pubkey,
+        new_key: Pubkey,
+    ) -> Result<()> {
+        let program_config_transfer = &mut ctx.accounts.program_config_transfer;
+
+        program_config_transfer.kind = kind;
+        program_config_transfer.proposed_by = ctx.accounts.authority.key();
+        program_config_transfer.new_key = new_key;
+        program_config_transfer.proposed_at = Clock::get()?.unix_timestamp;
+        program_config_transfer.rent_collector = ctx.accounts.rent_payer.key();
+        program_config_transfer.bump = ctx.bumps.program_config_transfer;
+
+        let event = ProgramConfigEvent {
+            event_type: ProgramConfigEventType::ProposeTransfer,
+            program_config_pubkey: ctx.accounts.program_config.key(),
+            transfer_kind: kind,
+            old_key,
+            new_key,
+            signer: ctx.accounts.authority.key(),
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: ctx.accounts.program_config.to_account_info(),
+            authority_seeds: get_program_config_signer_seeds(),
+            bump: ctx.bumps.program_config,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::ProgramConfigEvent(event).log(&log_authority_info)?;
+
+        Ok(())
+    }
+}
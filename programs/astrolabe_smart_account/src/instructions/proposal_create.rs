@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
 use crate::state::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -9,6 +11,9 @@ pub struct CreateProposalArgs {
     pub transaction_index: u64,
     /// Whether the proposal should be initialized with status `Draft`.
     pub draft: bool,
+    /// Unix timestamp after which the proposal can no longer be voted on.
+    /// `None` means no deadline.
+    pub voting_deadline: Option<i64>,
 }
 
 #[derive(Accounts)]
@@ -43,6 +48,16 @@ pub struct CreateProposal<'info> {
     pub fee_payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    /// CHECK: Checked by `VaultPolicy::load_for_proposal`.
+    pub transaction: Option<AccountInfo<'info>>,
+
+    /// The policy of the smart account the transaction is for, required if it has one.
+    pub vault_policy: Option<Account<'info, VaultPolicy>>,
 }
 
 impl CreateProposal<'_> {
@@ -65,6 +80,14 @@ impl CreateProposal<'_> {
             SmartAccountError::StaleProposal
         );
 
+        // The voting deadline must be in the future.
+        if let Some(voting_deadline) = args.voting_deadline {
+            require!(
+                voting_deadline > Clock::get()?.unix_timestamp,
+                SmartAccountError::InvalidVotingDeadline
+            );
+        }
+
         // creator
         // Has to be a signer on the smart account.
         require!(
@@ -94,7 +117,7 @@ impl CreateProposal<'_> {
 
         proposal.settings = settings.key();
         proposal.transaction_index = args.transaction_index;
-        proposal.rent_collector = fee_payer.key();
+        proposal.rent_collector = settings.rent_collector.unwrap_or(fee_payer.key());
         proposal.status = if args.draft {
             ProposalStatus::Draft {
                 timestamp: Clock::get()?.unix_timestamp,
@@ -104,11 +127,36 @@ impl CreateProposal<'_> {
                 timestamp: Clock::get()?.unix_timestamp,
             }
         };
+        proposal.voting_deadline = args.voting_deadline;
         proposal.bump = ctx.bumps.proposal;
         proposal.approved = vec![];
         proposal.rejected = vec![];
         proposal.cancelled = vec![];
 
+        // Log the event
+        let vault_policy = VaultPolicy::load_for_proposal(
+            settings,
+            &settings.key(),
+            proposal,
+            ctx.accounts.transaction.as_ref(),
+            ctx.accounts.vault_policy.as_deref(),
+        )?;
+        let event = ProposalEvent::new(
+            ProposalEventType::Create,
+            settings,
+            proposal,
+            vault_policy,
+            Some(ctx.accounts.creator.key()),
+            None,
+        );
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)?;
+
         Ok(())
     }
 }
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ExpireProposal<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Account<'info, Settings>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_TRANSACTION,
+            &proposal.transaction_index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    /// CHECK: Checked by `VaultPolicy::load_for_proposal`.
+    pub transaction: Option<AccountInfo<'info>>,
+
+    /// The policy of the smart account the transaction is for, required if it has one.
+    pub vault_policy: Option<Account<'info, VaultPolicy>>,
+}
+
+impl ExpireProposal<'_> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            settings, proposal, ..
+        } = self;
+
+        // Proposal must be past its voting deadline, or past the execution window if approved
+        require!(
+            proposal.has_expired(settings.execution_window, Clock::get()?.unix_timestamp),
+            SmartAccountError::ProposalNotExpired
+        );
+
+        Ok(())
+    }
+
+    /// Update status of a proposal to `Expired` once its voting deadline or execution window has passed.
+    /// Anyone can expire a proposal, so that its accounts can be closed.
+    #[access_control(ctx.accounts.validate())]
+    pub fn expire_proposal(ctx: Context<Self>) -> Result<()> {
+        let settings = &ctx.accounts.settings;
+        let proposal = &mut ctx.accounts.proposal;
+
+        proposal.status = ProposalStatus::Expired {
+            timestamp: Clock::get()?.unix_timestamp,
+        };
+
+        // Log the event
+        let vault_policy = VaultPolicy::load_for_proposal(
+            settings,
+            &settings.key(),
+            proposal,
+            ctx.accounts.transaction.as_ref(),
+            ctx.accounts.vault_policy.as_deref(),
+        )?;
+        let event = ProposalEvent::new(
+            ProposalEventType::Expire,
+            settings,
+            proposal,
+            vault_policy,
+            None,
+            None,
+        );
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)?;
+
+        Ok(())
+    }
+}
@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
 use crate::state::*;
+use crate::utils::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct VoteOnProposalArgs {
@@ -34,6 +37,16 @@ pub struct VoteOnProposal<'info> {
 
     // Only required for cancelling a proposal.
     pub system_program: Option<Program<'info, System>>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    /// CHECK: Checked by `VaultPolicy::load_for_proposal`.
+    pub transaction: Option<AccountInfo<'info>>,
+
+    /// The policy of the smart account the transaction is for, required if it has one.
+    pub vault_policy: Option<Account<'info, VaultPolicy>>,
 }
 
 impl VoteOnProposal<'_> {
@@ -42,100 +55,533 @@ impl VoteOnProposal<'_> {
             settings,
             proposal,
             signer,
+            transaction,
+            vault_policy,
             ..
         } = self;
 
-        // signer
-        require!(
-            settings.is_signer(signer.key()).is_some(),
-            SmartAccountError::NotASigner
-        );
-        require!(
-            settings.signer_has_permission(signer.key(), Permission::Vote),
-            SmartAccountError::Unauthorized
-        );
-
-        // proposal
-        match vote {
-            Vote::Approve | Vote::Reject => {
-                require!(
-                    matches!(proposal.status, ProposalStatus::Active { .. }),
-                    SmartAccountError::InvalidProposalStatus
-                );
-                // CANNOT approve or reject a stale proposal
-                require!(
-                    proposal.transaction_index > settings.stale_transaction_index,
-                    SmartAccountError::StaleProposal
-                );
-            }
-            Vote::Cancel => {
-                require!(
-                    matches!(proposal.status, ProposalStatus::Approved { .. }),
-                    SmartAccountError::InvalidProposalStatus
-                );
-                // CAN cancel a stale proposal.
-            }
-        }
+        let vault_policy = VaultPolicy::load_for_proposal(
+            settings,
+            &settings.key(),
+            proposal,
+            transaction.as_ref(),
+            vault_policy.as_deref(),
+        )?;
 
-        Ok(())
+        validate_vote(settings, proposal, signer.key(), vote, vault_policy)
     }
 
     /// Approve a smart account proposal on behalf of the `signer`.
     /// The proposal must be `Active`.
     #[access_control(ctx.accounts.validate(Vote::Approve))]
-    pub fn approve_proposal(ctx: Context<Self>, _args: VoteOnProposalArgs) -> Result<()> {
-        let settings = &mut ctx.accounts.settings;
-        let proposal = &mut ctx.accounts.proposal;
-        let signer = &mut ctx.accounts.signer;
+    pub fn approve_proposal(ctx: Context<Self>, args: VoteOnProposalArgs) -> Result<()> {
+        ctx.accounts.vote(Vote::Approve, args.memo)
+    }
 
-        proposal.approve(signer.key(), usize::from(settings.threshold))?;
+    /// Reject a smart account proposal on behalf of the `signer`.
+    /// The proposal must be `Active`.
+    #[access_control(ctx.accounts.validate(Vote::Reject))]
+    pub fn reject_proposal(ctx: Context<Self>, args: VoteOnProposalArgs) -> Result<()> {
+        ctx.accounts.vote(Vote::Reject, args.memo)
+    }
+
+    /// Cancel a smart account proposal on behalf of the `signer`.
+    /// The proposal must be `Approved`.
+    #[access_control(ctx.accounts.validate(Vote::Cancel))]
+    pub fn cancel_proposal(ctx: Context<Self>, args: VoteOnProposalArgs) -> Result<()> {
+        ctx.accounts.vote(Vote::Cancel, args.memo)
+    }
+
+    fn vote(&mut self, vote: Vote, memo: Option<String>) -> Result<()> {
+        let Self {
+            settings,
+            signer,
+            proposal,
+            system_program,
+            program,
+            transaction,
+            vault_policy,
+        } = self;
+
+        let vault_policy = VaultPolicy::load_for_proposal(
+            settings,
+            &settings.key(),
+            proposal,
+            transaction.as_ref(),
+            vault_policy.as_deref(),
+        )?;
+        let voter = signer.key();
+
+        let event = match vote {
+            Vote::Approve => approve(settings, proposal, voter, vault_policy, memo)?,
+            Vote::Reject => reject(settings, proposal, voter, vault_policy, memo)?,
+            Vote::Cancel => cancel(
+                settings,
+                proposal,
+                voter,
+                vault_policy,
+                signer.to_account_info(),
+                system_program.as_ref(),
+                memo,
+            )?,
+        };
+
+        log_vote(settings, program, event)
+    }
+}
+
+#[derive(Accounts)]
+pub struct VoteOnProposalWithPasskey<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Account<'info, Settings>,
+
+    /// The passkey signer voting on the proposal.
+    #[account(mut)]
+    pub passkey: Account<'info, Passkey>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_TRANSACTION,
+            &proposal.transaction_index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// Used to verify the passkey signature checked by the secp256r1 precompile.
+    /// CHECK: Checked by the address constraint.
+    #[account(address = INSTRUCTIONS_SYSVAR_ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Only required for cancelling a proposal, pays for the proposal reallocation.
+    #[account(mut)]
+    pub fee_payer: Option<Signer<'info>>,
+
+    // Only required for cancelling a proposal.
+    pub system_program: Option<Program<'info, System>>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    /// CHECK: Checked by `VaultPolicy::load_for_proposal`.
+    pub transaction: Option<AccountInfo<'info>>,
+
+    /// The policy of the smart account the transaction is for, required if it has one.
+    pub vault_policy: Option<Account<'info, VaultPolicy>>,
+}
+
+impl VoteOnProposalWithPasskey<'_> {
+    /// Also consumes the passkey nonce.
+    fn validate(&mut self, vote: Vote) -> Result<()> {
+        let Self {
+            settings,
+            passkey,
+            proposal,
+            instructions_sysvar,
+            transaction,
+            vault_policy,
+            ..
+        } = self;
+
+        let vault_policy = VaultPolicy::load_for_proposal(
+            settings,
+            &settings.key(),
+            proposal,
+            transaction.as_ref(),
+            vault_policy.as_deref(),
+        )?;
+
+        validate_vote(settings, proposal, passkey.key(), vote, vault_policy)?;
+
+        // passkey
+        let passkey_key = passkey.key();
+        passkey.verify_approval(passkey_key, instructions_sysvar)?;
 
         Ok(())
     }
 
-    /// Reject a smart account proposal on behalf of the `signer`.
+    /// Approve a smart account proposal on behalf of the `passkey`.
+    /// The proposal must be `Active`.
+    #[access_control(ctx.accounts.validate(Vote::Approve))]
+    pub fn approve_proposal_with_passkey(
+        ctx: Context<Self>,
+        args: VoteOnProposalArgs,
+    ) -> Result<()> {
+        ctx.accounts.vote(Vote::Approve, args.memo)
+    }
+
+    /// Reject a smart account proposal on behalf of the `passkey`.
     /// The proposal must be `Active`.
     #[access_control(ctx.accounts.validate(Vote::Reject))]
-    pub fn reject_proposal(ctx: Context<Self>, _args: VoteOnProposalArgs) -> Result<()> {
-        let settings = &mut ctx.accounts.settings;
-        let proposal = &mut ctx.accounts.proposal;
-        let signer = &mut ctx.accounts.signer;
+    pub fn reject_proposal_with_passkey(
+        ctx: Context<Self>,
+        args: VoteOnProposalArgs,
+    ) -> Result<()> {
+        ctx.accounts.vote(Vote::Reject, args.memo)
+    }
+
+    /// Cancel a smart account proposal on behalf of the `passkey`.
+    /// The proposal must be `Approved`.
+    #[access_control(ctx.accounts.validate(Vote::Cancel))]
+    pub fn cancel_proposal_with_passkey(
+        ctx: Context<Self>,
+        args: VoteOnProposalArgs,
+    ) -> Result<()> {
+        ctx.accounts.vote(Vote::Cancel, args.memo)
+    }
 
-        let cutoff = Settings::cutoff(&settings);
+    fn vote(&mut self, vote: Vote, memo: Option<String>) -> Result<()> {
+        let Self {
+            settings,
+            passkey,
+            proposal,
+            fee_payer,
+            system_program,
+            program,
+            transaction,
+            vault_policy,
+            ..
+        } = self;
 
-        proposal.reject(signer.key(), cutoff)?;
+        let vault_policy = VaultPolicy::load_for_proposal(
+            settings,
+            &settings.key(),
+            proposal,
+            transaction.as_ref(),
+            vault_policy.as_deref(),
+        )?;
+        let voter = passkey.key();
 
-        Ok(())
+        let event = match vote {
+            Vote::Approve => approve(settings, proposal, voter, vault_policy, memo)?,
+            Vote::Reject => reject(settings, proposal, voter, vault_policy, memo)?,
+            Vote::Cancel => {
+                // The passkey account can't pay for the reallocation.
+                let fee_payer = fee_payer
+                    .as_ref()
+                    .ok_or(SmartAccountError::MissingAccount)?;
+                cancel(
+                    settings,
+                    proposal,
+                    voter,
+                    vault_policy,
+                    fee_payer.to_account_info(),
+                    system_program.as_ref(),
+                    memo,
+                )?
+            }
+        };
+
+        log_vote(settings, program, event)
     }
+}
 
-    /// Cancel a smart account proposal on behalf of the `signer`.
+#[derive(Accounts)]
+pub struct VoteOnProposalWithSessionKey<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Account<'info, Settings>,
+
+    /// The session key voting on behalf of its `granter`.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SESSION_KEY,
+            signer.key().as_ref(),
+        ],
+        bump = session_key.bump,
+    )]
+    pub session_key: Account<'info, SessionKey>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_TRANSACTION,
+            &proposal.transaction_index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    // Only required for cancelling a proposal.
+    pub system_program: Option<Program<'info, System>>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    /// CHECK: Checked by `VaultPolicy::load_for_proposal`.
+    pub transaction: Option<AccountInfo<'info>>,
+
+    /// The policy of the smart account the transaction is for, required if it has one.
+    pub vault_policy: Option<Account<'info, VaultPolicy>>,
+}
+
+impl VoteOnProposalWithSessionKey<'_> {
+    fn validate(&self, vote: Vote) -> Result<()> {
+        let Self {
+            settings,
+            session_key,
+            proposal,
+            transaction,
+            vault_policy,
+            ..
+        } = self;
+
+        let vault_policy = VaultPolicy::load_for_proposal(
+            settings,
+            &settings.key(),
+            proposal,
+            transaction.as_ref(),
+            vault_policy.as_deref(),
+        )?;
+
+        // session_key
+        let permissions = session_key.validate_use(settings)?;
+        require!(
+            permissions.has(Permission::Vote),
+            SmartAccountError::Unauthorized
+        );
+        // A vote can approve any instructions, so it can't be limited to the scope of the session.
+        require!(
+            !session_key.is_scoped(),
+            SmartAccountError::SessionKeyOutOfScope
+        );
+
+        validate_vote(settings, proposal, session_key.granter, vote, vault_policy)
+    }
+
+    /// Approve a smart account proposal on behalf of the `granter` of the session key.
+    /// The proposal must be `Active`.
+    #[access_control(ctx.accounts.validate(Vote::Approve))]
+    pub fn approve_proposal_with_session_key(
+        ctx: Context<Self>,
+        args: VoteOnProposalArgs,
+    ) -> Result<()> {
+        ctx.accounts.vote(Vote::Approve, args.memo)
+    }
+
+    /// Reject a smart account proposal on behalf of the `granter` of the session key.
+    /// The proposal must be `Active`.
+    #[access_control(ctx.accounts.validate(Vote::Reject))]
+    pub fn reject_proposal_with_session_key(
+        ctx: Context<Self>,
+        args: VoteOnProposalArgs,
+    ) -> Result<()> {
+        ctx.accounts.vote(Vote::Reject, args.memo)
+    }
+
+    /// Cancel a smart account proposal on behalf of the `granter` of the session key.
     /// The proposal must be `Approved`.
     #[access_control(ctx.accounts.validate(Vote::Cancel))]
-    pub fn cancel_proposal(ctx: Context<Self>, _args: VoteOnProposalArgs) -> Result<()> {
-        let settings = &mut ctx.accounts.settings;
-        let proposal = &mut ctx.accounts.proposal;
-        let signer = &mut ctx.accounts.signer;
-        let system_program = &ctx
-            .accounts
-            .system_program
-            .as_ref()
-            .ok_or(SmartAccountError::MissingAccount)?;
-
-        proposal
-            .cancelled
-            .retain(|k| settings.is_signer(*k).is_some());
-
-        proposal.cancel(signer.key(), usize::from(settings.threshold))?;
-
-        Proposal::realloc_if_needed(
-            proposal.to_account_info().clone(),
-            settings.signers.len(),
-            Some(signer.to_account_info().clone()),
-            Some(system_program.to_account_info().clone()),
+    pub fn cancel_proposal_with_session_key(
+        ctx: Context<Self>,
+        args: VoteOnProposalArgs,
+    ) -> Result<()> {
+        ctx.accounts.vote(Vote::Cancel, args.memo)
+    }
+
+    fn vote(&mut self, vote: Vote, memo: Option<String>) -> Result<()> {
+        let Self {
+            settings,
+            signer,
+            session_key,
+            proposal,
+            system_program,
+            program,
+            transaction,
+            vault_policy,
+        } = self;
+
+        let vault_policy = VaultPolicy::load_for_proposal(
+            settings,
+            &settings.key(),
+            proposal,
+            transaction.as_ref(),
+            vault_policy.as_deref(),
         )?;
+        // The session key votes on behalf of its granter, and pays for the reallocation.
+        let voter = session_key.granter;
 
-        Ok(())
+        let event = match vote {
+            Vote::Approve => approve(settings, proposal, voter, vault_policy, memo)?,
+            Vote::Reject => reject(settings, proposal, voter, vault_policy, memo)?,
+            Vote::Cancel => cancel(
+                settings,
+                proposal,
+                voter,
+                vault_policy,
+                signer.to_account_info(),
+                system_program.as_ref(),
+                memo,
+            )?,
+        };
+
+        log_vote(settings, program, event)
+    }
+}
+
+/// Approves the proposal on behalf of `voter`, and returns the event to log.
+fn approve(
+    settings: &Account<Settings>,
+    proposal: &mut Account<Proposal>,
+    voter: Pubkey,
+    vault_policy: Option<&VaultPolicy>,
+    memo: Option<String>,
+) -> Result<ProposalEvent> {
+    proposal.approve(voter, settings, vault_policy)?;
+
+    Ok(ProposalEvent::new(
+        ProposalEventType::Approve,
+        settings,
+        proposal,
+        vault_policy,
+        Some(voter),
+        memo,
+    ))
+}
+
+/// Rejects the proposal on behalf of `voter`, and returns the event to log.
+fn reject(
+    settings: &Account<Settings>,
+    proposal: &mut Account<Proposal>,
+    voter: Pubkey,
+    vault_policy: Option<&VaultPolicy>,
+    memo: Option<String>,
+) -> Result<ProposalEvent> {
+    proposal.reject(voter, settings, vault_policy)?;
+
+    Ok(ProposalEvent::new(
+        ProposalEventType::Reject,
+        settings,
+        proposal,
+        vault_policy,
+        Some(voter),
+        memo,
+    ))
+}
+
+/// Cancels the proposal on behalf of `voter`, and returns the event to log.
+/// The proposal is reallocated to fit the cancellations of all the signers, paid by `rent_payer`.
+fn cancel<'info>(
+    settings: &Account<Settings>,
+    proposal: &mut Account<'info, Proposal>,
+    voter: Pubkey,
+    vault_policy: Option<&VaultPolicy>,
+    rent_payer: AccountInfo<'info>,
+    system_program: Option<&Program<'info, System>>,
+    memo: Option<String>,
+) -> Result<ProposalEvent> {
+    let system_program = system_program.ok_or(SmartAccountError::MissingAccount)?;
+
+    proposal
+        .cancelled
+        .retain(|k| settings.is_signer(*k).is_some());
+
+    proposal.cancel(voter, settings, vault_policy)?;
+
+    Proposal::realloc_if_needed(
+        proposal.to_account_info(),
+        settings.signers.len(),
+        Some(rent_payer),
+        Some(system_program.to_account_info()),
+    )?;
+
+    Ok(ProposalEvent::new(
+        ProposalEventType::Cancel,
+        settings,
+        proposal,
+        vault_policy,
+        Some(voter),
+        memo,
+    ))
+}
+
+/// Logs the `ProposalEvent` of a vote, with the settings as the log authority.
+fn log_vote<'info>(
+    settings: &Account<'info, Settings>,
+    program: &Program<'info, AstrolabeSmartAccount>,
+    event: ProposalEvent,
+) -> Result<()> {
+    let log_authority_info = LogAuthorityInfo {
+        authority: settings.to_account_info(),
+        authority_seeds: get_settings_signer_seeds(settings.seed),
+        bump: settings.bump,
+        program: program.to_account_info(),
+    };
+    SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)
+}
+
+/// Checks shared by all the ways of voting on a proposal.
+/// `vault_policy` is the policy of the smart account the transaction is for, if any.
+fn validate_vote(
+    settings: &Settings,
+    proposal: &Proposal,
+    voter: Pubkey,
+    vote: Vote,
+    vault_policy: Option<&VaultPolicy>,
+) -> Result<()> {
+    // voter
+    require!(
+        settings.is_signer(voter).is_some(),
+        SmartAccountError::NotASigner
+    );
+    require!(
+        settings.signer_has_permission(voter, Permission::Vote),
+        SmartAccountError::Unauthorized
+    );
+    if let Some(vault_policy) = vault_policy {
+        require!(
+            vault_policy.is_signer(voter),
+            SmartAccountError::Unauthorized
+        );
+    }
+
+    // proposal
+    match vote {
+        Vote::Approve | Vote::Reject => {
+            require!(
+                matches!(proposal.status, ProposalStatus::Active { .. }),
+                SmartAccountError::InvalidProposalStatus
+            );
+            // CANNOT approve or reject a stale proposal
+            require!(
+                proposal.transaction_index > settings.stale_transaction_index,
+                SmartAccountError::StaleProposal
+            );
+            // CANNOT approve or reject a proposal past its voting deadline
+            require!(
+                !proposal.has_expired(settings.execution_window, Clock::get()?.unix_timestamp),
+                SmartAccountError::ProposalExpired
+            );
+        }
+        Vote::Cancel => {
+            require!(
+                matches!(proposal.status, ProposalStatus::Approved { .. }),
+                SmartAccountError::InvalidProposalStatus
+            );
+            // CAN cancel a stale proposal.
+        }
     }
+
+    Ok(())
 }
 
 pub enum Vote {
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ApproveRecoveryArgs {
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveRecovery<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, settings.key().as_ref(), SEED_RECOVERY],
+        bump = recovery.bump,
+    )]
+    pub recovery: Account<'info, Recovery>,
+
+    /// Restricted signer with the `Recover` permission.
+    /// Pays for the recovery account reallocation if needed.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl ApproveRecovery<'_> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            settings, guardian, ..
+        } = self;
+
+        // guardian
+        require!(
+            settings
+                .restricted_signer_has_permission(guardian.key(), RestrictedPermission::Recover),
+            SmartAccountError::UnauthorizedRestrictedSigner
+        );
+
+        Ok(())
+    }
+
+    /// Approve a pending recovery on behalf of the `guardian`.
+    /// Once approved by `recovery_threshold` guardians, the veto period starts.
+    #[access_control(ctx.accounts.validate())]
+    pub fn approve_recovery(ctx: Context<Self>, _args: ApproveRecoveryArgs) -> Result<()> {
+        let settings = &ctx.accounts.settings;
+        let recovery = &mut ctx.accounts.recovery;
+        let guardian = &ctx.accounts.guardian;
+
+        // Guardians may have been added since the recovery account was created.
+        Recovery::realloc_if_needed(
+            recovery.to_account_info(),
+            recovery.new_signers.len(),
+            recovery.approved.len() + 1,
+            Some(guardian.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+
+        recovery.approve(guardian.key(), usize::from(settings.recovery_threshold))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateRecoveryArgs {
+    /// The signers replacing the current signers of the smart account.
+    pub new_signers: Vec<SmartAccountSigner>,
+    /// The threshold replacing the current threshold of the smart account.
+    pub new_threshold: u16,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: CreateRecoveryArgs)]
+pub struct CreateRecovery<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        init,
+        payer = fee_payer,
+        space = Recovery::size(
+            args.new_signers.len(),
+            Settings::num_guardians(&settings.restricted_signers)
+        ),
+        seeds = [SEED_PREFIX, settings.key().as_ref(), SEED_RECOVERY],
+        bump
+    )]
+    pub recovery: Account<'info, Recovery>,
+
+    /// Restricted signer with the `Recover` permission.
+    pub guardian: Signer<'info>,
+
+    /// The payer for the recovery account rent.
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl CreateRecovery<'_> {
+    fn validate(&self, args: &CreateRecoveryArgs) -> Result<()> {
+        let Self {
+            settings, guardian, ..
+        } = self;
+
+        // settings
+        require!(
+            settings.recovery_threshold > 0,
+            SmartAccountError::RecoveryDisabled
+        );
+
+        // guardian
+        require!(
+            settings
+                .restricted_signer_has_permission(guardian.key(), RestrictedPermission::Recover),
+            SmartAccountError::UnauthorizedRestrictedSigner
+        );
+
+        // new_signers, new_threshold
+        // Make sure the recovered settings would be valid, so an invalid recovery can't get through the veto period.
+        let mut recovered_settings = Settings::clone(settings);
+        recovered_settings.signers = sorted_signers(&args.new_signers);
+        recovered_settings.threshold = args.new_threshold;
+        recovered_settings.invariant()?;
+
+        Ok(())
+    }
+
+    /// Start a recovery of the smart account that replaces its signers and threshold.
+    /// The `guardian` starting the recovery approves it right away.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn create_recovery(ctx: Context<Self>, args: CreateRecoveryArgs) -> Result<()> {
+        let settings = &ctx.accounts.settings;
+        let recovery = &mut ctx.accounts.recovery;
+
+        recovery.settings = settings.key();
+        recovery.creator = ctx.accounts.guardian.key();
+        recovery.new_signers = sorted_signers(&args.new_signers);
+        recovery.new_threshold = args.new_threshold;
+        recovery.approved = Vec::new();
+        recovery.approved_at = None;
+        recovery.rent_collector = ctx.accounts.fee_payer.key();
+        recovery.bump = ctx.bumps.recovery;
+
+        recovery.approve(
+            ctx.accounts.guardian.key(),
+            usize::from(settings.recovery_threshold),
+        )?;
+
+        Ok(())
+    }
+}
+
+fn sorted_signers(signers: &[SmartAccountSigner]) -> Vec<SmartAccountSigner> {
+    let mut signers = signers.to_vec();
+    signers.sort_by_key(|m| m.key);
+    signers
+}
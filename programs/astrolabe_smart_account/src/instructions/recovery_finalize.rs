@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct FinalizeRecovery<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, settings.key().as_ref(), SEED_RECOVERY],
+        bump = recovery.bump,
+        close = rent_collector,
+    )]
+    pub recovery: Account<'info, Recovery>,
+
+    /// The rent collector.
+    /// CHECK: We only need to validate the address.
+    #[account(
+        mut,
+        address = recovery.rent_collector @ SmartAccountError::InvalidRentCollector,
+    )]
+    pub rent_collector: AccountInfo<'info>,
+
+    /// The account that will be charged in case the settings account needs to reallocate space
+    /// to fit the new signers.
+    #[account(mut)]
+    pub fee_payer: Option<Signer<'info>>,
+
+    /// We might need it in case reallocation is needed.
+    pub system_program: Option<Program<'info, System>>,
+}
+
+impl FinalizeRecovery<'_> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            settings, recovery, ..
+        } = self;
+
+        // settings
+        require!(
+            settings.recovery_threshold > 0,
+            SmartAccountError::RecoveryDisabled
+        );
+
+        // recovery
+        // Only approvals of the current guardians count.
+        let approvals = recovery
+            .approved
+            .iter()
+            .filter(|guardian| {
+                settings.restricted_signer_has_permission(**guardian, RestrictedPermission::Recover)
+            })
+            .count();
+        let approved_at = recovery
+            .approved_at
+            .filter(|_| approvals >= usize::from(settings.recovery_threshold))
+            .ok_or(SmartAccountError::RecoveryNotApproved)?;
+        require!(
+            Clock::get()?.unix_timestamp - approved_at >= i64::from(settings.recovery_delay),
+            SmartAccountError::RecoveryDelayNotElapsed
+        );
+
+        Ok(())
+    }
+
+    /// Finalize an approved recovery once the veto period has passed, replacing
+    /// the signers and threshold of the smart account. Anyone can finalize a recovery.
+    #[access_control(ctx.accounts.validate())]
+    pub fn finalize_recovery(ctx: Context<Self>) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+        let recovery = &ctx.accounts.recovery;
+
+        settings.signers = recovery.new_signers.clone();
+        settings.threshold = recovery.new_threshold;
+
+        // Make sure the settings account can fit the new signers.
+        Settings::realloc_if_needed(
+            settings.to_account_info(),
+            settings,
+            ctx.accounts
+                .fee_payer
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+            ctx.accounts
+                .system_program
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
+        // Transactions approved by the replaced signers must not be executable anymore.
+        settings.invalidate_prior_transactions();
+
+        settings.invariant()?;
+
+        // Anchor will close the `recovery` account for us.
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VetoRecoveryArgs {
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct VetoRecovery<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, settings.key().as_ref(), SEED_RECOVERY],
+        bump = recovery.bump,
+        close = rent_collector,
+    )]
+    pub recovery: Account<'info, Recovery>,
+
+    /// A current signer of the smart account.
+    pub signer: Signer<'info>,
+
+    /// The rent collector.
+    /// CHECK: We only need to validate the address.
+    #[account(
+        mut,
+        address = recovery.rent_collector @ SmartAccountError::InvalidRentCollector,
+    )]
+    pub rent_collector: AccountInfo<'info>,
+}
+
+impl VetoRecovery<'_> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            settings, signer, ..
+        } = self;
+
+        // signer
+        require!(
+            settings.is_signer(signer.key()).is_some(),
+            SmartAccountError::NotASigner
+        );
+
+        Ok(())
+    }
+
+    /// Veto a pending recovery on behalf of a current signer of the smart account.
+    /// The recovery account is closed, so the recovery can no longer be finalized.
+    #[access_control(ctx.accounts.validate())]
+    pub fn veto_recovery(ctx: Context<Self>, _args: VetoRecoveryArgs) -> Result<()> {
+        // Anchor will close the `recovery` account for us.
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateSessionKeyArgs {
+    /// The key that acts on behalf of the `granter` during the session.
+    pub key: Pubkey,
+    /// Permissions of the session, must be a subset of the `granter` permissions.
+    pub permissions: Permissions,
+    /// The moment after which the session key can no longer be used.
+    pub expiration: SessionExpiration,
+    /// The programs the session key is allowed to invoke.
+    /// If empty, the session key can invoke any program.
+    pub allowed_programs: Vec<Pubkey>,
+    /// The maximum amount of lamports the session key can move out of the smart accounts.
+    pub max_lamports: Option<u64>,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: CreateSessionKeyArgs)]
+pub struct CreateSessionKey<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Account<'info, Settings>,
+
+    #[account(
+        init,
+        payer = fee_payer,
+        space = SessionKey::size(args.allowed_programs.len()),
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SESSION_KEY,
+            args.key.as_ref(),
+        ],
+        bump
+    )]
+    pub session_key: Account<'info, SessionKey>,
+
+    /// The signer of the smart account granting the session.
+    pub granter: Signer<'info>,
+
+    /// The payer for the session key account rent.
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl CreateSessionKey<'_> {
+    fn validate(&self, args: &CreateSessionKeyArgs) -> Result<()> {
+        let Self {
+            settings, granter, ..
+        } = self;
+
+        // granter
+        let granter_index = settings
+            .is_signer(granter.key())
+            .ok_or(SmartAccountError::NotASigner)?;
+        let granter_permissions = settings.signers[granter_index].permissions;
+
+        // permissions
+        require!(
+            args.permissions.mask != 0 && args.permissions.mask & !granter_permissions.mask == 0,
+            SmartAccountError::Unauthorized
+        );
+
+        // expiration
+        require!(
+            !args.expiration.has_passed()?,
+            SmartAccountError::SessionKeyExpired
+        );
+
+        Ok(())
+    }
+
+    /// Grant a session key that can act on behalf of the `granter` until it expires.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn create_session_key(ctx: Context<Self>, args: CreateSessionKeyArgs) -> Result<()> {
+        let session_key = &mut ctx.accounts.session_key;
+
+        session_key.settings = ctx.accounts.settings.key();
+        session_key.granter = ctx.accounts.granter.key();
+        session_key.key = args.key;
+        session_key.permissions = args.permissions;
+        session_key.expiration = args.expiration;
+        session_key.allowed_programs = args.allowed_programs;
+        session_key.max_lamports = args.max_lamports;
+        session_key.lamports_spent = 0;
+        session_key.rent_collector = ctx.accounts.fee_payer.key();
+        session_key.bump = ctx.bumps.session_key;
+
+        Ok(())
+    }
+}
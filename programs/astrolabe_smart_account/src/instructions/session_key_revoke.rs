@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RevokeSessionKeyArgs {
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeSessionKey<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Account<'info, Settings>,
+
+    #[account(
+        mut,
+        has_one = settings @ SmartAccountError::InvalidAccount,
+        close = rent_collector,
+    )]
+    pub session_key: Account<'info, SessionKey>,
+
+    /// The `granter` or the session key itself. Anyone can close an expired session key.
+    pub signer: Signer<'info>,
+
+    /// The rent collector.
+    /// CHECK: We only need to validate the address.
+    #[account(
+        mut,
+        address = session_key.rent_collector @ SmartAccountError::InvalidRentCollector,
+    )]
+    pub rent_collector: AccountInfo<'info>,
+}
+
+impl RevokeSessionKey<'_> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            session_key,
+            signer,
+            ..
+        } = self;
+
+        // signer
+        require!(
+            signer.key() == session_key.granter
+                || signer.key() == session_key.key
+                || session_key.expiration.has_passed()?,
+            SmartAccountError::Unauthorized
+        );
+
+        Ok(())
+    }
+
+    /// Revoke a session key before it expires, or close an expired one.
+    #[access_control(ctx.accounts.validate())]
+    pub fn revoke_session_key(ctx: Context<Self>, _args: RevokeSessionKeyArgs) -> Result<()> {
+        // Anchor will close the `session_key` account for us.
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::{
+    errors::*, program::AstrolabeSmartAccount, state::*, ArchiveSettingsEvent, LogAuthorityInfo,
+    SmartAccountEvent,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ArchiveSettingsArgs {
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct ArchiveSettings<'info> {
+    /// The settings account to archive. It is closed and its rent is returned to `fee_payer`.
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+        close = fee_payer,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    /// The record keeping the hash commitment of the archived settings.
+    #[account(
+        init,
+        payer = fee_payer,
+        space = 8 + ArchivedSettings::INIT_SPACE,
+        seeds = [SEED_PREFIX, settings.key().as_ref(), SEED_ARCHIVED_SETTINGS],
+        bump
+    )]
+    pub archived_settings: Account<'info, ArchivedSettings>,
+
+    /// Settings `archival_authority` that must authorize the archival.
+    pub archival_authority: Signer<'info>,
+
+    /// The payer for the archived settings account rent.
+    /// Receives the rent reclaimed from the closed settings account.
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub program: Program<'info, AstrolabeSmartAccount>,
+}
+
+impl ArchiveSettings<'_> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            settings,
+            archival_authority,
+            ..
+        } = self;
+
+        // archival_authority
+        require!(
+            settings.archival_authority.is_some_and(|authority| {
+                authority != Pubkey::default() && authority == archival_authority.key()
+            }),
+            SmartAccountError::Unauthorized
+        );
+
+        // archivable_after
+        require!(
+            Clock::get()?.unix_timestamp
+                >= i64::try_from(settings.archivable_after).unwrap_or(i64::MAX),
+            SmartAccountError::ArchivalCooldownNotElapsed
+        );
+
+        Ok(())
+    }
+
+    /// Close the settings account and keep only a hash commitment of its contents
+    /// in an `ArchivedSettings` record. The settings can be restored with `unarchive_settings`,
+    /// so they can only be archived up to `MAX_ARCHIVED_SETTINGS_LEN`.
+    #[access_control(ctx.accounts.validate())]
+    pub fn archive_settings(ctx: Context<Self>, _args: ArchiveSettingsArgs) -> Result<()> {
+        let settings = &ctx.accounts.settings;
+        let archived_settings = &mut ctx.accounts.archived_settings;
+
+        let settings_data = settings.try_to_vec()?;
+        // The settings could never be restored if they don't fit in an `unarchive_settings` transaction.
+        require!(
+            settings_data.len() <= MAX_ARCHIVED_SETTINGS_LEN,
+            SmartAccountError::SettingsTooLargeToArchive
+        );
+        let settings_hash = hash(&settings_data).to_bytes();
+
+        archived_settings.settings = settings.key();
+        archived_settings.settings_hash = settings_hash;
+        archived_settings.settings_space = settings.to_account_info().data_len() as u64;
+        archived_settings.archived_at = Clock::get()?.unix_timestamp;
+        archived_settings.bump = ctx.bumps.archived_settings;
+
+        // Log the event
+        let event = ArchiveSettingsEvent {
+            settings_pubkey: settings.key(),
+            archival_authority: ctx.accounts.archival_authority.key(),
+            archived_settings_pubkey: archived_settings.key(),
+            settings_hash,
+            settings: (***settings).clone(),
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::ArchiveSettingsEvent(event).log(&log_authority_info)?;
+
+        Ok(())
+    }
+}
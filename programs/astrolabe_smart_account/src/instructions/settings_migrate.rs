@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct MigrateSettings<'info> {
+    /// The settings account in the legacy layout.
+    /// CHECK: deserialized and checked against its seeds in `migrate_settings`.
+    #[account(
+        mut,
+        owner = crate::id() @ SmartAccountError::IllegalAccountOwner,
+    )]
+    pub settings: AccountInfo<'info>,
+
+    /// The payer for the rent of the settings account growing to the current layout.
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl MigrateSettings<'_> {
+    /// Rewrite a settings account created before its layout changed into the current layout.
+    /// The migration keeps every parameter of the settings and leaves the features added since disabled,
+    /// see `LegacySettings::migrate`, so anyone can run it.
+    pub fn migrate_settings(ctx: Context<Self>) -> Result<()> {
+        let settings_info = &ctx.accounts.settings;
+
+        let legacy_settings =
+            LegacySettings::try_from_account_data(&settings_info.try_borrow_data()?)?;
+
+        let settings_key = Pubkey::create_program_address(
+            &[
+                SEED_PREFIX,
+                SEED_SETTINGS,
+                &legacy_settings.seed.to_le_bytes(),
+                &[legacy_settings.bump],
+            ],
+            &crate::id(),
+        )
+        .map_err(|_| SmartAccountError::InvalidAccount)?;
+        require_keys_eq!(
+            settings_info.key(),
+            settings_key,
+            SmartAccountError::InvalidAccount
+        );
+
+        let settings = legacy_settings.migrate();
+        settings.invariant()?;
+
+        Settings::realloc_if_needed(
+            settings_info.clone(),
+            &settings,
+            Some(ctx.accounts.fee_payer.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+        settings.try_serialize(&mut &mut settings_info.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
 use crate::state::*;
 use crate::utils::validate_settings_actions;
 
@@ -42,6 +44,8 @@ pub struct CreateSettingsTransaction<'info> {
     pub fee_payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
 }
 
 impl CreateSettingsTransaction<'_> {
@@ -88,9 +92,10 @@ impl CreateSettingsTransaction<'_> {
         // Initialize the transaction fields.
         transaction.settings = settings_key;
         transaction.creator = creator.key();
-        transaction.rent_collector = fee_payer.key();
+        transaction.rent_collector = settings.rent_collector.unwrap_or(fee_payer.key());
         transaction.index = transaction_index;
         transaction.bump = ctx.bumps.transaction;
+        transaction.time_lock = settings.actions_time_lock(&args.actions);
         transaction.actions = args.actions;
 
         // Updated last transaction index in the settings account.
@@ -101,6 +106,26 @@ impl CreateSettingsTransaction<'_> {
         // Logs for indexing.
         msg!("transaction index: {}", transaction_index);
 
+        // Log the event
+        let event = TransactionEvent {
+            event_type: TransactionEventType::Create,
+            settings_pubkey: settings_key,
+            transaction_pubkey: transaction.key(),
+            transaction_index,
+            signer: Some(creator.key()),
+            memo: args.memo,
+            transaction_content: TransactionContent::SettingsTransaction {
+                actions: transaction.actions.clone(),
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+
         Ok(())
     }
 }
@@ -0,0 +1,116 @@
+use crate::errors::*;
+use crate::instructions::*;
+use crate::state::*;
+use crate::utils::realloc;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+
+#[derive(Accounts)]
+pub struct CreateSettingsTransactionFromBuffer<'info> {
+    // The context needed for the CreateSettingsTransaction instruction
+    pub settings_transaction_create: CreateSettingsTransaction<'info>,
+
+    #[account(
+        mut,
+        close = from_buffer_creator,
+        // Only the creator can turn the buffer into a settings transaction and reclaim
+        // the rent
+        constraint = transaction_buffer.creator == from_buffer_creator.key() @ SmartAccountError::Unauthorized,
+        seeds = [
+            SEED_PREFIX,
+            settings_transaction_create.settings.key().as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            from_buffer_creator.key().as_ref(),
+            &transaction_buffer.buffer_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub transaction_buffer: Box<Account<'info, TransactionBuffer>>,
+
+    // Anchor doesn't allow us to use the creator inside of
+    // settings_transaction_create, so we just re-pass it here with the same constraint
+    #[account(
+        mut,
+        address = settings_transaction_create.creator.key(),
+    )]
+    pub from_buffer_creator: Signer<'info>,
+}
+
+impl<'info> CreateSettingsTransactionFromBuffer<'info> {
+    pub fn validate(&self, args: &CreateSettingsTransactionArgs) -> Result<()> {
+        let transaction_buffer_account = &self.transaction_buffer;
+
+        // The actions are read from the buffer, `args.actions` must be left empty
+        // so the transaction account is initialized with the minimal size.
+        require!(
+            args.actions.is_empty(),
+            SmartAccountError::InvalidInstructionArgs
+        );
+
+        // Validate that the final hash matches the buffer
+        transaction_buffer_account.validate_hash()?;
+
+        // Validate that the final size is correct
+        transaction_buffer_account.validate_size()?;
+        Ok(())
+    }
+
+    /// Create a new settings transaction from a completed transaction buffer account
+    /// holding the Borsh-serialized `Vec<SettingsAction>`.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn create_settings_transaction_from_buffer(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        args: CreateSettingsTransactionArgs,
+    ) -> Result<()> {
+        let actions =
+            Vec::<SettingsAction>::try_from_slice(&ctx.accounts.transaction_buffer.buffer)
+                .map_err(|_| SmartAccountError::InvalidInstructionArgs)?;
+
+        // The settings transaction account was initialized with no actions,
+        // reallocate it to fit the actions of the buffer.
+        let new_len = SettingsTransaction::size(&actions);
+
+        // The settings transaction account is created by this instruction, so it can only be grown up to the realloc limit.
+        require!(
+            new_len <= MAX_PERMITTED_DATA_INCREASE,
+            SmartAccountError::TransactionSizeExceeded
+        );
+        realloc(
+            &ctx.accounts
+                .settings_transaction_create
+                .transaction
+                .to_account_info(),
+            new_len,
+            Some(
+                ctx.accounts
+                    .settings_transaction_create
+                    .fee_payer
+                    .to_account_info(),
+            ),
+            Some(
+                ctx.accounts
+                    .settings_transaction_create
+                    .system_program
+                    .to_account_info(),
+            ),
+        )?;
+
+        // Create the args for the `create_settings_transaction` instruction
+        let create_args = CreateSettingsTransactionArgs {
+            actions,
+            memo: args.memo,
+        };
+        // Create the context for the `create_settings_transaction` instruction
+        let context = Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.settings_transaction_create,
+            ctx.remaining_accounts,
+            ctx.bumps.settings_transaction_create,
+        );
+
+        // Call the `create_settings_transaction` instruction
+        CreateSettingsTransaction::create_settings_transaction(context, create_args)?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -49,6 +51,8 @@ pub struct ExecuteSettingsTransaction<'info> {
 
     /// We might need it in case reallocation is needed.
     pub system_program: Option<Program<'info, System>>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
 }
 
 impl<'info> ExecuteSettingsTransaction<'info> {
@@ -56,6 +60,7 @@ impl<'info> ExecuteSettingsTransaction<'info> {
         let Self {
             settings,
             proposal,
+            transaction,
             signer,
             ..
         } = self;
@@ -71,10 +76,17 @@ impl<'info> ExecuteSettingsTransaction<'info> {
         );
 
         // proposal
+        // Approved proposals can only be executed within the execution window of the settings.
+        require!(
+            !proposal.has_expired(settings.execution_window, Clock::get()?.unix_timestamp),
+            SmartAccountError::ProposalExpired
+        );
         match proposal.status {
             ProposalStatus::Approved { timestamp } => {
+                // The time lock of the actions of the transaction applies on top of the settings one.
                 require!(
-                    Clock::get()?.unix_timestamp - timestamp >= i64::from(settings.time_lock),
+                    Clock::get()?.unix_timestamp - timestamp
+                        >= i64::from(settings.time_lock.max(transaction.time_lock)),
                     SmartAccountError::TimeLockNotReleased
                 );
             }
@@ -115,10 +127,10 @@ impl<'info> ExecuteSettingsTransaction<'info> {
             )?;
         }
 
-        // Make sure the smart account can fit the updated state: added signers or newly set rent_collector.
+        // Make sure the smart account can fit the updated state: added signers, restricted signers or target time locks.
         Settings::realloc_if_needed(
             settings.to_account_info(),
-            settings.signers.len(),
+            settings,
             ctx.accounts
                 .fee_payer
                 .as_ref()
@@ -137,6 +149,36 @@ impl<'info> ExecuteSettingsTransaction<'info> {
             timestamp: Clock::get()?.unix_timestamp,
         };
 
+        // Log the events
+        let signer = ctx.accounts.signer.key();
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        let event = TransactionEvent {
+            event_type: TransactionEventType::Execute,
+            settings_pubkey: settings_key,
+            transaction_pubkey: transaction.key(),
+            transaction_index: transaction.index,
+            signer: Some(signer),
+            memo: None,
+            transaction_content: TransactionContent::SettingsTransaction {
+                actions: transaction.actions.clone(),
+            },
+        };
+        SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+        let event = ProposalEvent::new(
+            ProposalEventType::Execute,
+            settings,
+            proposal,
+            None,
+            Some(signer),
+            None,
+        );
+        SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)?;
+
         Ok(())
     }
 }
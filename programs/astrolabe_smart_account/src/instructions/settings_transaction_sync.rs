@@ -30,7 +30,11 @@ pub struct SyncSettingsTransaction<'info> {
     pub system_program: Option<Program<'info, System>>,
     // `remaining_accounts` must include the following accounts in the exact order:
     // 1. The amount of signers specified in `num_signers`
-    // 2. Any SpendingLimit accounts that need to be initialized/closed based on actions
+    //    Passkey signers are passed as their writable `Passkey` accounts.
+    // 2. Any SpendingLimit accounts that need to be initialized/closed based on actions,
+    //    and the settings `rent_collector` if any is closed
+    // 3. The instructions sysvar if any of the signers is a passkey
+    // 4. The `SessionKey` accounts of any signers that are session keys
     pub program: Program<'info, AstrolabeSmartAccount>,
 }
 
@@ -52,8 +56,21 @@ impl<'info> SyncSettingsTransaction<'info> {
         // Validates the proposed settings changes
         validate_settings_actions(&args.actions)?;
 
+        // The actions must not be time locked
+        require_eq!(
+            settings.actions_time_lock(&args.actions),
+            0,
+            SmartAccountError::TimeLockNotZero
+        );
+
         // Validates synchronous consensus across the signers
-        validate_synchronous_consensus(settings, args.num_signers, remaining_accounts)?;
+        validate_synchronous_consensus(settings, args.num_signers, remaining_accounts, None)?;
+
+        // Session keys among the signers must be allowed to invoke this program
+        let signers = &remaining_accounts[..usize::from(args.num_signers)];
+        for (_, session_key) in find_consensus_session_keys(settings, signers, remaining_accounts) {
+            session_key.check_program(&crate::ID)?;
+        }
 
         Ok(())
     }
@@ -80,10 +97,10 @@ impl<'info> SyncSettingsTransaction<'info> {
             )?;
         }
 
-        // Make sure the smart account can fit the updated state: added signers or newly set archival_authority.
+        // Make sure the smart account can fit the updated state: added signers, restricted signers, target time locks or newly set archival_authority.
         Settings::realloc_if_needed(
             settings.to_account_info(),
-            settings.signers.len(),
+            settings,
             ctx.accounts
                 .fee_payer
                 .as_ref()
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::{
+    errors::*, program::AstrolabeSmartAccount, state::*, utils::*, LogAuthorityInfo,
+    SmartAccountEvent, UnarchiveSettingsEvent,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UnarchiveSettingsArgs {
+    /// Borsh-serialized `Settings` (without the account discriminator),
+    /// must be the preimage of the `settings_hash` committed to on archival.
+    pub settings_data: Vec<u8>,
+}
+
+#[derive(Accounts)]
+pub struct UnarchiveSettings<'info> {
+    /// The settings account to restore.
+    /// CHECK: Tied to `archived_settings` by its seeds, and can only be created by
+    /// signing with the seeds of the archived settings.
+    #[account(mut)]
+    pub settings: AccountInfo<'info>,
+
+    /// The record keeping the hash commitment of the archived settings.
+    /// It is closed and its rent is returned to `fee_payer`.
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, settings.key().as_ref(), SEED_ARCHIVED_SETTINGS],
+        bump = archived_settings.bump,
+        close = fee_payer,
+    )]
+    pub archived_settings: Account<'info, ArchivedSettings>,
+
+    /// The payer for the restored settings account rent.
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub program: Program<'info, AstrolabeSmartAccount>,
+}
+
+impl UnarchiveSettings<'_> {
+    fn validate(&self, args: &UnarchiveSettingsArgs) -> Result<()> {
+        // settings_data
+        require!(
+            hash(&args.settings_data).to_bytes() == self.archived_settings.settings_hash,
+            SmartAccountError::ArchivedSettingsHashMismatch
+        );
+
+        Ok(())
+    }
+
+    /// Restore an archived settings account from the preimage of its hash commitment.
+    /// Anyone can unarchive a smart account, the archival cooldown prevents it from
+    /// being archived again right away.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn unarchive_settings(ctx: Context<Self>, args: UnarchiveSettingsArgs) -> Result<()> {
+        let settings_info = &ctx.accounts.settings;
+        let fee_payer = ctx.accounts.fee_payer.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+
+        let mut settings = Settings::deserialize(&mut args.settings_data.as_slice())?;
+
+        // Prevent the archival authority from archiving the smart account again right away.
+        let now = u64::try_from(Clock::get()?.unix_timestamp).unwrap();
+        settings.archivable_after = now.checked_add(ARCHIVAL_COOLDOWN).unwrap();
+
+        settings.invariant()?;
+
+        // Re-create the settings account with the same space it had when archived.
+        let mut settings_seeds = get_settings_signer_seeds(settings.seed);
+        settings_seeds.push(vec![settings.bump]);
+        create_account(
+            &fee_payer,
+            settings_info,
+            &system_program,
+            &crate::ID,
+            &Rent::get()?,
+            usize::try_from(ctx.accounts.archived_settings.settings_space).unwrap(),
+            settings_seeds,
+        )?;
+        settings.try_serialize(&mut &mut settings_info.try_borrow_mut_data()?[..])?;
+
+        // Log the event
+        let event = UnarchiveSettingsEvent {
+            settings_pubkey: settings_info.key(),
+            archived_settings_pubkey: ctx.accounts.archived_settings.key(),
+            settings: settings.clone(),
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings_info.clone(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::UnarchiveSettingsEvent(event).log(&log_authority_info)?;
+
+        Ok(())
+    }
+}
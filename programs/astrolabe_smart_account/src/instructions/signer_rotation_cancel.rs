@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CancelSignerRotationArgs {
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct CancelSignerRotation<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SIGNER_ROTATION,
+            signer_rotation.signer.as_ref(),
+        ],
+        bump = signer_rotation.bump,
+        close = rent_collector,
+    )]
+    pub signer_rotation: Account<'info, SignerRotation>,
+
+    /// A current signer of the smart account.
+    pub signer: Signer<'info>,
+
+    /// The rent collector.
+    /// CHECK: We only need to validate the address.
+    #[account(
+        mut,
+        address = signer_rotation.rent_collector @ SmartAccountError::InvalidRentCollector,
+    )]
+    pub rent_collector: AccountInfo<'info>,
+}
+
+impl CancelSignerRotation<'_> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            settings, signer, ..
+        } = self;
+
+        // signer
+        require!(
+            settings.is_signer(signer.key()).is_some(),
+            SmartAccountError::NotASigner
+        );
+
+        Ok(())
+    }
+
+    /// Cancel a pending signer rotation on behalf of a current signer of the smart account.
+    /// The signer rotation account is closed, so the rotation can no longer be finalized.
+    #[access_control(ctx.accounts.validate())]
+    pub fn cancel_signer_rotation(
+        ctx: Context<Self>,
+        _args: CancelSignerRotationArgs,
+    ) -> Result<()> {
+        // Anchor will close the `signer_rotation` account for us.
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::program::AstrolabeSmartAccount;
+use crate::state::*;
+use crate::{AuthoritySettingsEvent, LogAuthorityInfo, SmartAccountEvent};
+
+#[derive(Accounts)]
+pub struct FinalizeSignerRotation<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SIGNER_ROTATION,
+            signer_rotation.signer.as_ref(),
+        ],
+        bump = signer_rotation.bump,
+        close = rent_collector,
+    )]
+    pub signer_rotation: Account<'info, SignerRotation>,
+
+    /// The rent collector.
+    /// CHECK: We only need to validate the address.
+    #[account(
+        mut,
+        address = signer_rotation.rent_collector @ SmartAccountError::InvalidRentCollector,
+    )]
+    pub rent_collector: AccountInfo<'info>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+}
+
+impl FinalizeSignerRotation<'_> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            settings,
+            signer_rotation,
+            ..
+        } = self;
+
+        // settings
+        let signer_rotation_delay = settings
+            .signer_rotation_delay
+            .ok_or(SmartAccountError::SignerRotationDisabled)?;
+
+        // signer_rotation
+        require!(
+            Clock::get()?.unix_timestamp - signer_rotation.requested_at
+                >= i64::from(signer_rotation_delay),
+            SmartAccountError::SignerRotationDelayNotElapsed
+        );
+
+        Ok(())
+    }
+
+    /// Finalize a signer rotation once the delay has passed, replacing the key of the signer
+    /// while keeping its permissions and weight. Anyone can finalize a signer rotation.
+    ///
+    /// NOTE: Vault policies and spending limits that list the old key are not updated.
+    #[access_control(ctx.accounts.validate())]
+    pub fn finalize_signer_rotation(ctx: Context<Self>) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+        let signer_rotation = &ctx.accounts.signer_rotation;
+
+        settings.rotate_signer(signer_rotation.signer, signer_rotation.new_signer)?;
+
+        // Transactions approved by the old key must not be executable anymore.
+        settings.invalidate_prior_transactions();
+
+        settings.invariant()?;
+
+        // Log the event, the rotated signer is the authority of the change.
+        let event = AuthoritySettingsEvent {
+            settings: (***settings).clone(),
+            settings_pubkey: settings.key(),
+            authority: signer_rotation.signer,
+            change: SettingsAction::RotateSigner {
+                old_signer: signer_rotation.signer,
+                new_signer: signer_rotation.new_signer,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+
+        // Anchor will close the `signer_rotation` account for us.
+        Ok(())
+    }
+}
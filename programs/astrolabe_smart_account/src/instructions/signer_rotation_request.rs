@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RequestSignerRotationArgs {
+    /// The key replacing the key of the signer.
+    pub new_signer: Pubkey,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct RequestSignerRotation<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        init,
+        payer = fee_payer,
+        space = 8 + SignerRotation::INIT_SPACE,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SIGNER_ROTATION,
+            signer.key().as_ref(),
+        ],
+        bump
+    )]
+    pub signer_rotation: Account<'info, SignerRotation>,
+
+    /// The signer rotating its own key.
+    pub signer: Signer<'info>,
+
+    /// The payer for the signer rotation account rent.
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl RequestSignerRotation<'_> {
+    fn validate(&self, args: &RequestSignerRotationArgs) -> Result<()> {
+        let Self {
+            settings, signer, ..
+        } = self;
+
+        // settings
+        require!(
+            settings.signer_rotation_delay.is_some(),
+            SmartAccountError::SignerRotationDisabled
+        );
+
+        // signer
+        require!(
+            settings.is_signer(signer.key()).is_some(),
+            SmartAccountError::NotASigner
+        );
+
+        // new_signer
+        require!(
+            args.new_signer != Pubkey::default(),
+            SmartAccountError::InvalidAccount
+        );
+        require!(
+            settings.is_signer(args.new_signer).is_none(),
+            SmartAccountError::DuplicateSigner
+        );
+
+        Ok(())
+    }
+
+    /// Request the rotation of the key of the `signer`, which can be finalized
+    /// once the `signer_rotation_delay` of the settings has passed.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn request_signer_rotation(
+        ctx: Context<Self>,
+        args: RequestSignerRotationArgs,
+    ) -> Result<()> {
+        let signer_rotation = &mut ctx.accounts.signer_rotation;
+
+        signer_rotation.settings = ctx.accounts.settings.key();
+        signer_rotation.signer = ctx.accounts.signer.key();
+        signer_rotation.new_signer = args.new_signer;
+        signer_rotation.requested_at = Clock::get()?.unix_timestamp;
+        signer_rotation.rent_collector = ctx.accounts.fee_payer.key();
+        signer_rotation.bump = ctx.bumps.signer_rotation;
+
+        Ok(())
+    }
+}
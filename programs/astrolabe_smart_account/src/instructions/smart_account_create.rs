@@ -4,6 +4,7 @@ use account_events::CreateSmartAccountEvent;
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
 use crate::errors::SmartAccountError;
 use crate::events::*;
@@ -28,6 +29,9 @@ pub struct CreateSmartAccountArgs {
     pub rent_collector: Option<Pubkey>,
     /// Memo is used for indexing only.
     pub memo: Option<String>,
+    /// The partner that referred the creation of the smart account, who gets the
+    /// `referral_fee_bps` share of the creation fees.
+    pub referrer: Option<Pubkey>,
 }
 
 #[derive(Accounts)]
@@ -65,10 +69,51 @@ pub struct CreateSmartAccount<'info> {
 
     pub system_program: Program<'info, System>,
     pub program: Program<'info, AstrolabeSmartAccount>,
+
+    /// The referrer the referral share of the creation fee is transferred to.
+    /// CHECK: validation is performed in the `CreateSmartAccount::validate()` method.
+    #[account(mut)]
+    pub referrer_account: Option<AccountInfo<'info>>,
+
+    /// The mint of the creation token fee in case `program_config.smart_account_creation_token_fee` is set.
+    pub creation_fee_mint: Option<Account<'info, Mint>>,
+
+    /// The token account the creation token fee is paid from.
+    #[account(
+        mut,
+        token::mint = creation_fee_mint,
+        token::authority = fee_payer,
+    )]
+    pub fee_payer_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// The treasury token account the creation token fee is transferred to.
+    #[account(
+        mut,
+        token::mint = creation_fee_mint,
+        token::authority = treasury,
+    )]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// The referrer token account the referral share of the creation token fee is transferred to.
+    /// Its owner is validated in the `CreateSmartAccount::validate()` method.
+    #[account(
+        mut,
+        token::mint = creation_fee_mint,
+    )]
+    pub referrer_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// In case `program_config.smart_account_creation_token_fee` is set.
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// The allowlist page the `creator` is on, in case `program_config.smart_account_creation_mode` is `Allowlist`.
+    pub creator_allowlist: Option<Account<'info, CreatorAllowlistPage>>,
+
+    /// The program config authority, in case `program_config.smart_account_creation_mode` is `Cosigned`.
+    pub program_config_authority: Option<Signer<'info>>,
 }
 
 impl<'info> CreateSmartAccount<'info> {
-    fn validate(&self) -> Result<()> {
+    fn validate(&self, args: &CreateSmartAccountArgs) -> Result<()> {
         //region treasury
         require_keys_eq!(
             self.treasury.key(),
@@ -77,11 +122,46 @@ impl<'info> CreateSmartAccount<'info> {
         );
         //endregion
 
+        //region creation mode
+        match self.program_config.smart_account_creation_mode {
+            SmartAccountCreationMode::Open => {}
+            SmartAccountCreationMode::Allowlist => {
+                let is_allowlisted = self
+                    .creator_allowlist
+                    .as_ref()
+                    .is_some_and(|page| page.contains(&self.creator.key()));
+                require!(is_allowlisted, SmartAccountError::CreatorNotAllowlisted);
+            }
+            SmartAccountCreationMode::Cosigned => {
+                let is_cosigned = self
+                    .program_config_authority
+                    .as_ref()
+                    .is_some_and(|authority| authority.key() == self.program_config.authority);
+                require!(is_cosigned, SmartAccountError::CreationNotCosigned);
+            }
+        }
+        //endregion
+
+        //region referrer
+        if let Some(referrer_account) = &self.referrer_account {
+            require!(
+                args.referrer == Some(referrer_account.key()),
+                SmartAccountError::InvalidReferrer
+            );
+        }
+        if let Some(referrer_token_account) = &self.referrer_token_account {
+            require!(
+                args.referrer == Some(referrer_token_account.owner),
+                SmartAccountError::InvalidReferrer
+            );
+        }
+        //endregion
+
         Ok(())
     }
 
     /// Creates a multisig.
-    #[access_control(ctx.accounts.validate())]
+    #[access_control(ctx.accounts.validate(&args))]
     pub fn create_smart_account(
         ctx: Context<'_, '_, 'info, 'info, Self>,
         args: CreateSmartAccountArgs,
@@ -102,11 +182,20 @@ impl<'info> CreateSmartAccount<'info> {
         settings.time_lock = args.time_lock;
         settings.transaction_index = 0;
         settings.stale_transaction_index = 0;
+        settings.rent_collector = args.rent_collector;
         settings.archival_authority = Some(Pubkey::default());
         settings.archivable_after = 0;
+        settings.recovery_threshold = 0;
+        settings.recovery_delay = 0;
+        settings.vault_policies = [0; 32];
+        settings.execution_window = 0;
+        settings.signer_rotation_delay = None;
+        settings.program_filter_mode = ProgramFilterMode::Disabled;
         settings.bump = ctx.bumps.settings;
         settings.signers = signers;
         settings.restricted_signers = restricted_signers;
+        settings.target_time_locks = vec![];
+        settings.program_filters = vec![];
         settings.account_utilization = 0;
         settings._reserved1 = 0;
         settings._reserved2 = 0;
@@ -114,9 +203,16 @@ impl<'info> CreateSmartAccount<'info> {
         settings.invariant()?;
 
         // Check if the creation fee is set and transfer the fee to the treasury if necessary.
+        // The referrer, if any, gets its share of the fee instead of the treasury.
         let creation_fee = program_config.smart_account_creation_fee;
 
         if creation_fee > 0 {
+            let referral_fee = if args.referrer.is_some() {
+                program_config.referral_fee(creation_fee)
+            } else {
+                0
+            };
+
             system_program::transfer(
                 CpiContext::new(
                     ctx.accounts.system_program.to_account_info(),
@@ -125,11 +221,101 @@ impl<'info> CreateSmartAccount<'info> {
                         to: ctx.accounts.treasury.to_account_info(),
                     },
                 ),
-                creation_fee,
+                creation_fee - referral_fee,
             )?;
+
+            if referral_fee > 0 {
+                let referrer_account = ctx
+                    .accounts
+                    .referrer_account
+                    .as_ref()
+                    .ok_or(SmartAccountError::MissingAccount)?;
+
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.fee_payer.to_account_info(),
+                            to: referrer_account.clone(),
+                        },
+                    ),
+                    referral_fee,
+                )?;
+            }
             msg!("Creation fee: {}", creation_fee / LAMPORTS_PER_SOL);
         }
 
+        // Same for the token fee.
+        let creation_token_fee = program_config.smart_account_creation_token_fee;
+
+        if creation_token_fee > 0 {
+            let creation_fee_mint = ctx
+                .accounts
+                .creation_fee_mint
+                .as_ref()
+                .ok_or(SmartAccountError::MissingAccount)?;
+            let fee_payer_token_account = ctx
+                .accounts
+                .fee_payer_token_account
+                .as_ref()
+                .ok_or(SmartAccountError::MissingAccount)?;
+            let treasury_token_account = ctx
+                .accounts
+                .treasury_token_account
+                .as_ref()
+                .ok_or(SmartAccountError::MissingAccount)?;
+            let token_program = ctx
+                .accounts
+                .token_program
+                .as_ref()
+                .ok_or(SmartAccountError::MissingAccount)?;
+
+            // The mint must match the one the fee is charged in.
+            require_keys_eq!(
+                creation_fee_mint.key(),
+                program_config.creation_fee_mint,
+                SmartAccountError::InvalidMint
+            );
+
+            let referral_fee = if args.referrer.is_some() {
+                program_config.referral_fee(creation_token_fee)
+            } else {
+                0
+            };
+
+            let transfer_fee = |to: AccountInfo<'info>, amount: u64| {
+                token::transfer_checked(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        token::TransferChecked {
+                            from: fee_payer_token_account.to_account_info(),
+                            mint: creation_fee_mint.to_account_info(),
+                            to,
+                            authority: ctx.accounts.fee_payer.to_account_info(),
+                        },
+                    ),
+                    amount,
+                    creation_fee_mint.decimals,
+                )
+            };
+
+            transfer_fee(
+                treasury_token_account.to_account_info(),
+                creation_token_fee - referral_fee,
+            )?;
+
+            if referral_fee > 0 {
+                let referrer_token_account = ctx
+                    .accounts
+                    .referrer_token_account
+                    .as_ref()
+                    .ok_or(SmartAccountError::MissingAccount)?;
+
+                transfer_fee(referrer_token_account.to_account_info(), referral_fee)?;
+            }
+            msg!("Creation token fee: {}", creation_token_fee);
+        }
+
         // Increment the smart account index.
         program_config.increment_smart_account_index()?;
 
@@ -0,0 +1,269 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::{
+    errors::*, program::AstrolabeSmartAccount, state::*, LogAuthorityInfo, SmartAccountEvent,
+    UseSpendingLimitEvent,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UseSpendingLimitArgs {
+    /// Amount of tokens to transfer.
+    pub amount: u64,
+    /// Decimals of the token mint. Used for double-checking against incorrect order of magnitude of `amount`.
+    pub decimals: u8,
+    /// Memo used for indexing.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct UseSpendingLimit<'info> {
+    /// The settings the `spending_limit` belongs to.
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    pub signer: Signer<'info>,
+
+    /// The SpendingLimit account to use.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SPENDING_LIMIT,
+            spending_limit.seed.as_ref(),
+        ],
+        bump = spending_limit.bump,
+    )]
+    pub spending_limit: Account<'info, SpendingLimit>,
+
+    /// Smart account to transfer tokens from.
+    /// CHECK: All the required checks are done by checking the seeds.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SMART_ACCOUNT,
+            &spending_limit.account_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub smart_account: AccountInfo<'info>,
+
+    /// Destination account to transfer tokens to.
+    /// CHECK: We do the checks in `UseSpendingLimit::validate`.
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+
+    /// In case `spending_limit.mint` is SOL.
+    pub system_program: Option<Program<'info, System>>,
+
+    /// The mint of the tokens to transfer in case `spending_limit.mint` is an SPL token.
+    pub mint: Option<Account<'info, Mint>>,
+
+    /// Smart account token account to transfer tokens from in case `spending_limit.mint` is an SPL token.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = smart_account,
+    )]
+    pub smart_account_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Destination token account in case `spending_limit.mint` is an SPL token.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = destination,
+    )]
+    pub destination_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// In case `spending_limit.mint` is an SPL token.
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+
+    /// The policy of the smart account the spending limit is for, required if it has one.
+    /// Only its signers can use the spending limit. Its threshold and time lock don't apply,
+    /// the spending limit having been approved by the settings consensus that added it.
+    pub vault_policy: Option<Account<'info, VaultPolicy>>,
+}
+
+impl UseSpendingLimit<'_> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            settings,
+            signer,
+            spending_limit,
+            destination,
+            vault_policy,
+            ..
+        } = self;
+
+        // signer
+        require!(
+            settings.is_signer(signer.key()).is_some(),
+            SmartAccountError::NotASigner
+        );
+        // We don't check the signer permissions here because the spending limit is scoped to specific signers.
+        require!(
+            spending_limit.is_signer(signer.key()).is_some(),
+            SmartAccountError::Unauthorized
+        );
+
+        // spending_limit - needs no checking.
+
+        // vault_policy
+        if let Some(vault_policy) = VaultPolicy::load(
+            settings,
+            &settings.key(),
+            spending_limit.account_index,
+            vault_policy.as_deref(),
+        )? {
+            require!(
+                vault_policy.is_signer(signer.key()),
+                SmartAccountError::Unauthorized
+            );
+        }
+
+        // destination
+        if !spending_limit.destinations.is_empty() {
+            require!(
+                spending_limit.destinations.contains(destination.key),
+                SmartAccountError::InvalidDestination
+            );
+        }
+
+        // expiration
+        if spending_limit.expiration != i64::MAX {
+            require!(
+                Clock::get()?.unix_timestamp < spending_limit.expiration,
+                SmartAccountError::SpendingLimitExpired
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Use a spending limit to transfer tokens from a smart account to a destination account.
+    #[access_control(ctx.accounts.validate())]
+    pub fn use_spending_limit(ctx: Context<Self>, args: UseSpendingLimitArgs) -> Result<()> {
+        let spending_limit = &mut ctx.accounts.spending_limit;
+        let smart_account = &mut ctx.accounts.smart_account;
+        let destination = &mut ctx.accounts.destination;
+
+        let settings_key = ctx.accounts.settings.key();
+        let smart_account_bump = ctx.bumps.smart_account;
+        let now = Clock::get()?.unix_timestamp;
+
+        // Reset `spending_limit.remaining_amount` if the `spending_limit.period` has passed.
+        spending_limit.reset_if_needed(now);
+
+        // Update `spending_limit.remaining_amount`.
+        // This will also check if `amount` doesn't exceed `spending_limit.remaining_amount`.
+        spending_limit.remaining_amount = spending_limit
+            .remaining_amount
+            .checked_sub(args.amount)
+            .ok_or(SmartAccountError::SpendingLimitExceeded)?;
+
+        let smart_account_signer_seeds: &[&[u8]] = &[
+            SEED_PREFIX,
+            settings_key.as_ref(),
+            SEED_SMART_ACCOUNT,
+            &spending_limit.account_index.to_le_bytes(),
+            &[smart_account_bump],
+        ];
+
+        // Transfer tokens.
+        if spending_limit.mint == Pubkey::default() {
+            // Transfer SOL.
+            let system_program = &ctx
+                .accounts
+                .system_program
+                .as_ref()
+                .ok_or(SmartAccountError::MissingAccount)?;
+
+            // Amount must have 9 decimals for SOL.
+            require_eq!(args.decimals, 9, SmartAccountError::DecimalsMismatch);
+
+            anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: smart_account.clone(),
+                        to: destination.clone(),
+                    },
+                    &[smart_account_signer_seeds],
+                ),
+                args.amount,
+            )?
+        } else {
+            // Transfer SPL token.
+            let mint = &ctx
+                .accounts
+                .mint
+                .as_ref()
+                .ok_or(SmartAccountError::MissingAccount)?;
+            let smart_account_token_account = &ctx
+                .accounts
+                .smart_account_token_account
+                .as_ref()
+                .ok_or(SmartAccountError::MissingAccount)?;
+            let destination_token_account = &ctx
+                .accounts
+                .destination_token_account
+                .as_ref()
+                .ok_or(SmartAccountError::MissingAccount)?;
+            let token_program = &ctx
+                .accounts
+                .token_program
+                .as_ref()
+                .ok_or(SmartAccountError::MissingAccount)?;
+
+            // The mint must match the one the spending limit is for.
+            require_keys_eq!(
+                mint.key(),
+                spending_limit.mint,
+                SmartAccountError::InvalidMint
+            );
+
+            token::transfer_checked(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token::TransferChecked {
+                        from: smart_account_token_account.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: destination_token_account.to_account_info(),
+                        authority: smart_account.clone(),
+                    },
+                    &[smart_account_signer_seeds],
+                ),
+                args.amount,
+                args.decimals,
+            )?;
+        }
+
+        // Log the event
+        let event = UseSpendingLimitEvent {
+            settings_pubkey: settings_key,
+            spending_limit_pubkey: spending_limit.key(),
+            signer: ctx.accounts.signer.key(),
+            destination: destination.key(),
+            mint: spending_limit.mint,
+            amount: args.amount,
+            remaining_amount: spending_limit.remaining_amount,
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: ctx.accounts.settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(ctx.accounts.settings.seed),
+            bump: ctx.accounts.settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::UseSpendingLimitEvent(event).log(&log_authority_info)?;
+
+        Ok(())
+    }
+}
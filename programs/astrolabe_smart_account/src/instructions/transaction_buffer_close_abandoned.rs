@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CloseAbandonedTransactionBuffer<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Account<'info, Settings>,
+
+    #[account(
+        mut,
+        // Rent gets returned to the account that paid for it
+        close = rent_payer,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            transaction_buffer.creator.as_ref(),
+            &transaction_buffer.buffer_index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub transaction_buffer: Account<'info, TransactionBuffer>,
+
+    /// The signer on the smart account that is closing the TransactionBuffer.
+    pub closer: Signer<'info>,
+
+    /// The account that paid the rent for the TransactionBuffer.
+    /// CHECK: validated by the `address` constraint.
+    #[account(mut, address = transaction_buffer.rent_payer)]
+    pub rent_payer: AccountInfo<'info>,
+}
+
+impl CloseAbandonedTransactionBuffer<'_> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            settings,
+            transaction_buffer,
+            closer,
+            ..
+        } = self;
+
+        // closer
+        require!(
+            settings.is_signer(closer.key()).is_some(),
+            SmartAccountError::NotASigner
+        );
+        require!(
+            settings.signer_has_permission(closer.key(), Permission::Initiate),
+            SmartAccountError::Unauthorized
+        );
+
+        // transaction_buffer
+        require!(
+            transaction_buffer.is_abandoned(settings, Clock::get()?.unix_timestamp),
+            SmartAccountError::TransactionBufferNotAbandoned
+        );
+
+        Ok(())
+    }
+
+    /// Close a transaction buffer account that timed out or went stale.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_abandoned_transaction_buffer(ctx: Context<Self>) -> Result<()> {
+        Ok(())
+    }
+}
@@ -74,7 +74,7 @@ impl CreateTransactionBuffer<'_> {
             SmartAccountError::Unauthorized
         );
 
-        // Final Buffer Size must not exceed 4000 bytes
+        // Final Buffer Size must not exceed MAX_BUFFER_SIZE bytes
         require!(
             args.final_buffer_size as usize <= MAX_BUFFER_SIZE,
             SmartAccountError::FinalBufferSizeExceeded
@@ -94,6 +94,7 @@ impl CreateTransactionBuffer<'_> {
         let transaction_buffer = &mut ctx.accounts.transaction_buffer;
         let settings = &ctx.accounts.settings;
         let buffer_creator = &mut ctx.accounts.buffer_creator;
+        let fee_payer = &ctx.accounts.fee_payer;
 
         // Get the buffer index.
         let buffer_index = args.buffer_index;
@@ -101,6 +102,9 @@ impl CreateTransactionBuffer<'_> {
         // Initialize the transaction fields.
         transaction_buffer.settings = settings.key();
         transaction_buffer.creator = buffer_creator.key();
+        transaction_buffer.rent_payer = fee_payer.key();
+        transaction_buffer.created_at = Clock::get()?.unix_timestamp;
+        transaction_buffer.transaction_index = settings.transaction_index;
         transaction_buffer.buffer_index = buffer_index;
         transaction_buffer.account_index = args.account_index;
         transaction_buffer.final_buffer_hash = args.final_buffer_hash;
@@ -12,6 +12,8 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -59,13 +61,27 @@ pub struct CloseSettingsTransaction<'info> {
     pub transaction_rent_collector: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
 }
 
 impl CloseSettingsTransaction<'_> {
+    fn validate(&self) -> Result<()> {
+        // Rent reclamation must be enabled. The rent goes to the `rent_collector`s
+        // the accounts were created with, which are checked separately.
+        require!(
+            self.settings.rent_collector.is_some(),
+            SmartAccountError::RentReclamationDisabled
+        );
+
+        Ok(())
+    }
+
     /// Closes a `SettingsTransaction` and the corresponding `Proposal`.
     /// `transaction` can be closed if either:
     /// - the `proposal` is in a terminal state: `Executed`, `Rejected`, or `Cancelled`.
     /// - the `proposal` is stale.
+    #[access_control(ctx.accounts.validate())]
     pub fn close_settings_transaction(ctx: Context<Self>) -> Result<()> {
         let settings = &ctx.accounts.settings;
         let transaction = &ctx.accounts.transaction;
@@ -100,6 +116,8 @@ impl CloseSettingsTransaction<'_> {
                 ProposalStatus::Executed { .. } => true,
                 // Cancelled proposals can be closed.
                 ProposalStatus::Cancelled { .. } => true,
+                // Expired proposals can be closed.
+                ProposalStatus::Expired { .. } => true,
             }
         } else {
             // If no Proposal account exists then the ConfigTransaction can only be closed if stale
@@ -115,6 +133,26 @@ impl CloseSettingsTransaction<'_> {
             proposal_rent_collector.clone(),
         )?;
 
+        // Log the event
+        let event = TransactionEvent {
+            event_type: TransactionEventType::Close,
+            settings_pubkey: settings.key(),
+            transaction_pubkey: transaction.key(),
+            transaction_index: transaction.index,
+            signer: None,
+            memo: None,
+            transaction_content: TransactionContent::SettingsTransaction {
+                actions: transaction.actions.clone(),
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+
         // Anchor will close the `transaction` account for us.
         Ok(())
     }
@@ -165,13 +203,26 @@ pub struct CloseTransaction<'info> {
     pub transaction_rent_collector: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
 }
 
 impl CloseTransaction<'_> {
+    fn validate(&self) -> Result<()> {
+        // Rent reclamation must be enabled.
+        require!(
+            self.settings.rent_collector.is_some(),
+            SmartAccountError::RentReclamationDisabled
+        );
+
+        Ok(())
+    }
+
     /// Closes a `Transaction` and the corresponding `Proposal`.
     /// `transaction` can be closed if either:
     /// - the `proposal` is in a terminal state: `Executed`, `Rejected`, or `Cancelled`.
     /// - the `proposal` is stale and not `Approved`.
+    #[access_control(ctx.accounts.validate())]
     pub fn close_transaction(ctx: Context<Self>) -> Result<()> {
         let settings = &ctx.accounts.settings;
         let transaction = &ctx.accounts.transaction;
@@ -206,6 +257,8 @@ impl CloseTransaction<'_> {
                 ProposalStatus::Executed { .. } => true,
                 // Cancelled proposals can be closed.
                 ProposalStatus::Cancelled { .. } => true,
+                // Expired proposals can be closed.
+                ProposalStatus::Expired { .. } => true,
             }
         } else {
             // If no Proposal account exists then the VaultTransaction can only be closed if stale
@@ -221,6 +274,27 @@ impl CloseTransaction<'_> {
             proposal_rent_collector.clone(),
         )?;
 
+        // Log the event
+        let event = TransactionEvent {
+            event_type: TransactionEventType::Close,
+            settings_pubkey: settings.key(),
+            transaction_pubkey: transaction.key(),
+            transaction_index: transaction.index,
+            signer: None,
+            memo: None,
+            transaction_content: TransactionContent::Transaction {
+                account_index: transaction.account_index,
+                instructions: InstructionSummary::from_message(&transaction.message),
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+
         // Anchor will close the `transaction` account for us.
         Ok(())
     }
@@ -265,6 +339,8 @@ pub struct CloseBatchTransaction<'info> {
     pub transaction_rent_collector: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
 }
 
 impl CloseBatchTransaction<'_> {
@@ -277,6 +353,12 @@ impl CloseBatchTransaction<'_> {
             ..
         } = self;
 
+        // Rent reclamation must be enabled.
+        require!(
+            settings.rent_collector.is_some(),
+            SmartAccountError::RentReclamationDisabled
+        );
+
         // Transaction must be the last one in the batch.
         // We do it here instead of the Anchor macro because we want to throw a more specific error,
         // and the macro doesn't allow us to override the default "seeds constraint is violated" one.
@@ -323,6 +405,8 @@ impl CloseBatchTransaction<'_> {
             ProposalStatus::Executed { .. } => true,
             // Transactions of Cancelled proposals can be closed.
             ProposalStatus::Cancelled { .. } => true,
+            // Transactions of Expired proposals can be closed.
+            ProposalStatus::Expired { .. } => true,
         };
 
         require!(can_close, SmartAccountError::InvalidProposalStatus);
@@ -338,8 +422,32 @@ impl CloseBatchTransaction<'_> {
     /// - the `proposal` is stale and not `Approved`.
     #[access_control(ctx.accounts.validate())]
     pub fn close_batch_transaction(ctx: Context<Self>) -> Result<()> {
+        let settings = &ctx.accounts.settings;
+        let transaction = &ctx.accounts.transaction;
         let batch = &mut ctx.accounts.batch;
 
+        // Log the event
+        let event = TransactionEvent {
+            event_type: TransactionEventType::Close,
+            settings_pubkey: settings.key(),
+            transaction_pubkey: transaction.key(),
+            transaction_index: batch.index,
+            signer: None,
+            memo: None,
+            transaction_content: TransactionContent::BatchTransaction {
+                batch_transaction_index: batch.size,
+                outcome: batch.transaction_outcome(batch.size),
+                instructions: InstructionSummary::from_message(&transaction.message),
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+
         batch.size = batch.size.checked_sub(1).expect("overflow");
 
         // Anchor macro will close the `transaction` account for us.
@@ -396,14 +504,27 @@ pub struct CloseBatch<'info> {
     pub batch_rent_collector: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
 }
 
 impl CloseBatch<'_> {
+    fn validate(&self) -> Result<()> {
+        // Rent reclamation must be enabled.
+        require!(
+            self.settings.rent_collector.is_some(),
+            SmartAccountError::RentReclamationDisabled
+        );
+
+        Ok(())
+    }
+
     /// Closes Batch and the corresponding Proposal accounts for proposals in terminal states:
     /// `Executed`, `Rejected`, or `Cancelled` or stale proposals that aren't `Approved`.
     ///
     /// This instruction is only allowed to be executed when all `BatchTransaction` accounts
     /// in the `batch` are already closed: `batch.size == 0`.
+    #[access_control(ctx.accounts.validate())]
     pub fn close_batch(ctx: Context<Self>) -> Result<()> {
         let settings = &ctx.accounts.settings;
         let batch = &ctx.accounts.batch;
@@ -438,6 +559,8 @@ impl CloseBatch<'_> {
                 ProposalStatus::Executed { .. } => true,
                 // Cancelled proposals can be closed.
                 ProposalStatus::Cancelled { .. } => true,
+                // Expired proposals can be closed.
+                ProposalStatus::Expired { .. } => true,
             }
         } else {
             // If no Proposal account exists then the Batch can only be closed if stale
@@ -456,6 +579,27 @@ impl CloseBatch<'_> {
             proposal_rent_collector.clone(),
         )?;
 
+        // Log the event
+        let event = TransactionEvent {
+            event_type: TransactionEventType::Close,
+            settings_pubkey: settings.key(),
+            transaction_pubkey: batch.key(),
+            transaction_index: batch.index,
+            signer: None,
+            memo: None,
+            transaction_content: TransactionContent::Batch {
+                account_index: batch.account_index,
+                size: batch.size,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+
         // Anchor will close the `batch` account for us.
         Ok(())
     }
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
 use crate::state::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
@@ -46,12 +48,20 @@ pub struct CreateTransaction<'info> {
     pub fee_payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    pub vault_policy: Option<Account<'info, VaultPolicy>>,
 }
 
 impl<'info> CreateTransaction<'info> {
-    pub fn validate(&self) -> Result<()> {
+    pub fn validate(&self, args: &CreateTransactionArgs) -> Result<()> {
         let Self {
-            settings, creator, ..
+            settings,
+            creator,
+            vault_policy,
+            ..
         } = self;
 
         // creator
@@ -64,11 +74,24 @@ impl<'info> CreateTransaction<'info> {
             SmartAccountError::Unauthorized
         );
 
+        // vault_policy
+        if let Some(vault_policy) = VaultPolicy::load(
+            settings,
+            &settings.key(),
+            args.account_index,
+            vault_policy.as_deref(),
+        )? {
+            require!(
+                vault_policy.is_signer(creator.key()),
+                SmartAccountError::Unauthorized
+            );
+        }
+
         Ok(())
     }
 
     /// Create a new vault transaction.
-    #[access_control(ctx.accounts.validate())]
+    #[access_control(ctx.accounts.validate(&args))]
     pub fn create_transaction(ctx: Context<Self>, args: CreateTransactionArgs) -> Result<()> {
         let settings = &mut ctx.accounts.settings;
         let transaction = &mut ctx.accounts.transaction;
@@ -111,13 +134,14 @@ impl<'info> CreateTransaction<'info> {
         // Initialize the transaction fields.
         transaction.settings = settings_key;
         transaction.creator = creator.key();
-        transaction.rent_collector = fee_payer.key();
+        transaction.rent_collector = settings.rent_collector.unwrap_or(fee_payer.key());
         transaction.index = transaction_index;
         transaction.bump = ctx.bumps.transaction;
         transaction.account_index = args.account_index;
         transaction.account_bump = args.account_bump;
         transaction.ephemeral_signer_bumps = ephemeral_signer_bumps;
         transaction.message = transaction_message.try_into()?;
+        transaction.time_lock = settings.message_time_lock(&transaction.message);
 
         // Updated last transaction index in the settings account.
         settings.transaction_index = transaction_index;
@@ -127,6 +151,27 @@ impl<'info> CreateTransaction<'info> {
         // Logs for indexing.
         msg!("transaction index: {}", transaction_index);
 
+        // Log the event
+        let event = TransactionEvent {
+            event_type: TransactionEventType::Create,
+            settings_pubkey: settings_key,
+            transaction_pubkey: transaction_key,
+            transaction_index,
+            signer: Some(creator.key()),
+            memo: args.memo,
+            transaction_content: TransactionContent::Transaction {
+                account_index: transaction.account_index,
+                instructions: InstructionSummary::from_message(&transaction.message),
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+
         Ok(())
     }
 }
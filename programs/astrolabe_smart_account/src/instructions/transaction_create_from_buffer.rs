@@ -2,6 +2,7 @@ use crate::errors::*;
 use crate::instructions::*;
 use crate::state::*;
 use anchor_lang::{prelude::*, system_program};
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
 use anchor_lang::solana_program::hash::hash;
 
 #[derive(Accounts)]
@@ -40,9 +41,10 @@ impl<'info> CreateTransactionFromBuffer<'info> {
         let transaction_buffer_account = &self.transaction_buffer;
         let from_buffer_creator = &self.from_buffer_creator;
 
-        // Accept either an empty args.transaction_message (old clients)
-        // or a populated one that matches the buffer (new clients provide
-        // bytes so Anchor can size/init the account during context build).
+        // Accept either an empty args.transaction_message, required for buffers
+        // that don't fit in a Solana transaction, or a populated one that matches
+        // the buffer (clients provide bytes so Anchor can size/init the account
+        // during context build).
         if !args.transaction_message.is_empty() {
             // Size must match the declared final size
             require_eq!(
@@ -101,6 +103,12 @@ impl<'info> CreateTransactionFromBuffer<'info> {
         let new_len =
             Transaction::size(args.ephemeral_signers, transaction_buffer.buffer.as_slice())?;
 
+        // The transaction account is created by this instruction, so it can only be grown up to the realloc limit.
+        require!(
+            new_len <= MAX_PERMITTED_DATA_INCREASE,
+            SmartAccountError::TransactionSizeExceeded
+        );
+
         // Calculate the rent exemption for new length
         let rent_exempt_lamports = Rent::get().unwrap().minimum_balance(new_len).max(1);
 
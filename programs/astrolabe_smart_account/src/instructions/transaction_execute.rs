@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
+use crate::program::AstrolabeSmartAccount;
 use crate::state::*;
 use crate::utils::*;
 
@@ -44,6 +46,11 @@ pub struct ExecuteTransaction<'info> {
     pub fee_payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    pub program: Program<'info, AstrolabeSmartAccount>,
+
+    /// The policy of the smart account the transaction is for, required if it has one.
+    pub vault_policy: Option<Account<'info, VaultPolicy>>,
     // `remaining_accounts` must include the following accounts in the exact order:
     // 1. AddressLookupTable accounts in the order they appear in `message.address_table_lookups`.
     // 2. Accounts in the order they appear in `message.account_keys`.
@@ -55,10 +62,19 @@ impl<'info> ExecuteTransaction<'info> {
         let Self {
             settings,
             proposal,
+            transaction,
             signer,
+            vault_policy,
             ..
         } = self;
 
+        let vault_policy = VaultPolicy::load(
+            settings,
+            &settings.key(),
+            transaction.account_index,
+            vault_policy.as_deref(),
+        )?;
+
         // signer
         require!(
             settings.is_signer(signer.key()).is_some(),
@@ -68,15 +84,35 @@ impl<'info> ExecuteTransaction<'info> {
             settings.signer_has_permission(signer.key(), Permission::Execute),
             SmartAccountError::Unauthorized
         );
+        if let Some(vault_policy) = vault_policy {
+            require!(
+                vault_policy.is_signer(signer.key()),
+                SmartAccountError::Unauthorized
+            );
+        }
 
         // proposal
+        // Approved proposals can only be executed within the execution window of the settings.
+        require!(
+            !proposal.has_expired(settings.execution_window, Clock::get()?.unix_timestamp),
+            SmartAccountError::ProposalExpired
+        );
         match proposal.status {
-            ProposalStatus::Approved { timestamp } => {
-                require!(
-                    Clock::get()?.unix_timestamp - timestamp >= i64::from(settings.time_lock),
+            ProposalStatus::Approved { timestamp } => match vault_policy {
+                // The vault policy replaces the settings time lock.
+                // The time lock of the programs the transaction touches applies on top of either.
+                Some(vault_policy) => vault_policy.validate_execution(
+                    settings,
+                    &proposal.approved,
+                    timestamp,
+                    transaction.time_lock,
+                )?,
+                None => require!(
+                    Clock::get()?.unix_timestamp - timestamp
+                        >= i64::from(settings.time_lock.max(transaction.time_lock)),
                     SmartAccountError::TimeLockNotReleased
-                );
-            }
+                ),
+            },
             _ => return err!(SmartAccountError::InvalidProposalStatus),
         }
         // Stale transaction proposals CAN be executed if they were approved
@@ -113,6 +149,7 @@ impl<'info> ExecuteTransaction<'info> {
 
         let transaction_message = transaction.message;
         let num_lookups = transaction_message.address_table_lookups.len();
+        let instructions = InstructionSummary::from_message(&transaction_message);
 
         let message_account_infos = ctx
             .remaining_accounts
@@ -150,6 +187,7 @@ impl<'info> ExecuteTransaction<'info> {
             smart_account_seeds,
             &ephemeral_signer_seeds,
             protected_accounts,
+            settings,
         )?;
 
         // Mark the proposal as executed.
@@ -157,6 +195,37 @@ impl<'info> ExecuteTransaction<'info> {
             timestamp: Clock::get()?.unix_timestamp,
         };
 
+        // Log the events
+        let signer = ctx.accounts.signer.key();
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            program: ctx.accounts.program.to_account_info(),
+        };
+        let event = TransactionEvent {
+            event_type: TransactionEventType::Execute,
+            settings_pubkey: settings_key,
+            transaction_pubkey: transaction_key,
+            transaction_index: transaction.index,
+            signer: Some(signer),
+            memo: None,
+            transaction_content: TransactionContent::Transaction {
+                account_index: transaction.account_index,
+                instructions,
+            },
+        };
+        SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+        let event = ProposalEvent::new(
+            ProposalEventType::Execute,
+            settings,
+            proposal,
+            ctx.accounts.vault_policy.as_deref(),
+            Some(signer),
+            None,
+        );
+        SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)?;
+
         Ok(())
     }
 }
\ No newline at end of file
@@ -30,13 +30,42 @@ pub struct SyncTransaction<'info> {
     )]
     pub settings: Box<Account<'info, Settings>>,
     pub program: Program<'info, AstrolabeSmartAccount>,
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    pub vault_policy: Option<Account<'info, VaultPolicy>>,
     // `remaining_accounts` must include the following accounts in the exact order:
-    // 1. The exact amount of signers required to reach the threshold
-    // 2. Any remaining accounts associated with the instructions
+    // 1. The exact amount of signers required to reach the threshold.
+    //    Passkey signers are passed as their writable `Passkey` accounts.
+    // 2. Any remaining accounts associated with the instructions. The
+    //    `program_id_index` and `account_indexes` of the compiled instructions
+    //    index into these accounts only, the signers above are not included.
+    //    Must include the instructions sysvar if any of the signers is a passkey.
+    //    Must include the `SessionKey` account of any signer that is a session key,
+    //    writable if the session key has a lamports budget.
 }
 
 impl SyncTransaction<'_> {
-    #[access_control(validate_synchronous_consensus( &ctx.accounts.settings, args.num_signers, &ctx.remaining_accounts))]
+    fn validate(
+        &self,
+        args: &SyncTransactionArgs,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        let Self {
+            settings,
+            vault_policy,
+            ..
+        } = self;
+
+        let vault_policy = VaultPolicy::load(
+            settings,
+            &settings.key(),
+            args.account_index,
+            vault_policy.as_deref(),
+        )?;
+
+        validate_synchronous_consensus(settings, args.num_signers, remaining_accounts, vault_policy)
+    }
+
+    #[access_control(ctx.accounts.validate(&args, ctx.remaining_accounts))]
     pub fn sync_transaction(ctx: Context<Self>, args: SyncTransactionArgs) -> Result<()> {
         // Readonly Accounts
         let settings = &ctx.accounts.settings;
@@ -47,7 +76,12 @@ impl SyncTransaction<'_> {
             SmallVec::<u8, CompiledInstruction>::try_from_slice(&args.instructions)
                 .map_err(|_| SmartAccountError::InvalidInstructionArgs)?;
         // Convert to SmartAccountCompiledInstruction
-        let settings_compiled_instructions: Vec<SmartAccountCompiledInstruction> = vec![];
+        let compiled_instructions: Vec<CompiledInstruction> = compiled_instructions.into();
+        let settings_compiled_instructions: Vec<SmartAccountCompiledInstruction> =
+            compiled_instructions
+                .into_iter()
+                .map(SmartAccountCompiledInstruction::from)
+                .collect();
 
         let smart_account_seeds = &[
             SEED_PREFIX,
@@ -68,35 +102,75 @@ impl SyncTransaction<'_> {
             &[smart_account_bump],
         ];
 
+        // The signers are only used to reach consensus, the instruction indices
+        // are relative to the accounts that follow them.
+        let (signers, instruction_accounts) = ctx
+            .remaining_accounts
+            .split_at(args.num_signers as usize);
+
         let executable_message = SynchronousTransactionMessage::new_validated(
-            &settings.key(),
-            &settings,
+            &settings_key,
+            settings,
             &smart_account_pubkey,
             settings_compiled_instructions,
-            &ctx.remaining_accounts,
+            instruction_accounts,
         )?;
 
+        // The programs invoked by the message must not be time locked.
+        require_eq!(
+            settings.target_time_lock(executable_message.instructions.iter().map(|instruction| {
+                TimeLockTarget::Program {
+                    program_id: *executable_message.accounts
+                        [usize::from(instruction.program_id_index)]
+                    .key,
+                }
+            })),
+            0,
+            SmartAccountError::TimeLockNotZero
+        );
+
+        // Session keys among the signers must be allowed to invoke every program in the message.
+        let mut session_keys =
+            find_consensus_session_keys(settings, signers, ctx.remaining_accounts);
+        for (_, session_key) in session_keys.iter() {
+            for instruction in executable_message.instructions.iter() {
+                session_key.check_program(
+                    executable_message.accounts[usize::from(instruction.program_id_index)].key,
+                )?;
+            }
+        }
+
+        let smart_account_info = executable_message
+            .accounts
+            .iter()
+            .find(|account| account.key == &smart_account_pubkey);
+        let smart_account_lamports_before = smart_account_info.map_or(0, |a| a.lamports());
+
         // Execute the transaction message instructions one-by-one.
-        // NOTE: `execute_message()` calls `self.to_instructions_and_accounts()`
-        // which in turn calls `take()` on
-        // `self.message.instructions`, therefore after this point no more
-        // references or usages of `self.message` should be made to avoid
-        // faulty behavior.
-        executable_message.execute(smart_account_signer_seeds)?;
+        executable_message.execute(smart_account_signer_seeds, settings)?;
+
+        // Charge the lamports that left the smart account to the session keys budgets.
+        let smart_account_lamports_after = smart_account_info.map_or(0, |a| a.lamports());
+        let lamports_spent =
+            smart_account_lamports_before.saturating_sub(smart_account_lamports_after);
+        for (session_key_info, session_key) in session_keys.iter_mut() {
+            if session_key.max_lamports.is_some() {
+                require!(session_key_info.is_writable, ErrorCode::AccountNotMutable);
+                session_key.record_spend(lamports_spent)?;
+                session_key.try_serialize(&mut &mut session_key_info.try_borrow_mut_data()?[..])?;
+            }
+        }
 
         // Log the event
         let event = SynchronousTransactionEvent {
             settings_pubkey: settings.key(),
-            signers: ctx.remaining_accounts[..args.num_signers as usize]
-                .iter()
-                .map(|acc| acc.key.clone())
-                .collect(),
+            signers: signers.iter().map(|acc| *acc.key).collect(),
             account_index: args.account_index,
             instructions: executable_message.instructions,
             instruction_accounts: executable_message
                 .accounts
                 .iter()
-                .map(|a| a.key.clone())
+                .map(|a| *a.key)
                 .collect(),
         };
         let log_authority_info = LogAuthorityInfo {
@@ -54,15 +54,6 @@ pub mod astrolabe_smart_account {
         InitProgramConfig::init_program_config(ctx, args)
     }
 
-    /// Set the `authority` parameter of the program config.
-    #[instruction(discriminator = [2])]
-    pub fn set_program_config_authority(
-        ctx: Context<ProgramConfig>,
-        args: ProgramConfigSetAuthorityArgs,
-    ) -> Result<()> {
-        ProgramConfig::set_authority(ctx, args)
-    }
-
     /// Set the `smart_account_creation_fee` parameter of the program config.
     #[instruction(discriminator = [3])]
     pub fn set_program_config_smart_account_creation_fee(
@@ -71,15 +62,6 @@ pub mod astrolabe_smart_account {
     ) -> Result<()> {
         ProgramConfig::set_smart_account_creation_fee(ctx, args)
     }
-
-    /// Set the `treasury` parameter of the program config.
-    #[instruction(discriminator = [4])]
-    pub fn set_program_config_treasury(
-        ctx: Context<ProgramConfig>,
-        args: ProgramConfigSetTreasuryArgs,
-    ) -> Result<()> {
-        ProgramConfig::set_treasury(ctx, args)
-    }
     /// Create a smart account.
     #[instruction(discriminator = [5])]
     pub fn create_smart_account<'info>(
@@ -325,4 +307,463 @@ pub mod astrolabe_smart_account {
     pub fn log_event<'info>(ctx: Context<'_, '_, 'info, 'info, LogEvent<'info>>, args: LogEventArgs) -> Result<()> {
         LogEvent::log_event(ctx, args)
     }
+
+    /// Use a spending limit to transfer tokens from a smart account to a destination account.
+    #[instruction(discriminator = [35])]
+    pub fn use_spending_limit(
+        ctx: Context<UseSpendingLimit>,
+        args: UseSpendingLimitArgs,
+    ) -> Result<()> {
+        UseSpendingLimit::use_spending_limit(ctx, args)
+    }
+
+    /// Sweep the funds of a smart account to the exit destination of a restricted signer
+    /// with the `EmergencyExit` permission.
+    #[instruction(discriminator = [36])]
+    pub fn emergency_exit<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RestrictedEmergencyExit<'info>>,
+        args: EmergencyExitArgs,
+    ) -> Result<()> {
+        RestrictedEmergencyExit::emergency_exit(ctx, args)
+    }
+
+    /// Archive a smart account: close its settings account and record a hash commitment of its contents.
+    #[instruction(discriminator = [37])]
+    pub fn archive_settings(ctx: Context<ArchiveSettings>, args: ArchiveSettingsArgs) -> Result<()> {
+        ArchiveSettings::archive_settings(ctx, args)
+    }
+
+    /// Restore an archived smart account from the preimage of its hash commitment.
+    #[instruction(discriminator = [38])]
+    pub fn unarchive_settings(
+        ctx: Context<UnarchiveSettings>,
+        args: UnarchiveSettingsArgs,
+    ) -> Result<()> {
+        UnarchiveSettings::unarchive_settings(ctx, args)
+    }
+
+    /// Create a secp256r1 (WebAuthn) passkey account that can be added to smart accounts as a signer.
+    #[instruction(discriminator = [39])]
+    pub fn create_passkey(ctx: Context<CreatePasskey>, args: CreatePasskeyArgs) -> Result<()> {
+        CreatePasskey::create_passkey(ctx, args)
+    }
+
+    /// Approve a smart account proposal on behalf of a passkey signer.
+    /// The proposal must be `Active`.
+    #[instruction(discriminator = [40])]
+    pub fn approve_proposal_with_passkey(
+        ctx: Context<VoteOnProposalWithPasskey>,
+        args: VoteOnProposalArgs,
+    ) -> Result<()> {
+        VoteOnProposalWithPasskey::approve_proposal_with_passkey(ctx, args)
+    }
+
+    /// Reject a smart account proposal on behalf of a passkey signer.
+    /// The proposal must be `Active`.
+    #[instruction(discriminator = [41])]
+    pub fn reject_proposal_with_passkey(
+        ctx: Context<VoteOnProposalWithPasskey>,
+        args: VoteOnProposalArgs,
+    ) -> Result<()> {
+        VoteOnProposalWithPasskey::reject_proposal_with_passkey(ctx, args)
+    }
+
+    /// Cancel a smart account proposal on behalf of a passkey signer.
+    /// The proposal must be `Approved`.
+    #[instruction(discriminator = [42])]
+    pub fn cancel_proposal_with_passkey(
+        ctx: Context<VoteOnProposalWithPasskey>,
+        args: VoteOnProposalArgs,
+    ) -> Result<()> {
+        VoteOnProposalWithPasskey::cancel_proposal_with_passkey(ctx, args)
+    }
+
+    /// Set the smart account `rent_collector`.
+    #[instruction(discriminator = [43])]
+    pub fn set_rent_collector_as_authority(
+        ctx: Context<ExecuteSettingsTransactionAsAuthority>,
+        args: SetRentCollectorArgs,
+    ) -> Result<()> {
+        ExecuteSettingsTransactionAsAuthority::set_rent_collector(ctx, args)
+    }
+
+    /// Grant a session key that can act on behalf of a smart account signer until it expires.
+    #[instruction(discriminator = [44])]
+    pub fn create_session_key(
+        ctx: Context<CreateSessionKey>,
+        args: CreateSessionKeyArgs,
+    ) -> Result<()> {
+        CreateSessionKey::create_session_key(ctx, args)
+    }
+
+    /// Revoke a session key, or close an expired one.
+    #[instruction(discriminator = [45])]
+    pub fn revoke_session_key(
+        ctx: Context<RevokeSessionKey>,
+        args: RevokeSessionKeyArgs,
+    ) -> Result<()> {
+        RevokeSessionKey::revoke_session_key(ctx, args)
+    }
+
+    /// Approve a smart account proposal with a session key on behalf of its granter.
+    /// The proposal must be `Active`.
+    #[instruction(discriminator = [46])]
+    pub fn approve_proposal_with_session_key(
+        ctx: Context<VoteOnProposalWithSessionKey>,
+        args: VoteOnProposalArgs,
+    ) -> Result<()> {
+        VoteOnProposalWithSessionKey::approve_proposal_with_session_key(ctx, args)
+    }
+
+    /// Reject a smart account proposal with a session key on behalf of its granter.
+    /// The proposal must be `Active`.
+    #[instruction(discriminator = [47])]
+    pub fn reject_proposal_with_session_key(
+        ctx: Context<VoteOnProposalWithSessionKey>,
+        args: VoteOnProposalArgs,
+    ) -> Result<()> {
+        VoteOnProposalWithSessionKey::reject_proposal_with_session_key(ctx, args)
+    }
+
+    /// Cancel a smart account proposal with a session key on behalf of its granter.
+    /// The proposal must be `Approved`.
+    #[instruction(discriminator = [48])]
+    pub fn cancel_proposal_with_session_key(
+        ctx: Context<VoteOnProposalWithSessionKey>,
+        args: VoteOnProposalArgs,
+    ) -> Result<()> {
+        VoteOnProposalWithSessionKey::cancel_proposal_with_session_key(ctx, args)
+    }
+
+    /// Start a recovery of the smart account by one of its guardians,
+    /// proposing a replacement signer set and threshold.
+    #[instruction(discriminator = [49])]
+    pub fn create_recovery(ctx: Context<CreateRecovery>, args: CreateRecoveryArgs) -> Result<()> {
+        CreateRecovery::create_recovery(ctx, args)
+    }
+
+    /// Approve a pending recovery on behalf of a guardian.
+    #[instruction(discriminator = [50])]
+    pub fn approve_recovery(
+        ctx: Context<ApproveRecovery>,
+        args: ApproveRecoveryArgs,
+    ) -> Result<()> {
+        ApproveRecovery::approve_recovery(ctx, args)
+    }
+
+    /// Veto a pending recovery on behalf of a current signer.
+    #[instruction(discriminator = [51])]
+    pub fn veto_recovery(ctx: Context<VetoRecovery>, args: VetoRecoveryArgs) -> Result<()> {
+        VetoRecovery::veto_recovery(ctx, args)
+    }
+
+    /// Finalize an approved recovery after the veto period, replacing the signers and threshold.
+    #[instruction(discriminator = [52])]
+    pub fn finalize_recovery(ctx: Context<FinalizeRecovery>) -> Result<()> {
+        FinalizeRecovery::finalize_recovery(ctx)
+    }
+
+    /// Set the smart account `recovery_threshold` and `recovery_delay`.
+    #[instruction(discriminator = [53])]
+    pub fn set_recovery_config_as_authority(
+        ctx: Context<ExecuteSettingsTransactionAsAuthority>,
+        args: SetRecoveryConfigArgs,
+    ) -> Result<()> {
+        ExecuteSettingsTransactionAsAuthority::set_recovery_config(ctx, args)
+    }
+
+    /// Create or update the policy of one of the smart accounts.
+    #[instruction(discriminator = [54])]
+    pub fn set_vault_policy_as_authority<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteSettingsTransactionAsAuthority<'info>>,
+        args: SetVaultPolicyArgs,
+    ) -> Result<()> {
+        ExecuteSettingsTransactionAsAuthority::set_vault_policy(ctx, args)
+    }
+
+    /// Remove the policy of one of the smart accounts.
+    #[instruction(discriminator = [55])]
+    pub fn remove_vault_policy_as_authority<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteSettingsTransactionAsAuthority<'info>>,
+        args: RemoveVaultPolicyArgs,
+    ) -> Result<()> {
+        ExecuteSettingsTransactionAsAuthority::remove_vault_policy(ctx, args)
+    }
+
+    /// Set the smart account `execution_window`.
+    #[instruction(discriminator = [56])]
+    pub fn set_execution_window_as_authority(
+        ctx: Context<ExecuteSettingsTransactionAsAuthority>,
+        args: SetExecutionWindowArgs,
+    ) -> Result<()> {
+        ExecuteSettingsTransactionAsAuthority::set_execution_window(ctx, args)
+    }
+
+    /// Update status of a smart account proposal to `Expired` once its voting deadline
+    /// or the execution window of the settings has passed.
+    #[instruction(discriminator = [57])]
+    pub fn expire_proposal(ctx: Context<ExpireProposal>) -> Result<()> {
+        ExpireProposal::expire_proposal(ctx)
+    }
+
+    /// Set the time lock of a program or settings action kind.
+    #[instruction(discriminator = [58])]
+    pub fn set_target_time_lock_as_authority(
+        ctx: Context<ExecuteSettingsTransactionAsAuthority>,
+        args: SetTargetTimeLockArgs,
+    ) -> Result<()> {
+        ExecuteSettingsTransactionAsAuthority::set_target_time_lock(ctx, args)
+    }
+
+    /// Remove the time lock of a program or settings action kind.
+    #[instruction(discriminator = [59])]
+    pub fn remove_target_time_lock_as_authority(
+        ctx: Context<ExecuteSettingsTransactionAsAuthority>,
+        args: RemoveTargetTimeLockArgs,
+    ) -> Result<()> {
+        ExecuteSettingsTransactionAsAuthority::remove_target_time_lock(ctx, args)
+    }
+
+    /// Add a new restricted signer to the controlled smart account.
+    #[instruction(discriminator = [60])]
+    pub fn add_restricted_signer_as_authority(
+        ctx: Context<ExecuteSettingsTransactionAsAuthority>,
+        args: AddRestrictedSignerArgs,
+    ) -> Result<()> {
+        ExecuteSettingsTransactionAsAuthority::add_restricted_signer(ctx, args)
+    }
+
+    /// Remove a restricted signer from the controlled smart account.
+    #[instruction(discriminator = [61])]
+    pub fn remove_restricted_signer_as_authority(
+        ctx: Context<ExecuteSettingsTransactionAsAuthority>,
+        args: RemoveRestrictedSignerArgs,
+    ) -> Result<()> {
+        ExecuteSettingsTransactionAsAuthority::remove_restricted_signer(ctx, args)
+    }
+
+    /// Change the permissions of a signer of the controlled smart account.
+    #[instruction(discriminator = [62])]
+    pub fn change_signer_permissions_as_authority(
+        ctx: Context<ExecuteSettingsTransactionAsAuthority>,
+        args: ChangeSignerPermissionsArgs,
+    ) -> Result<()> {
+        ExecuteSettingsTransactionAsAuthority::change_signer_permissions(ctx, args)
+    }
+
+    /// Replace the key of a signer of the controlled smart account, keeping its permissions and weight.
+    #[instruction(discriminator = [63])]
+    pub fn rotate_signer_as_authority(
+        ctx: Context<ExecuteSettingsTransactionAsAuthority>,
+        args: RotateSignerArgs,
+    ) -> Result<()> {
+        ExecuteSettingsTransactionAsAuthority::rotate_signer(ctx, args)
+    }
+
+    /// Set the smart account `signer_rotation_delay`.
+    #[instruction(discriminator = [64])]
+    pub fn set_signer_rotation_delay_as_authority(
+        ctx: Context<ExecuteSettingsTransactionAsAuthority>,
+        args: SetSignerRotationDelayArgs,
+    ) -> Result<()> {
+        ExecuteSettingsTransactionAsAuthority::set_signer_rotation_delay(ctx, args)
+    }
+
+    /// Request the rotation of the key of a signer, on behalf of the signer itself.
+    #[instruction(discriminator = [65])]
+    pub fn request_signer_rotation(
+        ctx: Context<RequestSignerRotation>,
+        args: RequestSignerRotationArgs,
+    ) -> Result<()> {
+        RequestSignerRotation::request_signer_rotation(ctx, args)
+    }
+
+    /// Cancel a pending signer rotation on behalf of a current signer.
+    #[instruction(discriminator = [66])]
+    pub fn cancel_signer_rotation(
+        ctx: Context<CancelSignerRotation>,
+        args: CancelSignerRotationArgs,
+    ) -> Result<()> {
+        CancelSignerRotation::cancel_signer_rotation(ctx, args)
+    }
+
+    /// Finalize a signer rotation after the signer rotation delay, replacing the key of the signer.
+    #[instruction(discriminator = [67])]
+    pub fn finalize_signer_rotation(ctx: Context<FinalizeSignerRotation>) -> Result<()> {
+        FinalizeSignerRotation::finalize_signer_rotation(ctx)
+    }
+
+    /// Set the smart account `program_filter_mode`.
+    #[instruction(discriminator = [68])]
+    pub fn set_program_filter_mode_as_authority(
+        ctx: Context<ExecuteSettingsTransactionAsAuthority>,
+        args: SetProgramFilterModeArgs,
+    ) -> Result<()> {
+        ExecuteSettingsTransactionAsAuthority::set_program_filter_mode(ctx, args)
+    }
+
+    /// Add a filter to the smart account `program_filters`.
+    #[instruction(discriminator = [69])]
+    pub fn add_program_filter_as_authority(
+        ctx: Context<ExecuteSettingsTransactionAsAuthority>,
+        args: AddProgramFilterArgs,
+    ) -> Result<()> {
+        ExecuteSettingsTransactionAsAuthority::add_program_filter(ctx, args)
+    }
+
+    /// Remove a filter from the smart account `program_filters`.
+    #[instruction(discriminator = [70])]
+    pub fn remove_program_filter_as_authority(
+        ctx: Context<ExecuteSettingsTransactionAsAuthority>,
+        args: RemoveProgramFilterArgs,
+    ) -> Result<()> {
+        ExecuteSettingsTransactionAsAuthority::remove_program_filter(ctx, args)
+    }
+
+    /// Execute all the remaining transactions of the batch atomically.
+    /// The batch must be `Approved`.
+    #[instruction(discriminator = [71])]
+    pub fn execute_batch_atomic<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteBatchAtomic<'info>>,
+    ) -> Result<()> {
+        ExecuteBatchAtomic::execute_batch_atomic(ctx)
+    }
+
+    /// Skip the next transaction of the batch, on the vote of the signers.
+    /// The batch must be `Approved`.
+    #[instruction(discriminator = [72])]
+    pub fn skip_batch_transaction(
+        ctx: Context<SkipBatchTransaction>,
+        args: SkipBatchTransactionArgs,
+    ) -> Result<()> {
+        SkipBatchTransaction::skip_batch_transaction(ctx, args)
+    }
+
+    /// Add a transaction to the batch from a completed transaction buffer.
+    /// Finalized buffer hash must match `final_buffer_hash`
+    #[instruction(discriminator = [73])]
+    pub fn add_transaction_to_batch_from_buffer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AddTransactionToBatchFromBuffer<'info>>,
+        args: AddTransactionToBatchArgs,
+    ) -> Result<()> {
+        AddTransactionToBatchFromBuffer::add_transaction_to_batch_from_buffer(ctx, args)
+    }
+
+    /// Create a new settings transaction from a completed transaction buffer
+    /// holding the serialized settings actions.
+    /// Finalized buffer hash must match `final_buffer_hash`
+    #[instruction(discriminator = [74])]
+    pub fn create_settings_transaction_from_buffer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateSettingsTransactionFromBuffer<'info>>,
+        args: CreateSettingsTransactionArgs,
+    ) -> Result<()> {
+        CreateSettingsTransactionFromBuffer::create_settings_transaction_from_buffer(ctx, args)
+    }
+
+    /// Close a transaction buffer account of another signer that timed out or went stale.
+    /// Rent is refunded to the account that paid for it.
+    #[instruction(discriminator = [75])]
+    pub fn close_abandoned_transaction_buffer(
+        ctx: Context<CloseAbandonedTransactionBuffer>,
+    ) -> Result<()> {
+        CloseAbandonedTransactionBuffer::close_abandoned_transaction_buffer(ctx)
+    }
+
+    /// Set the `creation_fee_mint` and `smart_account_creation_token_fee` parameters of the program config.
+    #[instruction(discriminator = [76])]
+    pub fn set_program_config_creation_token_fee(
+        ctx: Context<ProgramConfig>,
+        args: ProgramConfigSetCreationTokenFeeArgs,
+    ) -> Result<()> {
+        ProgramConfig::set_creation_token_fee(ctx, args)
+    }
+
+    /// Set the `referral_fee_bps` parameter of the program config.
+    #[instruction(discriminator = [77])]
+    pub fn set_program_config_referral_fee(
+        ctx: Context<ProgramConfig>,
+        args: ProgramConfigSetReferralFeeArgs,
+    ) -> Result<()> {
+        ProgramConfig::set_referral_fee(ctx, args)
+    }
+
+    /// Set the `smart_account_creation_mode` parameter of the program config.
+    #[instruction(discriminator = [78])]
+    pub fn set_program_config_creation_mode(
+        ctx: Context<ProgramConfig>,
+        args: ProgramConfigSetCreationModeArgs,
+    ) -> Result<()> {
+        ProgramConfig::set_creation_mode(ctx, args)
+    }
+
+    /// Create a page of the smart account creation allowlist.
+    #[instruction(discriminator = [79])]
+    pub fn create_creator_allowlist_page(
+        ctx: Context<CreateCreatorAllowlistPage>,
+        args: CreateCreatorAllowlistPageArgs,
+    ) -> Result<()> {
+        CreateCreatorAllowlistPage::create_creator_allowlist_page(ctx, args)
+    }
+
+    /// Add a creator to a page of the smart account creation allowlist.
+    #[instruction(discriminator = [80])]
+    pub fn add_creator_to_allowlist(
+        ctx: Context<ChangeCreatorAllowlist>,
+        args: CreatorAllowlistChangeArgs,
+    ) -> Result<()> {
+        ChangeCreatorAllowlist::add_creator(ctx, args)
+    }
+
+    /// Remove a creator from a page of the smart account creation allowlist.
+    #[instruction(discriminator = [81])]
+    pub fn remove_creator_from_allowlist(
+        ctx: Context<ChangeCreatorAllowlist>,
+        args: CreatorAllowlistChangeArgs,
+    ) -> Result<()> {
+        ChangeCreatorAllowlist::remove_creator(ctx, args)
+    }
+
+    /// Propose a new `authority` for the program config, which takes effect once it's accepted.
+    #[instruction(discriminator = [82])]
+    pub fn propose_program_config_authority(
+        ctx: Context<ProposeProgramConfigTransfer>,
+        args: ProposeProgramConfigAuthorityArgs,
+    ) -> Result<()> {
+        ProposeProgramConfigTransfer::propose_authority(ctx, args)
+    }
+
+    /// Accept the pending program config `authority` transfer as the new authority.
+    #[instruction(discriminator = [83])]
+    pub fn accept_program_config_authority(
+        ctx: Context<AcceptProgramConfigTransfer>,
+    ) -> Result<()> {
+        AcceptProgramConfigTransfer::accept_authority(ctx)
+    }
+
+    /// Propose a new `treasury` for the program config, which takes effect once it's accepted.
+    #[instruction(discriminator = [84])]
+    pub fn propose_program_config_treasury(
+        ctx: Context<ProposeProgramConfigTransfer>,
+        args: ProposeProgramConfigTreasuryArgs,
+    ) -> Result<()> {
+        ProposeProgramConfigTransfer::propose_treasury(ctx, args)
+    }
+
+    /// Accept the pending program config `treasury` transfer as the new treasury.
+    #[instruction(discriminator = [85])]
+    pub fn accept_program_config_treasury(ctx: Context<AcceptProgramConfigTransfer>) -> Result<()> {
+        AcceptProgramConfigTransfer::accept_treasury(ctx)
+    }
+
+    /// Cancel the pending program config transfer, as the authority or the proposed key.
+    #[instruction(discriminator = [86])]
+    pub fn cancel_program_config_transfer(ctx: Context<CancelProgramConfigTransfer>) -> Result<()> {
+        CancelProgramConfigTransfer::cancel_program_config_transfer(ctx)
+    }
+
+    /// Rewrite a settings account created before its layout changed into the current layout.
+    #[instruction(discriminator = [87])]
+    pub fn migrate_settings(ctx: Context<MigrateSettings>) -> Result<()> {
+        MigrateSettings::migrate_settings(ctx)
+    }
 }
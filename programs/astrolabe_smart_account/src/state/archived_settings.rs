@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// The largest Borsh-serialized `Settings` that can be archived.
+/// `unarchive_settings` takes the settings inline, so they must fit in a 1232 bytes transaction
+/// along with its signature, accounts and a compute budget instruction.
+pub const MAX_ARCHIVED_SETTINGS_LEN: usize = 900;
+
+/// On-chain record of an archived `Settings` account.
+/// Only a hash commitment of the settings is kept, the account itself is closed to reclaim its rent.
+/// Anyone can restore the settings with `unarchive_settings` by supplying the preimage of `settings_hash`,
+/// which is logged in the `ArchiveSettingsEvent`.
+#[account]
+#[derive(InitSpace)]
+pub struct ArchivedSettings {
+    /// The address of the archived settings account.
+    pub settings: Pubkey,
+    /// SHA-256 hash of the Borsh-serialized `Settings` (without the account discriminator)
+    /// at the moment of archival.
+    pub settings_hash: [u8; 32],
+    /// Data length of the settings account at the moment of archival.
+    /// The settings account is restored with the same space.
+    pub settings_space: u64,
+    /// Unix timestamp of when the settings were archived.
+    pub archived_at: i64,
+    /// Bump for the archived settings PDA seed.
+    pub bump: u8,
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::*;
 use crate::{TransactionMessage, SmartAccountTransactionMessage};
 
 /// Stores data required for serial execution of a batch of smart account transactions.
@@ -7,13 +8,13 @@ use crate::{TransactionMessage, SmartAccountTransactionMessage};
 /// and wraps arbitrary Solana instructions, typically calling into other Solana programs.
 /// The transactions themselves are stored in separate PDAs associated with the this account.
 #[account]
-#[derive(InitSpace)]
 pub struct Batch {
     /// The settings this belongs to.
     pub settings: Pubkey,
     /// Signer of the smart account who submitted the batch.
     pub creator: Pubkey,
     /// The rent collector for the batch account.
+    /// Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
     pub rent_collector: Pubkey,
     /// Index of this batch within the smart account transactions.
     pub index: u64,
@@ -25,18 +26,73 @@ pub struct Batch {
     pub account_bump: u8,
     /// Number of transactions in the batch.
     pub size: u32,
-    /// Index of the last executed transaction within the batch.
+    /// Index of the last executed or skipped transaction within the batch.
     /// 0 means that no transactions have been executed yet.
     pub executed_transaction_index: u32,
+    /// The longest time lock of the programs the transactions of the batch touch,
+    /// from the settings `target_time_locks` captured at the creation of each transaction.
+    pub time_lock: u32,
+    /// Indexes of the transactions within the batch that were skipped instead of executed, in ascending order.
+    pub skipped_transaction_indexes: Vec<u32>,
 }
 
 impl Batch {
+    pub fn size(skipped_transaction_indexes_length: usize) -> usize {
+        8  + // anchor account discriminator
+        32 + // settings
+        32 + // creator
+        32 + // rent_collector
+        8  + // index
+        1  + // bump
+        1  + // account_index
+        1  + // account_bump
+        4  + // size
+        4  + // executed_transaction_index
+        4  + // time_lock
+        4  + // skipped_transaction_indexes vector length
+        skipped_transaction_indexes_length * 4 // skipped_transaction_indexes
+    }
+
     pub fn invariant(&self) -> Result<()> {
         // Just a sanity check.
         require_gte!(self.size, self.executed_transaction_index);
 
+        // Only executed transactions can have been skipped, and only once.
+        let has_invalid_skipped_indexes = self
+            .skipped_transaction_indexes
+            .windows(2)
+            .any(|win| win[0] >= win[1]);
+        require!(
+            !has_invalid_skipped_indexes,
+            SmartAccountError::InvalidTransactionIndex
+        );
+        if let Some(last_skipped_index) = self.skipped_transaction_indexes.last() {
+            require_gte!(self.executed_transaction_index, *last_skipped_index);
+        }
+
         Ok(())
     }
+
+    /// The outcome of the transaction at `index` within the batch.
+    pub fn transaction_outcome(&self, index: u32) -> BatchTransactionOutcome {
+        if index > self.executed_transaction_index {
+            BatchTransactionOutcome::Pending
+        } else if self.skipped_transaction_indexes.contains(&index) {
+            BatchTransactionOutcome::Skipped
+        } else {
+            BatchTransactionOutcome::Executed
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BatchTransactionOutcome {
+    /// The transaction wasn't executed nor skipped yet.
+    Pending,
+    /// The transaction was executed.
+    Executed,
+    /// The transaction was skipped by the signers instead of being executed.
+    Skipped,
 }
 
 /// Stores data required for execution of one transaction from a batch.
@@ -46,6 +102,7 @@ pub struct BatchTransaction {
     /// PDA bump.
     pub bump: u8,
     /// The rent collector for the batch transaction account.
+    /// Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
     pub rent_collector: Pubkey,
     /// Derivation bumps for additional signers.
     /// Some transactions require multiple signers. Often these additional signers are "ephemeral" keypairs
@@ -61,10 +118,15 @@ pub struct BatchTransaction {
 
 impl BatchTransaction {
     pub fn size(ephemeral_signers_length: u8, transaction_message: &[u8]) -> Result<usize> {
-        let transaction_message: SmartAccountTransactionMessage =
-            TransactionMessage::deserialize(&mut &transaction_message[..])?.try_into()?;
-
-        let message_size = transaction_message.borsh_size();
+        // An empty `transaction_message` stands for the message of a transaction buffer,
+        // `add_transaction_to_batch_from_buffer` reallocates the account to fit it.
+        let message_size = if transaction_message.is_empty() {
+            SmartAccountTransactionMessage::default().borsh_size()
+        } else {
+            let transaction_message: SmartAccountTransactionMessage =
+                TransactionMessage::deserialize(&mut &transaction_message[..])?.try_into()?;
+            transaction_message.borsh_size()
+        };
 
         Ok(
             8 +   // anchor account discriminator
@@ -80,4 +142,48 @@ impl BatchTransaction {
     pub fn take(&mut self) -> BatchTransaction {
         core::mem::take(self)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_transaction_outcome() {
+        let mut batch = Batch {
+            settings: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            rent_collector: Pubkey::new_unique(),
+            index: 1,
+            bump: 255,
+            account_index: 0,
+            account_bump: 255,
+            size: 3,
+            executed_transaction_index: 0,
+            time_lock: 0,
+            skipped_transaction_indexes: vec![],
+        };
+
+        // Skip the first transaction, then execute the second one.
+        batch.skipped_transaction_indexes.push(1);
+        batch.executed_transaction_index = 2;
+        batch.invariant().unwrap();
+
+        assert_eq!(
+            batch.transaction_outcome(1),
+            BatchTransactionOutcome::Skipped
+        );
+        assert_eq!(
+            batch.transaction_outcome(2),
+            BatchTransactionOutcome::Executed
+        );
+        assert_eq!(
+            batch.transaction_outcome(3),
+            BatchTransactionOutcome::Pending
+        );
+
+        // Transactions can't be skipped before being reached.
+        batch.skipped_transaction_indexes.push(3);
+        assert!(batch.invariant().is_err());
+    }
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+
+/// How many creators a `CreatorAllowlistPage` can hold.
+pub const MAX_CREATORS_PER_ALLOWLIST_PAGE: usize = 64;
+
+/// A page of the creators allowed to create smart accounts when the program config
+/// `smart_account_creation_mode` is `Allowlist`. Pages are managed by the program config `authority`.
+#[account]
+pub struct CreatorAllowlistPage {
+    /// The index of the page.
+    pub page: u32,
+    /// PDA bump.
+    pub bump: u8,
+    /// The allowlisted creators, sorted.
+    pub creators: Vec<Pubkey>,
+}
+
+impl CreatorAllowlistPage {
+    /// Pages are allocated with room for `MAX_CREATORS_PER_ALLOWLIST_PAGE` creators upfront.
+    pub fn size() -> usize {
+        8  + // anchor discriminator
+        4  + // page
+        1  + // bump
+        4  + // creators vector length
+        MAX_CREATORS_PER_ALLOWLIST_PAGE * 32 // creators
+    }
+
+    pub fn contains(&self, creator: &Pubkey) -> bool {
+        self.creators.binary_search(creator).is_ok()
+    }
+
+    pub fn add_creator(&mut self, creator: Pubkey) -> Result<()> {
+        let index = match self.creators.binary_search(&creator) {
+            Ok(_) => return err!(SmartAccountError::CreatorAlreadyAllowlisted),
+            Err(index) => index,
+        };
+        require!(
+            self.creators.len() < MAX_CREATORS_PER_ALLOWLIST_PAGE,
+            SmartAccountError::CreatorAllowlistPageFull
+        );
+        self.creators.insert(index, creator);
+        Ok(())
+    }
+
+    pub fn remove_creator(&mut self, creator: &Pubkey) -> Result<()> {
+        let index = self
+            .creators
+            .binary_search(creator)
+            .map_err(|_| SmartAccountError::CreatorNotAllowlisted)?;
+        self.creators.remove(index);
+        Ok(())
+    }
+}
@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::errors::*;
+use crate::state::*;
+
+/// The layout of `Settings` accounts created before the rent collector, recovery, vault policies,
+/// execution windows, signer rotation, target time locks, program filters, signer weights
+/// and exit destinations were added. Such accounts must be migrated with `migrate_settings`.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct LegacySettings {
+    pub seed: u128,
+    pub settings_authority: Pubkey,
+    pub threshold: u16,
+    pub time_lock: u32,
+    pub transaction_index: u64,
+    pub stale_transaction_index: u64,
+    pub archival_authority: Option<Pubkey>,
+    pub archivable_after: u64,
+    pub bump: u8,
+    pub signers: Vec<LegacySmartAccountSigner>,
+    pub restricted_signers: Vec<LegacyRestrictedSmartAccountSigner>,
+    pub account_utilization: u8,
+    pub _reserved1: u8,
+    pub _reserved2: u8,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct LegacySmartAccountSigner {
+    pub key: Pubkey,
+    pub permissions: Permissions,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct LegacyRestrictedSmartAccountSigner {
+    pub key: Pubkey,
+    pub restricted_permissions: RestrictedPermissions,
+}
+
+impl LegacySettings {
+    /// Read the legacy settings from the data of a settings account,
+    /// failing if the account is already in the current layout.
+    pub fn try_from_account_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.starts_with(Settings::DISCRIMINATOR),
+            SmartAccountError::InvalidAccount
+        );
+        require!(
+            Settings::try_deserialize(&mut &data[..]).is_err(),
+            SmartAccountError::SettingsAlreadyMigrated
+        );
+
+        Self::deserialize(&mut &data[Settings::DISCRIMINATOR.len()..])
+            .map_err(|_| SmartAccountError::InvalidAccount.into())
+    }
+
+    /// The settings in the current layout, with the features added since disabled.
+    /// Signers get a voting weight of 1, so the threshold keeps its meaning.
+    /// Restricted signers lose the `EmergencyExit` permission, as they have no exit destination,
+    /// they need to be re-added with one to keep it.
+    pub fn migrate(self) -> Settings {
+        Settings {
+            seed: self.seed,
+            settings_authority: self.settings_authority,
+            threshold: self.threshold,
+            time_lock: self.time_lock,
+            transaction_index: self.transaction_index,
+            stale_transaction_index: self.stale_transaction_index,
+            rent_collector: None,
+            archival_authority: self.archival_authority,
+            archivable_after: self.archivable_after,
+            recovery_threshold: 0,
+            recovery_delay: 0,
+            vault_policies: [0; 32],
+            execution_window: 0,
+            signer_rotation_delay: None,
+            program_filter_mode: ProgramFilterMode::Disabled,
+            bump: self.bump,
+            signers: self
+                .signers
+                .into_iter()
+                .map(|signer| SmartAccountSigner {
+                    key: signer.key,
+                    permissions: signer.permissions,
+                    weight: 1,
+                })
+                .collect(),
+            restricted_signers: self
+                .restricted_signers
+                .into_iter()
+                .map(|signer| RestrictedSmartAccountSigner {
+                    key: signer.key,
+                    restricted_permissions: RestrictedPermissions {
+                        mask: signer.restricted_permissions.mask
+                            & !(RestrictedPermission::EmergencyExit as u8),
+                    },
+                    exit_destination: Pubkey::default(),
+                })
+                .collect(),
+            target_time_locks: vec![],
+            program_filters: vec![],
+            account_utilization: self.account_utilization,
+            _reserved1: self._reserved1,
+            _reserved2: self._reserved2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn legacy_settings() -> LegacySettings {
+        LegacySettings {
+            seed: 7,
+            settings_authority: Pubkey::default(),
+            threshold: 2,
+            time_lock: 60,
+            transaction_index: 5,
+            stale_transaction_index: 3,
+            archival_authority: Some(Pubkey::new_unique()),
+            archivable_after: 1_000,
+            bump: 254,
+            signers: vec![
+                LegacySmartAccountSigner {
+                    key: Pubkey::new_unique(),
+                    permissions: Permissions { mask: 7 },
+                },
+                LegacySmartAccountSigner {
+                    key: Pubkey::new_unique(),
+                    permissions: Permissions { mask: 2 },
+                },
+            ],
+            restricted_signers: vec![LegacyRestrictedSmartAccountSigner {
+                key: Pubkey::new_unique(),
+                restricted_permissions: RestrictedPermissions { mask: 1 },
+            }],
+            account_utilization: 1,
+            _reserved1: 0,
+            _reserved2: 0,
+        }
+    }
+
+    fn account_data(legacy_settings: &LegacySettings) -> Vec<u8> {
+        let mut data = Settings::DISCRIMINATOR.to_vec();
+        legacy_settings.serialize(&mut data).unwrap();
+        // Settings accounts can be larger than their contents.
+        data.extend([0; 16]);
+        data
+    }
+
+    #[test]
+    fn test_migrate() {
+        let legacy_settings = legacy_settings();
+        let data = account_data(&legacy_settings);
+
+        let settings = LegacySettings::try_from_account_data(&data)
+            .unwrap()
+            .migrate();
+
+        assert_eq!(settings.seed, 7);
+        assert_eq!(settings.threshold, 2);
+        assert_eq!(settings.time_lock, 60);
+        assert_eq!(settings.transaction_index, 5);
+        assert_eq!(settings.stale_transaction_index, 3);
+        assert_eq!(
+            settings.archival_authority,
+            legacy_settings.archival_authority
+        );
+        assert_eq!(settings.archivable_after, 1_000);
+        assert_eq!(settings.bump, 254);
+        assert_eq!(settings.account_utilization, 1);
+        assert_eq!(settings.rent_collector, None);
+        assert_eq!(settings.recovery_threshold, 0);
+        assert_eq!(settings.signers.len(), 2);
+        assert_eq!(settings.signers[0].key, legacy_settings.signers[0].key);
+        assert_eq!(settings.signers[1].permissions.mask, 2);
+        assert!(settings.signers.iter().all(|signer| signer.weight == 1));
+        // The emergency exit permission is dropped, as there is no exit destination.
+        assert_eq!(
+            settings.restricted_signers[0].key,
+            legacy_settings.restricted_signers[0].key
+        );
+        assert_eq!(
+            settings.restricted_signers[0].restricted_permissions.mask,
+            0
+        );
+        settings.invariant().unwrap();
+    }
+
+    #[test]
+    fn test_migrate_already_migrated() {
+        let mut data = vec![];
+        legacy_settings()
+            .migrate()
+            .try_serialize(&mut data)
+            .unwrap();
+
+        assert_eq!(
+            LegacySettings::try_from_account_data(&data).err().unwrap(),
+            SmartAccountError::SettingsAlreadyMigrated.into()
+        );
+    }
+
+    #[test]
+    fn test_migrate_other_account() {
+        let mut data = account_data(&legacy_settings());
+        data[..8].copy_from_slice(Proposal::DISCRIMINATOR);
+
+        assert_eq!(
+            LegacySettings::try_from_account_data(&data).err().unwrap(),
+            SmartAccountError::InvalidAccount.into()
+        );
+    }
+}
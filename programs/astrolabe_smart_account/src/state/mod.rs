@@ -1,17 +1,37 @@
 pub use self::settings::*;
+pub use archived_settings::*;
 pub use batch::*;
+pub use creator_allowlist::*;
+pub use legacy_settings::*;
 pub use settings_transaction::*;
 pub use program_config::*;
+pub use program_config_transfer::*;
+pub use passkey::*;
 pub use proposal::*;
+pub use recovery::*;
 pub use seeds::*;
+pub use session_key::*;
+pub use signer_rotation::*;
+pub use spending_limit::*;
 pub use transaction_buffer::*;
 pub use transaction::*;
+pub use vault_policy::*;
 
+mod archived_settings;
 mod batch;
+mod creator_allowlist;
+mod legacy_settings;
 mod settings_transaction;
 mod settings;
 mod program_config;
+mod program_config_transfer;
+mod passkey;
 mod proposal;
+mod recovery;
 mod seeds;
+mod session_key;
+mod signer_rotation;
+mod spending_limit;
 mod transaction_buffer;
-mod transaction;
\ No newline at end of file
+mod transaction;
+mod vault_policy;
\ No newline at end of file
@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::instruction::Instruction;
+
+use crate::errors::*;
+use crate::utils::*;
+
+/// A secp256r1 (P-256) WebAuthn passkey that can be a smart account signer.
+/// The address of the passkey PDA is used as the `SmartAccountSigner.key`, and its approvals
+/// are verified against the secp256r1 precompile through the instructions sysvar.
+#[account]
+#[derive(InitSpace)]
+pub struct Passkey {
+    /// Compressed secp256r1 public key of the passkey.
+    pub public_key: [u8; 33],
+    /// SHA-256 hash of the WebAuthn relying party ID the passkey is registered for.
+    pub rp_id_hash: [u8; 32],
+    /// Included in the signed challenge and incremented on every approval to prevent replays.
+    pub nonce: u64,
+    /// Bump for the passkey PDA seed.
+    pub bump: u8,
+}
+
+impl Passkey {
+    /// Verify that the passkey approved the currently executing instruction and consume the nonce.
+    pub fn verify_approval(
+        &mut self,
+        passkey_key: Pubkey,
+        instructions_sysvar: &AccountInfo,
+    ) -> Result<()> {
+        let current_index = load_current_index_checked(instructions_sysvar)?;
+        let current_instruction =
+            load_instruction_at_checked(usize::from(current_index), instructions_sysvar)?;
+
+        verify_webauthn_signature(
+            instructions_sysvar,
+            current_index,
+            &self.public_key,
+            &self.rp_id_hash,
+            &self.challenge(passkey_key, &current_instruction),
+        )?;
+
+        self.nonce = self
+            .nonce
+            .checked_add(1)
+            .ok_or(SmartAccountError::Overflow)?;
+
+        Ok(())
+    }
+
+    /// The WebAuthn challenge the passkey must sign to approve `instruction`:
+    /// `sha256(passkey || nonce || program_id || account keys || data)`,
+    /// so an approval cannot be used for anything else.
+    pub fn challenge(&self, passkey_key: Pubkey, instruction: &Instruction) -> [u8; 32] {
+        let nonce = self.nonce.to_le_bytes();
+        let mut challenge_data: Vec<&[u8]> = Vec::with_capacity(4 + instruction.accounts.len());
+        challenge_data.push(passkey_key.as_ref());
+        challenge_data.push(&nonce);
+        challenge_data.push(instruction.program_id.as_ref());
+        challenge_data.extend(
+            instruction
+                .accounts
+                .iter()
+                .map(|account| account.pubkey.as_ref()),
+        );
+        challenge_data.push(&instruction.data);
+        hashv(&challenge_data).to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use anchor_lang::solana_program::hash::hash;
+    use anchor_lang::solana_program::instruction::AccountMeta;
+
+    use super::*;
+    use crate::utils::test_utils::*;
+
+    fn passkey(nonce: u64) -> Passkey {
+        Passkey {
+            public_key: [2; 33],
+            rp_id_hash: hash(b"example.com").to_bytes(),
+            nonce,
+            bump: 255,
+        }
+    }
+
+    fn approved_instruction(passkey_key: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crate::id(),
+            accounts: vec![
+                AccountMeta::new(Pubkey::new_unique(), false),
+                AccountMeta::new_readonly(passkey_key, false),
+            ],
+            data: vec![1, 2, 3],
+        }
+    }
+
+    /// Verify an approval of `instruction` with the instructions sysvar holding a precompile
+    /// instruction that checked an assertion of `signed_challenge`, followed by `instruction`.
+    fn verify_approval(
+        passkey: &mut Passkey,
+        passkey_key: Pubkey,
+        instruction: &Instruction,
+        signed_challenge: &[u8; 32],
+    ) -> Result<()> {
+        let instructions = [
+            webauthn_precompile_instruction(
+                &passkey.public_key,
+                &authenticator_data("example.com"),
+                &client_data_json("webauthn.get", signed_challenge),
+            ),
+            instruction.clone(),
+        ];
+        let mut lamports = 0;
+        let mut data = instructions_sysvar_data(&instructions, 1);
+        let owner = Pubkey::default();
+        let instructions_sysvar = AccountInfo::new(
+            &INSTRUCTIONS_SYSVAR_ID,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        passkey.verify_approval(passkey_key, &instructions_sysvar)
+    }
+
+    #[test]
+    fn test_challenge() {
+        let passkey_key = Pubkey::new_unique();
+        let instruction = approved_instruction(passkey_key);
+
+        let challenge = passkey(5).challenge(passkey_key, &instruction);
+
+        let expected = hash(
+            &[
+                passkey_key.as_ref(),
+                &5u64.to_le_bytes(),
+                crate::id().as_ref(),
+                instruction.accounts[0].pubkey.as_ref(),
+                passkey_key.as_ref(),
+                &[1, 2, 3],
+            ]
+            .concat(),
+        );
+        assert_eq!(challenge, expected.to_bytes());
+    }
+
+    #[test]
+    fn test_challenge_binds_instruction() {
+        let passkey_key = Pubkey::new_unique();
+        let instruction = approved_instruction(passkey_key);
+        let challenge = passkey(5).challenge(passkey_key, &instruction);
+
+        assert_ne!(passkey(6).challenge(passkey_key, &instruction), challenge);
+        assert_ne!(
+            passkey(5).challenge(Pubkey::new_unique(), &instruction),
+            challenge
+        );
+
+        let mut other_data = instruction.clone();
+        other_data.data = vec![1, 2, 4];
+        assert_ne!(passkey(5).challenge(passkey_key, &other_data), challenge);
+
+        let mut other_accounts = instruction.clone();
+        other_accounts.accounts[0].pubkey = Pubkey::new_unique();
+        assert_ne!(
+            passkey(5).challenge(passkey_key, &other_accounts),
+            challenge
+        );
+
+        let mut other_program = instruction;
+        other_program.program_id = Pubkey::new_unique();
+        assert_ne!(passkey(5).challenge(passkey_key, &other_program), challenge);
+    }
+
+    #[test]
+    fn test_verify_approval_consumes_nonce() {
+        let passkey_key = Pubkey::new_unique();
+        let instruction = approved_instruction(passkey_key);
+        let mut passkey = passkey(0);
+        let challenge = passkey.challenge(passkey_key, &instruction);
+
+        verify_approval(&mut passkey, passkey_key, &instruction, &challenge).unwrap();
+        assert_eq!(passkey.nonce, 1);
+
+        // Replaying the same assertion fails, as it signed the previous nonce.
+        assert_eq!(
+            verify_approval(&mut passkey, passkey_key, &instruction, &challenge).unwrap_err(),
+            SmartAccountError::InvalidWebAuthnClientData.into()
+        );
+        assert_eq!(passkey.nonce, 1);
+    }
+
+    #[test]
+    fn test_verify_approval_nonce_overflow() {
+        let passkey_key = Pubkey::new_unique();
+        let instruction = approved_instruction(passkey_key);
+        let mut passkey = passkey(u64::MAX);
+        let challenge = passkey.challenge(passkey_key, &instruction);
+
+        assert_eq!(
+            verify_approval(&mut passkey, passkey_key, &instruction, &challenge).unwrap_err(),
+            SmartAccountError::Overflow.into()
+        );
+        assert_eq!(passkey.nonce, u64::MAX);
+    }
+}
@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 use crate::errors::SmartAccountError;
 
+/// The basis points of a whole fee.
+pub const MAX_REFERRAL_FEE_BPS: u16 = 10_000;
+
 /// Global program configuration account.
 #[account]
 #[derive(InitSpace)]
@@ -15,8 +18,32 @@ pub struct ProgramConfig {
     pub smart_account_creation_fee: u64,
     /// The treasury account to send charged fees to.
     pub treasury: Pubkey,
+    /// The SPL mint the `smart_account_creation_token_fee` is charged in.
+    /// `Pubkey::default()` if no token fee is set.
+    pub creation_fee_mint: Pubkey,
+    /// The amount of `creation_fee_mint` tokens charged for creating a new smart account,
+    /// on top of `smart_account_creation_fee`. This fee is sent to the `treasury` token account.
+    pub smart_account_creation_token_fee: u64,
+    /// The share of the creation fees, in basis points, that is sent to the referrer
+    /// of a new smart account instead of the `treasury`.
+    pub referral_fee_bps: u16,
+    /// Who can create new smart accounts.
+    pub smart_account_creation_mode: SmartAccountCreationMode,
     /// Reserved for future use.
-    pub _reserved: [u8; 64],
+    pub _reserved: [u8; 21],
+}
+
+#[derive(
+    AnchorDeserialize, AnchorSerialize, InitSpace, Eq, PartialEq, Clone, Copy, Default, Debug,
+)]
+pub enum SmartAccountCreationMode {
+    /// Anyone can create a smart account.
+    #[default]
+    Open,
+    /// Only the creators in a `CreatorAllowlistPage` can create a smart account.
+    Allowlist,
+    /// Smart account creation must be cosigned by the program config `authority`.
+    Cosigned,
 }
 
 impl ProgramConfig {
@@ -35,9 +62,31 @@ impl ProgramConfig {
             SmartAccountError::InvalidAccount
         );
 
+        // token fee must have a mint.
+        if self.smart_account_creation_token_fee > 0 {
+            require_keys_neq!(
+                self.creation_fee_mint,
+                Pubkey::default(),
+                SmartAccountError::InvalidMint
+            );
+        }
+
+        // referral fee can't exceed the whole fee.
+        require!(
+            self.referral_fee_bps <= MAX_REFERRAL_FEE_BPS,
+            SmartAccountError::InvalidReferralFee
+        );
+
         Ok(())
     }
 
+    /// The part of `fee` that goes to the referrer.
+    pub fn referral_fee(&self, fee: u64) -> u64 {
+        // Can't overflow as `referral_fee_bps` is at most `MAX_REFERRAL_FEE_BPS`.
+        (u128::from(fee) * u128::from(self.referral_fee_bps) / u128::from(MAX_REFERRAL_FEE_BPS))
+            as u64
+    }
+
     pub fn increment_smart_account_index(&mut self) -> Result<()>   {
         self.smart_account_index = self.smart_account_index.checked_add(1).unwrap();
         Ok(())
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// A pending change of the program config `authority` or `treasury`, proposed by the `authority`.
+/// It only takes effect once the new key accepts it, so a mistyped key can't lock us out of
+/// the program config. There is at most one pending transfer at a time.
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramConfigTransfer {
+    /// What the transfer changes.
+    pub kind: ProgramConfigTransferKind,
+    /// The program config authority that proposed the transfer.
+    /// The transfer can't be accepted anymore once the authority changes.
+    pub proposed_by: Pubkey,
+    /// The key replacing the current `authority` or `treasury`, which must accept the transfer.
+    pub new_key: Pubkey,
+    /// Unix timestamp of when the transfer was proposed.
+    pub proposed_at: i64,
+    /// The rent collector for the program config transfer account.
+    pub rent_collector: Pubkey,
+    /// PDA bump.
+    pub bump: u8,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, InitSpace, Eq, PartialEq, Clone, Copy, Debug)]
+pub enum ProgramConfigTransferKind {
+    /// Transfer of the program config `authority`.
+    Authority,
+    /// Transfer of the program config `treasury`.
+    Treasury,
+}
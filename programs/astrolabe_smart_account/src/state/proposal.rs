@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 
 use crate::errors::*;
 use crate::id;
+use crate::state::{Settings, VaultPolicy};
 use crate::utils;
 use crate::utils::realloc;
 
@@ -16,9 +17,13 @@ pub struct Proposal {
     /// Index of the smart account transaction this proposal is associated with.
     pub transaction_index: u64,
     /// The rent collector for the proposal account.
+    /// Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
     pub rent_collector: Pubkey,
     /// The status of the transaction.
     pub status: ProposalStatus,
+    /// Unix timestamp after which the proposal can no longer be voted on.
+    /// `None` means the proposal can be voted on until it's approved, rejected or becomes stale.
+    pub voting_deadline: Option<i64>,
     /// PDA bump.
     pub bump: u8,
     /// Keys that have approved/signed.
@@ -37,14 +42,48 @@ impl Proposal {
         32 +  // fee_payer
         1 +   // status enum variant
         8 +   // status enum wrapped timestamp (i64)
+        1 +   // voting_deadline Option discriminator
+        8 +   // voting_deadline
         1 +   // bump
         (4 + (signers_len * 32)) + // approved vec
         (4 + (signers_len * 32)) + // rejected vec
         (4 + (signers_len * 32)) // cancelled vec
     }
 
+    /// The voting weight of the `keys`, only counting the signers allowed by the `vault_policy` if any.
+    pub fn voting_weight(
+        settings: &Settings,
+        vault_policy: Option<&VaultPolicy>,
+        keys: &[Pubkey],
+    ) -> usize {
+        match vault_policy {
+            Some(vault_policy) => vault_policy.voting_weight(settings, keys),
+            None => settings.voting_weight(keys),
+        }
+    }
+
+    /// How much voting weight is needed to approve or cancel a proposal,
+    /// the `vault_policy` threshold if the smart account of the transaction has one.
+    pub fn threshold(settings: &Settings, vault_policy: Option<&VaultPolicy>) -> u16 {
+        vault_policy.map_or(settings.threshold, |vault_policy| vault_policy.threshold)
+    }
+
+    /// How much voting weight is needed to reject a proposal, under the `vault_policy` if any.
+    pub fn cutoff(settings: &Settings, vault_policy: Option<&VaultPolicy>) -> usize {
+        match vault_policy {
+            Some(vault_policy) => vault_policy.cutoff(settings),
+            None => settings.cutoff(),
+        }
+    }
+
     /// Register an approval vote.
-    pub fn approve(&mut self, signer: Pubkey, threshold: usize) -> Result<()> {
+    /// `vault_policy` is the policy of the smart account the transaction is for, if any.
+    pub fn approve(
+        &mut self,
+        signer: Pubkey,
+        settings: &Settings,
+        vault_policy: Option<&VaultPolicy>,
+    ) -> Result<()> {
         // If `signer` has previously voted to reject, remove that vote.
         if let Some(vote_index) = self.has_voted_reject(signer.key()) {
             self.remove_rejection_vote(vote_index);
@@ -56,8 +95,10 @@ impl Proposal {
             Err(pos) => self.approved.insert(pos, signer),
         };
 
-        // If current number of approvals reaches threshold, mark the transaction as `Approved`.
-        if self.approved.len() >= threshold {
+        // If current voting weight of approvals reaches threshold, mark the transaction as `Approved`.
+        if Self::voting_weight(settings, vault_policy, &self.approved)
+            >= usize::from(Self::threshold(settings, vault_policy))
+        {
             self.status = ProposalStatus::Approved {
                 timestamp: Clock::get()?.unix_timestamp,
             };
@@ -67,7 +108,13 @@ impl Proposal {
     }
 
     /// Register a rejection vote.
-    pub fn reject(&mut self, signer: Pubkey, cutoff: usize) -> Result<()> {
+    /// `vault_policy` is the policy of the smart account the transaction is for, if any.
+    pub fn reject(
+        &mut self,
+        signer: Pubkey,
+        settings: &Settings,
+        vault_policy: Option<&VaultPolicy>,
+    ) -> Result<()> {
         // If `signer` has previously voted to approve, remove that vote.
         if let Some(vote_index) = self.has_voted_approve(signer.key()) {
             self.remove_approval_vote(vote_index);
@@ -79,8 +126,10 @@ impl Proposal {
             Err(pos) => self.rejected.insert(pos, signer),
         };
 
-        // If current number of rejections reaches cutoff, mark the transaction as `Rejected`.
-        if self.rejected.len() >= cutoff {
+        // If current voting weight of rejections reaches cutoff, mark the transaction as `Rejected`.
+        if Self::voting_weight(settings, vault_policy, &self.rejected)
+            >= Self::cutoff(settings, vault_policy)
+        {
             self.status = ProposalStatus::Rejected {
                 timestamp: Clock::get()?.unix_timestamp,
             };
@@ -90,15 +139,23 @@ impl Proposal {
     }
 
     /// Registers a cancellation vote.
-    pub fn cancel(&mut self, signer: Pubkey, threshold: usize) -> Result<()> {
+    /// `vault_policy` is the policy of the smart account the transaction is for, if any.
+    pub fn cancel(
+        &mut self,
+        signer: Pubkey,
+        settings: &Settings,
+        vault_policy: Option<&VaultPolicy>,
+    ) -> Result<()> {
         // Insert the vote of cancellation.
         match self.cancelled.binary_search(&signer) {
             Ok(_) => return err!(SmartAccountError::AlreadyCancelled),
             Err(pos) => self.cancelled.insert(pos, signer),
         };
 
-        // If current number of cancellations reaches threshold, mark the transaction as `Cancelled`.
-        if self.cancelled.len() >= threshold {
+        // If current voting weight of cancellations reaches threshold, mark the transaction as `Cancelled`.
+        if Self::voting_weight(settings, vault_policy, &self.cancelled)
+            >= usize::from(Self::threshold(settings, vault_policy))
+        {
             self.status = ProposalStatus::Cancelled {
                 timestamp: Clock::get()?.unix_timestamp,
             };
@@ -107,6 +164,20 @@ impl Proposal {
         Ok(())
     }
 
+    /// Whether the proposal can be marked as `Expired`: it's still being voted on past its `voting_deadline`,
+    /// or it's `Approved` and the settings `execution_window` has passed since its approval.
+    pub fn has_expired(&self, execution_window: u32, now: i64) -> bool {
+        match self.status {
+            ProposalStatus::Draft { .. } | ProposalStatus::Active { .. } => self
+                .voting_deadline
+                .is_some_and(|voting_deadline| now > voting_deadline),
+            ProposalStatus::Approved { timestamp } => {
+                execution_window != 0 && now - timestamp > i64::from(execution_window)
+            }
+            _ => false,
+        }
+    }
+
     /// Check if the signer approved the transaction.
     /// Returns `Some(index)` if `signer` has approved the transaction, with `index` into the `approved` vec.
     fn has_voted_approve(&self, signer: Pubkey) -> Option<usize> {
@@ -195,4 +266,7 @@ pub enum ProposalStatus {
     Executed { timestamp: i64 },
     /// Proposal has been cancelled.
     Cancelled { timestamp: i64 },
+    /// Proposal has not been approved before its voting deadline,
+    /// or has not been executed within the execution window of the settings.
+    Expired { timestamp: i64 },
 }
\ No newline at end of file
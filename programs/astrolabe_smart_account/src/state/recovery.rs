@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::id;
+use crate::state::*;
+use crate::utils::realloc;
+
+/// A pending recovery of a smart account started by one of its guardians.
+/// Once approved by `Settings.recovery_threshold` guardians and after `Settings.recovery_delay`
+/// seconds without a veto from the current signers, it replaces the signers of the smart account.
+#[account]
+pub struct Recovery {
+    /// The settings this belongs to.
+    pub settings: Pubkey,
+    /// The guardian who started the recovery.
+    pub creator: Pubkey,
+    /// The signers replacing the current signers of the smart account.
+    pub new_signers: Vec<SmartAccountSigner>,
+    /// The threshold replacing the current threshold of the smart account.
+    pub new_threshold: u16,
+    /// Guardians who approved the recovery, sorted.
+    pub approved: Vec<Pubkey>,
+    /// Unix timestamp of when the recovery reached the recovery threshold.
+    /// `None` while the recovery is not approved by enough guardians.
+    pub approved_at: Option<i64>,
+    /// The rent collector for the recovery account.
+    pub rent_collector: Pubkey,
+    /// PDA bump.
+    pub bump: u8,
+}
+
+impl Recovery {
+    pub fn size(new_signers_length: usize, guardians_length: usize) -> usize {
+        8  + // anchor discriminator
+        32 + // settings
+        32 + // creator
+        4  + // new_signers vector length
+        new_signers_length * SmartAccountSigner::INIT_SPACE + // new_signers
+        2  + // new_threshold
+        4  + // approved vector length
+        guardians_length * 32 + // approved
+        1  + // approved_at Option discriminator
+        8  + // approved_at
+        32 + // rent_collector
+        1 // bump
+    }
+
+    /// Check if the recovery account space needs to be reallocated to accommodate `guardians_length` approvals.
+    /// Returns `true` if the account was reallocated.
+    pub fn realloc_if_needed<'a>(
+        recovery: AccountInfo<'a>,
+        new_signers_length: usize,
+        guardians_length: usize,
+        fee_payer: Option<AccountInfo<'a>>,
+        system_program: Option<AccountInfo<'a>>,
+    ) -> Result<bool> {
+        // Sanity checks
+        require_keys_eq!(
+            *recovery.owner,
+            id(),
+            SmartAccountError::IllegalAccountOwner
+        );
+
+        let current_account_size = recovery.data.borrow().len();
+        let account_size_to_fit_guardians = Recovery::size(new_signers_length, guardians_length);
+
+        // Check if we need to reallocate space.
+        if current_account_size >= account_size_to_fit_guardians {
+            return Ok(false);
+        }
+        // Reallocate more space.
+        realloc(
+            &recovery,
+            account_size_to_fit_guardians,
+            fee_payer,
+            system_program,
+        )?;
+
+        Ok(true)
+    }
+
+    /// Register the approval of `guardian` and start the veto period once
+    /// the recovery threshold is reached.
+    pub fn approve(&mut self, guardian: Pubkey, recovery_threshold: usize) -> Result<()> {
+        match self.approved.binary_search(&guardian) {
+            Ok(_) => return err!(SmartAccountError::RecoveryAlreadyApproved),
+            Err(pos) => self.approved.insert(pos, guardian),
+        };
+
+        if self.approved_at.is_none() && self.approved.len() >= recovery_threshold {
+            self.approved_at = Some(Clock::get()?.unix_timestamp);
+        }
+
+        Ok(())
+    }
+}
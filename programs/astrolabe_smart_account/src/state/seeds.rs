@@ -7,6 +7,19 @@ pub const SEED_BATCH_TRANSACTION: &[u8] = b"batch_transaction";
 pub const SEED_SMART_ACCOUNT: &[u8] = b"smart_account";
 pub const SEED_EPHEMERAL_SIGNER: &[u8] = b"ephemeral_signer";
 pub const SEED_TRANSACTION_BUFFER: &[u8] = b"transaction_buffer";
+pub const SEED_SPENDING_LIMIT: &[u8] = b"spending_limit";
+pub const SEED_ARCHIVED_SETTINGS: &[u8] = b"archived_settings";
+pub const SEED_PASSKEY: &[u8] = b"passkey";
+pub const SEED_SESSION_KEY: &[u8] = b"session_key";
+pub const SEED_RECOVERY: &[u8] = b"recovery";
+pub const SEED_VAULT_POLICY: &[u8] = b"vault_policy";
+pub const SEED_SIGNER_ROTATION: &[u8] = b"signer_rotation";
+pub const SEED_CREATOR_ALLOWLIST: &[u8] = b"creator_allowlist";
+pub const SEED_PROGRAM_CONFIG_TRANSFER: &[u8] = b"program_config_transfer";
+
+pub fn get_program_config_signer_seeds() -> Vec<Vec<u8>> {
+    vec![SEED_PREFIX.to_vec(), SEED_PROGRAM_CONFIG.to_vec()]
+}
 
 pub fn get_settings_signer_seeds(settings_seed: u128) -> Vec<Vec<u8>> {
     vec![
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+/// A short-lived key that a signer of the smart account grants to act on its behalf.
+/// The session key can reach synchronous consensus or vote in place of the `granter`,
+/// within the scope of the session and until it expires.
+#[account]
+pub struct SessionKey {
+    /// The settings this belongs to.
+    pub settings: Pubkey,
+    /// Signer of the smart account who granted the session.
+    pub granter: Pubkey,
+    /// The key that acts on behalf of the `granter` during the session.
+    pub key: Pubkey,
+    /// Permissions of the session. Always capped by the current permissions of the `granter`.
+    pub permissions: Permissions,
+    /// The moment after which the session key can no longer be used.
+    pub expiration: SessionExpiration,
+    /// The programs the session key is allowed to invoke.
+    /// If empty, the session key can invoke any program.
+    pub allowed_programs: Vec<Pubkey>,
+    /// The maximum amount of lamports the session key can move out of the smart accounts.
+    /// `None` means there is no budget.
+    pub max_lamports: Option<u64>,
+    /// The amount of lamports the session key has moved out of the smart accounts so far.
+    pub lamports_spent: u64,
+    /// The rent collector for the session key account.
+    pub rent_collector: Pubkey,
+    /// PDA bump.
+    pub bump: u8,
+}
+
+impl SessionKey {
+    pub fn size(allowed_programs_length: usize) -> usize {
+        8  + // anchor discriminator
+        32 + // settings
+        32 + // granter
+        32 + // key
+        1  + // permissions
+        1  + // expiration enum discriminator
+        8  + // expiration
+        4  + // allowed_programs vector length
+        allowed_programs_length * 32 + // allowed_programs
+        1  + // max_lamports Option discriminator
+        8  + // max_lamports
+        8  + // lamports_spent
+        32 + // rent_collector
+        1 // bump
+    }
+
+    /// Look up the `SessionKey` of `key` for the settings among `accounts`.
+    /// The account must be owned by the program, so it can only have been created by `create_session_key`.
+    pub fn find<'a, 'info>(
+        settings_key: &Pubkey,
+        key: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Option<(&'a AccountInfo<'info>, SessionKey)> {
+        accounts.iter().find_map(|account| {
+            if account.owner != &crate::ID {
+                return None;
+            }
+            let session_key =
+                SessionKey::try_deserialize(&mut &account.try_borrow_data().ok()?[..]).ok()?;
+            (session_key.settings == *settings_key && session_key.key == *key)
+                .then_some((account, session_key))
+        })
+    }
+
+    /// Check that the session key can currently act on behalf of its `granter` and
+    /// return the permissions it acts with.
+    pub fn validate_use(&self, settings: &Settings) -> Result<Permissions> {
+        require!(
+            !self.expiration.has_passed()?,
+            SmartAccountError::SessionKeyExpired
+        );
+
+        // The granter must still be a signer of the smart account.
+        let granter_index = settings
+            .is_signer(self.granter)
+            .ok_or(SmartAccountError::NotASigner)?;
+        let granter_permissions = settings.signers[granter_index].permissions;
+
+        Ok(Permissions {
+            mask: self.permissions.mask & granter_permissions.mask,
+        })
+    }
+
+    /// Whether the session key is limited to some programs or a lamports budget.
+    pub fn is_scoped(&self) -> bool {
+        !self.allowed_programs.is_empty() || self.max_lamports.is_some()
+    }
+
+    /// Check that the session key is allowed to invoke `program_id`.
+    pub fn check_program(&self, program_id: &Pubkey) -> Result<()> {
+        require!(
+            self.allowed_programs.is_empty() || self.allowed_programs.contains(program_id),
+            SmartAccountError::SessionKeyOutOfScope
+        );
+
+        Ok(())
+    }
+
+    /// Record `lamports` moved out of the smart accounts by the session key against its budget.
+    pub fn record_spend(&mut self, lamports: u64) -> Result<()> {
+        self.lamports_spent = self.lamports_spent.checked_add(lamports).unwrap();
+
+        if let Some(max_lamports) = self.max_lamports {
+            require!(
+                self.lamports_spent <= max_lamports,
+                SmartAccountError::SessionKeyBudgetExceeded
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// The moment a session key expires, either as a unix timestamp or as a slot.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SessionExpiration {
+    /// The session key expires at this unix timestamp.
+    Timestamp(i64),
+    /// The session key expires at this slot.
+    Slot(u64),
+}
+
+impl SessionExpiration {
+    pub fn has_passed(&self) -> Result<bool> {
+        let clock = Clock::get()?;
+
+        Ok(match self {
+            SessionExpiration::Timestamp(timestamp) => clock.unix_timestamp >= *timestamp,
+            SessionExpiration::Slot(slot) => clock.slot >= *slot,
+        })
+    }
+}
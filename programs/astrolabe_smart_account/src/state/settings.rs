@@ -3,6 +3,10 @@ use anchor_lang::system_program;
 use crate::{errors::*, id, state::*, utils::*, SettingsAction};
 
 pub const MAX_TIME_LOCK: u32 = 3 * 30 * 24 * 60 * 60; // 3 months
+/// How many seconds must pass after unarchival before a smart account can be archived again.
+pub const ARCHIVAL_COOLDOWN: u64 = 30 * 24 * 60 * 60; // 1 month
+/// Max length of the instruction discriminator of a `ProgramFilter`, the length of Anchor discriminators.
+pub const MAX_PROGRAM_FILTER_DISCRIMINATOR_LEN: usize = 8;
 
 #[account]
 pub struct Settings {
@@ -11,7 +15,8 @@ pub struct Settings {
     /// to ensure uniqueness of each settings PDA without relying on user input.
     ///
     /// Note: As this represents a DOS vector in the current creation architecture,
-    /// account creation will be permissioned until compression is implemented.
+    /// account creation can be permissioned with the program config `smart_account_creation_mode`
+    /// until compression is implemented.
     pub seed: u128,
     /// The authority that can change the smart account settings.
     /// This is a very important parameter as this authority can change the signers and threshold.
@@ -23,7 +28,7 @@ pub struct Settings {
     /// However, if this parameter is set to any other key, all the setting changes for this smart account settings
     /// will need to be signed by the `settings_authority`. We call such a smart account a "controlled smart account".
     pub settings_authority: Pubkey,
-    /// Threshold for signatures.
+    /// Threshold for signatures, as the voting weight of the signers that approved.
     pub threshold: u16,
     /// How many seconds must pass between transaction voting settlement and execution.
     pub time_lock: u32,
@@ -32,21 +37,46 @@ pub struct Settings {
     /// Last stale transaction index. All transactions up until this index are stale.
     /// This index is updated when smart account settings (signers/threshold/time_lock) change.
     pub stale_transaction_index: u64,
-    /// Field reserved for when archival/compression is implemented.
-    /// Will be set to Pubkey::default() to mark accounts that should
-    /// be eligible for archival before the feature is implemented.
+    /// The address where the rent for the accounts related to executed, rejected, or cancelled
+    /// transactions can be reclaimed. If set to `None`, the rent reclamation feature is turned off.
+    pub rent_collector: Option<Pubkey>,
+    /// The authority that can archive the settings account with `archive_settings` to reclaim its rent.
+    /// `None` and `Pubkey::default()` both mean that the smart account cannot be archived.
     pub archival_authority: Option<Pubkey>,
-    /// Field that will prevent a smart account from being archived immediately after unarchival.
+    /// Unix timestamp before which the smart account cannot be archived.
+    /// Set to `ARCHIVAL_COOLDOWN` seconds after the moment of unarchival.
     /// This is to prevent a DOS vector where the archival authority could
     /// constantly unarchive and archive the smart account to prevent it from
     /// being used.
     pub archivable_after: u64,
+    /// How many guardians (restricted signers with the `Recover` permission) must approve a recovery.
+    /// `0` means that recovery is disabled.
+    pub recovery_threshold: u16,
+    /// How many seconds the current signers have to veto a recovery once it's approved by the guardians.
+    pub recovery_delay: u32,
+    /// Bitmap of the account indices that have a `VaultPolicy`, bit `i % 8` of byte `i / 8` for account index `i`.
+    /// Used to require the `VaultPolicy` account whenever one exists for the smart account being used.
+    pub vault_policies: [u8; 32],
+    /// How many seconds an approved proposal can be executed for after its approval.
+    /// It includes the time lock, so it should be longer than the time lock of the smart accounts.
+    /// `0` means approved proposals can be executed at any time.
+    pub execution_window: u32,
+    /// How many seconds must pass between a signer requesting the rotation of its own key and the rotation,
+    /// giving the other signers time to cancel it. `None` means that signers can't rotate their own keys.
+    pub signer_rotation_delay: Option<u32>,
+    /// Whether `program_filters` are the only instructions the smart accounts can invoke, or the ones they can't.
+    pub program_filter_mode: ProgramFilterMode,
     /// Bump for the smart account PDA seed.
     pub bump: u8,
     /// Signers attached to the smart account
     pub signers: Vec<SmartAccountSigner>,
     // Restricted signers attached to the smart account
     pub restricted_signers: Vec<RestrictedSmartAccountSigner>,
+    /// Time locks of specific programs or settings actions, sorted by target.
+    /// A transaction is time locked for the longest of `time_lock` and the time locks of the targets it touches.
+    pub target_time_locks: Vec<TargetTimeLock>,
+    /// Program instructions the smart accounts are allowed or denied to invoke, sorted, see `program_filter_mode`.
+    pub program_filters: Vec<ProgramFilter>,
     /// Counter for how many sub accounts are in use (improves off-chain indexing)
     pub account_utilization: u8,
     // Reserved for future use
@@ -89,7 +119,12 @@ impl Settings {
             system_program,
             &crate::ID,
             &rent,
-            Settings::size(self.signers.len(), self.restricted_signers.len()),
+            Settings::size(
+                self.signers.len(),
+                self.restricted_signers.len(),
+                self.target_time_locks.len(),
+                self.program_filters.len(),
+            ),
             vec![
                 SEED_PREFIX.to_vec(),
                 SEED_SETTINGS.to_vec(),
@@ -101,7 +136,12 @@ impl Settings {
         Ok(settings_account_info)
     }
 
-    pub fn size(signers_length: usize, restricted_signers_length: usize) -> usize {
+    pub fn size(
+        signers_length: usize,
+        restricted_signers_length: usize,
+        target_time_locks_length: usize,
+        program_filters_length: usize,
+    ) -> usize {
         8  + // anchor account discriminator
         16 + // seed
         32 + // settings_authority
@@ -109,14 +149,27 @@ impl Settings {
         4  + // time_lock
         8  + // transaction_index
         8  + // stale_transaction_index
+        1  + // rent_collector Option discriminator
+        32 + // rent_collector (always 32 bytes, even if None, just to keep the realloc logic simpler)
         1  + // archival_authority Option discriminator
         32 + // archival_authority (always 32 bytes, even if None, just to keep the realloc logic simpler)
         8  + // archivable_after
+        2  + // recovery_threshold
+        4  + // recovery_delay
+        32 + // vault_policies
+        4  + // execution_window
+        1  + // signer_rotation_delay Option discriminator
+        4  + // signer_rotation_delay (always 4 bytes, even if None, just to keep the realloc logic simpler)
+        1  + // program_filter_mode
         1  + // bump
         4  + // signers vector length
         signers_length * SmartAccountSigner::INIT_SPACE + // signers
         4  + // restricted_signers vector length
         restricted_signers_length * RestrictedSmartAccountSigner::INIT_SPACE + // restricted_signers
+        4  + // target_time_locks vector length
+        target_time_locks_length * TargetTimeLock::INIT_SPACE + // target_time_locks
+        4  + // program_filters vector length
+        program_filters_length * ProgramFilter::INIT_SPACE + // program_filters (always the max discriminator length)
         1  + // sub_account_utilization
         1  + // _reserved_1
         1 // _reserved_2
@@ -136,6 +189,15 @@ impl Settings {
             .count()
     }
 
+    /// The sum of the voting weights of the `signers` with the `Vote` permission.
+    pub fn total_voting_weight(signers: &[SmartAccountSigner]) -> usize {
+        signers
+            .iter()
+            .filter(|m| m.permissions.has(Permission::Vote))
+            .map(|m| usize::from(m.weight))
+            .sum()
+    }
+
     pub fn num_executors(signers: &[SmartAccountSigner]) -> usize {
         signers
             .iter()
@@ -143,11 +205,19 @@ impl Settings {
             .count()
     }
 
-    /// Check if the settings account space needs to be reallocated to accommodate `signers_length`.
+    pub fn num_guardians(restricted_signers: &[RestrictedSmartAccountSigner]) -> usize {
+        restricted_signers
+            .iter()
+            .filter(|m| m.restricted_permissions.has(RestrictedPermission::Recover))
+            .count()
+    }
+
+    /// Check if the settings account space needs to be reallocated to accommodate the signers,
+    /// restricted signers, target time locks and program filters of `settings_data`.
     /// Returns `true` if the account was reallocated.
     pub fn realloc_if_needed<'a>(
         settings: AccountInfo<'a>,
-        signers_length: usize,
+        settings_data: &Settings,
         fee_payer: Option<AccountInfo<'a>>,
         system_program: Option<AccountInfo<'a>>,
     ) -> Result<bool> {
@@ -159,7 +229,12 @@ impl Settings {
         );
 
         let current_account_size = settings.data.borrow().len();
-        let account_size_to_fit_signers = Settings::size(signers_length, 0); // Note: This function doesn't handle restricted signers reallocation
+        let account_size_to_fit_signers = Settings::size(
+            settings_data.signers.len(),
+            settings_data.restricted_signers.len(),
+            settings_data.target_time_locks.len(),
+            settings_data.program_filters.len(),
+        );
 
         // Check if we need to reallocate space.
         if current_account_size >= account_size_to_fit_signers {
@@ -180,6 +255,7 @@ impl Settings {
         let Self {
             threshold,
             signers,
+            restricted_signers,
             transaction_index,
             stale_transaction_index,
             ..
@@ -200,6 +276,38 @@ impl Settings {
             SmartAccountError::UnknownPermission
         );
 
+        // There must be no duplicate restricted signers.
+        let has_duplicate_restricted_signers = restricted_signers
+            .windows(2)
+            .any(|win| win[0].key == win[1].key);
+        require!(
+            !has_duplicate_restricted_signers,
+            SmartAccountError::DuplicateSigner
+        );
+
+        // Restricted signers must not have unknown permissions.
+        require!(
+            restricted_signers
+                .iter()
+                .all(|m| m.restricted_permissions.mask < 4), // 4 = EmergencyExit | Recover
+            SmartAccountError::UnknownPermission
+        );
+
+        // Restricted signers that can trigger an emergency exit must have somewhere to exit to.
+        require!(
+            restricted_signers.iter().all(|m| {
+                !m.restricted_permissions.has(RestrictedPermission::EmergencyExit)
+                    || m.exit_destination != Pubkey::default()
+            }),
+            SmartAccountError::MissingExitDestination
+        );
+
+        // Recovery threshold must not exceed the number of guardians.
+        require!(
+            usize::from(self.recovery_threshold) <= Self::num_guardians(restricted_signers),
+            SmartAccountError::InvalidRecoveryThreshold
+        );
+
         // There must be at least one signer with Initiate permission.
         let num_proposers = Self::num_proposers(signers);
         require!(num_proposers > 0, SmartAccountError::NoProposers);
@@ -215,9 +323,9 @@ impl Settings {
         // Threshold must be greater than 0.
         require!(*threshold > 0, SmartAccountError::InvalidThreshold);
 
-        // Threshold must not exceed the number of voters.
+        // Threshold must not exceed the total voting weight.
         require!(
-            usize::from(*threshold) <= num_voters,
+            usize::from(*threshold) <= Self::total_voting_weight(signers),
             SmartAccountError::InvalidThreshold
         );
 
@@ -233,6 +341,57 @@ impl Settings {
             SmartAccountError::TimeLockExceedsMaxAllowed
         );
 
+        // Signer rotation delay must not exceed the maximum allowed either.
+        require!(
+            self.signer_rotation_delay.unwrap_or(0) <= MAX_TIME_LOCK,
+            SmartAccountError::TimeLockExceedsMaxAllowed
+        );
+
+        // There must be no duplicate target time locks, we make sure they are sorted when setting one.
+        let has_duplicate_target_time_locks = self
+            .target_time_locks
+            .windows(2)
+            .any(|win| win[0].target >= win[1].target);
+        require!(
+            !has_duplicate_target_time_locks,
+            SmartAccountError::DuplicateTargetTimeLock
+        );
+
+        // Target time locks must not exceed the maximum allowed either.
+        require!(
+            self.target_time_locks
+                .iter()
+                .all(|t| t.time_lock <= MAX_TIME_LOCK),
+            SmartAccountError::TimeLockExceedsMaxAllowed
+        );
+
+        // There must be no duplicate program filters, we make sure they are sorted when adding one.
+        let has_duplicate_program_filters =
+            self.program_filters.windows(2).any(|win| win[0] >= win[1]);
+        require!(
+            !has_duplicate_program_filters,
+            SmartAccountError::DuplicateProgramFilter
+        );
+
+        // Program filter discriminators must fit the space reserved for them.
+        require!(
+            self.program_filters
+                .iter()
+                .all(|f| f.discriminator.len() <= MAX_PROGRAM_FILTER_DISCRIMINATOR_LEN),
+            SmartAccountError::InvalidProgramFilter
+        );
+
+        // Execution window must leave time to execute proposals once the time lock is released.
+        let max_time_lock = self
+            .target_time_locks
+            .iter()
+            .map(|t| t.time_lock)
+            .fold(self.time_lock, u32::max);
+        require!(
+            self.execution_window == 0 || self.execution_window > max_time_lock,
+            SmartAccountError::InvalidExecutionWindow
+        );
+
         Ok(())
     }
 
@@ -250,6 +409,27 @@ impl Settings {
             .ok()
     }
 
+    /// Returns `Some(index)` if `signer_pubkey` is a restricted signer, with `index` into the `restricted_signers` vec.
+    /// `None` otherwise.
+    pub fn is_restricted_signer(&self, signer_pubkey: Pubkey) -> Option<usize> {
+        self.restricted_signers
+            .binary_search_by_key(&signer_pubkey, |m| m.key)
+            .ok()
+    }
+
+    pub fn restricted_signer_has_permission(
+        &self,
+        signer_pubkey: Pubkey,
+        restricted_permission: RestrictedPermission,
+    ) -> bool {
+        match self.is_restricted_signer(signer_pubkey) {
+            Some(index) => self.restricted_signers[index]
+                .restricted_permissions
+                .has(restricted_permission),
+            _ => false,
+        }
+    }
+
     pub fn signer_has_permission(&self, signer_pubkey: Pubkey, permission: Permission) -> bool {
         match self.is_signer(signer_pubkey) {
             Some(index) => self.signers[index].permissions.has(permission),
@@ -257,11 +437,95 @@ impl Settings {
         }
     }
 
-    /// How many "reject" votes are enough to make the transaction "Rejected".
+    /// The sum of the voting weights of the `keys` that are signers with the `Vote` permission.
+    pub fn voting_weight(&self, keys: &[Pubkey]) -> usize {
+        keys.iter()
+            .filter_map(|key| self.is_signer(*key))
+            .map(|index| &self.signers[index])
+            .filter(|m| m.permissions.has(Permission::Vote))
+            .map(|m| usize::from(m.weight))
+            .sum()
+    }
+
+    /// The longest time lock of the `targets`, `0` if none of them has one.
+    /// Doesn't include the settings `time_lock`, which applies to all transactions.
+    pub fn target_time_lock(&self, targets: impl IntoIterator<Item = TimeLockTarget>) -> u32 {
+        targets
+            .into_iter()
+            .filter_map(|target| {
+                self.target_time_locks
+                    .binary_search_by_key(&target, |t| t.target)
+                    .ok()
+            })
+            .map(|index| self.target_time_locks[index].time_lock)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The longest time lock of the programs invoked by the instructions of `message`.
+    /// Programs loaded from address lookup tables are unknown until execution,
+    /// so they are time locked for the longest time lock of any program.
+    pub fn message_time_lock(&self, message: &SmartAccountTransactionMessage) -> u32 {
+        let any_program_time_lock = || {
+            self.target_time_locks
+                .iter()
+                .filter(|t| matches!(t.target, TimeLockTarget::Program { .. }))
+                .map(|t| t.time_lock)
+                .max()
+                .unwrap_or(0)
+        };
+
+        message
+            .instructions
+            .iter()
+            .map(|instruction| {
+                match message
+                    .account_keys
+                    .get(usize::from(instruction.program_id_index))
+                {
+                    Some(program_id) => self.target_time_lock([TimeLockTarget::Program {
+                        program_id: *program_id,
+                    }]),
+                    None => any_program_time_lock(),
+                }
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The longest time lock of the kinds of `actions`.
+    pub fn actions_time_lock(&self, actions: &[SettingsAction]) -> u32 {
+        self.target_time_lock(actions.iter().map(|action| TimeLockTarget::SettingsAction {
+            kind: action.kind(),
+        }))
+    }
+
+    /// Check that the smart accounts are allowed to invoke the instruction of `program_id` with `data`.
+    ///
+    /// # Errors
+    /// - `SmartAccountError::ProgramNotAllowed` if the instruction is not allowed by the `program_filters`.
+    pub fn check_program_filters(&self, program_id: &Pubkey, data: &[u8]) -> Result<()> {
+        let matches_any_filter = || {
+            self.program_filters
+                .iter()
+                .any(|filter| filter.matches(program_id, data))
+        };
+
+        let allowed = match self.program_filter_mode {
+            ProgramFilterMode::Disabled => true,
+            ProgramFilterMode::Allowlist => matches_any_filter(),
+            ProgramFilterMode::Denylist => !matches_any_filter(),
+        };
+        require!(allowed, SmartAccountError::ProgramNotAllowed);
+
+        Ok(())
+    }
+
+    /// How much "reject" voting weight is enough to make the transaction "Rejected".
     /// The cutoff must be such that it is impossible for the remaining voters to reach the approval threshold.
-    /// For example: total voters = 7, threshold = 3, cutoff = 5.
+    /// For example: total voting weight = 7, threshold = 3, cutoff = 5.
     pub fn cutoff(&self) -> usize {
-        Self::num_voters(&self.signers)
+        Self::total_voting_weight(&self.signers)
             .checked_sub(usize::from(self.threshold))
             .unwrap()
             .checked_add(1)
@@ -288,6 +552,126 @@ impl Settings {
 
         Ok(())
     }
+
+    /// Replace the key of the `old_signer` signer with `new_signer`, keeping its permissions and weight,
+    /// and sort the `signers` vec.
+    ///
+    /// # Errors
+    /// - `SmartAccountError::NotASigner` if `old_signer` is not a signer.
+    /// - `SmartAccountError::DuplicateSigner` if `new_signer` is already a signer.
+    pub fn rotate_signer(&mut self, old_signer: Pubkey, new_signer: Pubkey) -> Result<()> {
+        let old_signer_index = self
+            .is_signer(old_signer)
+            .ok_or(SmartAccountError::NotASigner)?;
+        require!(
+            self.is_signer(new_signer).is_none(),
+            SmartAccountError::DuplicateSigner
+        );
+
+        self.signers[old_signer_index].key = new_signer;
+        self.signers.sort_by_key(|m| m.key);
+
+        Ok(())
+    }
+
+    /// Add `new_restricted_signer` to the settings `restricted_signers` vec and sort the vec.
+    pub fn add_restricted_signer(&mut self, new_restricted_signer: RestrictedSmartAccountSigner) {
+        self.restricted_signers.push(new_restricted_signer);
+        self.restricted_signers.sort_by_key(|m| m.key);
+    }
+
+    /// Remove `signer_pubkey` from the settings `restricted_signers` vec.
+    ///
+    /// # Errors
+    /// - `SmartAccountError::NotASigner` if `signer_pubkey` is not a restricted signer.
+    pub fn remove_restricted_signer(&mut self, signer_pubkey: Pubkey) -> Result<()> {
+        let old_restricted_signer_index = match self.is_restricted_signer(signer_pubkey) {
+            Some(old_restricted_signer_index) => old_restricted_signer_index,
+            None => return err!(SmartAccountError::NotASigner),
+        };
+
+        self.restricted_signers.remove(old_restricted_signer_index);
+
+        Ok(())
+    }
+
+    /// Replace the `permissions` of the `signer_pubkey` signer.
+    ///
+    /// # Errors
+    /// - `SmartAccountError::NotASigner` if `signer_pubkey` is not a signer.
+    pub fn change_signer_permissions(
+        &mut self,
+        signer_pubkey: Pubkey,
+        new_permissions: Permissions,
+    ) -> Result<()> {
+        let signer_index = self
+            .is_signer(signer_pubkey)
+            .ok_or(SmartAccountError::NotASigner)?;
+
+        self.signers[signer_index].permissions = new_permissions;
+
+        Ok(())
+    }
+
+    /// Set the time lock of `target` in the settings `target_time_locks` vec, keeping the vec sorted.
+    pub fn set_target_time_lock(&mut self, target: TimeLockTarget, time_lock: u32) {
+        match self
+            .target_time_locks
+            .binary_search_by_key(&target, |t| t.target)
+        {
+            Ok(index) => self.target_time_locks[index].time_lock = time_lock,
+            Err(index) => self
+                .target_time_locks
+                .insert(index, TargetTimeLock { target, time_lock }),
+        }
+    }
+
+    /// Remove the time lock of `target` from the settings `target_time_locks` vec.
+    ///
+    /// # Errors
+    /// - `SmartAccountError::MissingTargetTimeLock` if `target` has no time lock.
+    pub fn remove_target_time_lock(&mut self, target: TimeLockTarget) -> Result<()> {
+        let index = self
+            .target_time_locks
+            .binary_search_by_key(&target, |t| t.target)
+            .map_err(|_| SmartAccountError::MissingTargetTimeLock)?;
+
+        self.target_time_locks.remove(index);
+
+        Ok(())
+    }
+
+    /// Add `filter` to the settings `program_filters` vec, keeping the vec sorted.
+    ///
+    /// # Errors
+    /// - `SmartAccountError::DuplicateProgramFilter` if `filter` is already in the vec.
+    pub fn add_program_filter(&mut self, filter: ProgramFilter) -> Result<()> {
+        let index = self
+            .program_filters
+            .binary_search(&filter)
+            .err()
+            .ok_or(SmartAccountError::DuplicateProgramFilter)?;
+
+        self.program_filters.insert(index, filter);
+
+        Ok(())
+    }
+
+    /// Remove `filter` from the settings `program_filters` vec.
+    ///
+    /// # Errors
+    /// - `SmartAccountError::MissingProgramFilter` if `filter` is not in the vec.
+    pub fn remove_program_filter(&mut self, filter: &ProgramFilter) -> Result<()> {
+        let index = self
+            .program_filters
+            .binary_search(filter)
+            .map_err(|_| SmartAccountError::MissingProgramFilter)?;
+
+        self.program_filters.remove(index);
+
+        Ok(())
+    }
+
     // Modify the settings with a given action.
     pub fn modify_with_action<'info>(
         &mut self,
@@ -323,14 +707,355 @@ impl Settings {
             SettingsAction::SetArchivalAuthority {
                 new_archival_authority,
             } => {
-                // Marked as NotImplemented until archival feature is implemented.
-                return err!(SmartAccountError::NotImplemented);
+                // We don't need to invalidate prior transactions here because changing
+                // `archival_authority` doesn't affect the consensus parameters of the settings.
+                self.archival_authority = *new_archival_authority;
+            }
+
+            SettingsAction::AddSpendingLimit {
+                seed,
+                account_index,
+                mint,
+                amount,
+                period,
+                signers,
+                destinations,
+                expiration,
+            } => {
+                let (spending_limit_key, spending_limit_bump) = Pubkey::find_program_address(
+                    &[
+                        SEED_PREFIX,
+                        self_key.as_ref(),
+                        SEED_SPENDING_LIMIT,
+                        seed.as_ref(),
+                    ],
+                    program_id,
+                );
+
+                // Find the SpendingLimit account in `remaining_accounts`.
+                let spending_limit_info = remaining_accounts
+                    .iter()
+                    .find(|acc| acc.key == &spending_limit_key)
+                    .ok_or(SmartAccountError::MissingAccount)?;
+
+                // `fee_payer` and `system_program` must also be present.
+                let fee_payer = fee_payer
+                    .as_ref()
+                    .ok_or(SmartAccountError::MissingAccount)?;
+                let system_program = system_program
+                    .as_ref()
+                    .ok_or(SmartAccountError::MissingAccount)?;
+
+                // Initialize the SpendingLimit account.
+                create_account(
+                    fee_payer,
+                    spending_limit_info,
+                    system_program,
+                    &id(),
+                    rent,
+                    SpendingLimit::size(signers.len(), destinations.len()),
+                    vec![
+                        SEED_PREFIX.to_vec(),
+                        self_key.as_ref().to_vec(),
+                        SEED_SPENDING_LIMIT.to_vec(),
+                        seed.as_ref().to_vec(),
+                        vec![spending_limit_bump],
+                    ],
+                )?;
+
+                // Signers are sorted so that `SpendingLimit::is_signer` can use binary search.
+                let mut signers = signers.to_vec();
+                signers.sort();
+
+                let spending_limit = SpendingLimit {
+                    settings: self_key.to_owned(),
+                    seed: seed.to_owned(),
+                    account_index: *account_index,
+                    mint: *mint,
+                    amount: *amount,
+                    period: *period,
+                    remaining_amount: *amount,
+                    last_reset: Clock::get()?.unix_timestamp,
+                    bump: spending_limit_bump,
+                    signers,
+                    destinations: destinations.to_vec(),
+                    expiration: *expiration,
+                };
+
+                spending_limit.invariant()?;
+
+                spending_limit
+                    .try_serialize(&mut &mut spending_limit_info.data.borrow_mut()[..])?;
+            }
+
+            SettingsAction::RemoveSpendingLimit {
+                spending_limit: spending_limit_key,
+            } => {
+                // Find the SpendingLimit account in `remaining_accounts`.
+                let spending_limit_info = remaining_accounts
+                    .iter()
+                    .find(|acc| acc.key == spending_limit_key)
+                    .ok_or(SmartAccountError::MissingAccount)?;
+
+                // The rent of the closed account goes to the `rent_collector` of the settings,
+                // which must then also be present, or to `fee_payer` if rent reclamation is disabled.
+                let rent_collector = match self.rent_collector {
+                    Some(rent_collector) => remaining_accounts
+                        .iter()
+                        .find(|acc| acc.key == &rent_collector)
+                        .ok_or(SmartAccountError::MissingAccount)?
+                        .to_account_info(),
+                    None => fee_payer
+                        .as_ref()
+                        .ok_or(SmartAccountError::MissingAccount)?
+                        .to_account_info(),
+                };
+
+                let spending_limit = Account::<SpendingLimit>::try_from(spending_limit_info)?;
+
+                // SpendingLimit must belong to the settings.
+                require_keys_eq!(
+                    spending_limit.settings,
+                    *self_key,
+                    SmartAccountError::InvalidAccount
+                );
+
+                spending_limit.close(rent_collector)?;
+            }
+
+            SettingsAction::SetRentCollector { new_rent_collector } => {
+                // We don't need to invalidate prior transactions here because changing
+                // `rent_collector` doesn't affect the consensus parameters of the settings.
+                self.rent_collector = *new_rent_collector;
+            }
+
+            SettingsAction::SetRecoveryConfig {
+                new_recovery_threshold,
+                new_recovery_delay,
+            } => {
+                // We don't need to invalidate prior transactions here because the recovery
+                // config doesn't affect the consensus parameters of the settings.
+                self.recovery_threshold = *new_recovery_threshold;
+                self.recovery_delay = *new_recovery_delay;
+            }
+
+            SettingsAction::SetExecutionWindow {
+                new_execution_window,
+            } => {
+                // We don't need to invalidate prior transactions here because changing
+                // `execution_window` doesn't affect the consensus parameters of the settings.
+                self.execution_window = *new_execution_window;
+            }
+
+            SettingsAction::SetVaultPolicy {
+                account_index,
+                threshold,
+                time_lock,
+                signers,
+            } => {
+                let (vault_policy_key, vault_policy_bump) = Pubkey::find_program_address(
+                    &[
+                        SEED_PREFIX,
+                        self_key.as_ref(),
+                        SEED_VAULT_POLICY,
+                        &account_index.to_le_bytes(),
+                    ],
+                    program_id,
+                );
+
+                // Find the VaultPolicy account in `remaining_accounts`.
+                let vault_policy_info = remaining_accounts
+                    .iter()
+                    .find(|acc| acc.key == &vault_policy_key)
+                    .ok_or(SmartAccountError::MissingAccount)?;
+
+                // `fee_payer` and `system_program` must also be present.
+                let fee_payer = fee_payer
+                    .as_ref()
+                    .ok_or(SmartAccountError::MissingAccount)?;
+                let system_program = system_program
+                    .as_ref()
+                    .ok_or(SmartAccountError::MissingAccount)?;
+
+                if self.has_vault_policy(*account_index) {
+                    // Make sure the existing VaultPolicy account can fit the new signers.
+                    realloc(
+                        vault_policy_info,
+                        VaultPolicy::size(signers.len()),
+                        Some(fee_payer.to_account_info()),
+                        Some(system_program.to_account_info()),
+                    )?;
+                } else {
+                    // Initialize the VaultPolicy account.
+                    create_account(
+                        fee_payer,
+                        vault_policy_info,
+                        system_program,
+                        &id(),
+                        rent,
+                        VaultPolicy::size(signers.len()),
+                        vec![
+                            SEED_PREFIX.to_vec(),
+                            self_key.as_ref().to_vec(),
+                            SEED_VAULT_POLICY.to_vec(),
+                            account_index.to_le_bytes().to_vec(),
+                            vec![vault_policy_bump],
+                        ],
+                    )?;
+                    self.set_has_vault_policy(*account_index, true);
+                }
+
+                // Signers are sorted so that `VaultPolicy::is_signer` can use binary search.
+                let mut signers = signers.to_vec();
+                signers.sort();
+
+                let vault_policy = VaultPolicy {
+                    settings: self_key.to_owned(),
+                    account_index: *account_index,
+                    threshold: *threshold,
+                    time_lock: *time_lock,
+                    bump: vault_policy_bump,
+                    signers,
+                };
+
+                vault_policy.invariant(self)?;
+
+                vault_policy.try_serialize(&mut &mut vault_policy_info.data.borrow_mut()[..])?;
+
+                self.invalidate_prior_transactions();
+            }
+
+            SettingsAction::RemoveVaultPolicy { account_index } => {
+                let (vault_policy_key, _) = Pubkey::find_program_address(
+                    &[
+                        SEED_PREFIX,
+                        self_key.as_ref(),
+                        SEED_VAULT_POLICY,
+                        &account_index.to_le_bytes(),
+                    ],
+                    program_id,
+                );
+
+                // Find the VaultPolicy account in `remaining_accounts`.
+                let vault_policy_info = remaining_accounts
+                    .iter()
+                    .find(|acc| acc.key == &vault_policy_key)
+                    .ok_or(SmartAccountError::MissingAccount)?;
+
+                // The rent of the closed account goes to the `rent_collector` of the settings,
+                // which must then also be present, or to `fee_payer` if rent reclamation is disabled.
+                let rent_collector = match self.rent_collector {
+                    Some(rent_collector) => remaining_accounts
+                        .iter()
+                        .find(|acc| acc.key == &rent_collector)
+                        .ok_or(SmartAccountError::MissingAccount)?
+                        .to_account_info(),
+                    None => fee_payer
+                        .as_ref()
+                        .ok_or(SmartAccountError::MissingAccount)?
+                        .to_account_info(),
+                };
+
+                let vault_policy = Account::<VaultPolicy>::try_from(vault_policy_info)?;
+
+                vault_policy.close(rent_collector)?;
+
+                self.set_has_vault_policy(*account_index, false);
+                self.invalidate_prior_transactions();
+            }
+
+            SettingsAction::SetTargetTimeLock { target, time_lock } => {
+                self.set_target_time_lock(*target, *time_lock);
+                self.invalidate_prior_transactions();
+            }
+
+            SettingsAction::RemoveTargetTimeLock { target } => {
+                self.remove_target_time_lock(*target)?;
+                self.invalidate_prior_transactions();
+            }
+
+            SettingsAction::AddRestrictedSigner {
+                new_restricted_signer,
+            } => {
+                // We don't need to invalidate prior transactions here because restricted
+                // signers don't take part in the voting consensus of the settings.
+                self.add_restricted_signer(new_restricted_signer.to_owned());
+            }
+
+            SettingsAction::RemoveRestrictedSigner {
+                old_restricted_signer,
+            } => {
+                self.remove_restricted_signer(*old_restricted_signer)?;
+            }
+
+            SettingsAction::ChangeSignerPermissions {
+                signer,
+                new_permissions,
+            } => {
+                self.change_signer_permissions(*signer, *new_permissions)?;
+                self.invalidate_prior_transactions();
+            }
+
+            SettingsAction::SetSettingsAuthority {
+                new_settings_authority,
+            } => {
+                self.settings_authority = *new_settings_authority;
+                self.invalidate_prior_transactions();
+            }
+
+            SettingsAction::RotateSigner {
+                old_signer,
+                new_signer,
+            } => {
+                self.rotate_signer(*old_signer, *new_signer)?;
+                self.invalidate_prior_transactions();
+            }
+
+            SettingsAction::SetSignerRotationDelay {
+                new_signer_rotation_delay,
+            } => {
+                // We don't need to invalidate prior transactions here because the signer
+                // rotation delay doesn't affect the consensus parameters of the settings.
+                self.signer_rotation_delay = *new_signer_rotation_delay;
+            }
+
+            // We don't need to invalidate prior transactions when changing the program filters
+            // because they are checked when executing the transactions.
+            SettingsAction::SetProgramFilterMode { mode } => {
+                self.program_filter_mode = *mode;
+            }
+
+            SettingsAction::AddProgramFilter { filter } => {
+                self.add_program_filter(filter.to_owned())?;
+            }
+
+            SettingsAction::RemoveProgramFilter { filter } => {
+                self.remove_program_filter(filter)?;
             }
         }
 
         Ok(())
     }
 
+    /// Whether the smart account at `account_index` has a `VaultPolicy`.
+    pub fn has_vault_policy(&self, account_index: u8) -> bool {
+        self.vault_policies[usize::from(account_index / 8)] & (1 << (account_index % 8)) != 0
+    }
+
+    /// Whether any of the smart accounts has a `VaultPolicy`.
+    pub fn has_any_vault_policy(&self) -> bool {
+        self.vault_policies.iter().any(|byte| *byte != 0)
+    }
+
+    pub fn set_has_vault_policy(&mut self, account_index: u8, has_vault_policy: bool) {
+        let byte = &mut self.vault_policies[usize::from(account_index / 8)];
+        if has_vault_policy {
+            *byte |= 1 << (account_index % 8);
+        } else {
+            *byte &= !(1 << (account_index % 8));
+        }
+    }
+
     pub fn increment_account_utilization(&mut self) {
         self.account_utilization = self.account_utilization.checked_add(1).unwrap();
     }
@@ -340,12 +1065,63 @@ impl Settings {
 pub struct SmartAccountSigner {
     pub key: Pubkey,
     pub permissions: Permissions,
+    /// Voting weight of the signer, counted towards the `threshold` when it has the `Vote` permission.
+    pub weight: u16,
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize, InitSpace, Eq, PartialEq, Clone)]
 pub struct RestrictedSmartAccountSigner {
     pub key: Pubkey,
     pub restricted_permissions: RestrictedPermissions,
+    /// The pre-registered address that funds are swept to when this signer
+    /// triggers an emergency exit.
+    pub exit_destination: Pubkey,
+}
+
+/// A time lock that applies to transactions touching `target`.
+#[derive(AnchorDeserialize, AnchorSerialize, InitSpace, Eq, PartialEq, Clone, Copy)]
+pub struct TargetTimeLock {
+    pub target: TimeLockTarget,
+    /// How many seconds must pass between transaction voting settlement and execution.
+    pub time_lock: u32,
+}
+
+#[derive(
+    AnchorDeserialize, AnchorSerialize, InitSpace, Eq, PartialEq, Ord, PartialOrd, Clone, Copy,
+)]
+pub enum TimeLockTarget {
+    /// Transactions with an instruction for the program.
+    Program { program_id: Pubkey },
+    /// Settings transactions with an action of this kind, see `SettingsAction::kind`.
+    SettingsAction { kind: u8 },
+}
+
+#[derive(
+    AnchorDeserialize, AnchorSerialize, InitSpace, Eq, PartialEq, Clone, Copy, Default, Debug,
+)]
+pub enum ProgramFilterMode {
+    /// The smart accounts can invoke any program.
+    #[default]
+    Disabled,
+    /// The smart accounts can only invoke the instructions matching one of the `program_filters`.
+    Allowlist,
+    /// The smart accounts can't invoke the instructions matching any of the `program_filters`.
+    Denylist,
+}
+
+/// The instructions of `program_id` whose data starts with `discriminator`.
+#[derive(AnchorDeserialize, AnchorSerialize, InitSpace, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct ProgramFilter {
+    pub program_id: Pubkey,
+    /// Empty to match all the instructions of the program.
+    #[max_len(MAX_PROGRAM_FILTER_DISCRIMINATOR_LEN)]
+    pub discriminator: Vec<u8>,
+}
+
+impl ProgramFilter {
+    pub fn matches(&self, program_id: &Pubkey, data: &[u8]) -> bool {
+        self.program_id == *program_id && data.starts_with(&self.discriminator)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
@@ -358,6 +1134,7 @@ pub enum Permission {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
 pub enum RestrictedPermission {
     EmergencyExit = 1 << 0,
+    Recover = 1 << 1,
 }
 
 /// Bitmask for permissions.
@@ -12,11 +12,15 @@ pub struct SettingsTransaction {
     /// Signer on the settings who submitted the transaction.
     pub creator: Pubkey,
     /// The rent collector for the settings transaction account.
+    /// Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
     pub rent_collector: Pubkey,
     /// Index of this transaction within the settings.
     pub index: u64,
     /// bump for the transaction seeds.
     pub bump: u8,
+    /// The longest time lock of the actions the transaction touches, from the settings `target_time_locks`.
+    /// Those can't change without making the transaction stale, so it's the same as at approval time.
+    pub time_lock: u32,
     /// Action to be performed on the settings.
     pub actions: Vec<SettingsAction>,
 }
@@ -34,6 +38,7 @@ impl SettingsTransaction {
         32 +  // rent_collector
         8 +   // index
         1 +   // bump
+        4 +   // time_lock
         4 +  // actions vector length
         actions_size
     }
@@ -52,6 +57,90 @@ pub enum SettingsAction {
     SetTimeLock { new_time_lock: u32 },
     /// Set the `archival_authority` config parameter of the settings.
     SetArchivalAuthority { new_archival_authority: Option<Pubkey> },
+    /// Create a new spending limit for the smart account.
+    AddSpendingLimit {
+        /// Key that is used to seed the SpendingLimit PDA.
+        seed: Pubkey,
+        /// The index of the smart account that the spending limit is for.
+        account_index: u8,
+        /// The token mint the spending limit is for.
+        mint: Pubkey,
+        /// The amount of tokens that can be spent in a period.
+        /// This amount is in decimals of the mint,
+        /// so 1 SOL would be `1_000_000_000` and 1 USDC would be `1_000_000`.
+        amount: u64,
+        /// The reset period of the spending limit.
+        /// When it passes, the remaining amount is reset, unless it's `Period::OneTime`.
+        period: Period,
+        /// Signers of the smart account that can use the spending limit.
+        /// In case a signer is removed from the smart account, the spending limit will remain existent
+        /// (until explicitly deleted), but the removed signer will not be able to use it anymore.
+        signers: Vec<Pubkey>,
+        /// The destination addresses the spending limit is allowed to send funds to.
+        /// If empty, funds can be sent to any address.
+        destinations: Vec<Pubkey>,
+        /// Unix timestamp after which the spending limit can no longer be used.
+        /// `i64::MAX` means the spending limit never expires.
+        expiration: i64,
+    },
+    /// Remove a spending limit from the smart account.
+    RemoveSpendingLimit { spending_limit: Pubkey },
+    /// Set the `rent_collector` config parameter of the settings.
+    SetRentCollector { new_rent_collector: Option<Pubkey> },
+    /// Set the `recovery_threshold` and `recovery_delay` config parameters of the settings.
+    SetRecoveryConfig {
+        new_recovery_threshold: u16,
+        new_recovery_delay: u32,
+    },
+    /// Set the `execution_window` config parameter of the settings.
+    SetExecutionWindow { new_execution_window: u32 },
+    /// Create or update the vault policy of one of the smart accounts.
+    SetVaultPolicy {
+        /// The index of the smart account that the policy is for.
+        account_index: u8,
+        /// How much voting weight of approvals from `signers` a transaction for the smart account needs to be executed.
+        threshold: u16,
+        /// How many seconds must pass between transaction voting settlement and execution.
+        time_lock: u32,
+        /// Signers of the settings that can create, approve and execute transactions for the smart account.
+        /// If empty, all the signers of the settings can.
+        signers: Vec<Pubkey>,
+    },
+    /// Remove the vault policy of one of the smart accounts.
+    RemoveVaultPolicy { account_index: u8 },
+    /// Set the time lock of a program or settings action kind, overriding the one it has if any.
+    SetTargetTimeLock {
+        target: TimeLockTarget,
+        /// How many seconds must pass between transaction voting settlement and execution.
+        time_lock: u32,
+    },
+    /// Remove the time lock of a program or settings action kind.
+    RemoveTargetTimeLock { target: TimeLockTarget },
+    /// Add a new restricted signer to the settings.
+    AddRestrictedSigner {
+        new_restricted_signer: RestrictedSmartAccountSigner,
+    },
+    /// Remove a restricted signer from the settings.
+    RemoveRestrictedSigner { old_restricted_signer: Pubkey },
+    /// Change the `permissions` of an existing signer of the settings.
+    ChangeSignerPermissions {
+        signer: Pubkey,
+        new_permissions: Permissions,
+    },
+    /// Set the `settings_authority` of the settings, making the smart account controlled.
+    SetSettingsAuthority { new_settings_authority: Pubkey },
+    /// Replace the key of a signer, keeping its permissions and weight.
+    RotateSigner { old_signer: Pubkey, new_signer: Pubkey },
+    /// Set the `signer_rotation_delay` config parameter of the settings.
+    SetSignerRotationDelay {
+        new_signer_rotation_delay: Option<u32>,
+    },
+    /// Set the `program_filter_mode` config parameter of the settings.
+    SetProgramFilterMode { mode: ProgramFilterMode },
+    /// Add a filter to the `program_filters` of the settings.
+    AddProgramFilter { filter: ProgramFilter },
+    /// Remove a filter from the `program_filters` of the settings.
+    RemoveProgramFilter { filter: ProgramFilter },
 }
 
 impl SettingsAction {
@@ -62,7 +151,8 @@ impl SettingsAction {
         match self {
             SettingsAction::AddSigner { .. } => {
                 32 + // SmartAccountSigner.key (Pubkey)
-                1    // SmartAccountSigner.permissions.mask (u8)
+                1  + // SmartAccountSigner.permissions.mask (u8)
+                2    // SmartAccountSigner.weight (u16)
             }
             SettingsAction::RemoveSigner { .. } => 32, // Pubkey
             SettingsAction::ChangeThreshold { .. } => 2, // u16
@@ -71,6 +161,112 @@ impl SettingsAction {
                 1 + // Option discriminator
                 if new_archival_authority.is_some() { 32 } else { 0 }
             }
+            SettingsAction::AddSpendingLimit { signers, destinations, .. } => {
+                32 + // seed
+                1  + // account_index
+                32 + // mint
+                8  + // amount
+                1  + // period
+                4  + // signers vector length
+                signers.len() * 32 + // signers
+                4  + // destinations vector length
+                destinations.len() * 32 + // destinations
+                8    // expiration
+            }
+            SettingsAction::RemoveSpendingLimit { .. } => 32, // Pubkey
+            SettingsAction::SetRentCollector { new_rent_collector } => {
+                1 + // Option discriminator
+                if new_rent_collector.is_some() { 32 } else { 0 }
+            }
+            SettingsAction::SetRecoveryConfig { .. } => {
+                2 + // new_recovery_threshold
+                4   // new_recovery_delay
+            }
+            SettingsAction::SetExecutionWindow { .. } => 4, // u32
+            SettingsAction::SetVaultPolicy { signers, .. } => {
+                1 + // account_index
+                2 + // threshold
+                4 + // time_lock
+                4 + // signers vector length
+                signers.len() * 32 // signers
+            }
+            SettingsAction::RemoveVaultPolicy { .. } => 1, // u8
+            SettingsAction::SetTargetTimeLock { target, .. } => {
+                target.borsh_size() +
+                4   // time_lock
+            }
+            SettingsAction::RemoveTargetTimeLock { target } => target.borsh_size(),
+            SettingsAction::AddRestrictedSigner { .. } => {
+                32 + // RestrictedSmartAccountSigner.key (Pubkey)
+                1  + // RestrictedSmartAccountSigner.restricted_permissions.mask (u8)
+                32   // RestrictedSmartAccountSigner.exit_destination (Pubkey)
+            }
+            SettingsAction::RemoveRestrictedSigner { .. } => 32, // Pubkey
+            SettingsAction::ChangeSignerPermissions { .. } => {
+                32 + // signer
+                1    // new_permissions.mask (u8)
+            }
+            SettingsAction::SetSettingsAuthority { .. } => 32, // Pubkey
+            SettingsAction::RotateSigner { .. } => {
+                32 + // old_signer
+                32   // new_signer
+            }
+            SettingsAction::SetSignerRotationDelay { new_signer_rotation_delay } => {
+                1 + // Option discriminator
+                if new_signer_rotation_delay.is_some() { 4 } else { 0 }
+            }
+            SettingsAction::SetProgramFilterMode { .. } => 1, // ProgramFilterMode
+            SettingsAction::AddProgramFilter { filter }
+            | SettingsAction::RemoveProgramFilter { filter } => filter.borsh_size(),
         }
     }
-}
\ No newline at end of file
+
+    /// The index of the variant of the action, used to target it with a `TimeLockTarget::SettingsAction`.
+    pub const fn kind(&self) -> u8 {
+        match self {
+            SettingsAction::AddSigner { .. } => 0,
+            SettingsAction::RemoveSigner { .. } => 1,
+            SettingsAction::ChangeThreshold { .. } => 2,
+            SettingsAction::SetTimeLock { .. } => 3,
+            SettingsAction::SetArchivalAuthority { .. } => 4,
+            SettingsAction::AddSpendingLimit { .. } => 5,
+            SettingsAction::RemoveSpendingLimit { .. } => 6,
+            SettingsAction::SetRentCollector { .. } => 7,
+            SettingsAction::SetRecoveryConfig { .. } => 8,
+            SettingsAction::SetExecutionWindow { .. } => 9,
+            SettingsAction::SetVaultPolicy { .. } => 10,
+            SettingsAction::RemoveVaultPolicy { .. } => 11,
+            SettingsAction::SetTargetTimeLock { .. } => 12,
+            SettingsAction::RemoveTargetTimeLock { .. } => 13,
+            SettingsAction::AddRestrictedSigner { .. } => 14,
+            SettingsAction::RemoveRestrictedSigner { .. } => 15,
+            SettingsAction::ChangeSignerPermissions { .. } => 16,
+            SettingsAction::SetSettingsAuthority { .. } => 17,
+            SettingsAction::RotateSigner { .. } => 18,
+            SettingsAction::SetSignerRotationDelay { .. } => 19,
+            SettingsAction::SetProgramFilterMode { .. } => 20,
+            SettingsAction::AddProgramFilter { .. } => 21,
+            SettingsAction::RemoveProgramFilter { .. } => 22,
+        }
+    }
+}
+
+impl TimeLockTarget {
+    /// Calculate the Borsh-serialized size.
+    pub const fn borsh_size(&self) -> usize {
+        1 + // enum discriminator
+        match self {
+            TimeLockTarget::Program { .. } => 32, // Pubkey
+            TimeLockTarget::SettingsAction { .. } => 1, // u8
+        }
+    }
+}
+
+impl ProgramFilter {
+    /// Calculate the Borsh-serialized size.
+    pub const fn borsh_size(&self) -> usize {
+        32 + // program_id
+        4  + // discriminator vector length
+        self.discriminator.len() // discriminator
+    }
+}
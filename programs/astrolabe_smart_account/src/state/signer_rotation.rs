@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// A pending rotation of the key of a signer, requested by the signer itself.
+/// After `Settings.signer_rotation_delay` seconds without being cancelled by any signer,
+/// it replaces the key of the signer, keeping its permissions and weight.
+#[account]
+#[derive(InitSpace)]
+pub struct SignerRotation {
+    /// The settings this belongs to.
+    pub settings: Pubkey,
+    /// The signer whose key is rotated.
+    pub signer: Pubkey,
+    /// The key replacing the key of the signer.
+    pub new_signer: Pubkey,
+    /// Unix timestamp of when the rotation was requested.
+    pub requested_at: i64,
+    /// The rent collector for the signer rotation account.
+    pub rent_collector: Pubkey,
+    /// PDA bump.
+    pub bump: u8,
+}
@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+
+/// Allows signers of the smart account to transfer tokens out of one of its
+/// smart accounts without going through the proposal flow, within a limit per period.
+#[account]
+pub struct SpendingLimit {
+    /// The settings this belongs to.
+    pub settings: Pubkey,
+    /// Key that is used to seed the SpendingLimit PDA.
+    pub seed: Pubkey,
+    /// The index of the smart account that the spending limit is for.
+    pub account_index: u8,
+    /// The token mint the spending limit is for.
+    /// Pubkey::default() means SOL.
+    /// use NATIVE_MINT for Wrapped SOL.
+    pub mint: Pubkey,
+    /// The amount of tokens that can be spent in a period.
+    /// This amount is in decimals of the mint,
+    /// so 1 SOL would be `1_000_000_000` and 1 USDC would be `1_000_000`.
+    pub amount: u64,
+    /// The reset period of the spending limit.
+    /// When it passes, the remaining amount is reset, unless it's `Period::OneTime`.
+    pub period: Period,
+    /// The remaining amount of tokens that can be spent in the current period.
+    /// When reaches 0, the spending limit cannot be used anymore until the period reset.
+    pub remaining_amount: u64,
+    /// Unix timestamp marking the last time the spending limit was reset (or created).
+    pub last_reset: i64,
+    /// PDA bump.
+    pub bump: u8,
+    /// Signers of the smart account that can use the spending limit.
+    /// In case a signer is removed from the smart account, the spending limit will remain existent
+    /// (until explicitly deleted), but the removed signer will not be able to use it anymore.
+    pub signers: Vec<Pubkey>,
+    /// The destination addresses the spending limit is allowed to send funds to.
+    /// If empty, funds can be sent to any address.
+    pub destinations: Vec<Pubkey>,
+    /// Unix timestamp after which the spending limit can no longer be used.
+    /// `i64::MAX` means the spending limit never expires.
+    pub expiration: i64,
+}
+
+impl SpendingLimit {
+    pub fn size(signers_length: usize, destinations_length: usize) -> usize {
+        8  + // anchor discriminator
+        32 + // settings
+        32 + // seed
+        1  + // account_index
+        32 + // mint
+        8  + // amount
+        1  + // period
+        8  + // remaining_amount
+        8  + // last_reset
+        1  + // bump
+        4  + // signers vector length
+        signers_length * 32 + // signers
+        4  + // destinations vector length
+        destinations_length * 32 + // destinations
+        8 // expiration
+    }
+
+    pub fn invariant(&self) -> Result<()> {
+        // Amount must be a non-zero value.
+        require_neq!(self.amount, 0, SmartAccountError::SpendingLimitInvalidAmount);
+
+        require!(!self.signers.is_empty(), SmartAccountError::EmptySigners);
+
+        // There must be no duplicate signers, we make sure signers are sorted when creating a SpendingLimit.
+        let has_duplicates = self.signers.windows(2).any(|win| win[0] == win[1]);
+        require!(!has_duplicates, SmartAccountError::DuplicateSigner);
+
+        Ok(())
+    }
+
+    /// Returns `Some(index)` if `signer_pubkey` is allowed to use the spending limit,
+    /// with `index` into the `signers` vec. `None` otherwise.
+    pub fn is_signer(&self, signer_pubkey: Pubkey) -> Option<usize> {
+        self.signers.binary_search(&signer_pubkey).ok()
+    }
+
+    /// Reset the `remaining_amount` if one or more periods have passed since `last_reset`.
+    /// `last_reset` is moved forward by whole periods so that the reset schedule doesn't drift.
+    pub fn reset_if_needed(&mut self, now: i64) {
+        if let Some(reset_period) = self.period.to_seconds() {
+            let passed_since_last_reset = now.checked_sub(self.last_reset).unwrap();
+
+            if passed_since_last_reset >= reset_period {
+                self.remaining_amount = self.amount;
+
+                let periods_passed = passed_since_last_reset.checked_div(reset_period).unwrap();
+
+                self.last_reset = self
+                    .last_reset
+                    .checked_add(periods_passed.checked_mul(reset_period).unwrap())
+                    .unwrap();
+            }
+        }
+    }
+}
+
+/// The reset period of the spending limit.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Period {
+    /// The spending limit is never reset, `amount` can only be spent once in total.
+    OneTime,
+    /// The spending limit is reset every day.
+    Day,
+    /// The spending limit is reset every week (7 days).
+    Week,
+    /// The spending limit is reset every month (30 days).
+    Month,
+}
+
+impl Period {
+    pub fn to_seconds(&self) -> Option<i64> {
+        match self {
+            Period::OneTime => None,
+            Period::Day => Some(24 * 60 * 60),
+            Period::Week => Some(7 * 24 * 60 * 60),
+            Period::Month => Some(30 * 24 * 60 * 60),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    fn spending_limit(period: Period) -> SpendingLimit {
+        SpendingLimit {
+            settings: Pubkey::new_unique(),
+            seed: Pubkey::new_unique(),
+            account_index: 0,
+            mint: Pubkey::default(),
+            amount: 100,
+            period,
+            remaining_amount: 40,
+            last_reset: 1_000,
+            bump: 255,
+            signers: vec![Pubkey::new_unique()],
+            destinations: vec![],
+            expiration: i64::MAX,
+        }
+    }
+
+    #[test]
+    fn test_reset_if_needed_within_period() {
+        let mut spending_limit = spending_limit(Period::Day);
+
+        spending_limit.reset_if_needed(1_000 + DAY - 1);
+
+        assert_eq!(spending_limit.remaining_amount, 40);
+        assert_eq!(spending_limit.last_reset, 1_000);
+    }
+
+    #[test]
+    fn test_reset_if_needed_after_period() {
+        let mut spending_limit = spending_limit(Period::Day);
+
+        spending_limit.reset_if_needed(1_000 + DAY);
+
+        assert_eq!(spending_limit.remaining_amount, 100);
+        assert_eq!(spending_limit.last_reset, 1_000 + DAY);
+    }
+
+    #[test]
+    fn test_reset_if_needed_keeps_schedule() {
+        let mut spending_limit = spending_limit(Period::Day);
+
+        // Resetting 2.5 periods later moves `last_reset` forward by whole periods only.
+        spending_limit.reset_if_needed(1_000 + 5 * DAY / 2);
+
+        assert_eq!(spending_limit.remaining_amount, 100);
+        assert_eq!(spending_limit.last_reset, 1_000 + 2 * DAY);
+    }
+
+    #[test]
+    fn test_reset_if_needed_one_time() {
+        let mut spending_limit = spending_limit(Period::OneTime);
+
+        spending_limit.reset_if_needed(1_000 + 365 * DAY);
+
+        assert_eq!(spending_limit.remaining_amount, 40);
+        assert_eq!(spending_limit.last_reset, 1_000);
+    }
+}
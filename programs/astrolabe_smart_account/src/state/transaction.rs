@@ -16,6 +16,7 @@ pub struct Transaction {
     /// Signer of the Smart Account who submitted the transaction.
     pub creator: Pubkey,
     /// The rent collector for the transaction account.
+    /// Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
     pub rent_collector: Pubkey,
     /// Index of this transaction within the smart account.
     pub index: u64,
@@ -25,6 +26,9 @@ pub struct Transaction {
     pub account_index: u8,
     /// Derivation bump of the smart account PDA this transaction belongs to.
     pub account_bump: u8,
+    /// The longest time lock of the programs the transaction touches,
+    /// from the settings `target_time_locks` captured at creation.
+    pub time_lock: u32,
     /// Derivation bumps for additional signers.
     /// Some transactions require multiple signers. Often these additional signers are "ephemeral" keypairs
     /// that are generated on the client with a sole purpose of signing the transaction and be discarded immediately after.
@@ -40,9 +44,15 @@ pub struct Transaction {
 
 impl Transaction {
     pub fn size(ephemeral_signers_length: u8, transaction_message: &[u8]) -> Result<usize> {
-        let transaction_message: SmartAccountTransactionMessage =
-            TransactionMessage::deserialize(&mut &transaction_message[..])?.try_into()?;
-        let message_size = transaction_message.borsh_size();
+        // An empty `transaction_message` stands for the message of a transaction buffer,
+        // `create_transaction_from_buffer` reallocates the account to fit it.
+        let message_size = if transaction_message.is_empty() {
+            SmartAccountTransactionMessage::default().borsh_size()
+        } else {
+            let transaction_message: SmartAccountTransactionMessage =
+                TransactionMessage::deserialize(&mut &transaction_message[..])?.try_into()?;
+            transaction_message.borsh_size()
+        };
 
         Ok(
             8 +   // anchor account discriminator
@@ -53,6 +63,7 @@ impl Transaction {
             1 +   // bump
             1 +   // account_index
             1 +   // account_bump
+            4 +   // time_lock
             (4 + usize::from(ephemeral_signers_length)) +   // ephemeral_signers_bumps vec
             message_size, // message
         )
@@ -2,8 +2,15 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::hash::hash;
 
 use crate::errors::SmartAccountError;
+use crate::state::Settings;
 
-pub const MAX_BUFFER_SIZE: usize = 4000;
+/// Accounts can only be created or grown by 10 KiB within an instruction, which bounds both the buffer account
+/// and the growth of the transaction account created from it. The rest is left for the other transaction fields
+/// and the message being slightly bigger once deserialized.
+pub const MAX_BUFFER_SIZE: usize = 9 * 1024;
+
+/// Time after which any signer with `Initiate` permission can close a buffer left open by its creator.
+pub const TRANSACTION_BUFFER_TIMEOUT: i64 = 7 * 24 * 60 * 60; // 1 week
 
 #[account]
 #[derive(Default, Debug)]
@@ -12,6 +19,12 @@ pub struct TransactionBuffer {
     pub settings: Pubkey,
     /// Signer of the smart account who created the TransactionBuffer.
     pub creator: Pubkey,
+    /// The account that paid the rent for the TransactionBuffer, refunded when it is closed by others.
+    pub rent_payer: Pubkey,
+    /// Timestamp when the TransactionBuffer was created.
+    pub created_at: i64,
+    /// `transaction_index` of the settings when the TransactionBuffer was created.
+    pub transaction_index: u64,
     /// Index to seed address derivation
     pub buffer_index: u8,
     /// Smart account index of the transaction this buffer belongs to.
@@ -34,6 +47,9 @@ impl TransactionBuffer {
             8 +   // anchor account discriminator
             32 +  // multisig
             32 +  // creator
+            32 +  // rent_payer
+            8 +   // created_at
+            8 +   // transaction_index
             1 +   // buffer_index
             1 +   // vault_index
             32 +  // transaction_message_hash
@@ -43,6 +59,15 @@ impl TransactionBuffer {
         )
     }
 
+    /// Whether the buffer can be closed by signers other than its creator: once it has timed out,
+    /// once a settings change made the transactions created before it stale, or once its creator
+    /// is no longer a signer of the smart account.
+    pub fn is_abandoned(&self, settings: &Settings, now: i64) -> bool {
+        now.saturating_sub(self.created_at) >= TRANSACTION_BUFFER_TIMEOUT
+            || self.transaction_index < settings.stale_transaction_index
+            || settings.is_signer(self.creator).is_none()
+    }
+
     pub fn validate_hash(&self) -> Result<()> {
         let message_buffer_hash = hash(&self.buffer);
         require!(
@@ -0,0 +1,186 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::errors::*;
+use crate::state::*;
+
+/// Overrides the security parameters of the settings for one of its smart accounts,
+/// so that vaults under the same settings can have different security levels.
+#[account]
+pub struct VaultPolicy {
+    /// The settings this belongs to.
+    pub settings: Pubkey,
+    /// The index of the smart account that the policy is for.
+    pub account_index: u8,
+    /// How much voting weight of approvals from `signers` a transaction for the smart account needs to be executed.
+    pub threshold: u16,
+    /// How many seconds must pass between transaction voting settlement and execution.
+    /// Replaces the settings `time_lock` for the smart account.
+    pub time_lock: u32,
+    /// PDA bump.
+    pub bump: u8,
+    /// Signers of the settings that can create, approve and execute transactions for the smart account, sorted.
+    /// If empty, all the signers of the settings can.
+    pub signers: Vec<Pubkey>,
+}
+
+impl VaultPolicy {
+    pub fn size(signers_length: usize) -> usize {
+        8  + // anchor discriminator
+        32 + // settings
+        1  + // account_index
+        2  + // threshold
+        4  + // time_lock
+        1  + // bump
+        4  + // signers vector length
+        signers_length * 32 // signers
+    }
+
+    pub fn invariant(&self, settings: &Settings) -> Result<()> {
+        // There must be no duplicate signers, we make sure signers are sorted when setting a VaultPolicy.
+        let has_duplicates = self.signers.windows(2).any(|win| win[0] == win[1]);
+        require!(!has_duplicates, SmartAccountError::DuplicateSigner);
+
+        // Threshold must be reachable by the signers of the policy.
+        require!(
+            self.threshold > 0 && usize::from(self.threshold) <= self.total_voting_weight(settings),
+            SmartAccountError::InvalidThreshold
+        );
+
+        // Time Lock must not exceed the maximum allowed to prevent bricking the smart account.
+        require!(
+            self.time_lock <= MAX_TIME_LOCK,
+            SmartAccountError::TimeLockExceedsMaxAllowed
+        );
+
+        Ok(())
+    }
+
+    /// Whether `signer_pubkey` is allowed by the policy.
+    pub fn is_signer(&self, signer_pubkey: Pubkey) -> bool {
+        self.signers.is_empty() || self.signers.binary_search(&signer_pubkey).is_ok()
+    }
+
+    /// The voting weight of the `keys` that are signers allowed by the policy.
+    pub fn voting_weight(&self, settings: &Settings, keys: &[Pubkey]) -> usize {
+        let keys: Vec<Pubkey> = keys
+            .iter()
+            .filter(|k| self.is_signer(**k))
+            .copied()
+            .collect();
+        settings.voting_weight(&keys)
+    }
+
+    /// The voting weight of all the signers allowed by the policy.
+    pub fn total_voting_weight(&self, settings: &Settings) -> usize {
+        if self.signers.is_empty() {
+            Settings::total_voting_weight(&settings.signers)
+        } else {
+            settings.voting_weight(&self.signers)
+        }
+    }
+
+    /// How much "reject" voting weight is enough to make a transaction for the smart account "Rejected".
+    /// Saturates, as the signers of the policy may have been removed from the settings since it was set.
+    pub fn cutoff(&self, settings: &Settings) -> usize {
+        self.total_voting_weight(settings)
+            .saturating_sub(usize::from(self.threshold))
+            .saturating_add(1)
+    }
+
+    /// Check that `vault_policy` is the policy of the smart account at `account_index`,
+    /// and that it's provided if the settings have a policy for this smart account.
+    pub fn load<'a>(
+        settings: &Settings,
+        settings_key: &Pubkey,
+        account_index: u8,
+        vault_policy: Option<&'a VaultPolicy>,
+    ) -> Result<Option<&'a VaultPolicy>> {
+        match vault_policy {
+            Some(vault_policy) => {
+                require!(
+                    vault_policy.settings == *settings_key
+                        && vault_policy.account_index == account_index,
+                    SmartAccountError::InvalidAccount
+                );
+            }
+            None => require!(
+                !settings.has_vault_policy(account_index),
+                SmartAccountError::MissingVaultPolicy
+            ),
+        }
+
+        Ok(vault_policy)
+    }
+
+    /// Like `load`, for the smart account that the transaction of `proposal` is for.
+    /// `transaction` is the `Transaction` or `Batch` of the proposal, only required if the settings have any policy.
+    /// Settings transactions are not for a smart account, so they have no policy.
+    pub fn load_for_proposal<'a>(
+        settings: &Settings,
+        settings_key: &Pubkey,
+        proposal: &Proposal,
+        transaction: Option<&AccountInfo>,
+        vault_policy: Option<&'a VaultPolicy>,
+    ) -> Result<Option<&'a VaultPolicy>> {
+        if !settings.has_any_vault_policy() {
+            return Ok(None);
+        }
+
+        let transaction = transaction.ok_or(SmartAccountError::MissingAccount)?;
+        let (transaction_key, _) = Pubkey::find_program_address(
+            &[
+                SEED_PREFIX,
+                settings_key.as_ref(),
+                SEED_TRANSACTION,
+                &proposal.transaction_index.to_le_bytes(),
+            ],
+            &crate::id(),
+        );
+        require_keys_eq!(
+            transaction.key(),
+            transaction_key,
+            SmartAccountError::InvalidAccount
+        );
+        require_keys_eq!(
+            *transaction.owner,
+            crate::id(),
+            SmartAccountError::IllegalAccountOwner
+        );
+
+        let data = transaction.try_borrow_data()?;
+        let account_index = if data.starts_with(Transaction::DISCRIMINATOR) {
+            Transaction::try_deserialize(&mut &data[..])?.account_index
+        } else if data.starts_with(Batch::DISCRIMINATOR) {
+            Batch::try_deserialize(&mut &data[..])?.account_index
+        } else {
+            require!(vault_policy.is_none(), SmartAccountError::InvalidAccount);
+            return Ok(None);
+        };
+
+        Self::load(settings, settings_key, account_index, vault_policy)
+    }
+
+    /// Check that the approvals of a proposal reach the policy threshold and that its time lock is released.
+    /// `target_time_lock` is the time lock of the programs the transaction touches, applied on top of the policy one.
+    pub fn validate_execution(
+        &self,
+        settings: &Settings,
+        approved: &[Pubkey],
+        approved_at: i64,
+        target_time_lock: u32,
+    ) -> Result<()> {
+        require!(
+            self.voting_weight(settings, approved) >= usize::from(self.threshold),
+            SmartAccountError::VaultPolicyThresholdNotReached
+        );
+
+        require!(
+            Clock::get()?.unix_timestamp - approved_at
+                >= i64::from(self.time_lock.max(target_time_lock)),
+            SmartAccountError::TimeLockNotReleased
+        );
+
+        Ok(())
+    }
+}
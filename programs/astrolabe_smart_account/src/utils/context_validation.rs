@@ -1,68 +1,138 @@
-use crate::{errors::*, state::*};
+use crate::{errors::*, state::*, utils::*};
 use anchor_lang::prelude::*;
 
+/// `vault_policy` is the policy of the smart account the transaction is for, if any.
+/// It replaces the settings threshold and time lock, and limits the signers that can take part.
 pub fn validate_synchronous_consensus(
     settings: &Account<Settings>,
     num_signers: u8,
     remaining_accounts: &[AccountInfo],
+    vault_policy: Option<&VaultPolicy>,
 ) -> Result<()> {
+    let (threshold, time_lock) = match vault_policy {
+        Some(vault_policy) => (vault_policy.threshold, vault_policy.time_lock),
+        None => (settings.threshold, settings.time_lock),
+    };
+
     // Settings must not be time locked
-    require_eq!(settings.time_lock, 0, SmartAccountError::TimeLockNotZero);
+    require_eq!(time_lock, 0, SmartAccountError::TimeLockNotZero);
 
-    // Get signers from remaining accounts using threshold
-    let required_signer_count = settings.threshold as usize;
-    let signer_count = num_signers as usize;
+    let (aggregated_permissions, voting_weight) =
+        validate_consensus_signers(settings, num_signers, remaining_accounts, vault_policy)?;
+
+    // Check if we have all required permissions (Initiate | Vote | Execute = 7)
     require!(
-        signer_count >= required_signer_count,
-        SmartAccountError::InvalidSignerCount
+        aggregated_permissions.mask == 7,
+        SmartAccountError::InsufficientAggregatePermissions
     );
 
+    // Verify threshold is met across all voting weights
+    require!(
+        voting_weight >= threshold as usize,
+        SmartAccountError::InsufficientVotePermissions
+    );
+
+    Ok(())
+}
+
+/// Validates the first `num_signers` of `remaining_accounts` as distinct signers of the settings,
+/// and returns their aggregated permissions and their voting weight.
+/// `vault_policy` limits the signers that can take part, if any.
+pub fn validate_consensus_signers(
+    settings: &Account<Settings>,
+    num_signers: u8,
+    remaining_accounts: &[AccountInfo],
+    vault_policy: Option<&VaultPolicy>,
+) -> Result<(Permissions, usize)> {
+    // Get signers from remaining accounts
+    let signer_count = num_signers as usize;
+
     let signers = remaining_accounts
         .get(..signer_count)
         .ok_or(SmartAccountError::InvalidSignerCount)?;
 
-    // Setup the aggregated permissions and the vote permission count
+    // Setup the aggregated permissions and the voting weight
     let mut aggregated_permissions = Permissions { mask: 0 };
-    let mut vote_permission_count = 0;
+    let mut voting_weight = 0;
     let mut seen_signers = Vec::with_capacity(signer_count);
 
     // Check permissions for all signers
     for signer in signers.iter() {
-        if let Some(member_index) = settings.is_signer(signer.key()) {
-            // Check that the signer is indeed a signer
-            if !signer.is_signer {
-                return err!(SmartAccountError::MissingSignature);
-            }
-            // Check for duplicate signer
-            if seen_signers.contains(&signer.key()) {
-                return err!(SmartAccountError::DuplicateSigner);
-            }
-            seen_signers.push(signer.key());
-
-            let signer_permissions = settings.signers[member_index].permissions;
-            // Add to the aggregated permissions mask
-            aggregated_permissions.mask |= signer_permissions.mask;
-
-            // Count the vote permissions
-            if signer_permissions.has(Permission::Vote) {
-                vote_permission_count += 1;
-            }
-        } else {
-            return err!(SmartAccountError::NotASigner);
+        let (member_key, signer_permissions) =
+            if let Some(member_index) = settings.is_signer(signer.key()) {
+                // Check that the signer is indeed a signer, or a passkey that approved the transaction
+                if !signer.is_signer {
+                    verify_passkey_approval(signer, remaining_accounts)?;
+                }
+                (signer.key(), settings.signers[member_index].permissions)
+            } else if let Some((_, session_key)) =
+                SessionKey::find(&settings.key(), signer.key, remaining_accounts)
+            {
+                // A session key acts in place of its granter
+                require!(signer.is_signer, SmartAccountError::MissingSignature);
+                (session_key.granter, session_key.validate_use(settings)?)
+            } else {
+                return err!(SmartAccountError::NotASigner);
+            };
+
+        // Check for duplicate signer, a session key and its granter count as the same signer
+        if seen_signers.contains(&member_key) {
+            return err!(SmartAccountError::DuplicateSigner);
+        }
+        seen_signers.push(member_key);
+
+        // The signer must be allowed by the vault policy
+        if let Some(vault_policy) = vault_policy {
+            require!(
+                vault_policy.is_signer(member_key),
+                SmartAccountError::Unauthorized
+            );
+        }
+
+        // Add to the aggregated permissions mask
+        aggregated_permissions.mask |= signer_permissions.mask;
+
+        // Add the voting weight of the signer, a session key votes with the weight of its granter
+        if signer_permissions.has(Permission::Vote) {
+            voting_weight += settings.voting_weight(&[member_key]);
         }
     }
 
-    // Check if we have all required permissions (Initiate | Vote | Execute = 7)
-    require!(
-        aggregated_permissions.mask == 7,
-        SmartAccountError::InsufficientAggregatePermissions
-    );
+    Ok((aggregated_permissions, voting_weight))
+}
 
-    // Verify threshold is met across all voting permissions
-    require!(
-        vote_permission_count >= settings.threshold as usize,
-        SmartAccountError::InsufficientVotePermissions
-    );
+/// Returns the `SessionKey` accounts of the session keys among `signers` of a synchronous transaction.
+/// Must only be called after `validate_synchronous_consensus`.
+pub fn find_consensus_session_keys<'a, 'info>(
+    settings: &Account<Settings>,
+    signers: &[AccountInfo],
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Vec<(&'a AccountInfo<'info>, SessionKey)> {
+    signers
+        .iter()
+        .filter(|signer| settings.is_signer(signer.key()).is_none())
+        .filter_map(|signer| SessionKey::find(&settings.key(), signer.key, remaining_accounts))
+        .collect()
+}
+
+/// Verify the approval of a `Passkey` signer through the instructions sysvar, which must be
+/// present in `remaining_accounts`, and consume its nonce.
+fn verify_passkey_approval(signer: &AccountInfo, remaining_accounts: &[AccountInfo]) -> Result<()> {
+    // Any account that is not a `Passkey` is a regular signer that didn't sign.
+    if signer.owner != &crate::ID {
+        return err!(SmartAccountError::MissingSignature);
+    }
+    let mut passkey = Passkey::try_deserialize(&mut &signer.try_borrow_data()?[..])
+        .map_err(|_| SmartAccountError::MissingSignature)?;
+    require!(signer.is_writable, ErrorCode::AccountNotMutable);
+
+    let instructions_sysvar = remaining_accounts
+        .iter()
+        .find(|account| account.key == &INSTRUCTIONS_SYSVAR_ID)
+        .ok_or(SmartAccountError::MissingAccount)?;
+
+    passkey.verify_approval(signer.key(), instructions_sysvar)?;
+    passkey.try_serialize(&mut &mut signer.try_borrow_mut_data()?[..])?;
 
     Ok(())
 }
@@ -74,13 +144,144 @@ pub fn validate_settings_actions(actions: &Vec<SettingsAction>) -> Result<()> {
     let current_timestamp = Clock::get()?.unix_timestamp;
     // time_lock must not exceed the maximum allowed.
     for action in actions {
-        if let SettingsAction::SetTimeLock { new_time_lock, .. } = action {
+        if let SettingsAction::SetTimeLock { new_time_lock, .. }
+        | SettingsAction::SetTargetTimeLock {
+            time_lock: new_time_lock,
+            ..
+        } = action
+        {
             require!(
                 *new_time_lock <= MAX_TIME_LOCK,
                 SmartAccountError::TimeLockExceedsMaxAllowed
             );
         }
+        // program filter discriminators must fit the space reserved for them.
+        if let SettingsAction::AddProgramFilter { filter } = action {
+            require!(
+                filter.discriminator.len() <= MAX_PROGRAM_FILTER_DISCRIMINATOR_LEN,
+                SmartAccountError::InvalidProgramFilter
+            );
+        }
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn signer(key: Pubkey, mask: u8, weight: u16) -> SmartAccountSigner {
+        SmartAccountSigner {
+            key,
+            permissions: Permissions { mask },
+            weight,
+        }
+    }
+
+    fn settings_data(threshold: u16, signers: Vec<SmartAccountSigner>) -> Vec<u8> {
+        let settings = Settings {
+            seed: 0,
+            settings_authority: Pubkey::default(),
+            threshold,
+            time_lock: 0,
+            transaction_index: 0,
+            stale_transaction_index: 0,
+            rent_collector: None,
+            archival_authority: None,
+            archivable_after: 0,
+            recovery_threshold: 0,
+            recovery_delay: 0,
+            vault_policies: [0; 32],
+            execution_window: 0,
+            signer_rotation_delay: None,
+            program_filter_mode: ProgramFilterMode::Disabled,
+            bump: 255,
+            signers,
+            restricted_signers: vec![],
+            target_time_locks: vec![],
+            program_filters: vec![],
+            account_utilization: 0,
+            _reserved1: 0,
+            _reserved2: 0,
+        };
+        let mut data = vec![];
+        settings.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_validate_synchronous_consensus_weighted() {
+        let mut keys = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        keys.sort();
+        let [heavy, light, non_voter] = keys;
+        // A threshold of 3 needs both voters, the weight of a signer without the `Vote` permission doesn't count.
+        let mut data = settings_data(
+            3,
+            vec![
+                signer(heavy, 7, 2),
+                signer(light, 7, 1),
+                signer(non_voter, 5, 5),
+            ],
+        );
+        let settings_key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let settings_info = AccountInfo::new(
+            &settings_key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &crate::ID,
+            false,
+            0,
+        );
+        let settings = Account::<Settings>::try_from(&settings_info).unwrap();
+
+        let owner = Pubkey::default();
+        let mut signer_lamports = [0; 3];
+        let mut signer_data = [vec![], vec![], vec![]];
+        let signer_infos: Vec<AccountInfo> = keys
+            .iter()
+            .zip(signer_lamports.iter_mut())
+            .zip(signer_data.iter_mut())
+            .map(|((key, lamports), data)| {
+                AccountInfo::new(key, true, false, lamports, data, &owner, false, 0)
+            })
+            .collect();
+        let [heavy_info, light_info, non_voter_info] = [0, 1, 2].map(|i| signer_infos[i].clone());
+
+        // Too few signers to reach the threshold.
+        assert_eq!(
+            validate_synchronous_consensus(&settings, 1, &[heavy_info.clone()], None).unwrap_err(),
+            SmartAccountError::InsufficientVotePermissions.into()
+        );
+        assert_eq!(
+            validate_synchronous_consensus(
+                &settings,
+                2,
+                &[heavy_info.clone(), non_voter_info],
+                None
+            )
+            .unwrap_err(),
+            SmartAccountError::InsufficientVotePermissions.into()
+        );
+        // Fewer signers than `num_signers`.
+        assert_eq!(
+            validate_synchronous_consensus(
+                &settings,
+                3,
+                &[heavy_info.clone(), light_info.clone()],
+                None
+            )
+            .unwrap_err(),
+            SmartAccountError::InvalidSignerCount.into()
+        );
+
+        validate_synchronous_consensus(&settings, 2, &[heavy_info, light_info], None).unwrap();
+    }
+}
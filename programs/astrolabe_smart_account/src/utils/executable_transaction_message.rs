@@ -199,11 +199,13 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
     /// * `smart_account_seeds` - Seeds for the smart account PDA.
     /// * `ephemeral_signer_seeds` - Seeds for the ephemeral signer PDAs.
     /// * `protected_accounts` - Accounts that should not be closed by the transaction.
+    /// * `settings` - Settings of the smart account, whose program filters the instructions must pass.
     pub fn execute_message(
         self,
         smart_account_seeds: &[&[u8]],
         ephemeral_signer_seeds: &[Vec<Vec<u8>>],
         protected_accounts: &[Pubkey],
+        settings: &Settings,
     ) -> Result<()> {
         // First round of type conversion; from Vec<Vec<Vec<u8>>> to Vec<Vec<&[u8]>>.
         let ephemeral_signer_seeds = &ephemeral_signer_seeds
@@ -223,6 +225,8 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
         // references or usages of `self.message` should be made to avoid
         // faulty behavior.
         for (ix, account_infos) in self.to_instructions_and_accounts().iter() {
+            // Make sure the smart account is allowed to invoke the instruction.
+            settings.check_program_filters(&ix.program_id, &ix.data)?;
             // Make sure we don't pass protected accounts as writable to CPI calls.
             for account_meta in ix.accounts.iter().filter(|m| m.is_writable) {
                 require!(
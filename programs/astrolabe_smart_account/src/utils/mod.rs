@@ -4,10 +4,12 @@ mod small_vec;
 mod system;
 mod synchronous_transaction_message;
 mod context_validation;
+mod webauthn;
 
 pub use context_validation::*;
 pub use ephemeral_signers::*;
 pub use executable_transaction_message::*;
 pub use small_vec::*;
 pub use system::*;
-pub use synchronous_transaction_message::*;
\ No newline at end of file
+pub use synchronous_transaction_message::*;
+pub use webauthn::*;
\ No newline at end of file
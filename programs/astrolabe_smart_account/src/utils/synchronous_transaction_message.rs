@@ -36,7 +36,7 @@ impl<'info> SynchronousTransactionMessage<'info> {
         let mut accounts = Vec::with_capacity(remaining_accounts.len());
 
         // Process accounts and modify signer states
-        for (i, account) in remaining_accounts.iter().enumerate() {
+        for account in remaining_accounts {
             let mut account_info = account.clone();
 
             // For remaining accounts:
@@ -63,11 +63,15 @@ impl<'info> SynchronousTransactionMessage<'info> {
         })
     }
 
-    /// Executes all instructions in the message via CPI calls
-    pub fn execute(&self, smart_account_seeds: &[&[u8]]) -> Result<()> {
+    /// Executes all instructions in the message via CPI calls,
+    /// as long as they pass the program filters of the `settings`.
+    pub fn execute(&self, smart_account_seeds: &[&[u8]], settings: &Settings) -> Result<()> {
         for instruction in &self.instructions {
             let program_id = self.accounts[instruction.program_id_index as usize].key;
 
+            // Make sure the smart account is allowed to invoke the instruction.
+            settings.check_program_filters(program_id, &instruction.data)?;
+
             // Build account metas for this instruction
             let account_metas = instruction
                 .account_indexes
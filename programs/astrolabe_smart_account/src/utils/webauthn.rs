@@ -0,0 +1,420 @@
+#![allow(deprecated)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+pub use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked, ID as INSTRUCTIONS_SYSVAR_ID,
+};
+
+use crate::errors::SmartAccountError;
+
+pub const SECP256R1_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("Secp256r1SigVerify1111111111111111111111111");
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 14;
+const COMPRESSED_PUBKEY_SERIALIZED_SIZE: usize = 33;
+const CLIENT_DATA_HASH_SIZE: usize = 32;
+// rp_id_hash (32) + flags (1) + sign_count (4)
+const AUTHENTICATOR_DATA_MIN_SIZE: usize = 37;
+const AUTHENTICATOR_DATA_FLAGS_INDEX: usize = 32;
+const FLAG_USER_PRESENT: u8 = 0x01;
+const CLIENT_DATA_JSON_PREFIX: &[u8] = br#"{"type":"webauthn.get","challenge":""#;
+
+/// A WebAuthn assertion whose signature was verified by the secp256r1 precompile.
+struct WebAuthnAssertion {
+    authenticator_data: Vec<u8>,
+    client_data_json: Vec<u8>,
+}
+
+impl WebAuthnAssertion {
+    /// Read the assertion from the message at `message_offset` of a secp256r1 precompile
+    /// instruction's data, and the length-prefixed `client_data_json` that follows it.
+    fn from_precompile_data(
+        data: &[u8],
+        message_offset: usize,
+        message_size: usize,
+    ) -> Result<Self> {
+        let message = data
+            .get(message_offset..message_offset + message_size)
+            .filter(|message| message.len() >= AUTHENTICATOR_DATA_MIN_SIZE + CLIENT_DATA_HASH_SIZE)
+            .ok_or(SmartAccountError::InvalidWebAuthnAuthenticatorData)?;
+        let (authenticator_data, client_data_hash) =
+            message.split_at(message.len() - CLIENT_DATA_HASH_SIZE);
+
+        let client_data_offset = message_offset + message_size;
+        let client_data_json = read_u16(data, client_data_offset)
+            .and_then(|len| data.get(client_data_offset + 2..client_data_offset + 2 + len))
+            .ok_or(SmartAccountError::InvalidWebAuthnClientData)?;
+        require!(
+            hash(client_data_json).to_bytes() == client_data_hash,
+            SmartAccountError::InvalidWebAuthnClientData
+        );
+
+        Ok(Self {
+            authenticator_data: authenticator_data.to_vec(),
+            client_data_json: client_data_json.to_vec(),
+        })
+    }
+
+    /// Check that the assertion was made for `rp_id_hash` with user presence, and that it signs `challenge`.
+    fn verify(&self, rp_id_hash: &[u8; 32], challenge: &[u8; 32]) -> Result<()> {
+        let authenticator_data = &self.authenticator_data;
+        require!(
+            authenticator_data.len() >= AUTHENTICATOR_DATA_MIN_SIZE
+                && authenticator_data[..32] == rp_id_hash[..],
+            SmartAccountError::InvalidWebAuthnAuthenticatorData
+        );
+        require!(
+            authenticator_data[AUTHENTICATOR_DATA_FLAGS_INDEX] & FLAG_USER_PRESENT != 0,
+            SmartAccountError::InvalidWebAuthnAuthenticatorData
+        );
+
+        // Browsers serialize `type` and `challenge` first, so we can check the client data
+        // by its prefix instead of parsing the JSON (the WebAuthn "limited verification algorithm").
+        let mut expected_prefix = CLIENT_DATA_JSON_PREFIX.to_vec();
+        expected_prefix.extend(base64url_encode(challenge));
+        expected_prefix.push(b'"');
+        require!(
+            self.client_data_json.starts_with(&expected_prefix),
+            SmartAccountError::InvalidWebAuthnClientData
+        );
+
+        Ok(())
+    }
+}
+
+/// Verify that a secp256r1 precompile instruction preceding the instruction at `current_index`
+/// checked a WebAuthn assertion signed by `public_key`, made for `rp_id_hash` and signing `challenge`.
+///
+/// The signature, public key and message must be in the precompile instruction's own data.
+/// The message must be `authenticator_data || sha256(client_data_json)`, and must be followed by
+/// the `client_data_json` prefixed with its u16 length, which the precompile ignores.
+pub fn verify_webauthn_signature(
+    instructions_sysvar: &AccountInfo,
+    current_index: u16,
+    public_key: &[u8; COMPRESSED_PUBKEY_SERIALIZED_SIZE],
+    rp_id_hash: &[u8; 32],
+    challenge: &[u8; 32],
+) -> Result<()> {
+    // Report why the last signature by `public_key` didn't verify, if there was any.
+    let mut result = err!(SmartAccountError::MissingPasskeySignature);
+
+    for index in 0..current_index {
+        let instruction = load_instruction_at_checked(usize::from(index), instructions_sysvar)?;
+        if instruction.program_id != SECP256R1_PROGRAM_ID {
+            continue;
+        }
+        let data = &instruction.data;
+
+        let num_signatures = usize::from(data.first().copied().unwrap_or(0));
+        for signature_index in 0..num_signatures {
+            let offsets =
+                SIGNATURE_OFFSETS_START + signature_index * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+            let (
+                Some(signature_instruction_index),
+                Some(public_key_offset),
+                Some(public_key_instruction_index),
+                Some(message_offset),
+                Some(message_size),
+                Some(message_instruction_index),
+            ) = (
+                read_u16(data, offsets + 2),
+                read_u16(data, offsets + 4),
+                read_u16(data, offsets + 6),
+                read_u16(data, offsets + 8),
+                read_u16(data, offsets + 10),
+                read_u16(data, offsets + 12),
+            )
+            else {
+                break;
+            };
+
+            // `u16::MAX` means the data is in the precompile instruction itself.
+            let in_own_data = [
+                signature_instruction_index,
+                public_key_instruction_index,
+                message_instruction_index,
+            ]
+            .iter()
+            .all(|&instruction_index| instruction_index == usize::from(u16::MAX));
+            if !in_own_data
+                || data
+                    .get(public_key_offset..public_key_offset + COMPRESSED_PUBKEY_SERIALIZED_SIZE)
+                    != Some(public_key.as_slice())
+            {
+                continue;
+            }
+
+            result = WebAuthnAssertion::from_precompile_data(data, message_offset, message_size)
+                .and_then(|assertion| assertion.verify(rp_id_hash, challenge));
+            if result.is_ok() {
+                return result;
+            }
+        }
+    }
+
+    result
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<usize> {
+    data.get(offset..offset + 2)
+        .map(|bytes| usize::from(u16::from_le_bytes([bytes[0], bytes[1]])))
+}
+
+/// Base64url encoding without padding, as used for the WebAuthn challenge.
+fn base64url_encode(data: &[u8]) -> Vec<u8> {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+    let mut encoded = Vec::with_capacity((data.len() * 4).div_ceil(3));
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(bytes[0]) << 16) | (u32::from(bytes[1]) << 8) | u32::from(bytes[2]);
+        for i in 0..=chunk.len() {
+            encoded.push(ALPHABET[((n >> (18 - 6 * i)) & 63) as usize]);
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+pub mod test_utils {
+    use anchor_lang::solana_program::instruction::Instruction;
+
+    use super::*;
+
+    /// Serialize `instructions` the way the runtime lays out the instructions sysvar,
+    /// with the instruction at `current_index` being executed.
+    pub fn instructions_sysvar_data(instructions: &[Instruction], current_index: u16) -> Vec<u8> {
+        let mut data = (instructions.len() as u16).to_le_bytes().to_vec();
+        data.resize(2 + 2 * instructions.len(), 0);
+        for (i, instruction) in instructions.iter().enumerate() {
+            let offset = (data.len() as u16).to_le_bytes();
+            data[2 + 2 * i..4 + 2 * i].copy_from_slice(&offset);
+            data.extend((instruction.accounts.len() as u16).to_le_bytes());
+            for account in &instruction.accounts {
+                data.push(u8::from(account.is_signer) | u8::from(account.is_writable) << 1);
+                data.extend(account.pubkey.as_ref());
+            }
+            data.extend(instruction.program_id.as_ref());
+            data.extend((instruction.data.len() as u16).to_le_bytes());
+            data.extend(&instruction.data);
+        }
+        data.extend(current_index.to_le_bytes());
+        data
+    }
+
+    /// Authenticator data for `rp_id` with the user presence flag set.
+    pub fn authenticator_data(rp_id: &str) -> Vec<u8> {
+        let mut data = hash(rp_id.as_bytes()).to_bytes().to_vec();
+        data.push(FLAG_USER_PRESENT);
+        data.extend(0u32.to_be_bytes()); // sign_count
+        data
+    }
+
+    /// Client data JSON as a browser serializes it for a WebAuthn ceremony of `type_`.
+    pub fn client_data_json(type_: &str, challenge: &[u8; 32]) -> Vec<u8> {
+        let challenge = String::from_utf8(base64url_encode(challenge)).unwrap();
+        format!(
+            r#"{{"type":"{type_}","challenge":"{challenge}","origin":"https://example.com","crossOrigin":false}}"#
+        )
+        .into_bytes()
+    }
+
+    /// A secp256r1 precompile instruction checking a WebAuthn assertion by `public_key`.
+    /// The signature is a placeholder, verifying it is up to the precompile.
+    pub fn webauthn_precompile_instruction(
+        public_key: &[u8; COMPRESSED_PUBKEY_SERIALIZED_SIZE],
+        authenticator_data: &[u8],
+        client_data_json: &[u8],
+    ) -> Instruction {
+        let public_key_offset = SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+        let signature_offset = public_key_offset + COMPRESSED_PUBKEY_SERIALIZED_SIZE;
+        let message_offset = signature_offset + 64;
+        let message = [authenticator_data, &hash(client_data_json).to_bytes()].concat();
+
+        let mut data = vec![1, 0]; // num_signatures, padding
+        for field in [
+            signature_offset,
+            usize::from(u16::MAX),
+            public_key_offset,
+            usize::from(u16::MAX),
+            message_offset,
+            message.len(),
+            usize::from(u16::MAX),
+        ] {
+            data.extend((field as u16).to_le_bytes());
+        }
+        data.extend(public_key);
+        data.extend([0; 64]);
+        data.extend(&message);
+        data.extend((client_data_json.len() as u16).to_le_bytes());
+        data.extend(client_data_json);
+
+        Instruction {
+            program_id: SECP256R1_PROGRAM_ID,
+            accounts: vec![],
+            data,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use anchor_lang::solana_program::instruction::Instruction;
+
+    use super::test_utils::*;
+    use super::*;
+
+    const PUBLIC_KEY: [u8; 33] = [2; 33];
+    const CHALLENGE: [u8; 32] = [7; 32];
+
+    /// Verify the assertion checked by `precompile_instruction`, for the instruction right after it.
+    fn verify(precompile_instruction: Instruction, rp_id: &str) -> Result<()> {
+        let instructions = [
+            precompile_instruction,
+            Instruction {
+                program_id: crate::id(),
+                accounts: vec![],
+                data: vec![],
+            },
+        ];
+        let mut lamports = 0;
+        let mut data = instructions_sysvar_data(&instructions, 1);
+        let owner = Pubkey::default();
+        let instructions_sysvar = AccountInfo::new(
+            &INSTRUCTIONS_SYSVAR_ID,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        verify_webauthn_signature(
+            &instructions_sysvar,
+            1,
+            &PUBLIC_KEY,
+            &hash(rp_id.as_bytes()).to_bytes(),
+            &CHALLENGE,
+        )
+    }
+
+    #[test]
+    fn test_verify_webauthn_signature() {
+        let instruction = webauthn_precompile_instruction(
+            &PUBLIC_KEY,
+            &authenticator_data("example.com"),
+            &client_data_json("webauthn.get", &CHALLENGE),
+        );
+
+        assert!(verify(instruction, "example.com").is_ok());
+    }
+
+    #[test]
+    fn test_verify_webauthn_signature_other_public_key() {
+        let instruction = webauthn_precompile_instruction(
+            &[3; 33],
+            &authenticator_data("example.com"),
+            &client_data_json("webauthn.get", &CHALLENGE),
+        );
+
+        assert_eq!(
+            verify(instruction, "example.com").unwrap_err(),
+            SmartAccountError::MissingPasskeySignature.into()
+        );
+    }
+
+    #[test]
+    fn test_verify_webauthn_signature_wrong_type() {
+        // A registration ceremony signs the same challenge format, it must not count as an approval.
+        let instruction = webauthn_precompile_instruction(
+            &PUBLIC_KEY,
+            &authenticator_data("example.com"),
+            &client_data_json("webauthn.create", &CHALLENGE),
+        );
+
+        assert_eq!(
+            verify(instruction, "example.com").unwrap_err(),
+            SmartAccountError::InvalidWebAuthnClientData.into()
+        );
+    }
+
+    #[test]
+    fn test_verify_webauthn_signature_wrong_challenge() {
+        let instruction = webauthn_precompile_instruction(
+            &PUBLIC_KEY,
+            &authenticator_data("example.com"),
+            &client_data_json("webauthn.get", &[8; 32]),
+        );
+
+        assert_eq!(
+            verify(instruction, "example.com").unwrap_err(),
+            SmartAccountError::InvalidWebAuthnClientData.into()
+        );
+    }
+
+    #[test]
+    fn test_verify_webauthn_signature_client_data_hash_mismatch() {
+        let mut instruction = webauthn_precompile_instruction(
+            &PUBLIC_KEY,
+            &authenticator_data("example.com"),
+            &client_data_json("webauthn.get", &CHALLENGE),
+        );
+        // Tamper with the client data after it was hashed into the signed message.
+        *instruction.data.last_mut().unwrap() = b' ';
+
+        assert_eq!(
+            verify(instruction, "example.com").unwrap_err(),
+            SmartAccountError::InvalidWebAuthnClientData.into()
+        );
+    }
+
+    #[test]
+    fn test_verify_webauthn_signature_wrong_origin() {
+        // The origin is bound through the relying party ID: an assertion made on another site
+        // has the hash of that site's RP ID in its authenticator data.
+        let instruction = webauthn_precompile_instruction(
+            &PUBLIC_KEY,
+            &authenticator_data("evil.example"),
+            &client_data_json("webauthn.get", &CHALLENGE),
+        );
+
+        assert_eq!(
+            verify(instruction, "example.com").unwrap_err(),
+            SmartAccountError::InvalidWebAuthnAuthenticatorData.into()
+        );
+    }
+
+    #[test]
+    fn test_verify_webauthn_signature_user_not_present() {
+        let mut authenticator_data = authenticator_data("example.com");
+        authenticator_data[AUTHENTICATOR_DATA_FLAGS_INDEX] = 0;
+        let instruction = webauthn_precompile_instruction(
+            &PUBLIC_KEY,
+            &authenticator_data,
+            &client_data_json("webauthn.get", &CHALLENGE),
+        );
+
+        assert_eq!(
+            verify(instruction, "example.com").unwrap_err(),
+            SmartAccountError::InvalidWebAuthnAuthenticatorData.into()
+        );
+    }
+
+    #[test]
+    fn test_base64url_encode() {
+        assert_eq!(base64url_encode(b""), b"");
+        assert_eq!(base64url_encode(b"f"), b"Zg");
+        assert_eq!(base64url_encode(b"fo"), b"Zm8");
+        assert_eq!(base64url_encode(b"foo"), b"Zm9v");
+        assert_eq!(base64url_encode(b"foob"), b"Zm9vYg");
+        assert_eq!(base64url_encode(&[0xfb, 0xff, 0xbf]), b"-_-_");
+    }
+}
@@ -43,6 +43,8 @@ export type AddPasskeyAuthorityParams = {
   /**
    * The new passkey/signer to add to the smart account.
    * Should include the passkey's public key derived from the credential ID and permissions mask.
+   * For native secp256r1 passkeys, the key is the `Passkey` account address from `getPasskeyAddress`,
+   * and the `Passkey` account must be created with `createPasskey` first.
    */
   newSigner: SmartAccountSignerArgs;
   /** Optional: A memo for the transaction. Defaults to None. */
@@ -93,7 +95,8 @@ export type AddPasskeyAuthorityResult = {
  *   feePayer: backendFeePayerPubkey,    // Backend pays fees
  *   newSigner: {
  *     key: newPasskeyPublicKey,
- *     permissions: { mask: 0x07 } // Full permissions
+ *     permissions: { mask: 0x07 }, // Full permissions
+ *     weight: 1 // Voting weight towards the threshold
  *   },
  *   memo: 'Added new passkey'
  * });
@@ -17,6 +17,8 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
@@ -30,12 +32,12 @@ import {
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
@@ -55,7 +57,10 @@ export type Batch = {
   settings: Address;
   /** Signer of the smart account who submitted the batch. */
   creator: Address;
-  /** The rent collector for the batch account. */
+  /**
+   * The rent collector for the batch account.
+   * Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
+   */
   rentCollector: Address;
   /** Index of this batch within the smart account transactions. */
   index: bigint;
@@ -68,10 +73,17 @@ export type Batch = {
   /** Number of transactions in the batch. */
   size: number;
   /**
-   * Index of the last executed transaction within the batch.
+   * Index of the last executed or skipped transaction within the batch.
    * 0 means that no transactions have been executed yet.
    */
   executedTransactionIndex: number;
+  /**
+   * The longest time lock of the programs the transactions of the batch touch,
+   * from the settings `target_time_locks` captured at the creation of each transaction.
+   */
+  timeLock: number;
+  /** Indexes of the transactions within the batch that were skipped instead of executed, in ascending order. */
+  skippedTransactionIndexes: Array<number>;
 };
 
 export type BatchArgs = {
@@ -79,7 +91,10 @@ export type BatchArgs = {
   settings: Address;
   /** Signer of the smart account who submitted the batch. */
   creator: Address;
-  /** The rent collector for the batch account. */
+  /**
+   * The rent collector for the batch account.
+   * Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
+   */
   rentCollector: Address;
   /** Index of this batch within the smart account transactions. */
   index: number | bigint;
@@ -92,13 +107,20 @@ export type BatchArgs = {
   /** Number of transactions in the batch. */
   size: number;
   /**
-   * Index of the last executed transaction within the batch.
+   * Index of the last executed or skipped transaction within the batch.
    * 0 means that no transactions have been executed yet.
    */
   executedTransactionIndex: number;
+  /**
+   * The longest time lock of the programs the transactions of the batch touch,
+   * from the settings `target_time_locks` captured at the creation of each transaction.
+   */
+  timeLock: number;
+  /** Indexes of the transactions within the batch that were skipped instead of executed, in ascending order. */
+  skippedTransactionIndexes: Array<number>;
 };
 
-export function getBatchEncoder(): FixedSizeEncoder<BatchArgs> {
+export function getBatchEncoder(): Encoder<BatchArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
@@ -111,12 +133,14 @@ export function getBatchEncoder(): FixedSizeEncoder<BatchArgs> {
       ['accountBump', getU8Encoder()],
       ['size', getU32Encoder()],
       ['executedTransactionIndex', getU32Encoder()],
+      ['timeLock', getU32Encoder()],
+      ['skippedTransactionIndexes', getArrayEncoder(getU32Encoder())],
     ]),
     (value) => ({ ...value, discriminator: BATCH_DISCRIMINATOR })
   );
 }
 
-export function getBatchDecoder(): FixedSizeDecoder<Batch> {
+export function getBatchDecoder(): Decoder<Batch> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['settings', getAddressDecoder()],
@@ -128,10 +152,12 @@ export function getBatchDecoder(): FixedSizeDecoder<Batch> {
     ['accountBump', getU8Decoder()],
     ['size', getU32Decoder()],
     ['executedTransactionIndex', getU32Decoder()],
+    ['timeLock', getU32Decoder()],
+    ['skippedTransactionIndexes', getArrayDecoder(getU32Decoder())],
   ]);
 }
 
-export function getBatchCodec(): FixedSizeCodec<BatchArgs, Batch> {
+export function getBatchCodec(): Codec<BatchArgs, Batch> {
   return combineCodec(getBatchEncoder(), getBatchDecoder());
 }
 
@@ -187,7 +213,3 @@ export async function fetchAllMaybeBatch(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeBatch(maybeAccount));
 }
-
-export function getBatchSize(): number {
-  return 123;
-}
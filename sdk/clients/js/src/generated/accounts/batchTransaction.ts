@@ -61,7 +61,10 @@ export type BatchTransaction = {
   discriminator: ReadonlyUint8Array;
   /** PDA bump. */
   bump: number;
-  /** The rent collector for the batch transaction account. */
+  /**
+   * The rent collector for the batch transaction account.
+   * Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
+   */
   rentCollector: Address;
   /**
    * Derivation bumps for additional signers.
@@ -80,7 +83,10 @@ export type BatchTransaction = {
 export type BatchTransactionArgs = {
   /** PDA bump. */
   bump: number;
-  /** The rent collector for the batch transaction account. */
+  /**
+   * The rent collector for the batch transaction account.
+   * Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
+   */
   rentCollector: Address;
   /**
    * Derivation bumps for additional signers.
@@ -6,11 +6,20 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './archivedSettings';
 export * from './batch';
 export * from './batchTransaction';
+export * from './creatorAllowlistPage';
+export * from './passkey';
 export * from './programConfig';
+export * from './programConfigTransfer';
 export * from './proposal';
+export * from './recovery';
+export * from './sessionKey';
 export * from './settings';
 export * from './settingsTransaction';
+export * from './signerRotation';
+export * from './spendingLimit';
 export * from './transaction';
 export * from './transactionBuffer';
+export * from './vaultPolicy';
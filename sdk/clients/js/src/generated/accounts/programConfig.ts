@@ -23,6 +23,8 @@ import {
   getStructEncoder,
   getU128Decoder,
   getU128Encoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   transformEncoder,
@@ -38,6 +40,12 @@ import {
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from '@solana/kit';
+import {
+  getSmartAccountCreationModeDecoder,
+  getSmartAccountCreationModeEncoder,
+  type SmartAccountCreationMode,
+  type SmartAccountCreationModeArgs,
+} from '../types';
 
 export const PROGRAM_CONFIG_DISCRIMINATOR = new Uint8Array([
   196, 210, 90, 231, 144, 149, 140, 63,
@@ -62,6 +70,23 @@ export type ProgramConfig = {
   smartAccountCreationFee: bigint;
   /** The treasury account to send charged fees to. */
   treasury: Address;
+  /**
+   * The SPL mint the `smart_account_creation_token_fee` is charged in.
+   * `Pubkey::default()` if no token fee is set.
+   */
+  creationFeeMint: Address;
+  /**
+   * The amount of `creation_fee_mint` tokens charged for creating a new smart account,
+   * on top of `smart_account_creation_fee`. This fee is sent to the `treasury` token account.
+   */
+  smartAccountCreationTokenFee: bigint;
+  /**
+   * The share of the creation fees, in basis points, that is sent to the referrer
+   * of a new smart account instead of the `treasury`.
+   */
+  referralFeeBps: number;
+  /** Who can create new smart accounts. */
+  smartAccountCreationMode: SmartAccountCreationMode;
   /** Reserved for future use. */
   reserved: ReadonlyUint8Array;
 };
@@ -78,6 +103,23 @@ export type ProgramConfigArgs = {
   smartAccountCreationFee: number | bigint;
   /** The treasury account to send charged fees to. */
   treasury: Address;
+  /**
+   * The SPL mint the `smart_account_creation_token_fee` is charged in.
+   * `Pubkey::default()` if no token fee is set.
+   */
+  creationFeeMint: Address;
+  /**
+   * The amount of `creation_fee_mint` tokens charged for creating a new smart account,
+   * on top of `smart_account_creation_fee`. This fee is sent to the `treasury` token account.
+   */
+  smartAccountCreationTokenFee: number | bigint;
+  /**
+   * The share of the creation fees, in basis points, that is sent to the referrer
+   * of a new smart account instead of the `treasury`.
+   */
+  referralFeeBps: number;
+  /** Who can create new smart accounts. */
+  smartAccountCreationMode: SmartAccountCreationModeArgs;
   /** Reserved for future use. */
   reserved: ReadonlyUint8Array;
 };
@@ -90,7 +132,11 @@ export function getProgramConfigEncoder(): FixedSizeEncoder<ProgramConfigArgs> {
       ['authority', getAddressEncoder()],
       ['smartAccountCreationFee', getU64Encoder()],
       ['treasury', getAddressEncoder()],
-      ['reserved', fixEncoderSize(getBytesEncoder(), 64)],
+      ['creationFeeMint', getAddressEncoder()],
+      ['smartAccountCreationTokenFee', getU64Encoder()],
+      ['referralFeeBps', getU16Encoder()],
+      ['smartAccountCreationMode', getSmartAccountCreationModeEncoder()],
+      ['reserved', fixEncoderSize(getBytesEncoder(), 21)],
     ]),
     (value) => ({ ...value, discriminator: PROGRAM_CONFIG_DISCRIMINATOR })
   );
@@ -103,7 +149,11 @@ export function getProgramConfigDecoder(): FixedSizeDecoder<ProgramConfig> {
     ['authority', getAddressDecoder()],
     ['smartAccountCreationFee', getU64Decoder()],
     ['treasury', getAddressDecoder()],
-    ['reserved', fixDecoderSize(getBytesDecoder(), 64)],
+    ['creationFeeMint', getAddressDecoder()],
+    ['smartAccountCreationTokenFee', getU64Decoder()],
+    ['referralFeeBps', getU16Decoder()],
+    ['smartAccountCreationMode', getSmartAccountCreationModeDecoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 21)],
   ]);
 }
 
@@ -21,6 +21,10 @@ import {
   getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -38,6 +42,8 @@ import {
   type FetchAccountsConfig,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
@@ -61,10 +67,18 @@ export type Proposal = {
   settings: Address;
   /** Index of the smart account transaction this proposal is associated with. */
   transactionIndex: bigint;
-  /** The rent collector for the proposal account. */
+  /**
+   * The rent collector for the proposal account.
+   * Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
+   */
   rentCollector: Address;
   /** The status of the transaction. */
   status: ProposalStatus;
+  /**
+   * Unix timestamp after which the proposal can no longer be voted on.
+   * `None` means the proposal can be voted on until it's approved, rejected or becomes stale.
+   */
+  votingDeadline: Option<bigint>;
   /** PDA bump. */
   bump: number;
   /** Keys that have approved/signed. */
@@ -80,10 +94,18 @@ export type ProposalArgs = {
   settings: Address;
   /** Index of the smart account transaction this proposal is associated with. */
   transactionIndex: number | bigint;
-  /** The rent collector for the proposal account. */
+  /**
+   * The rent collector for the proposal account.
+   * Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
+   */
   rentCollector: Address;
   /** The status of the transaction. */
   status: ProposalStatusArgs;
+  /**
+   * Unix timestamp after which the proposal can no longer be voted on.
+   * `None` means the proposal can be voted on until it's approved, rejected or becomes stale.
+   */
+  votingDeadline: OptionOrNullable<number | bigint>;
   /** PDA bump. */
   bump: number;
   /** Keys that have approved/signed. */
@@ -102,6 +124,7 @@ export function getProposalEncoder(): Encoder<ProposalArgs> {
       ['transactionIndex', getU64Encoder()],
       ['rentCollector', getAddressEncoder()],
       ['status', getProposalStatusEncoder()],
+      ['votingDeadline', getOptionEncoder(getI64Encoder())],
       ['bump', getU8Encoder()],
       ['approved', getArrayEncoder(getAddressEncoder())],
       ['rejected', getArrayEncoder(getAddressEncoder())],
@@ -118,6 +141,7 @@ export function getProposalDecoder(): Decoder<Proposal> {
     ['transactionIndex', getU64Decoder()],
     ['rentCollector', getAddressDecoder()],
     ['status', getProposalStatusDecoder()],
+    ['votingDeadline', getOptionDecoder(getI64Decoder())],
     ['bump', getU8Decoder()],
     ['approved', getArrayDecoder(getAddressDecoder())],
     ['rejected', getArrayDecoder(getAddressDecoder())],
@@ -51,14 +51,26 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  getProgramFilterDecoder,
+  getProgramFilterEncoder,
+  getProgramFilterModeDecoder,
+  getProgramFilterModeEncoder,
   getRestrictedSmartAccountSignerDecoder,
   getRestrictedSmartAccountSignerEncoder,
   getSmartAccountSignerDecoder,
   getSmartAccountSignerEncoder,
+  getTargetTimeLockDecoder,
+  getTargetTimeLockEncoder,
+  type ProgramFilter,
+  type ProgramFilterArgs,
+  type ProgramFilterMode,
+  type ProgramFilterModeArgs,
   type RestrictedSmartAccountSigner,
   type RestrictedSmartAccountSignerArgs,
   type SmartAccountSigner,
   type SmartAccountSignerArgs,
+  type TargetTimeLock,
+  type TargetTimeLockArgs,
 } from '../types';
 
 export const SETTINGS_DISCRIMINATOR = new Uint8Array([
@@ -77,7 +89,8 @@ export type Settings = {
    * to ensure uniqueness of each settings PDA without relying on user input.
    *
    * Note: As this represents a DOS vector in the current creation architecture,
-   * account creation will be permissioned until compression is implemented.
+   * account creation can be permissioned with the program config `smart_account_creation_mode`
+   * until compression is implemented.
    */
   seed: bigint;
   /**
@@ -92,7 +105,7 @@ export type Settings = {
    * will need to be signed by the `settings_authority`. We call such a smart account a "controlled smart account".
    */
   settingsAuthority: Address;
-  /** Threshold for signatures. */
+  /** Threshold for signatures, as the voting weight of the signers that approved. */
   threshold: number;
   /** How many seconds must pass between transaction voting settlement and execution. */
   timeLock: number;
@@ -104,23 +117,60 @@ export type Settings = {
    */
   staleTransactionIndex: bigint;
   /**
-   * Field reserved for when archival/compression is implemented.
-   * Will be set to Pubkey::default() to mark accounts that should
-   * be eligible for archival before the feature is implemented.
+   * The address where the rent for the accounts related to executed, rejected, or cancelled
+   * transactions can be reclaimed. If set to `None`, the rent reclamation feature is turned off.
+   */
+  rentCollector: Option<Address>;
+  /**
+   * The authority that can archive the settings account with `archive_settings` to reclaim its rent.
+   * `None` and `Pubkey::default()` both mean that the smart account cannot be archived.
    */
   archivalAuthority: Option<Address>;
   /**
-   * Field that will prevent a smart account from being archived immediately after unarchival.
+   * Unix timestamp before which the smart account cannot be archived.
+   * Set to `ARCHIVAL_COOLDOWN` seconds after the moment of unarchival.
    * This is to prevent a DOS vector where the archival authority could
    * constantly unarchive and archive the smart account to prevent it from
    * being used.
    */
   archivableAfter: bigint;
+  /**
+   * How many guardians (restricted signers with the `Recover` permission) must approve a recovery.
+   * `0` means that recovery is disabled.
+   */
+  recoveryThreshold: number;
+  /** How many seconds the current signers have to veto a recovery once it's approved by the guardians. */
+  recoveryDelay: number;
+  /**
+   * Bitmap of the account indices that have a `VaultPolicy`, bit `i % 8` of byte `i / 8` for account index `i`.
+   * Used to require the `VaultPolicy` account whenever one exists for the smart account being used.
+   */
+  vaultPolicies: ReadonlyUint8Array;
+  /**
+   * How many seconds an approved proposal can be executed for after its approval.
+   * It includes the time lock, so it should be longer than the time lock of the smart accounts.
+   * `0` means approved proposals can be executed at any time.
+   */
+  executionWindow: number;
+  /**
+   * How many seconds must pass between a signer requesting the rotation of its own key and the rotation,
+   * giving the other signers time to cancel it. `None` means that signers can't rotate their own keys.
+   */
+  signerRotationDelay: Option<number>;
+  /** Whether `program_filters` are the only instructions the smart accounts can invoke, or the ones they can't. */
+  programFilterMode: ProgramFilterMode;
   /** Bump for the smart account PDA seed. */
   bump: number;
   /** Signers attached to the smart account */
   signers: Array<SmartAccountSigner>;
   restrictedSigners: Array<RestrictedSmartAccountSigner>;
+  /**
+   * Time locks of specific programs or settings actions, sorted by target.
+   * A transaction is time locked for the longest of `time_lock` and the time locks of the targets it touches.
+   */
+  targetTimeLocks: Array<TargetTimeLock>;
+  /** Program instructions the smart accounts are allowed or denied to invoke, sorted, see `program_filter_mode`. */
+  programFilters: Array<ProgramFilter>;
   /** Counter for how many sub accounts are in use (improves off-chain indexing) */
   accountUtilization: number;
   reserved1: number;
@@ -134,7 +184,8 @@ export type SettingsArgs = {
    * to ensure uniqueness of each settings PDA without relying on user input.
    *
    * Note: As this represents a DOS vector in the current creation architecture,
-   * account creation will be permissioned until compression is implemented.
+   * account creation can be permissioned with the program config `smart_account_creation_mode`
+   * until compression is implemented.
    */
   seed: number | bigint;
   /**
@@ -149,7 +200,7 @@ export type SettingsArgs = {
    * will need to be signed by the `settings_authority`. We call such a smart account a "controlled smart account".
    */
   settingsAuthority: Address;
-  /** Threshold for signatures. */
+  /** Threshold for signatures, as the voting weight of the signers that approved. */
   threshold: number;
   /** How many seconds must pass between transaction voting settlement and execution. */
   timeLock: number;
@@ -161,23 +212,60 @@ export type SettingsArgs = {
    */
   staleTransactionIndex: number | bigint;
   /**
-   * Field reserved for when archival/compression is implemented.
-   * Will be set to Pubkey::default() to mark accounts that should
-   * be eligible for archival before the feature is implemented.
+   * The address where the rent for the accounts related to executed, rejected, or cancelled
+   * transactions can be reclaimed. If set to `None`, the rent reclamation feature is turned off.
+   */
+  rentCollector: OptionOrNullable<Address>;
+  /**
+   * The authority that can archive the settings account with `archive_settings` to reclaim its rent.
+   * `None` and `Pubkey::default()` both mean that the smart account cannot be archived.
    */
   archivalAuthority: OptionOrNullable<Address>;
   /**
-   * Field that will prevent a smart account from being archived immediately after unarchival.
+   * Unix timestamp before which the smart account cannot be archived.
+   * Set to `ARCHIVAL_COOLDOWN` seconds after the moment of unarchival.
    * This is to prevent a DOS vector where the archival authority could
    * constantly unarchive and archive the smart account to prevent it from
    * being used.
    */
   archivableAfter: number | bigint;
+  /**
+   * How many guardians (restricted signers with the `Recover` permission) must approve a recovery.
+   * `0` means that recovery is disabled.
+   */
+  recoveryThreshold: number;
+  /** How many seconds the current signers have to veto a recovery once it's approved by the guardians. */
+  recoveryDelay: number;
+  /**
+   * Bitmap of the account indices that have a `VaultPolicy`, bit `i % 8` of byte `i / 8` for account index `i`.
+   * Used to require the `VaultPolicy` account whenever one exists for the smart account being used.
+   */
+  vaultPolicies: ReadonlyUint8Array;
+  /**
+   * How many seconds an approved proposal can be executed for after its approval.
+   * It includes the time lock, so it should be longer than the time lock of the smart accounts.
+   * `0` means approved proposals can be executed at any time.
+   */
+  executionWindow: number;
+  /**
+   * How many seconds must pass between a signer requesting the rotation of its own key and the rotation,
+   * giving the other signers time to cancel it. `None` means that signers can't rotate their own keys.
+   */
+  signerRotationDelay: OptionOrNullable<number>;
+  /** Whether `program_filters` are the only instructions the smart accounts can invoke, or the ones they can't. */
+  programFilterMode: ProgramFilterModeArgs;
   /** Bump for the smart account PDA seed. */
   bump: number;
   /** Signers attached to the smart account */
   signers: Array<SmartAccountSignerArgs>;
   restrictedSigners: Array<RestrictedSmartAccountSignerArgs>;
+  /**
+   * Time locks of specific programs or settings actions, sorted by target.
+   * A transaction is time locked for the longest of `time_lock` and the time locks of the targets it touches.
+   */
+  targetTimeLocks: Array<TargetTimeLockArgs>;
+  /** Program instructions the smart accounts are allowed or denied to invoke, sorted, see `program_filter_mode`. */
+  programFilters: Array<ProgramFilterArgs>;
   /** Counter for how many sub accounts are in use (improves off-chain indexing) */
   accountUtilization: number;
   reserved1: number;
@@ -194,14 +282,23 @@ export function getSettingsEncoder(): Encoder<SettingsArgs> {
       ['timeLock', getU32Encoder()],
       ['transactionIndex', getU64Encoder()],
       ['staleTransactionIndex', getU64Encoder()],
+      ['rentCollector', getOptionEncoder(getAddressEncoder())],
       ['archivalAuthority', getOptionEncoder(getAddressEncoder())],
       ['archivableAfter', getU64Encoder()],
+      ['recoveryThreshold', getU16Encoder()],
+      ['recoveryDelay', getU32Encoder()],
+      ['vaultPolicies', fixEncoderSize(getBytesEncoder(), 32)],
+      ['executionWindow', getU32Encoder()],
+      ['signerRotationDelay', getOptionEncoder(getU32Encoder())],
+      ['programFilterMode', getProgramFilterModeEncoder()],
       ['bump', getU8Encoder()],
       ['signers', getArrayEncoder(getSmartAccountSignerEncoder())],
       [
         'restrictedSigners',
         getArrayEncoder(getRestrictedSmartAccountSignerEncoder()),
       ],
+      ['targetTimeLocks', getArrayEncoder(getTargetTimeLockEncoder())],
+      ['programFilters', getArrayEncoder(getProgramFilterEncoder())],
       ['accountUtilization', getU8Encoder()],
       ['reserved1', getU8Encoder()],
       ['reserved2', getU8Encoder()],
@@ -219,14 +316,23 @@ export function getSettingsDecoder(): Decoder<Settings> {
     ['timeLock', getU32Decoder()],
     ['transactionIndex', getU64Decoder()],
     ['staleTransactionIndex', getU64Decoder()],
+    ['rentCollector', getOptionDecoder(getAddressDecoder())],
     ['archivalAuthority', getOptionDecoder(getAddressDecoder())],
     ['archivableAfter', getU64Decoder()],
+    ['recoveryThreshold', getU16Decoder()],
+    ['recoveryDelay', getU32Decoder()],
+    ['vaultPolicies', fixDecoderSize(getBytesDecoder(), 32)],
+    ['executionWindow', getU32Decoder()],
+    ['signerRotationDelay', getOptionDecoder(getU32Decoder())],
+    ['programFilterMode', getProgramFilterModeDecoder()],
     ['bump', getU8Decoder()],
     ['signers', getArrayDecoder(getSmartAccountSignerDecoder())],
     [
       'restrictedSigners',
       getArrayDecoder(getRestrictedSmartAccountSignerDecoder()),
     ],
+    ['targetTimeLocks', getArrayDecoder(getTargetTimeLockDecoder())],
+    ['programFilters', getArrayDecoder(getProgramFilterDecoder())],
     ['accountUtilization', getU8Decoder()],
     ['reserved1', getU8Decoder()],
     ['reserved2', getU8Decoder()],
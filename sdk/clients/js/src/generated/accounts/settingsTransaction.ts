@@ -23,6 +23,8 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -63,12 +65,20 @@ export type SettingsTransaction = {
   settings: Address;
   /** Signer on the settings who submitted the transaction. */
   creator: Address;
-  /** The rent collector for the settings transaction account. */
+  /**
+   * The rent collector for the settings transaction account.
+   * Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
+   */
   rentCollector: Address;
   /** Index of this transaction within the settings. */
   index: bigint;
   /** bump for the transaction seeds. */
   bump: number;
+  /**
+   * The longest time lock of the actions the transaction touches, from the settings `target_time_locks`.
+   * Those can't change without making the transaction stale, so it's the same as at approval time.
+   */
+  timeLock: number;
   /** Action to be performed on the settings. */
   actions: Array<SettingsAction>;
 };
@@ -78,12 +88,20 @@ export type SettingsTransactionArgs = {
   settings: Address;
   /** Signer on the settings who submitted the transaction. */
   creator: Address;
-  /** The rent collector for the settings transaction account. */
+  /**
+   * The rent collector for the settings transaction account.
+   * Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
+   */
   rentCollector: Address;
   /** Index of this transaction within the settings. */
   index: number | bigint;
   /** bump for the transaction seeds. */
   bump: number;
+  /**
+   * The longest time lock of the actions the transaction touches, from the settings `target_time_locks`.
+   * Those can't change without making the transaction stale, so it's the same as at approval time.
+   */
+  timeLock: number;
   /** Action to be performed on the settings. */
   actions: Array<SettingsActionArgs>;
 };
@@ -97,6 +115,7 @@ export function getSettingsTransactionEncoder(): Encoder<SettingsTransactionArgs
       ['rentCollector', getAddressEncoder()],
       ['index', getU64Encoder()],
       ['bump', getU8Encoder()],
+      ['timeLock', getU32Encoder()],
       ['actions', getArrayEncoder(getSettingsActionEncoder())],
     ]),
     (value) => ({ ...value, discriminator: SETTINGS_TRANSACTION_DISCRIMINATOR })
@@ -111,6 +130,7 @@ export function getSettingsTransactionDecoder(): Decoder<SettingsTransaction> {
     ['rentCollector', getAddressDecoder()],
     ['index', getU64Decoder()],
     ['bump', getU8Decoder()],
+    ['timeLock', getU32Decoder()],
     ['actions', getArrayDecoder(getSettingsActionDecoder())],
   ]);
 }
@@ -63,7 +63,10 @@ export type Transaction = {
   settings: Address;
   /** Signer of the Smart Account who submitted the transaction. */
   creator: Address;
-  /** The rent collector for the transaction account. */
+  /**
+   * The rent collector for the transaction account.
+   * Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
+   */
   rentCollector: Address;
   /** Index of this transaction within the smart account. */
   index: bigint;
@@ -73,6 +76,11 @@ export type Transaction = {
   accountIndex: number;
   /** Derivation bump of the smart account PDA this transaction belongs to. */
   accountBump: number;
+  /**
+   * The longest time lock of the programs the transaction touches,
+   * from the settings `target_time_locks` captured at creation.
+   */
+  timeLock: number;
   /**
    * Derivation bumps for additional signers.
    * Some transactions require multiple signers. Often these additional signers are "ephemeral" keypairs
@@ -93,7 +101,10 @@ export type TransactionArgs = {
   settings: Address;
   /** Signer of the Smart Account who submitted the transaction. */
   creator: Address;
-  /** The rent collector for the transaction account. */
+  /**
+   * The rent collector for the transaction account.
+   * Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
+   */
   rentCollector: Address;
   /** Index of this transaction within the smart account. */
   index: number | bigint;
@@ -103,6 +114,11 @@ export type TransactionArgs = {
   accountIndex: number;
   /** Derivation bump of the smart account PDA this transaction belongs to. */
   accountBump: number;
+  /**
+   * The longest time lock of the programs the transaction touches,
+   * from the settings `target_time_locks` captured at creation.
+   */
+  timeLock: number;
   /**
    * Derivation bumps for additional signers.
    * Some transactions require multiple signers. Often these additional signers are "ephemeral" keypairs
@@ -129,6 +145,7 @@ export function getTransactionEncoder(): Encoder<TransactionArgs> {
       ['bump', getU8Encoder()],
       ['accountIndex', getU8Encoder()],
       ['accountBump', getU8Encoder()],
+      ['timeLock', getU32Encoder()],
       [
         'ephemeralSignerBumps',
         addEncoderSizePrefix(getBytesEncoder(), getU32Encoder()),
@@ -149,6 +166,7 @@ export function getTransactionDecoder(): Decoder<Transaction> {
     ['bump', getU8Decoder()],
     ['accountIndex', getU8Decoder()],
     ['accountBump', getU8Decoder()],
+    ['timeLock', getU32Decoder()],
     [
       'ephemeralSignerBumps',
       addDecoderSizePrefix(getBytesDecoder(), getU32Decoder()),
@@ -21,12 +21,16 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
   getU16Decoder,
   getU16Encoder,
   getU32Decoder,
   getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -59,6 +63,12 @@ export type TransactionBuffer = {
   settings: Address;
   /** Signer of the smart account who created the TransactionBuffer. */
   creator: Address;
+  /** The account that paid the rent for the TransactionBuffer, refunded when it is closed by others. */
+  rentPayer: Address;
+  /** Timestamp when the TransactionBuffer was created. */
+  createdAt: bigint;
+  /** `transaction_index` of the settings when the TransactionBuffer was created. */
+  transactionIndex: bigint;
   /** Index to seed address derivation */
   bufferIndex: number;
   /** Smart account index of the transaction this buffer belongs to. */
@@ -76,6 +86,12 @@ export type TransactionBufferArgs = {
   settings: Address;
   /** Signer of the smart account who created the TransactionBuffer. */
   creator: Address;
+  /** The account that paid the rent for the TransactionBuffer, refunded when it is closed by others. */
+  rentPayer: Address;
+  /** Timestamp when the TransactionBuffer was created. */
+  createdAt: number | bigint;
+  /** `transaction_index` of the settings when the TransactionBuffer was created. */
+  transactionIndex: number | bigint;
   /** Index to seed address derivation */
   bufferIndex: number;
   /** Smart account index of the transaction this buffer belongs to. */
@@ -94,6 +110,9 @@ export function getTransactionBufferEncoder(): Encoder<TransactionBufferArgs> {
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['settings', getAddressEncoder()],
       ['creator', getAddressEncoder()],
+      ['rentPayer', getAddressEncoder()],
+      ['createdAt', getI64Encoder()],
+      ['transactionIndex', getU64Encoder()],
       ['bufferIndex', getU8Encoder()],
       ['accountIndex', getU8Encoder()],
       ['finalBufferHash', fixEncoderSize(getBytesEncoder(), 32)],
@@ -109,6 +128,9 @@ export function getTransactionBufferDecoder(): Decoder<TransactionBuffer> {
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['settings', getAddressDecoder()],
     ['creator', getAddressDecoder()],
+    ['rentPayer', getAddressDecoder()],
+    ['createdAt', getI64Decoder()],
+    ['transactionIndex', getU64Decoder()],
     ['bufferIndex', getU8Decoder()],
     ['accountIndex', getU8Decoder()],
     ['finalBufferHash', fixDecoderSize(getBytesDecoder(), 32)],
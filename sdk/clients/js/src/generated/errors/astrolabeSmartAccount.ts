@@ -96,7 +96,7 @@ export const ASTROLABE_SMART_ACCOUNT_ERROR__BATCH_NOT_EMPTY = 0x1796; // 6038
 export const ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_INSTRUCTION_ARGS = 0x1797; // 6039
 /** FinalBufferHashMismatch: Final message buffer hash doesnt match the expected hash */
 export const ASTROLABE_SMART_ACCOUNT_ERROR__FINAL_BUFFER_HASH_MISMATCH = 0x1798; // 6040
-/** FinalBufferSizeExceeded: Final buffer size cannot exceed 4000 bytes */
+/** FinalBufferSizeExceeded: Final buffer size cannot exceed 9216 bytes */
 export const ASTROLABE_SMART_ACCOUNT_ERROR__FINAL_BUFFER_SIZE_EXCEEDED = 0x1799; // 6041
 /** FinalBufferSizeMismatch: Final buffer size mismatch */
 export const ASTROLABE_SMART_ACCOUNT_ERROR__FINAL_BUFFER_SIZE_MISMATCH = 0x179a; // 6042
@@ -116,15 +116,115 @@ export const ASTROLABE_SMART_ACCOUNT_ERROR__INSUFFICIENT_VOTE_PERMISSIONS = 0x17
 export const ASTROLABE_SMART_ACCOUNT_ERROR__TIME_LOCK_NOT_ZERO = 0x17a1; // 6049
 /** NotImplemented: Feature not implemented */
 export const ASTROLABE_SMART_ACCOUNT_ERROR__NOT_IMPLEMENTED = 0x17a2; // 6050
+/** SpendingLimitInvalidAmount: Spending limit amount must be greater than 0 */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__SPENDING_LIMIT_INVALID_AMOUNT = 0x17a3; // 6051
+/** SpendingLimitExceeded: Spending limit exceeded */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__SPENDING_LIMIT_EXCEEDED = 0x17a4; // 6052
+/** SpendingLimitExpired: Spending limit is expired */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__SPENDING_LIMIT_EXPIRED = 0x17a5; // 6053
+/** UnauthorizedRestrictedSigner: Signer is not a restricted signer with the required permission */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__UNAUTHORIZED_RESTRICTED_SIGNER = 0x17a6; // 6054
+/** MissingExitDestination: Restricted signer with the EmergencyExit permission must have an exit destination */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_EXIT_DESTINATION = 0x17a7; // 6055
+/** ArchivalCooldownNotElapsed: Smart account cannot be archived before `archivable_after` */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__ARCHIVAL_COOLDOWN_NOT_ELAPSED = 0x17a8; // 6056
+/** ArchivedSettingsHashMismatch: Supplied settings do not match the archived settings hash */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__ARCHIVED_SETTINGS_HASH_MISMATCH = 0x17a9; // 6057
+/** InvalidPasskeyPublicKey: Invalid compressed secp256r1 public key */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_PASSKEY_PUBLIC_KEY = 0x17aa; // 6058
+/** MissingPasskeySignature: No secp256r1 signature by the passkey found in the transaction */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_PASSKEY_SIGNATURE = 0x17ab; // 6059
+/** InvalidWebAuthnAuthenticatorData: Invalid WebAuthn authenticator data */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_WEB_AUTHN_AUTHENTICATOR_DATA = 0x17ac; // 6060
+/** InvalidWebAuthnClientData: Invalid WebAuthn client data */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_WEB_AUTHN_CLIENT_DATA = 0x17ad; // 6061
+/** SessionKeyExpired: Session key is expired */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__SESSION_KEY_EXPIRED = 0x17ae; // 6062
+/** SessionKeyOutOfScope: Session key is not allowed to perform this action */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__SESSION_KEY_OUT_OF_SCOPE = 0x17af; // 6063
+/** SessionKeyBudgetExceeded: Session key lamports budget exceeded */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__SESSION_KEY_BUDGET_EXCEEDED = 0x17b0; // 6064
+/** InvalidRecoveryThreshold: Recovery threshold must not exceed the number of guardians */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_RECOVERY_THRESHOLD = 0x17b1; // 6065
+/** RecoveryDisabled: Recovery is disabled for this smart account */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__RECOVERY_DISABLED = 0x17b2; // 6066
+/** RecoveryNotApproved: Recovery has not been approved by enough guardians */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__RECOVERY_NOT_APPROVED = 0x17b3; // 6067
+/** RecoveryDelayNotElapsed: Recovery veto period has not elapsed */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__RECOVERY_DELAY_NOT_ELAPSED = 0x17b4; // 6068
+/** RecoveryAlreadyApproved: Guardian has already approved the recovery */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__RECOVERY_ALREADY_APPROVED = 0x17b5; // 6069
+/** MissingVaultPolicy: Vault policy account is required for this smart account */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_VAULT_POLICY = 0x17b6; // 6070
+/** VaultPolicyThresholdNotReached: Transaction has not been approved by enough signers of the vault policy */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__VAULT_POLICY_THRESHOLD_NOT_REACHED = 0x17b7; // 6071
+/** InvalidVotingDeadline: Voting deadline must be in the future */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_VOTING_DEADLINE = 0x17b8; // 6072
+/** ProposalExpired: Proposal voting deadline or execution window has passed */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__PROPOSAL_EXPIRED = 0x17b9; // 6073
+/** ProposalNotExpired: Proposal has not expired */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__PROPOSAL_NOT_EXPIRED = 0x17ba; // 6074
+/** InvalidExecutionWindow: Execution window must be longer than the time lock */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_EXECUTION_WINDOW = 0x17bb; // 6075
+/** DuplicateTargetTimeLock: Found multiple time locks for the same target */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__DUPLICATE_TARGET_TIME_LOCK = 0x17bc; // 6076
+/** MissingTargetTimeLock: Target has no time lock */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_TARGET_TIME_LOCK = 0x17bd; // 6077
+/** SignerRotationDisabled: Signer rotation is disabled for this smart account */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__SIGNER_ROTATION_DISABLED = 0x17be; // 6078
+/** SignerRotationDelayNotElapsed: Signer rotation delay has not elapsed yet */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__SIGNER_ROTATION_DELAY_NOT_ELAPSED = 0x17bf; // 6079
+/** ProgramNotAllowed: Program instruction is not allowed by the program filters of the smart account */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__PROGRAM_NOT_ALLOWED = 0x17c0; // 6080
+/** DuplicateProgramFilter: Found multiple program filters for the same instructions */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__DUPLICATE_PROGRAM_FILTER = 0x17c1; // 6081
+/** MissingProgramFilter: Program filter not found */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_PROGRAM_FILTER = 0x17c2; // 6082
+/** InvalidProgramFilter: Program filter discriminator is too long */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_PROGRAM_FILTER = 0x17c3; // 6083
+/** TransactionBufferNotAbandoned: Transaction buffer has not timed out, gone stale or lost its creator */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__TRANSACTION_BUFFER_NOT_ABANDONED = 0x17c4; // 6084
+/** InvalidReferralFee: Referral fee can't exceed 10000 basis points */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_REFERRAL_FEE = 0x17c5; // 6085
+/** InvalidReferrer: Referrer account doesn't match the referrer of the smart account */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_REFERRER = 0x17c6; // 6086
+/** CreatorNotAllowlisted: Creator is not on the smart account creation allowlist */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__CREATOR_NOT_ALLOWLISTED = 0x17c7; // 6087
+/** CreatorAlreadyAllowlisted: Creator is already on the smart account creation allowlist */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__CREATOR_ALREADY_ALLOWLISTED = 0x17c8; // 6088
+/** CreatorAllowlistPageFull: Creator allowlist page is full */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__CREATOR_ALLOWLIST_PAGE_FULL = 0x17c9; // 6089
+/** CreationNotCosigned: Smart account creation must be cosigned by the program config authority */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__CREATION_NOT_COSIGNED = 0x17ca; // 6090
+/** ProgramConfigTransferStale: Program config transfer was proposed by a previous authority */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__PROGRAM_CONFIG_TRANSFER_STALE = 0x17cb; // 6091
+/** ProgramConfigTransferKindMismatch: Program config transfer is for another parameter */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__PROGRAM_CONFIG_TRANSFER_KIND_MISMATCH = 0x17cc; // 6092
+/** TransactionSizeExceeded: Transaction account can't grow by more than 10240 bytes within an instruction */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__TRANSACTION_SIZE_EXCEEDED = 0x17cd; // 6093
+/** Overflow: Arithmetic overflow */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__OVERFLOW = 0x17ce; // 6094
+/** SettingsAlreadyMigrated: Settings account is already in the current layout */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__SETTINGS_ALREADY_MIGRATED = 0x17cf; // 6095
+/** SettingsTooLargeToArchive: Settings are too large to be restored once archived */
+export const ASTROLABE_SMART_ACCOUNT_ERROR__SETTINGS_TOO_LARGE_TO_ARCHIVE = 0x17d0; // 6096
 
 export type AstrolabeSmartAccountError =
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__ACCOUNT_NOT_EMPTY
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__ALREADY_APPROVED
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__ALREADY_CANCELLED
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__ALREADY_REJECTED
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__ARCHIVAL_COOLDOWN_NOT_ELAPSED
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__ARCHIVED_SETTINGS_HASH_MISMATCH
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__BATCH_NOT_EMPTY
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__CREATION_NOT_COSIGNED
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__CREATOR_ALLOWLIST_PAGE_FULL
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__CREATOR_ALREADY_ALLOWLISTED
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__CREATOR_NOT_ALLOWLISTED
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__DECIMALS_MISMATCH
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__DUPLICATE_PROGRAM_FILTER
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__DUPLICATE_SIGNER
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__DUPLICATE_TARGET_TIME_LOCK
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__EMPTY_SIGNERS
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__FINAL_BUFFER_HASH_MISMATCH
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__FINAL_BUFFER_SIZE_EXCEEDED
@@ -134,18 +234,32 @@ export type AstrolabeSmartAccountError =
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INSUFFICIENT_VOTE_PERMISSIONS
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_ACCOUNT
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_DESTINATION
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_EXECUTION_WINDOW
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_INSTRUCTION_ARGS
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_MINT
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_NUMBER_OF_ACCOUNTS
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_PASSKEY_PUBLIC_KEY
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_PROGRAM_FILTER
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_PROPOSAL_STATUS
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_RECOVERY_THRESHOLD
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_REFERRAL_FEE
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_REFERRER
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_RENT_COLLECTOR
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_SIGNER_COUNT
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_STALE_TRANSACTION_INDEX
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_THRESHOLD
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_TRANSACTION_INDEX
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_TRANSACTION_MESSAGE
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_VOTING_DEADLINE
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_WEB_AUTHN_AUTHENTICATOR_DATA
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_WEB_AUTHN_CLIENT_DATA
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_ACCOUNT
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_EXIT_DESTINATION
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_PASSKEY_SIGNATURE
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_PROGRAM_FILTER
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_SIGNATURE
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_TARGET_TIME_LOCK
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_VAULT_POLICY
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__NO_ACTIONS
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__NO_EXECUTORS
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__NO_PROPOSERS
@@ -153,22 +267,46 @@ export type AstrolabeSmartAccountError =
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__NOT_IMPLEMENTED
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__NOT_SUPPORTED_FOR_CONTROLLED
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__NO_VOTERS
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__OVERFLOW
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__PROGRAM_CONFIG_TRANSFER_KIND_MISMATCH
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__PROGRAM_CONFIG_TRANSFER_STALE
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__PROGRAM_NOT_ALLOWED
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__PROPOSAL_EXPIRED
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__PROPOSAL_FOR_ANOTHER_SMART_ACCOUNT
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__PROPOSAL_NOT_EXPIRED
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__PROTECTED_ACCOUNT
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__RECOVERY_ALREADY_APPROVED
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__RECOVERY_DELAY_NOT_ELAPSED
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__RECOVERY_DISABLED
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__RECOVERY_NOT_APPROVED
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__REMOVE_LAST_SIGNER
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__RENT_RECLAMATION_DISABLED
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__SESSION_KEY_BUDGET_EXCEEDED
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__SESSION_KEY_EXPIRED
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__SESSION_KEY_OUT_OF_SCOPE
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__SETTINGS_ALREADY_MIGRATED
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__SETTINGS_TOO_LARGE_TO_ARCHIVE
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__SIGNER_ROTATION_DELAY_NOT_ELAPSED
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__SIGNER_ROTATION_DISABLED
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__SMART_ACCOUNT_CREATE_DEPRECATED
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__SPENDING_LIMIT_EXCEEDED
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__SPENDING_LIMIT_EXPIRED
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__SPENDING_LIMIT_INVALID_AMOUNT
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__STALE_PROPOSAL
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__THRESHOLD_NOT_REACHED
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__TIME_LOCK_EXCEEDS_MAX_ALLOWED
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__TIME_LOCK_NOT_RELEASED
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__TIME_LOCK_NOT_ZERO
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__TOO_MANY_SIGNERS
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__TRANSACTION_BUFFER_NOT_ABANDONED
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__TRANSACTION_FOR_ANOTHER_SMART_ACCOUNT
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__TRANSACTION_NOT_LAST_IN_BATCH
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__TRANSACTION_NOT_MATCHING_PROPOSAL
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__TRANSACTION_SIZE_EXCEEDED
   | typeof ASTROLABE_SMART_ACCOUNT_ERROR__UNAUTHORIZED
-  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__UNKNOWN_PERMISSION;
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__UNAUTHORIZED_RESTRICTED_SIGNER
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__UNKNOWN_PERMISSION
+  | typeof ASTROLABE_SMART_ACCOUNT_ERROR__VAULT_POLICY_THRESHOLD_NOT_REACHED;
 
 let astrolabeSmartAccountErrorMessages:
   | Record<AstrolabeSmartAccountError, string>
@@ -179,30 +317,52 @@ if (process.env.NODE_ENV !== 'production') {
     [ASTROLABE_SMART_ACCOUNT_ERROR__ALREADY_APPROVED]: `Signer already approved the transaction`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__ALREADY_CANCELLED]: `Signer already cancelled the transaction`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__ALREADY_REJECTED]: `Signer already rejected the transaction`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__ARCHIVAL_COOLDOWN_NOT_ELAPSED]: `Smart account cannot be archived before \`archivable_after\``,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__ARCHIVED_SETTINGS_HASH_MISMATCH]: `Supplied settings do not match the archived settings hash`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__BATCH_NOT_EMPTY]: `Batch is not empty`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__CREATION_NOT_COSIGNED]: `Smart account creation must be cosigned by the program config authority`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__CREATOR_ALLOWLIST_PAGE_FULL]: `Creator allowlist page is full`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__CREATOR_ALREADY_ALLOWLISTED]: `Creator is already on the smart account creation allowlist`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__CREATOR_NOT_ALLOWLISTED]: `Creator is not on the smart account creation allowlist`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__DECIMALS_MISMATCH]: `Decimals don't match the mint`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__DUPLICATE_PROGRAM_FILTER]: `Found multiple program filters for the same instructions`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__DUPLICATE_SIGNER]: `Found multiple signers with the same pubkey`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__DUPLICATE_TARGET_TIME_LOCK]: `Found multiple time locks for the same target`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__EMPTY_SIGNERS]: `Signers array is empty`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__FINAL_BUFFER_HASH_MISMATCH]: `Final message buffer hash doesnt match the expected hash`,
-    [ASTROLABE_SMART_ACCOUNT_ERROR__FINAL_BUFFER_SIZE_EXCEEDED]: `Final buffer size cannot exceed 4000 bytes`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__FINAL_BUFFER_SIZE_EXCEEDED]: `Final buffer size cannot exceed 9216 bytes`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__FINAL_BUFFER_SIZE_MISMATCH]: `Final buffer size mismatch`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__ILLEGAL_ACCOUNT_OWNER]: `Account is not owned by Smart Account program`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__INSUFFICIENT_AGGREGATE_PERMISSIONS]: `Insufficient aggregate permissions across signing members`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__INSUFFICIENT_VOTE_PERMISSIONS]: `Insufficient vote permissions across signing members`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_ACCOUNT]: `Invalid account provided`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_DESTINATION]: `Invalid destination`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_EXECUTION_WINDOW]: `Execution window must be longer than the time lock`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_INSTRUCTION_ARGS]: `Invalid Instruction Arguments`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_MINT]: `Invalid mint`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_NUMBER_OF_ACCOUNTS]: `Wrong number of accounts provided`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_PASSKEY_PUBLIC_KEY]: `Invalid compressed secp256r1 public key`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_PROGRAM_FILTER]: `Program filter discriminator is too long`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_PROPOSAL_STATUS]: `Invalid proposal status`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_RECOVERY_THRESHOLD]: `Recovery threshold must not exceed the number of guardians`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_REFERRAL_FEE]: `Referral fee can't exceed 10000 basis points`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_REFERRER]: `Referrer account doesn't match the referrer of the smart account`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_RENT_COLLECTOR]: `Invalid rent collector address`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_SIGNER_COUNT]: `Invalid number of signer accounts. Must be greater or equal to the threshold`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_STALE_TRANSACTION_INDEX]: `\`stale_transaction_index\` must be <= \`transaction_index\``,
     [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_THRESHOLD]: `Invalid threshold, must be between 1 and number of signers with vote permissions`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_TRANSACTION_INDEX]: `Invalid transaction index`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_TRANSACTION_MESSAGE]: `TransactionMessage is malformed.`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_VOTING_DEADLINE]: `Voting deadline must be in the future`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_WEB_AUTHN_AUTHENTICATOR_DATA]: `Invalid WebAuthn authenticator data`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__INVALID_WEB_AUTHN_CLIENT_DATA]: `Invalid WebAuthn client data`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_ACCOUNT]: `Missing account`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_EXIT_DESTINATION]: `Restricted signer with the EmergencyExit permission must have an exit destination`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_PASSKEY_SIGNATURE]: `No secp256r1 signature by the passkey found in the transaction`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_PROGRAM_FILTER]: `Program filter not found`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_SIGNATURE]: `Missing signature`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_TARGET_TIME_LOCK]: `Target has no time lock`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__MISSING_VAULT_POLICY]: `Vault policy account is required for this smart account`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__NO_ACTIONS]: `Config transaction must have at least one action`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__NO_EXECUTORS]: `Signers don't include any executors`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__NO_PROPOSERS]: `Signers don't include any proposers`,
@@ -210,22 +370,46 @@ if (process.env.NODE_ENV !== 'production') {
     [ASTROLABE_SMART_ACCOUNT_ERROR__NOT_IMPLEMENTED]: `Feature not implemented`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__NOT_SUPPORTED_FOR_CONTROLLED]: `Instruction not supported for controlled smart account`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__NO_VOTERS]: `Signers don't include any voters`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__OVERFLOW]: `Arithmetic overflow`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__PROGRAM_CONFIG_TRANSFER_KIND_MISMATCH]: `Program config transfer is for another parameter`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__PROGRAM_CONFIG_TRANSFER_STALE]: `Program config transfer was proposed by a previous authority`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__PROGRAM_NOT_ALLOWED]: `Program instruction is not allowed by the program filters of the smart account`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__PROPOSAL_EXPIRED]: `Proposal voting deadline or execution window has passed`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__PROPOSAL_FOR_ANOTHER_SMART_ACCOUNT]: `Proposal is for another smart account`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__PROPOSAL_NOT_EXPIRED]: `Proposal has not expired`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__PROTECTED_ACCOUNT]: `Account is protected, it cannot be passed into a CPI as writable`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__RECOVERY_ALREADY_APPROVED]: `Guardian has already approved the recovery`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__RECOVERY_DELAY_NOT_ELAPSED]: `Recovery veto period has not elapsed`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__RECOVERY_DISABLED]: `Recovery is disabled for this smart account`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__RECOVERY_NOT_APPROVED]: `Recovery has not been approved by enough guardians`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__REMOVE_LAST_SIGNER]: `Cannot remove last signer`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__RENT_RECLAMATION_DISABLED]: `Rent reclamation is disabled for this smart account`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__SESSION_KEY_BUDGET_EXCEEDED]: `Session key lamports budget exceeded`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__SESSION_KEY_EXPIRED]: `Session key is expired`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__SESSION_KEY_OUT_OF_SCOPE]: `Session key is not allowed to perform this action`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__SETTINGS_ALREADY_MIGRATED]: `Settings account is already in the current layout`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__SETTINGS_TOO_LARGE_TO_ARCHIVE]: `Settings are too large to be restored once archived`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__SIGNER_ROTATION_DELAY_NOT_ELAPSED]: `Signer rotation delay has not elapsed yet`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__SIGNER_ROTATION_DISABLED]: `Signer rotation is disabled for this smart account`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__SMART_ACCOUNT_CREATE_DEPRECATED]: `smart_account_create has been deprecated. Use smart_account_create_v2 instead.`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__SPENDING_LIMIT_EXCEEDED]: `Spending limit exceeded`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__SPENDING_LIMIT_EXPIRED]: `Spending limit is expired`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__SPENDING_LIMIT_INVALID_AMOUNT]: `Spending limit amount must be greater than 0`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__STALE_PROPOSAL]: `Proposal is stale`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__THRESHOLD_NOT_REACHED]: `Signers do not reach consensus threshold`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__TIME_LOCK_EXCEEDS_MAX_ALLOWED]: `Time lock exceeds the maximum allowed (90 days)`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__TIME_LOCK_NOT_RELEASED]: `Proposal time lock has not been released`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__TIME_LOCK_NOT_ZERO]: `Smart account must not be time locked`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__TOO_MANY_SIGNERS]: `Too many signers, can be up to 65535`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__TRANSACTION_BUFFER_NOT_ABANDONED]: `Transaction buffer has not timed out, gone stale or lost its creator`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__TRANSACTION_FOR_ANOTHER_SMART_ACCOUNT]: `Transaction is for another smart account`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__TRANSACTION_NOT_LAST_IN_BATCH]: `Transaction is not last in batch`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__TRANSACTION_NOT_MATCHING_PROPOSAL]: `Transaction doesn't match proposal`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__TRANSACTION_SIZE_EXCEEDED]: `Transaction account can't grow by more than 10240 bytes within an instruction`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__UNAUTHORIZED]: `Attempted to perform an unauthorized action`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__UNAUTHORIZED_RESTRICTED_SIGNER]: `Signer is not a restricted signer with the required permission`,
     [ASTROLABE_SMART_ACCOUNT_ERROR__UNKNOWN_PERMISSION]: `Signer has unknown permission`,
+    [ASTROLABE_SMART_ACCOUNT_ERROR__VAULT_POLICY_THRESHOLD_NOT_REACHED]: `Transaction has not been approved by enough signers of the vault policy`,
   };
 }
 
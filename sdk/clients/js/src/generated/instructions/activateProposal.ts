@@ -46,6 +46,11 @@ export type ActivateProposalInstruction<
   TAccountSettings extends string | AccountMeta<string> = string,
   TAccountSigner extends string | AccountMeta<string> = string,
   TAccountProposal extends string | AccountMeta<string> = string,
+  TAccountProgram extends
+    | string
+    | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
+  TAccountTransaction extends string | AccountMeta<string> = string,
+  TAccountVaultPolicy extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -61,6 +66,15 @@ export type ActivateProposalInstruction<
       TAccountProposal extends string
         ? WritableAccount<TAccountProposal>
         : TAccountProposal,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountTransaction extends string
+        ? ReadonlyAccount<TAccountTransaction>
+        : TAccountTransaction,
+      TAccountVaultPolicy extends string
+        ? ReadonlyAccount<TAccountVaultPolicy>
+        : TAccountVaultPolicy,
       ...TRemainingAccounts,
     ]
   >;
@@ -98,30 +112,50 @@ export type ActivateProposalInput<
   TAccountSettings extends string = string,
   TAccountSigner extends string = string,
   TAccountProposal extends string = string,
+  TAccountProgram extends string = string,
+  TAccountTransaction extends string = string,
+  TAccountVaultPolicy extends string = string,
 > = {
   settings: Address<TAccountSettings>;
   signer: TransactionSigner<TAccountSigner>;
   proposal: Address<TAccountProposal>;
+  program?: Address<TAccountProgram>;
+  /**
+   * The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+   * Only required if the settings have a `VaultPolicy`.
+   */
+  transaction?: Address<TAccountTransaction>;
+  /** The policy of the smart account the transaction is for, required if it has one. */
+  vaultPolicy?: Address<TAccountVaultPolicy>;
 };
 
 export function getActivateProposalInstruction<
   TAccountSettings extends string,
   TAccountSigner extends string,
   TAccountProposal extends string,
+  TAccountProgram extends string,
+  TAccountTransaction extends string,
+  TAccountVaultPolicy extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
   input: ActivateProposalInput<
     TAccountSettings,
     TAccountSigner,
-    TAccountProposal
+    TAccountProposal,
+    TAccountProgram,
+    TAccountTransaction,
+    TAccountVaultPolicy
   >,
   config?: { programAddress?: TProgramAddress }
 ): ActivateProposalInstruction<
   TProgramAddress,
   TAccountSettings,
   TAccountSigner,
-  TAccountProposal
+  TAccountProposal,
+  TAccountProgram,
+  TAccountTransaction,
+  TAccountVaultPolicy
 > {
   // Program address.
   const programAddress =
@@ -132,18 +166,30 @@ export function getActivateProposalInstruction<
     settings: { value: input.settings ?? null, isWritable: false },
     signer: { value: input.signer ?? null, isWritable: true },
     proposal: { value: input.proposal ?? null, isWritable: true },
+    program: { value: input.program ?? null, isWritable: false },
+    transaction: { value: input.transaction ?? null, isWritable: false },
+    vaultPolicy: { value: input.vaultPolicy ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Resolve default values.
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.settings),
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.proposal),
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.transaction),
+      getAccountMeta(accounts.vaultPolicy),
     ],
     data: getActivateProposalInstructionDataEncoder().encode({}),
     programAddress,
@@ -151,7 +197,10 @@ export function getActivateProposalInstruction<
     TProgramAddress,
     TAccountSettings,
     TAccountSigner,
-    TAccountProposal
+    TAccountProposal,
+    TAccountProgram,
+    TAccountTransaction,
+    TAccountVaultPolicy
   >);
 }
 
@@ -164,6 +213,14 @@ export type ParsedActivateProposalInstruction<
     settings: TAccountMetas[0];
     signer: TAccountMetas[1];
     proposal: TAccountMetas[2];
+    program: TAccountMetas[3];
+    /**
+     * The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+     * Only required if the settings have a `VaultPolicy`.
+     */
+    transaction?: TAccountMetas[4] | undefined;
+    /** The policy of the smart account the transaction is for, required if it has one. */
+    vaultPolicy?: TAccountMetas[5] | undefined;
   };
   data: ActivateProposalInstructionData;
 };
@@ -176,7 +233,7 @@ export function parseActivateProposalInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedActivateProposalInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -186,12 +243,21 @@ export function parseActivateProposalInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       settings: getNextAccount(),
       signer: getNextAccount(),
       proposal: getNextAccount(),
+      program: getNextAccount(),
+      transaction: getNextOptionalAccount(),
+      vaultPolicy: getNextOptionalAccount(),
     },
     data: getActivateProposalInstructionDataDecoder().decode(instruction.data),
   };
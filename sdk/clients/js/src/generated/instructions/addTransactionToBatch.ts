@@ -7,8 +7,6 @@
  */
 
 import {
-  addDecoderSizePrefix,
-  addEncoderSizePrefix,
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
@@ -16,10 +14,6 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
-  getU32Decoder,
-  getU32Encoder,
-  getU8Decoder,
-  getU8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -39,6 +33,12 @@ import {
 } from '@solana/kit';
 import { ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getAddTransactionToBatchArgsDecoder,
+  getAddTransactionToBatchArgsEncoder,
+  type AddTransactionToBatchArgs,
+  type AddTransactionToBatchArgsArgs,
+} from '../types';
 
 export const ADD_TRANSACTION_TO_BATCH_DISCRIMINATOR = new Uint8Array([21]);
 
@@ -59,6 +59,9 @@ export type AddTransactionToBatchInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountProgram extends
+    | string
+    | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -87,32 +90,27 @@ export type AddTransactionToBatchInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
 
 export type AddTransactionToBatchInstructionData = {
   discriminator: ReadonlyUint8Array;
-  /** Number of ephemeral signing PDAs required by the transaction. */
-  ephemeralSigners: number;
-  transactionMessage: ReadonlyUint8Array;
+  args: AddTransactionToBatchArgs;
 };
 
 export type AddTransactionToBatchInstructionDataArgs = {
-  /** Number of ephemeral signing PDAs required by the transaction. */
-  ephemeralSigners: number;
-  transactionMessage: ReadonlyUint8Array;
+  args: AddTransactionToBatchArgsArgs;
 };
 
 export function getAddTransactionToBatchInstructionDataEncoder(): Encoder<AddTransactionToBatchInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 1)],
-      ['ephemeralSigners', getU8Encoder()],
-      [
-        'transactionMessage',
-        addEncoderSizePrefix(getBytesEncoder(), getU32Encoder()),
-      ],
+      ['args', getAddTransactionToBatchArgsEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -124,11 +122,7 @@ export function getAddTransactionToBatchInstructionDataEncoder(): Encoder<AddTra
 export function getAddTransactionToBatchInstructionDataDecoder(): Decoder<AddTransactionToBatchInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 1)],
-    ['ephemeralSigners', getU8Decoder()],
-    [
-      'transactionMessage',
-      addDecoderSizePrefix(getBytesDecoder(), getU32Decoder()),
-    ],
+    ['args', getAddTransactionToBatchArgsDecoder()],
   ]);
 }
 
@@ -150,6 +144,7 @@ export type AddTransactionToBatchInput<
   TAccountSigner extends string = string,
   TAccountFeePayer extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountProgram extends string = string,
 > = {
   /** Settings account this batch belongs to. */
   settings: Address<TAccountSettings>;
@@ -163,8 +158,8 @@ export type AddTransactionToBatchInput<
   /** The payer for the batch transaction account rent. */
   feePayer: TransactionSigner<TAccountFeePayer>;
   systemProgram?: Address<TAccountSystemProgram>;
-  ephemeralSigners: AddTransactionToBatchInstructionDataArgs['ephemeralSigners'];
-  transactionMessage: AddTransactionToBatchInstructionDataArgs['transactionMessage'];
+  program?: Address<TAccountProgram>;
+  args: AddTransactionToBatchInstructionDataArgs['args'];
 };
 
 export function getAddTransactionToBatchInstruction<
@@ -175,6 +170,7 @@ export function getAddTransactionToBatchInstruction<
   TAccountSigner extends string,
   TAccountFeePayer extends string,
   TAccountSystemProgram extends string,
+  TAccountProgram extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
@@ -185,7 +181,8 @@ export function getAddTransactionToBatchInstruction<
     TAccountTransaction,
     TAccountSigner,
     TAccountFeePayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): AddTransactionToBatchInstruction<
@@ -196,7 +193,8 @@ export function getAddTransactionToBatchInstruction<
   TAccountTransaction,
   TAccountSigner,
   TAccountFeePayer,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -211,6 +209,7 @@ export function getAddTransactionToBatchInstruction<
     signer: { value: input.signer ?? null, isWritable: false },
     feePayer: { value: input.feePayer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -225,6 +224,10 @@ export function getAddTransactionToBatchInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -236,6 +239,7 @@ export function getAddTransactionToBatchInstruction<
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.feePayer),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.program),
     ],
     data: getAddTransactionToBatchInstructionDataEncoder().encode(
       args as AddTransactionToBatchInstructionDataArgs
@@ -249,7 +253,8 @@ export function getAddTransactionToBatchInstruction<
     TAccountTransaction,
     TAccountSigner,
     TAccountFeePayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram
   >);
 }
 
@@ -271,6 +276,7 @@ export type ParsedAddTransactionToBatchInstruction<
     /** The payer for the batch transaction account rent. */
     feePayer: TAccountMetas[5];
     systemProgram: TAccountMetas[6];
+    program: TAccountMetas[7];
   };
   data: AddTransactionToBatchInstructionData;
 };
@@ -283,7 +289,7 @@ export function parseAddTransactionToBatchInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedAddTransactionToBatchInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -303,6 +309,7 @@ export function parseAddTransactionToBatchInstruction<
       signer: getNextAccount(),
       feePayer: getNextAccount(),
       systemProgram: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getAddTransactionToBatchInstructionDataDecoder().decode(
       instruction.data
@@ -55,6 +55,11 @@ export type CancelProposalInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountProgram extends
+    | string
+    | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
+  TAccountTransaction extends string | AccountMeta<string> = string,
+  TAccountVaultPolicy extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -73,6 +78,15 @@ export type CancelProposalInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountTransaction extends string
+        ? ReadonlyAccount<TAccountTransaction>
+        : TAccountTransaction,
+      TAccountVaultPolicy extends string
+        ? ReadonlyAccount<TAccountVaultPolicy>
+        : TAccountVaultPolicy,
       ...TRemainingAccounts,
     ]
   >;
@@ -118,11 +132,22 @@ export type CancelProposalInput<
   TAccountSigner extends string = string,
   TAccountProposal extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountProgram extends string = string,
+  TAccountTransaction extends string = string,
+  TAccountVaultPolicy extends string = string,
 > = {
   settings: Address<TAccountSettings>;
   signer: TransactionSigner<TAccountSigner>;
   proposal: Address<TAccountProposal>;
   systemProgram?: Address<TAccountSystemProgram>;
+  program?: Address<TAccountProgram>;
+  /**
+   * The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+   * Only required if the settings have a `VaultPolicy`.
+   */
+  transaction?: Address<TAccountTransaction>;
+  /** The policy of the smart account the transaction is for, required if it has one. */
+  vaultPolicy?: Address<TAccountVaultPolicy>;
   args: CancelProposalInstructionDataArgs['args'];
 };
 
@@ -131,6 +156,9 @@ export function getCancelProposalInstruction<
   TAccountSigner extends string,
   TAccountProposal extends string,
   TAccountSystemProgram extends string,
+  TAccountProgram extends string,
+  TAccountTransaction extends string,
+  TAccountVaultPolicy extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
@@ -138,7 +166,10 @@ export function getCancelProposalInstruction<
     TAccountSettings,
     TAccountSigner,
     TAccountProposal,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram,
+    TAccountTransaction,
+    TAccountVaultPolicy
   >,
   config?: { programAddress?: TProgramAddress }
 ): CancelProposalInstruction<
@@ -146,7 +177,10 @@ export function getCancelProposalInstruction<
   TAccountSettings,
   TAccountSigner,
   TAccountProposal,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountProgram,
+  TAccountTransaction,
+  TAccountVaultPolicy
 > {
   // Program address.
   const programAddress =
@@ -158,6 +192,9 @@ export function getCancelProposalInstruction<
     signer: { value: input.signer ?? null, isWritable: true },
     proposal: { value: input.proposal ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+    transaction: { value: input.transaction ?? null, isWritable: false },
+    vaultPolicy: { value: input.vaultPolicy ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -172,6 +209,10 @@ export function getCancelProposalInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -180,6 +221,9 @@ export function getCancelProposalInstruction<
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.proposal),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.transaction),
+      getAccountMeta(accounts.vaultPolicy),
     ],
     data: getCancelProposalInstructionDataEncoder().encode(
       args as CancelProposalInstructionDataArgs
@@ -190,7 +234,10 @@ export function getCancelProposalInstruction<
     TAccountSettings,
     TAccountSigner,
     TAccountProposal,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram,
+    TAccountTransaction,
+    TAccountVaultPolicy
   >);
 }
 
@@ -204,6 +251,14 @@ export type ParsedCancelProposalInstruction<
     signer: TAccountMetas[1];
     proposal: TAccountMetas[2];
     systemProgram?: TAccountMetas[3] | undefined;
+    program: TAccountMetas[4];
+    /**
+     * The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+     * Only required if the settings have a `VaultPolicy`.
+     */
+    transaction?: TAccountMetas[5] | undefined;
+    /** The policy of the smart account the transaction is for, required if it has one. */
+    vaultPolicy?: TAccountMetas[6] | undefined;
   };
   data: CancelProposalInstructionData;
 };
@@ -216,7 +271,7 @@ export function parseCancelProposalInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCancelProposalInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -239,6 +294,9 @@ export function parseCancelProposalInstruction<
       signer: getNextAccount(),
       proposal: getNextAccount(),
       systemProgram: getNextOptionalAccount(),
+      program: getNextAccount(),
+      transaction: getNextOptionalAccount(),
+      vaultPolicy: getNextOptionalAccount(),
     },
     data: getCancelProposalInstructionDataDecoder().decode(instruction.data),
   };
@@ -46,6 +46,9 @@ export type CloseBatchInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountProgram extends
+    | string
+    | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -69,6 +72,9 @@ export type CloseBatchInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -107,6 +113,7 @@ export type CloseBatchInput<
   TAccountProposalRentCollector extends string = string,
   TAccountBatchRentCollector extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountProgram extends string = string,
 > = {
   settings: Address<TAccountSettings>;
   /** the logic within `close_batch` does the rest of the checks. */
@@ -118,6 +125,7 @@ export type CloseBatchInput<
   /** The rent collector. */
   batchRentCollector: Address<TAccountBatchRentCollector>;
   systemProgram?: Address<TAccountSystemProgram>;
+  program?: Address<TAccountProgram>;
 };
 
 export function getCloseBatchInstruction<
@@ -127,6 +135,7 @@ export function getCloseBatchInstruction<
   TAccountProposalRentCollector extends string,
   TAccountBatchRentCollector extends string,
   TAccountSystemProgram extends string,
+  TAccountProgram extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
@@ -136,7 +145,8 @@ export function getCloseBatchInstruction<
     TAccountBatch,
     TAccountProposalRentCollector,
     TAccountBatchRentCollector,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): CloseBatchInstruction<
@@ -146,7 +156,8 @@ export function getCloseBatchInstruction<
   TAccountBatch,
   TAccountProposalRentCollector,
   TAccountBatchRentCollector,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -166,6 +177,7 @@ export function getCloseBatchInstruction<
       isWritable: true,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -177,6 +189,10 @@ export function getCloseBatchInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -187,6 +203,7 @@ export function getCloseBatchInstruction<
       getAccountMeta(accounts.proposalRentCollector),
       getAccountMeta(accounts.batchRentCollector),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.program),
     ],
     data: getCloseBatchInstructionDataEncoder().encode({}),
     programAddress,
@@ -197,7 +214,8 @@ export function getCloseBatchInstruction<
     TAccountBatch,
     TAccountProposalRentCollector,
     TAccountBatchRentCollector,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram
   >);
 }
 
@@ -217,6 +235,7 @@ export type ParsedCloseBatchInstruction<
     /** The rent collector. */
     batchRentCollector: TAccountMetas[4];
     systemProgram: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: CloseBatchInstructionData;
 };
@@ -229,7 +248,7 @@ export function parseCloseBatchInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCloseBatchInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -248,6 +267,7 @@ export function parseCloseBatchInstruction<
       proposalRentCollector: getNextAccount(),
       batchRentCollector: getNextAccount(),
       systemProgram: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getCloseBatchInstructionDataDecoder().decode(instruction.data),
   };
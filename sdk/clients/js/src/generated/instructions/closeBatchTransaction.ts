@@ -50,6 +50,9 @@ export type CloseBatchTransactionInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountProgram extends
+    | string
+    | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -73,6 +76,9 @@ export type CloseBatchTransactionInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -116,6 +122,7 @@ export type CloseBatchTransactionInput<
   TAccountTransaction extends string = string,
   TAccountTransactionRentCollector extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountProgram extends string = string,
 > = {
   settings: Address<TAccountSettings>;
   proposal: Address<TAccountProposal>;
@@ -129,6 +136,7 @@ export type CloseBatchTransactionInput<
   /** The rent collector. */
   transactionRentCollector: Address<TAccountTransactionRentCollector>;
   systemProgram?: Address<TAccountSystemProgram>;
+  program?: Address<TAccountProgram>;
 };
 
 export function getCloseBatchTransactionInstruction<
@@ -138,6 +146,7 @@ export function getCloseBatchTransactionInstruction<
   TAccountTransaction extends string,
   TAccountTransactionRentCollector extends string,
   TAccountSystemProgram extends string,
+  TAccountProgram extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
@@ -147,7 +156,8 @@ export function getCloseBatchTransactionInstruction<
     TAccountBatch,
     TAccountTransaction,
     TAccountTransactionRentCollector,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): CloseBatchTransactionInstruction<
@@ -157,7 +167,8 @@ export function getCloseBatchTransactionInstruction<
   TAccountBatch,
   TAccountTransaction,
   TAccountTransactionRentCollector,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -174,6 +185,7 @@ export function getCloseBatchTransactionInstruction<
       isWritable: true,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -185,6 +197,10 @@ export function getCloseBatchTransactionInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -195,6 +211,7 @@ export function getCloseBatchTransactionInstruction<
       getAccountMeta(accounts.transaction),
       getAccountMeta(accounts.transactionRentCollector),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.program),
     ],
     data: getCloseBatchTransactionInstructionDataEncoder().encode({}),
     programAddress,
@@ -205,7 +222,8 @@ export function getCloseBatchTransactionInstruction<
     TAccountBatch,
     TAccountTransaction,
     TAccountTransactionRentCollector,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram
   >);
 }
 
@@ -227,6 +245,7 @@ export type ParsedCloseBatchTransactionInstruction<
     /** The rent collector. */
     transactionRentCollector: TAccountMetas[4];
     systemProgram: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: CloseBatchTransactionInstructionData;
 };
@@ -239,7 +258,7 @@ export function parseCloseBatchTransactionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCloseBatchTransactionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -258,6 +277,7 @@ export function parseCloseBatchTransactionInstruction<
       transaction: getNextAccount(),
       transactionRentCollector: getNextAccount(),
       systemProgram: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getCloseBatchTransactionInstructionDataDecoder().decode(
       instruction.data
@@ -50,6 +50,9 @@ export type CloseSettingsTransactionInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountProgram extends
+    | string
+    | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -73,6 +76,9 @@ export type CloseSettingsTransactionInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -116,6 +122,7 @@ export type CloseSettingsTransactionInput<
   TAccountProposalRentCollector extends string = string,
   TAccountTransactionRentCollector extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountProgram extends string = string,
 > = {
   settings: Address<TAccountSettings>;
   /** the logic within `settings_transaction_close` does the rest of the checks. */
@@ -127,6 +134,7 @@ export type CloseSettingsTransactionInput<
   /** The rent collector. */
   transactionRentCollector: Address<TAccountTransactionRentCollector>;
   systemProgram?: Address<TAccountSystemProgram>;
+  program?: Address<TAccountProgram>;
 };
 
 export function getCloseSettingsTransactionInstruction<
@@ -136,6 +144,7 @@ export function getCloseSettingsTransactionInstruction<
   TAccountProposalRentCollector extends string,
   TAccountTransactionRentCollector extends string,
   TAccountSystemProgram extends string,
+  TAccountProgram extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
@@ -145,7 +154,8 @@ export function getCloseSettingsTransactionInstruction<
     TAccountTransaction,
     TAccountProposalRentCollector,
     TAccountTransactionRentCollector,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): CloseSettingsTransactionInstruction<
@@ -155,7 +165,8 @@ export function getCloseSettingsTransactionInstruction<
   TAccountTransaction,
   TAccountProposalRentCollector,
   TAccountTransactionRentCollector,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -175,6 +186,7 @@ export function getCloseSettingsTransactionInstruction<
       isWritable: true,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -186,6 +198,10 @@ export function getCloseSettingsTransactionInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -196,6 +212,7 @@ export function getCloseSettingsTransactionInstruction<
       getAccountMeta(accounts.proposalRentCollector),
       getAccountMeta(accounts.transactionRentCollector),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.program),
     ],
     data: getCloseSettingsTransactionInstructionDataEncoder().encode({}),
     programAddress,
@@ -206,7 +223,8 @@ export function getCloseSettingsTransactionInstruction<
     TAccountTransaction,
     TAccountProposalRentCollector,
     TAccountTransactionRentCollector,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram
   >);
 }
 
@@ -226,6 +244,7 @@ export type ParsedCloseSettingsTransactionInstruction<
     /** The rent collector. */
     transactionRentCollector: TAccountMetas[4];
     systemProgram: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: CloseSettingsTransactionInstructionData;
 };
@@ -238,7 +257,7 @@ export function parseCloseSettingsTransactionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCloseSettingsTransactionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -257,6 +276,7 @@ export function parseCloseSettingsTransactionInstruction<
       proposalRentCollector: getNextAccount(),
       transactionRentCollector: getNextAccount(),
       systemProgram: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getCloseSettingsTransactionInstructionDataDecoder().decode(
       instruction.data
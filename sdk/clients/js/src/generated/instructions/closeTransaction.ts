@@ -50,6 +50,9 @@ export type CloseTransactionInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountProgram extends
+    | string
+    | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -73,6 +76,9 @@ export type CloseTransactionInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -113,6 +119,7 @@ export type CloseTransactionInput<
   TAccountProposalRentCollector extends string = string,
   TAccountTransactionRentCollector extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountProgram extends string = string,
 > = {
   settings: Address<TAccountSettings>;
   /** the logic within `transaction_close` does the rest of the checks. */
@@ -124,6 +131,7 @@ export type CloseTransactionInput<
   /** The rent collector. */
   transactionRentCollector: Address<TAccountTransactionRentCollector>;
   systemProgram?: Address<TAccountSystemProgram>;
+  program?: Address<TAccountProgram>;
 };
 
 export function getCloseTransactionInstruction<
@@ -133,6 +141,7 @@ export function getCloseTransactionInstruction<
   TAccountProposalRentCollector extends string,
   TAccountTransactionRentCollector extends string,
   TAccountSystemProgram extends string,
+  TAccountProgram extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
@@ -142,7 +151,8 @@ export function getCloseTransactionInstruction<
     TAccountTransaction,
     TAccountProposalRentCollector,
     TAccountTransactionRentCollector,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): CloseTransactionInstruction<
@@ -152,7 +162,8 @@ export function getCloseTransactionInstruction<
   TAccountTransaction,
   TAccountProposalRentCollector,
   TAccountTransactionRentCollector,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -172,6 +183,7 @@ export function getCloseTransactionInstruction<
       isWritable: true,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -183,6 +195,10 @@ export function getCloseTransactionInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -193,6 +209,7 @@ export function getCloseTransactionInstruction<
       getAccountMeta(accounts.proposalRentCollector),
       getAccountMeta(accounts.transactionRentCollector),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.program),
     ],
     data: getCloseTransactionInstructionDataEncoder().encode({}),
     programAddress,
@@ -203,7 +220,8 @@ export function getCloseTransactionInstruction<
     TAccountTransaction,
     TAccountProposalRentCollector,
     TAccountTransactionRentCollector,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram
   >);
 }
 
@@ -223,6 +241,7 @@ export type ParsedCloseTransactionInstruction<
     /** The rent collector. */
     transactionRentCollector: TAccountMetas[4];
     systemProgram: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: CloseTransactionInstructionData;
 };
@@ -235,7 +254,7 @@ export function parseCloseTransactionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCloseTransactionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -254,6 +273,7 @@ export function parseCloseTransactionInstruction<
       proposalRentCollector: getNextAccount(),
       transactionRentCollector: getNextAccount(),
       systemProgram: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getCloseTransactionInstructionDataDecoder().decode(instruction.data),
   };
@@ -63,6 +63,10 @@ export type CreateBatchInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountProgram extends
+    | string
+    | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
+  TAccountVaultPolicy extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -85,6 +89,12 @@ export type CreateBatchInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountVaultPolicy extends string
+        ? ReadonlyAccount<TAccountVaultPolicy>
+        : TAccountVaultPolicy,
       ...TRemainingAccounts,
     ]
   >;
@@ -145,6 +155,8 @@ export type CreateBatchInput<
   TAccountCreator extends string = string,
   TAccountFeePayer extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountProgram extends string = string,
+  TAccountVaultPolicy extends string = string,
 > = {
   settings: Address<TAccountSettings>;
   batch: Address<TAccountBatch>;
@@ -153,6 +165,9 @@ export type CreateBatchInput<
   /** The payer for the batch account rent. */
   feePayer: TransactionSigner<TAccountFeePayer>;
   systemProgram?: Address<TAccountSystemProgram>;
+  program?: Address<TAccountProgram>;
+  /** The policy of the smart account at `args.account_index`, required if it has one. */
+  vaultPolicy?: Address<TAccountVaultPolicy>;
   accountIndex: CreateBatchInstructionDataArgs['accountIndex'];
   memo: CreateBatchInstructionDataArgs['memo'];
 };
@@ -163,6 +178,8 @@ export function getCreateBatchInstruction<
   TAccountCreator extends string,
   TAccountFeePayer extends string,
   TAccountSystemProgram extends string,
+  TAccountProgram extends string,
+  TAccountVaultPolicy extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
@@ -171,7 +188,9 @@ export function getCreateBatchInstruction<
     TAccountBatch,
     TAccountCreator,
     TAccountFeePayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram,
+    TAccountVaultPolicy
   >,
   config?: { programAddress?: TProgramAddress }
 ): CreateBatchInstruction<
@@ -180,7 +199,9 @@ export function getCreateBatchInstruction<
   TAccountBatch,
   TAccountCreator,
   TAccountFeePayer,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountProgram,
+  TAccountVaultPolicy
 > {
   // Program address.
   const programAddress =
@@ -193,6 +214,8 @@ export function getCreateBatchInstruction<
     creator: { value: input.creator ?? null, isWritable: false },
     feePayer: { value: input.feePayer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+    vaultPolicy: { value: input.vaultPolicy ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -207,6 +230,10 @@ export function getCreateBatchInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -216,6 +243,8 @@ export function getCreateBatchInstruction<
       getAccountMeta(accounts.creator),
       getAccountMeta(accounts.feePayer),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.vaultPolicy),
     ],
     data: getCreateBatchInstructionDataEncoder().encode(
       args as CreateBatchInstructionDataArgs
@@ -227,7 +256,9 @@ export function getCreateBatchInstruction<
     TAccountBatch,
     TAccountCreator,
     TAccountFeePayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram,
+    TAccountVaultPolicy
   >);
 }
 
@@ -244,6 +275,9 @@ export type ParsedCreateBatchInstruction<
     /** The payer for the batch account rent. */
     feePayer: TAccountMetas[3];
     systemProgram: TAccountMetas[4];
+    program: TAccountMetas[5];
+    /** The policy of the smart account at `args.account_index`, required if it has one. */
+    vaultPolicy?: TAccountMetas[6] | undefined;
   };
   data: CreateBatchInstructionData;
 };
@@ -256,7 +290,7 @@ export function parseCreateBatchInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateBatchInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -266,6 +300,12 @@ export function parseCreateBatchInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -274,6 +314,8 @@ export function parseCreateBatchInstruction<
       creator: getNextAccount(),
       feePayer: getNextAccount(),
       systemProgram: getNextAccount(),
+      program: getNextAccount(),
+      vaultPolicy: getNextOptionalAccount(),
     },
     data: getCreateBatchInstructionDataDecoder().decode(instruction.data),
   };
@@ -14,6 +14,10 @@ import {
   getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -22,12 +26,14 @@ import {
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
@@ -55,6 +61,11 @@ export type CreateProposalInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountProgram extends
+    | string
+    | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
+  TAccountTransaction extends string | AccountMeta<string> = string,
+  TAccountVaultPolicy extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -77,6 +88,15 @@ export type CreateProposalInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountTransaction extends string
+        ? ReadonlyAccount<TAccountTransaction>
+        : TAccountTransaction,
+      TAccountVaultPolicy extends string
+        ? ReadonlyAccount<TAccountVaultPolicy>
+        : TAccountVaultPolicy,
       ...TRemainingAccounts,
     ]
   >;
@@ -87,6 +107,11 @@ export type CreateProposalInstructionData = {
   transactionIndex: bigint;
   /** Whether the proposal should be initialized with status `Draft`. */
   draft: boolean;
+  /**
+   * Unix timestamp after which the proposal can no longer be voted on.
+   * `None` means no deadline.
+   */
+  votingDeadline: Option<bigint>;
 };
 
 export type CreateProposalInstructionDataArgs = {
@@ -94,28 +119,35 @@ export type CreateProposalInstructionDataArgs = {
   transactionIndex: number | bigint;
   /** Whether the proposal should be initialized with status `Draft`. */
   draft: boolean;
+  /**
+   * Unix timestamp after which the proposal can no longer be voted on.
+   * `None` means no deadline.
+   */
+  votingDeadline: OptionOrNullable<number | bigint>;
 };
 
-export function getCreateProposalInstructionDataEncoder(): FixedSizeEncoder<CreateProposalInstructionDataArgs> {
+export function getCreateProposalInstructionDataEncoder(): Encoder<CreateProposalInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 1)],
       ['transactionIndex', getU64Encoder()],
       ['draft', getBooleanEncoder()],
+      ['votingDeadline', getOptionEncoder(getI64Encoder())],
     ]),
     (value) => ({ ...value, discriminator: CREATE_PROPOSAL_DISCRIMINATOR })
   );
 }
 
-export function getCreateProposalInstructionDataDecoder(): FixedSizeDecoder<CreateProposalInstructionData> {
+export function getCreateProposalInstructionDataDecoder(): Decoder<CreateProposalInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 1)],
     ['transactionIndex', getU64Decoder()],
     ['draft', getBooleanDecoder()],
+    ['votingDeadline', getOptionDecoder(getI64Decoder())],
   ]);
 }
 
-export function getCreateProposalInstructionDataCodec(): FixedSizeCodec<
+export function getCreateProposalInstructionDataCodec(): Codec<
   CreateProposalInstructionDataArgs,
   CreateProposalInstructionData
 > {
@@ -131,6 +163,9 @@ export type CreateProposalInput<
   TAccountCreator extends string = string,
   TAccountFeePayer extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountProgram extends string = string,
+  TAccountTransaction extends string = string,
+  TAccountVaultPolicy extends string = string,
 > = {
   settings: Address<TAccountSettings>;
   proposal: Address<TAccountProposal>;
@@ -139,8 +174,17 @@ export type CreateProposalInput<
   /** The payer for the proposal account rent. */
   feePayer: TransactionSigner<TAccountFeePayer>;
   systemProgram?: Address<TAccountSystemProgram>;
+  program?: Address<TAccountProgram>;
+  /**
+   * The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+   * Only required if the settings have a `VaultPolicy`.
+   */
+  transaction?: Address<TAccountTransaction>;
+  /** The policy of the smart account the transaction is for, required if it has one. */
+  vaultPolicy?: Address<TAccountVaultPolicy>;
   transactionIndex: CreateProposalInstructionDataArgs['transactionIndex'];
   draft: CreateProposalInstructionDataArgs['draft'];
+  votingDeadline: CreateProposalInstructionDataArgs['votingDeadline'];
 };
 
 export function getCreateProposalInstruction<
@@ -149,6 +193,9 @@ export function getCreateProposalInstruction<
   TAccountCreator extends string,
   TAccountFeePayer extends string,
   TAccountSystemProgram extends string,
+  TAccountProgram extends string,
+  TAccountTransaction extends string,
+  TAccountVaultPolicy extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
@@ -157,7 +204,10 @@ export function getCreateProposalInstruction<
     TAccountProposal,
     TAccountCreator,
     TAccountFeePayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram,
+    TAccountTransaction,
+    TAccountVaultPolicy
   >,
   config?: { programAddress?: TProgramAddress }
 ): CreateProposalInstruction<
@@ -166,7 +216,10 @@ export function getCreateProposalInstruction<
   TAccountProposal,
   TAccountCreator,
   TAccountFeePayer,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountProgram,
+  TAccountTransaction,
+  TAccountVaultPolicy
 > {
   // Program address.
   const programAddress =
@@ -179,6 +232,9 @@ export function getCreateProposalInstruction<
     creator: { value: input.creator ?? null, isWritable: false },
     feePayer: { value: input.feePayer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+    transaction: { value: input.transaction ?? null, isWritable: false },
+    vaultPolicy: { value: input.vaultPolicy ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -193,6 +249,10 @@ export function getCreateProposalInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -202,6 +262,9 @@ export function getCreateProposalInstruction<
       getAccountMeta(accounts.creator),
       getAccountMeta(accounts.feePayer),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.transaction),
+      getAccountMeta(accounts.vaultPolicy),
     ],
     data: getCreateProposalInstructionDataEncoder().encode(
       args as CreateProposalInstructionDataArgs
@@ -213,7 +276,10 @@ export function getCreateProposalInstruction<
     TAccountProposal,
     TAccountCreator,
     TAccountFeePayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram,
+    TAccountTransaction,
+    TAccountVaultPolicy
   >);
 }
 
@@ -230,6 +296,14 @@ export type ParsedCreateProposalInstruction<
     /** The payer for the proposal account rent. */
     feePayer: TAccountMetas[3];
     systemProgram: TAccountMetas[4];
+    program: TAccountMetas[5];
+    /**
+     * The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+     * Only required if the settings have a `VaultPolicy`.
+     */
+    transaction?: TAccountMetas[6] | undefined;
+    /** The policy of the smart account the transaction is for, required if it has one. */
+    vaultPolicy?: TAccountMetas[7] | undefined;
   };
   data: CreateProposalInstructionData;
 };
@@ -242,7 +316,7 @@ export function parseCreateProposalInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateProposalInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -252,6 +326,12 @@ export function parseCreateProposalInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -260,6 +340,9 @@ export function parseCreateProposalInstruction<
       creator: getNextAccount(),
       feePayer: getNextAccount(),
       systemProgram: getNextAccount(),
+      program: getNextAccount(),
+      transaction: getNextOptionalAccount(),
+      vaultPolicy: getNextOptionalAccount(),
     },
     data: getCreateProposalInstructionDataDecoder().decode(instruction.data),
   };
@@ -7,23 +7,13 @@
  */
 
 import {
-  addDecoderSizePrefix,
-  addEncoderSizePrefix,
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
-  getArrayDecoder,
-  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
-  getOptionDecoder,
-  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
-  getU32Decoder,
-  getU32Encoder,
-  getUtf8Decoder,
-  getUtf8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -34,8 +24,6 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
-  type Option,
-  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
@@ -46,10 +34,10 @@ import {
 import { ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 import {
-  getSettingsActionDecoder,
-  getSettingsActionEncoder,
-  type SettingsAction,
-  type SettingsActionArgs,
+  getCreateSettingsTransactionArgsDecoder,
+  getCreateSettingsTransactionArgsEncoder,
+  type CreateSettingsTransactionArgs,
+  type CreateSettingsTransactionArgsArgs,
 } from '../types';
 
 export const CREATE_SETTINGS_TRANSACTION_DISCRIMINATOR = new Uint8Array([12]);
@@ -69,6 +57,9 @@ export type CreateSettingsTransactionInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountProgram extends
+    | string
+    | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -91,32 +82,27 @@ export type CreateSettingsTransactionInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
 
 export type CreateSettingsTransactionInstructionData = {
   discriminator: ReadonlyUint8Array;
-  actions: Array<SettingsAction>;
-  memo: Option<string>;
+  args: CreateSettingsTransactionArgs;
 };
 
 export type CreateSettingsTransactionInstructionDataArgs = {
-  actions: Array<SettingsActionArgs>;
-  memo: OptionOrNullable<string>;
+  args: CreateSettingsTransactionArgsArgs;
 };
 
 export function getCreateSettingsTransactionInstructionDataEncoder(): Encoder<CreateSettingsTransactionInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 1)],
-      ['actions', getArrayEncoder(getSettingsActionEncoder())],
-      [
-        'memo',
-        getOptionEncoder(
-          addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
-        ),
-      ],
+      ['args', getCreateSettingsTransactionArgsEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -128,11 +114,7 @@ export function getCreateSettingsTransactionInstructionDataEncoder(): Encoder<Cr
 export function getCreateSettingsTransactionInstructionDataDecoder(): Decoder<CreateSettingsTransactionInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 1)],
-    ['actions', getArrayDecoder(getSettingsActionDecoder())],
-    [
-      'memo',
-      getOptionDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())),
-    ],
+    ['args', getCreateSettingsTransactionArgsDecoder()],
   ]);
 }
 
@@ -152,6 +134,7 @@ export type CreateSettingsTransactionInput<
   TAccountCreator extends string = string,
   TAccountFeePayer extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountProgram extends string = string,
 > = {
   settings: Address<TAccountSettings>;
   transaction: Address<TAccountTransaction>;
@@ -160,8 +143,8 @@ export type CreateSettingsTransactionInput<
   /** The payer for the transaction account rent. */
   feePayer: TransactionSigner<TAccountFeePayer>;
   systemProgram?: Address<TAccountSystemProgram>;
-  actions: CreateSettingsTransactionInstructionDataArgs['actions'];
-  memo: CreateSettingsTransactionInstructionDataArgs['memo'];
+  program?: Address<TAccountProgram>;
+  args: CreateSettingsTransactionInstructionDataArgs['args'];
 };
 
 export function getCreateSettingsTransactionInstruction<
@@ -170,6 +153,7 @@ export function getCreateSettingsTransactionInstruction<
   TAccountCreator extends string,
   TAccountFeePayer extends string,
   TAccountSystemProgram extends string,
+  TAccountProgram extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
@@ -178,7 +162,8 @@ export function getCreateSettingsTransactionInstruction<
     TAccountTransaction,
     TAccountCreator,
     TAccountFeePayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): CreateSettingsTransactionInstruction<
@@ -187,7 +172,8 @@ export function getCreateSettingsTransactionInstruction<
   TAccountTransaction,
   TAccountCreator,
   TAccountFeePayer,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -200,6 +186,7 @@ export function getCreateSettingsTransactionInstruction<
     creator: { value: input.creator ?? null, isWritable: false },
     feePayer: { value: input.feePayer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -214,6 +201,10 @@ export function getCreateSettingsTransactionInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -223,6 +214,7 @@ export function getCreateSettingsTransactionInstruction<
       getAccountMeta(accounts.creator),
       getAccountMeta(accounts.feePayer),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.program),
     ],
     data: getCreateSettingsTransactionInstructionDataEncoder().encode(
       args as CreateSettingsTransactionInstructionDataArgs
@@ -234,7 +226,8 @@ export function getCreateSettingsTransactionInstruction<
     TAccountTransaction,
     TAccountCreator,
     TAccountFeePayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram
   >);
 }
 
@@ -251,6 +244,7 @@ export type ParsedCreateSettingsTransactionInstruction<
     /** The payer for the transaction account rent. */
     feePayer: TAccountMetas[3];
     systemProgram: TAccountMetas[4];
+    program: TAccountMetas[5];
   };
   data: CreateSettingsTransactionInstructionData;
 };
@@ -263,7 +257,7 @@ export function parseCreateSettingsTransactionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateSettingsTransactionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -281,6 +275,7 @@ export function parseCreateSettingsTransactionInstruction<
       creator: getNextAccount(),
       feePayer: getNextAccount(),
       systemProgram: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getCreateSettingsTransactionInstructionDataDecoder().decode(
       instruction.data
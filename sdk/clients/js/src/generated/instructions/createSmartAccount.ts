@@ -82,6 +82,16 @@ export type CreateSmartAccountInstruction<
   TAccountProgram extends
     | string
     | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
+  TAccountReferrerAccount extends string | AccountMeta<string> = string,
+  TAccountCreationFeeMint extends string | AccountMeta<string> = string,
+  TAccountFeePayerTokenAccount extends string | AccountMeta<string> = string,
+  TAccountTreasuryTokenAccount extends string | AccountMeta<string> = string,
+  TAccountReferrerTokenAccount extends string | AccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountCreatorAllowlist extends string | AccountMeta<string> = string,
+  TAccountProgramConfigAuthority extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -110,6 +120,31 @@ export type CreateSmartAccountInstruction<
       TAccountProgram extends string
         ? ReadonlyAccount<TAccountProgram>
         : TAccountProgram,
+      TAccountReferrerAccount extends string
+        ? WritableAccount<TAccountReferrerAccount>
+        : TAccountReferrerAccount,
+      TAccountCreationFeeMint extends string
+        ? ReadonlyAccount<TAccountCreationFeeMint>
+        : TAccountCreationFeeMint,
+      TAccountFeePayerTokenAccount extends string
+        ? WritableAccount<TAccountFeePayerTokenAccount>
+        : TAccountFeePayerTokenAccount,
+      TAccountTreasuryTokenAccount extends string
+        ? WritableAccount<TAccountTreasuryTokenAccount>
+        : TAccountTreasuryTokenAccount,
+      TAccountReferrerTokenAccount extends string
+        ? WritableAccount<TAccountReferrerTokenAccount>
+        : TAccountReferrerTokenAccount,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountCreatorAllowlist extends string
+        ? ReadonlyAccount<TAccountCreatorAllowlist>
+        : TAccountCreatorAllowlist,
+      TAccountProgramConfigAuthority extends string
+        ? ReadonlySignerAccount<TAccountProgramConfigAuthority> &
+            AccountSignerMeta<TAccountProgramConfigAuthority>
+        : TAccountProgramConfigAuthority,
       ...TRemainingAccounts,
     ]
   >;
@@ -136,6 +171,11 @@ export type CreateSmartAccountInstructionData = {
   rentCollector: Option<Address>;
   /** Memo is used for indexing only. */
   memo: Option<string>;
+  /**
+   * The partner that referred the creation of the smart account, who gets the
+   * `referral_fee_bps` share of the creation fees.
+   */
+  referrer: Option<Address>;
 };
 
 export type CreateSmartAccountInstructionDataArgs = {
@@ -159,6 +199,11 @@ export type CreateSmartAccountInstructionDataArgs = {
   rentCollector: OptionOrNullable<Address>;
   /** Memo is used for indexing only. */
   memo: OptionOrNullable<string>;
+  /**
+   * The partner that referred the creation of the smart account, who gets the
+   * `referral_fee_bps` share of the creation fees.
+   */
+  referrer: OptionOrNullable<Address>;
 };
 
 export function getCreateSmartAccountInstructionDataEncoder(): Encoder<CreateSmartAccountInstructionDataArgs> {
@@ -180,6 +225,7 @@ export function getCreateSmartAccountInstructionDataEncoder(): Encoder<CreateSma
           addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
         ),
       ],
+      ['referrer', getOptionEncoder(getAddressEncoder())],
     ]),
     (value) => ({ ...value, discriminator: CREATE_SMART_ACCOUNT_DISCRIMINATOR })
   );
@@ -201,6 +247,7 @@ export function getCreateSmartAccountInstructionDataDecoder(): Decoder<CreateSma
       'memo',
       getOptionDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())),
     ],
+    ['referrer', getOptionDecoder(getAddressDecoder())],
   ]);
 }
 
@@ -222,6 +269,14 @@ export type CreateSmartAccountAsyncInput<
   TAccountFeePayer extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountProgram extends string = string,
+  TAccountReferrerAccount extends string = string,
+  TAccountCreationFeeMint extends string = string,
+  TAccountFeePayerTokenAccount extends string = string,
+  TAccountTreasuryTokenAccount extends string = string,
+  TAccountReferrerTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountCreatorAllowlist extends string = string,
+  TAccountProgramConfigAuthority extends string = string,
 > = {
   /** Global program config account. */
   programConfig?: Address<TAccountProgramConfig>;
@@ -235,6 +290,25 @@ export type CreateSmartAccountAsyncInput<
   feePayer: TransactionSigner<TAccountFeePayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   program?: Address<TAccountProgram>;
+  /** The referrer the referral share of the creation fee is transferred to. */
+  referrerAccount?: Address<TAccountReferrerAccount>;
+  /** The mint of the creation token fee in case `program_config.smart_account_creation_token_fee` is set. */
+  creationFeeMint?: Address<TAccountCreationFeeMint>;
+  /** The token account the creation token fee is paid from. */
+  feePayerTokenAccount?: Address<TAccountFeePayerTokenAccount>;
+  /** The treasury token account the creation token fee is transferred to. */
+  treasuryTokenAccount?: Address<TAccountTreasuryTokenAccount>;
+  /**
+   * The referrer token account the referral share of the creation token fee is transferred to.
+   * Its owner is validated in the `CreateSmartAccount::validate()` method.
+   */
+  referrerTokenAccount?: Address<TAccountReferrerTokenAccount>;
+  /** In case `program_config.smart_account_creation_token_fee` is set. */
+  tokenProgram?: Address<TAccountTokenProgram>;
+  /** The allowlist page the `creator` is on, in case `program_config.smart_account_creation_mode` is `Allowlist`. */
+  creatorAllowlist?: Address<TAccountCreatorAllowlist>;
+  /** The program config authority, in case `program_config.smart_account_creation_mode` is `Cosigned`. */
+  programConfigAuthority?: TransactionSigner<TAccountProgramConfigAuthority>;
   settingsAuthority: CreateSmartAccountInstructionDataArgs['settingsAuthority'];
   threshold: CreateSmartAccountInstructionDataArgs['threshold'];
   signers: CreateSmartAccountInstructionDataArgs['signers'];
@@ -242,6 +316,7 @@ export type CreateSmartAccountAsyncInput<
   timeLock: CreateSmartAccountInstructionDataArgs['timeLock'];
   rentCollector: CreateSmartAccountInstructionDataArgs['rentCollector'];
   memo: CreateSmartAccountInstructionDataArgs['memo'];
+  referrer: CreateSmartAccountInstructionDataArgs['referrer'];
 };
 
 export async function getCreateSmartAccountInstructionAsync<
@@ -252,6 +327,14 @@ export async function getCreateSmartAccountInstructionAsync<
   TAccountFeePayer extends string,
   TAccountSystemProgram extends string,
   TAccountProgram extends string,
+  TAccountReferrerAccount extends string,
+  TAccountCreationFeeMint extends string,
+  TAccountFeePayerTokenAccount extends string,
+  TAccountTreasuryTokenAccount extends string,
+  TAccountReferrerTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountCreatorAllowlist extends string,
+  TAccountProgramConfigAuthority extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
@@ -262,7 +345,15 @@ export async function getCreateSmartAccountInstructionAsync<
     TAccountCreator,
     TAccountFeePayer,
     TAccountSystemProgram,
-    TAccountProgram
+    TAccountProgram,
+    TAccountReferrerAccount,
+    TAccountCreationFeeMint,
+    TAccountFeePayerTokenAccount,
+    TAccountTreasuryTokenAccount,
+    TAccountReferrerTokenAccount,
+    TAccountTokenProgram,
+    TAccountCreatorAllowlist,
+    TAccountProgramConfigAuthority
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -274,7 +365,15 @@ export async function getCreateSmartAccountInstructionAsync<
     TAccountCreator,
     TAccountFeePayer,
     TAccountSystemProgram,
-    TAccountProgram
+    TAccountProgram,
+    TAccountReferrerAccount,
+    TAccountCreationFeeMint,
+    TAccountFeePayerTokenAccount,
+    TAccountTreasuryTokenAccount,
+    TAccountReferrerTokenAccount,
+    TAccountTokenProgram,
+    TAccountCreatorAllowlist,
+    TAccountProgramConfigAuthority
   >
 > {
   // Program address.
@@ -290,6 +389,32 @@ export async function getCreateSmartAccountInstructionAsync<
     feePayer: { value: input.feePayer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     program: { value: input.program ?? null, isWritable: false },
+    referrerAccount: { value: input.referrerAccount ?? null, isWritable: true },
+    creationFeeMint: {
+      value: input.creationFeeMint ?? null,
+      isWritable: false,
+    },
+    feePayerTokenAccount: {
+      value: input.feePayerTokenAccount ?? null,
+      isWritable: true,
+    },
+    treasuryTokenAccount: {
+      value: input.treasuryTokenAccount ?? null,
+      isWritable: true,
+    },
+    referrerTokenAccount: {
+      value: input.referrerTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    creatorAllowlist: {
+      value: input.creatorAllowlist ?? null,
+      isWritable: false,
+    },
+    programConfigAuthority: {
+      value: input.programConfigAuthority ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -325,6 +450,10 @@ export async function getCreateSmartAccountInstructionAsync<
     accounts.program.value =
       'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
   }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -336,6 +465,14 @@ export async function getCreateSmartAccountInstructionAsync<
       getAccountMeta(accounts.feePayer),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.program),
+      getAccountMeta(accounts.referrerAccount),
+      getAccountMeta(accounts.creationFeeMint),
+      getAccountMeta(accounts.feePayerTokenAccount),
+      getAccountMeta(accounts.treasuryTokenAccount),
+      getAccountMeta(accounts.referrerTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.creatorAllowlist),
+      getAccountMeta(accounts.programConfigAuthority),
     ],
     data: getCreateSmartAccountInstructionDataEncoder().encode(
       args as CreateSmartAccountInstructionDataArgs
@@ -349,7 +486,15 @@ export async function getCreateSmartAccountInstructionAsync<
     TAccountCreator,
     TAccountFeePayer,
     TAccountSystemProgram,
-    TAccountProgram
+    TAccountProgram,
+    TAccountReferrerAccount,
+    TAccountCreationFeeMint,
+    TAccountFeePayerTokenAccount,
+    TAccountTreasuryTokenAccount,
+    TAccountReferrerTokenAccount,
+    TAccountTokenProgram,
+    TAccountCreatorAllowlist,
+    TAccountProgramConfigAuthority
   >);
 }
 
@@ -361,6 +506,14 @@ export type CreateSmartAccountInput<
   TAccountFeePayer extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountProgram extends string = string,
+  TAccountReferrerAccount extends string = string,
+  TAccountCreationFeeMint extends string = string,
+  TAccountFeePayerTokenAccount extends string = string,
+  TAccountTreasuryTokenAccount extends string = string,
+  TAccountReferrerTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountCreatorAllowlist extends string = string,
+  TAccountProgramConfigAuthority extends string = string,
 > = {
   /** Global program config account. */
   programConfig: Address<TAccountProgramConfig>;
@@ -374,6 +527,25 @@ export type CreateSmartAccountInput<
   feePayer: TransactionSigner<TAccountFeePayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   program?: Address<TAccountProgram>;
+  /** The referrer the referral share of the creation fee is transferred to. */
+  referrerAccount?: Address<TAccountReferrerAccount>;
+  /** The mint of the creation token fee in case `program_config.smart_account_creation_token_fee` is set. */
+  creationFeeMint?: Address<TAccountCreationFeeMint>;
+  /** The token account the creation token fee is paid from. */
+  feePayerTokenAccount?: Address<TAccountFeePayerTokenAccount>;
+  /** The treasury token account the creation token fee is transferred to. */
+  treasuryTokenAccount?: Address<TAccountTreasuryTokenAccount>;
+  /**
+   * The referrer token account the referral share of the creation token fee is transferred to.
+   * Its owner is validated in the `CreateSmartAccount::validate()` method.
+   */
+  referrerTokenAccount?: Address<TAccountReferrerTokenAccount>;
+  /** In case `program_config.smart_account_creation_token_fee` is set. */
+  tokenProgram?: Address<TAccountTokenProgram>;
+  /** The allowlist page the `creator` is on, in case `program_config.smart_account_creation_mode` is `Allowlist`. */
+  creatorAllowlist?: Address<TAccountCreatorAllowlist>;
+  /** The program config authority, in case `program_config.smart_account_creation_mode` is `Cosigned`. */
+  programConfigAuthority?: TransactionSigner<TAccountProgramConfigAuthority>;
   settingsAuthority: CreateSmartAccountInstructionDataArgs['settingsAuthority'];
   threshold: CreateSmartAccountInstructionDataArgs['threshold'];
   signers: CreateSmartAccountInstructionDataArgs['signers'];
@@ -381,6 +553,7 @@ export type CreateSmartAccountInput<
   timeLock: CreateSmartAccountInstructionDataArgs['timeLock'];
   rentCollector: CreateSmartAccountInstructionDataArgs['rentCollector'];
   memo: CreateSmartAccountInstructionDataArgs['memo'];
+  referrer: CreateSmartAccountInstructionDataArgs['referrer'];
 };
 
 export function getCreateSmartAccountInstruction<
@@ -391,6 +564,14 @@ export function getCreateSmartAccountInstruction<
   TAccountFeePayer extends string,
   TAccountSystemProgram extends string,
   TAccountProgram extends string,
+  TAccountReferrerAccount extends string,
+  TAccountCreationFeeMint extends string,
+  TAccountFeePayerTokenAccount extends string,
+  TAccountTreasuryTokenAccount extends string,
+  TAccountReferrerTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountCreatorAllowlist extends string,
+  TAccountProgramConfigAuthority extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
@@ -401,7 +582,15 @@ export function getCreateSmartAccountInstruction<
     TAccountCreator,
     TAccountFeePayer,
     TAccountSystemProgram,
-    TAccountProgram
+    TAccountProgram,
+    TAccountReferrerAccount,
+    TAccountCreationFeeMint,
+    TAccountFeePayerTokenAccount,
+    TAccountTreasuryTokenAccount,
+    TAccountReferrerTokenAccount,
+    TAccountTokenProgram,
+    TAccountCreatorAllowlist,
+    TAccountProgramConfigAuthority
   >,
   config?: { programAddress?: TProgramAddress }
 ): CreateSmartAccountInstruction<
@@ -412,7 +601,15 @@ export function getCreateSmartAccountInstruction<
   TAccountCreator,
   TAccountFeePayer,
   TAccountSystemProgram,
-  TAccountProgram
+  TAccountProgram,
+  TAccountReferrerAccount,
+  TAccountCreationFeeMint,
+  TAccountFeePayerTokenAccount,
+  TAccountTreasuryTokenAccount,
+  TAccountReferrerTokenAccount,
+  TAccountTokenProgram,
+  TAccountCreatorAllowlist,
+  TAccountProgramConfigAuthority
 > {
   // Program address.
   const programAddress =
@@ -427,6 +624,32 @@ export function getCreateSmartAccountInstruction<
     feePayer: { value: input.feePayer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     program: { value: input.program ?? null, isWritable: false },
+    referrerAccount: { value: input.referrerAccount ?? null, isWritable: true },
+    creationFeeMint: {
+      value: input.creationFeeMint ?? null,
+      isWritable: false,
+    },
+    feePayerTokenAccount: {
+      value: input.feePayerTokenAccount ?? null,
+      isWritable: true,
+    },
+    treasuryTokenAccount: {
+      value: input.treasuryTokenAccount ?? null,
+      isWritable: true,
+    },
+    referrerTokenAccount: {
+      value: input.referrerTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    creatorAllowlist: {
+      value: input.creatorAllowlist ?? null,
+      isWritable: false,
+    },
+    programConfigAuthority: {
+      value: input.programConfigAuthority ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -445,6 +668,10 @@ export function getCreateSmartAccountInstruction<
     accounts.program.value =
       'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
   }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -456,6 +683,14 @@ export function getCreateSmartAccountInstruction<
       getAccountMeta(accounts.feePayer),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.program),
+      getAccountMeta(accounts.referrerAccount),
+      getAccountMeta(accounts.creationFeeMint),
+      getAccountMeta(accounts.feePayerTokenAccount),
+      getAccountMeta(accounts.treasuryTokenAccount),
+      getAccountMeta(accounts.referrerTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.creatorAllowlist),
+      getAccountMeta(accounts.programConfigAuthority),
     ],
     data: getCreateSmartAccountInstructionDataEncoder().encode(
       args as CreateSmartAccountInstructionDataArgs
@@ -469,7 +704,15 @@ export function getCreateSmartAccountInstruction<
     TAccountCreator,
     TAccountFeePayer,
     TAccountSystemProgram,
-    TAccountProgram
+    TAccountProgram,
+    TAccountReferrerAccount,
+    TAccountCreationFeeMint,
+    TAccountFeePayerTokenAccount,
+    TAccountTreasuryTokenAccount,
+    TAccountReferrerTokenAccount,
+    TAccountTokenProgram,
+    TAccountCreatorAllowlist,
+    TAccountProgramConfigAuthority
   >);
 }
 
@@ -491,6 +734,25 @@ export type ParsedCreateSmartAccountInstruction<
     feePayer: TAccountMetas[4];
     systemProgram: TAccountMetas[5];
     program: TAccountMetas[6];
+    /** The referrer the referral share of the creation fee is transferred to. */
+    referrerAccount?: TAccountMetas[7] | undefined;
+    /** The mint of the creation token fee in case `program_config.smart_account_creation_token_fee` is set. */
+    creationFeeMint?: TAccountMetas[8] | undefined;
+    /** The token account the creation token fee is paid from. */
+    feePayerTokenAccount?: TAccountMetas[9] | undefined;
+    /** The treasury token account the creation token fee is transferred to. */
+    treasuryTokenAccount?: TAccountMetas[10] | undefined;
+    /**
+     * The referrer token account the referral share of the creation token fee is transferred to.
+     * Its owner is validated in the `CreateSmartAccount::validate()` method.
+     */
+    referrerTokenAccount?: TAccountMetas[11] | undefined;
+    /** In case `program_config.smart_account_creation_token_fee` is set. */
+    tokenProgram?: TAccountMetas[12] | undefined;
+    /** The allowlist page the `creator` is on, in case `program_config.smart_account_creation_mode` is `Allowlist`. */
+    creatorAllowlist?: TAccountMetas[13] | undefined;
+    /** The program config authority, in case `program_config.smart_account_creation_mode` is `Cosigned`. */
+    programConfigAuthority?: TAccountMetas[14] | undefined;
   };
   data: CreateSmartAccountInstructionData;
 };
@@ -503,7 +765,7 @@ export function parseCreateSmartAccountInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateSmartAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 15) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -513,6 +775,12 @@ export function parseCreateSmartAccountInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -523,6 +791,14 @@ export function parseCreateSmartAccountInstruction<
       feePayer: getNextAccount(),
       systemProgram: getNextAccount(),
       program: getNextAccount(),
+      referrerAccount: getNextOptionalAccount(),
+      creationFeeMint: getNextOptionalAccount(),
+      feePayerTokenAccount: getNextOptionalAccount(),
+      treasuryTokenAccount: getNextOptionalAccount(),
+      referrerTokenAccount: getNextOptionalAccount(),
+      tokenProgram: getNextOptionalAccount(),
+      creatorAllowlist: getNextOptionalAccount(),
+      programConfigAuthority: getNextOptionalAccount(),
     },
     data: getCreateSmartAccountInstructionDataDecoder().decode(
       instruction.data
@@ -57,6 +57,10 @@ export type CreateTransactionInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountProgram extends
+    | string
+    | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
+  TAccountVaultPolicy extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -79,6 +83,12 @@ export type CreateTransactionInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountVaultPolicy extends string
+        ? ReadonlyAccount<TAccountVaultPolicy>
+        : TAccountVaultPolicy,
       ...TRemainingAccounts,
     ]
   >;
@@ -125,6 +135,8 @@ export type CreateTransactionInput<
   TAccountCreator extends string = string,
   TAccountFeePayer extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountProgram extends string = string,
+  TAccountVaultPolicy extends string = string,
 > = {
   settings: Address<TAccountSettings>;
   transaction: Address<TAccountTransaction>;
@@ -133,6 +145,9 @@ export type CreateTransactionInput<
   /** The payer for the transaction account rent. */
   feePayer: TransactionSigner<TAccountFeePayer>;
   systemProgram?: Address<TAccountSystemProgram>;
+  program?: Address<TAccountProgram>;
+  /** The policy of the smart account at `args.account_index`, required if it has one. */
+  vaultPolicy?: Address<TAccountVaultPolicy>;
   args: CreateTransactionInstructionDataArgs['args'];
 };
 
@@ -142,6 +157,8 @@ export function getCreateTransactionInstruction<
   TAccountCreator extends string,
   TAccountFeePayer extends string,
   TAccountSystemProgram extends string,
+  TAccountProgram extends string,
+  TAccountVaultPolicy extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
@@ -150,7 +167,9 @@ export function getCreateTransactionInstruction<
     TAccountTransaction,
     TAccountCreator,
     TAccountFeePayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram,
+    TAccountVaultPolicy
   >,
   config?: { programAddress?: TProgramAddress }
 ): CreateTransactionInstruction<
@@ -159,7 +178,9 @@ export function getCreateTransactionInstruction<
   TAccountTransaction,
   TAccountCreator,
   TAccountFeePayer,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountProgram,
+  TAccountVaultPolicy
 > {
   // Program address.
   const programAddress =
@@ -172,6 +193,8 @@ export function getCreateTransactionInstruction<
     creator: { value: input.creator ?? null, isWritable: false },
     feePayer: { value: input.feePayer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+    vaultPolicy: { value: input.vaultPolicy ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -186,6 +209,10 @@ export function getCreateTransactionInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -195,6 +222,8 @@ export function getCreateTransactionInstruction<
       getAccountMeta(accounts.creator),
       getAccountMeta(accounts.feePayer),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.vaultPolicy),
     ],
     data: getCreateTransactionInstructionDataEncoder().encode(
       args as CreateTransactionInstructionDataArgs
@@ -206,7 +235,9 @@ export function getCreateTransactionInstruction<
     TAccountTransaction,
     TAccountCreator,
     TAccountFeePayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram,
+    TAccountVaultPolicy
   >);
 }
 
@@ -223,6 +254,9 @@ export type ParsedCreateTransactionInstruction<
     /** The payer for the transaction account rent. */
     feePayer: TAccountMetas[3];
     systemProgram: TAccountMetas[4];
+    program: TAccountMetas[5];
+    /** The policy of the smart account at `args.account_index`, required if it has one. */
+    vaultPolicy?: TAccountMetas[6] | undefined;
   };
   data: CreateTransactionInstructionData;
 };
@@ -235,7 +269,7 @@ export function parseCreateTransactionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateTransactionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -245,6 +279,12 @@ export function parseCreateTransactionInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -253,6 +293,8 @@ export function parseCreateTransactionInstruction<
       creator: getNextAccount(),
       feePayer: getNextAccount(),
       systemProgram: getNextAccount(),
+      program: getNextAccount(),
+      vaultPolicy: getNextOptionalAccount(),
     },
     data: getCreateTransactionInstructionDataDecoder().decode(instruction.data),
   };
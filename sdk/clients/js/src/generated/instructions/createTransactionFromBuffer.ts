@@ -59,6 +59,10 @@ export type CreateTransactionFromBufferInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountProgram extends
+    | string
+    | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
+  TAccountVaultPolicy extends string | AccountMeta<string> = string,
   TAccountTransactionBuffer extends string | AccountMeta<string> = string,
   TAccountFromBufferCreator extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
@@ -83,6 +87,12 @@ export type CreateTransactionFromBufferInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountVaultPolicy extends string
+        ? ReadonlyAccount<TAccountVaultPolicy>
+        : TAccountVaultPolicy,
       TAccountTransactionBuffer extends string
         ? WritableAccount<TAccountTransactionBuffer>
         : TAccountTransactionBuffer,
@@ -139,6 +149,8 @@ export type CreateTransactionFromBufferInput<
   TAccountCreator extends string = string,
   TAccountFeePayer extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountProgram extends string = string,
+  TAccountVaultPolicy extends string = string,
   TAccountTransactionBuffer extends string = string,
   TAccountFromBufferCreator extends string = string,
 > = {
@@ -149,6 +161,9 @@ export type CreateTransactionFromBufferInput<
   /** The payer for the transaction account rent. */
   feePayer: TransactionSigner<TAccountFeePayer>;
   systemProgram?: Address<TAccountSystemProgram>;
+  program?: Address<TAccountProgram>;
+  /** The policy of the smart account at `args.account_index`, required if it has one. */
+  vaultPolicy?: Address<TAccountVaultPolicy>;
   transactionBuffer: Address<TAccountTransactionBuffer>;
   fromBufferCreator: TransactionSigner<TAccountFromBufferCreator>;
   args: CreateTransactionFromBufferInstructionDataArgs['args'];
@@ -160,6 +175,8 @@ export function getCreateTransactionFromBufferInstruction<
   TAccountCreator extends string,
   TAccountFeePayer extends string,
   TAccountSystemProgram extends string,
+  TAccountProgram extends string,
+  TAccountVaultPolicy extends string,
   TAccountTransactionBuffer extends string,
   TAccountFromBufferCreator extends string,
   TProgramAddress extends
@@ -171,6 +188,8 @@ export function getCreateTransactionFromBufferInstruction<
     TAccountCreator,
     TAccountFeePayer,
     TAccountSystemProgram,
+    TAccountProgram,
+    TAccountVaultPolicy,
     TAccountTransactionBuffer,
     TAccountFromBufferCreator
   >,
@@ -182,6 +201,8 @@ export function getCreateTransactionFromBufferInstruction<
   TAccountCreator,
   TAccountFeePayer,
   TAccountSystemProgram,
+  TAccountProgram,
+  TAccountVaultPolicy,
   TAccountTransactionBuffer,
   TAccountFromBufferCreator
 > {
@@ -196,6 +217,8 @@ export function getCreateTransactionFromBufferInstruction<
     creator: { value: input.creator ?? null, isWritable: false },
     feePayer: { value: input.feePayer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+    vaultPolicy: { value: input.vaultPolicy ?? null, isWritable: false },
     transactionBuffer: {
       value: input.transactionBuffer ?? null,
       isWritable: true,
@@ -218,6 +241,10 @@ export function getCreateTransactionFromBufferInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -227,6 +254,8 @@ export function getCreateTransactionFromBufferInstruction<
       getAccountMeta(accounts.creator),
       getAccountMeta(accounts.feePayer),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.vaultPolicy),
       getAccountMeta(accounts.transactionBuffer),
       getAccountMeta(accounts.fromBufferCreator),
     ],
@@ -241,6 +270,8 @@ export function getCreateTransactionFromBufferInstruction<
     TAccountCreator,
     TAccountFeePayer,
     TAccountSystemProgram,
+    TAccountProgram,
+    TAccountVaultPolicy,
     TAccountTransactionBuffer,
     TAccountFromBufferCreator
   >);
@@ -259,8 +290,11 @@ export type ParsedCreateTransactionFromBufferInstruction<
     /** The payer for the transaction account rent. */
     feePayer: TAccountMetas[3];
     systemProgram: TAccountMetas[4];
-    transactionBuffer: TAccountMetas[5];
-    fromBufferCreator: TAccountMetas[6];
+    program: TAccountMetas[5];
+    /** The policy of the smart account at `args.account_index`, required if it has one. */
+    vaultPolicy?: TAccountMetas[6] | undefined;
+    transactionBuffer: TAccountMetas[7];
+    fromBufferCreator: TAccountMetas[8];
   };
   data: CreateTransactionFromBufferInstructionData;
 };
@@ -273,7 +307,7 @@ export function parseCreateTransactionFromBufferInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateTransactionFromBufferInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -283,6 +317,12 @@ export function parseCreateTransactionFromBufferInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -291,6 +331,8 @@ export function parseCreateTransactionFromBufferInstruction<
       creator: getNextAccount(),
       feePayer: getNextAccount(),
       systemProgram: getNextAccount(),
+      program: getNextAccount(),
+      vaultPolicy: getNextOptionalAccount(),
       transactionBuffer: getNextAccount(),
       fromBufferCreator: getNextAccount(),
     },
@@ -53,6 +53,10 @@ export type ExecuteBatchTransactionInstruction<
   TAccountProposal extends string | AccountMeta<string> = string,
   TAccountBatch extends string | AccountMeta<string> = string,
   TAccountTransaction extends string | AccountMeta<string> = string,
+  TAccountProgram extends
+    | string
+    | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
+  TAccountVaultPolicy extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -81,6 +85,12 @@ export type ExecuteBatchTransactionInstruction<
       TAccountTransaction extends string
         ? ReadonlyAccount<TAccountTransaction>
         : TAccountTransaction,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountVaultPolicy extends string
+        ? ReadonlyAccount<TAccountVaultPolicy>
+        : TAccountVaultPolicy,
       ...TRemainingAccounts,
     ]
   >;
@@ -125,6 +135,8 @@ export type ExecuteBatchTransactionInput<
   TAccountProposal extends string = string,
   TAccountBatch extends string = string,
   TAccountTransaction extends string = string,
+  TAccountProgram extends string = string,
+  TAccountVaultPolicy extends string = string,
 > = {
   /** Settings account this batch belongs to. */
   settings: Address<TAccountSettings>;
@@ -140,6 +152,9 @@ export type ExecuteBatchTransactionInput<
   batch: Address<TAccountBatch>;
   /** Batch transaction to execute. */
   transaction: Address<TAccountTransaction>;
+  program?: Address<TAccountProgram>;
+  /** The policy of the smart account the batch is for, required if it has one. */
+  vaultPolicy?: Address<TAccountVaultPolicy>;
 };
 
 export function getExecuteBatchTransactionInstruction<
@@ -150,6 +165,8 @@ export function getExecuteBatchTransactionInstruction<
   TAccountProposal extends string,
   TAccountBatch extends string,
   TAccountTransaction extends string,
+  TAccountProgram extends string,
+  TAccountVaultPolicy extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
@@ -160,7 +177,9 @@ export function getExecuteBatchTransactionInstruction<
     TAccountSystemProgram,
     TAccountProposal,
     TAccountBatch,
-    TAccountTransaction
+    TAccountTransaction,
+    TAccountProgram,
+    TAccountVaultPolicy
   >,
   config?: { programAddress?: TProgramAddress }
 ): ExecuteBatchTransactionInstruction<
@@ -171,7 +190,9 @@ export function getExecuteBatchTransactionInstruction<
   TAccountSystemProgram,
   TAccountProposal,
   TAccountBatch,
-  TAccountTransaction
+  TAccountTransaction,
+  TAccountProgram,
+  TAccountVaultPolicy
 > {
   // Program address.
   const programAddress =
@@ -186,6 +207,8 @@ export function getExecuteBatchTransactionInstruction<
     proposal: { value: input.proposal ?? null, isWritable: true },
     batch: { value: input.batch ?? null, isWritable: true },
     transaction: { value: input.transaction ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+    vaultPolicy: { value: input.vaultPolicy ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -197,6 +220,10 @@ export function getExecuteBatchTransactionInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -208,6 +235,8 @@ export function getExecuteBatchTransactionInstruction<
       getAccountMeta(accounts.proposal),
       getAccountMeta(accounts.batch),
       getAccountMeta(accounts.transaction),
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.vaultPolicy),
     ],
     data: getExecuteBatchTransactionInstructionDataEncoder().encode({}),
     programAddress,
@@ -219,7 +248,9 @@ export function getExecuteBatchTransactionInstruction<
     TAccountSystemProgram,
     TAccountProposal,
     TAccountBatch,
-    TAccountTransaction
+    TAccountTransaction,
+    TAccountProgram,
+    TAccountVaultPolicy
   >);
 }
 
@@ -243,6 +274,9 @@ export type ParsedExecuteBatchTransactionInstruction<
     batch: TAccountMetas[5];
     /** Batch transaction to execute. */
     transaction: TAccountMetas[6];
+    program: TAccountMetas[7];
+    /** The policy of the smart account the batch is for, required if it has one. */
+    vaultPolicy?: TAccountMetas[8] | undefined;
   };
   data: ExecuteBatchTransactionInstructionData;
 };
@@ -255,7 +289,7 @@ export function parseExecuteBatchTransactionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedExecuteBatchTransactionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -265,6 +299,12 @@ export function parseExecuteBatchTransactionInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -275,6 +315,8 @@ export function parseExecuteBatchTransactionInstruction<
       proposal: getNextAccount(),
       batch: getNextAccount(),
       transaction: getNextAccount(),
+      program: getNextAccount(),
+      vaultPolicy: getNextOptionalAccount(),
     },
     data: getExecuteBatchTransactionInstructionDataDecoder().decode(
       instruction.data
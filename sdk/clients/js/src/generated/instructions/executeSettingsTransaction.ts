@@ -52,6 +52,9 @@ export type ExecuteSettingsTransactionInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountProgram extends
+    | string
+    | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -77,6 +80,9 @@ export type ExecuteSettingsTransactionInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -120,6 +126,7 @@ export type ExecuteSettingsTransactionInput<
   TAccountTransaction extends string = string,
   TAccountFeePayer extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountProgram extends string = string,
 > = {
   /** The settings account of the smart account that owns the transaction. */
   settings: Address<TAccountSettings>;
@@ -136,6 +143,7 @@ export type ExecuteSettingsTransactionInput<
   feePayer?: TransactionSigner<TAccountFeePayer>;
   /** We might need it in case reallocation is needed. */
   systemProgram?: Address<TAccountSystemProgram>;
+  program?: Address<TAccountProgram>;
 };
 
 export function getExecuteSettingsTransactionInstruction<
@@ -145,6 +153,7 @@ export function getExecuteSettingsTransactionInstruction<
   TAccountTransaction extends string,
   TAccountFeePayer extends string,
   TAccountSystemProgram extends string,
+  TAccountProgram extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
@@ -154,7 +163,8 @@ export function getExecuteSettingsTransactionInstruction<
     TAccountProposal,
     TAccountTransaction,
     TAccountFeePayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): ExecuteSettingsTransactionInstruction<
@@ -164,7 +174,8 @@ export function getExecuteSettingsTransactionInstruction<
   TAccountProposal,
   TAccountTransaction,
   TAccountFeePayer,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -178,6 +189,7 @@ export function getExecuteSettingsTransactionInstruction<
     transaction: { value: input.transaction ?? null, isWritable: false },
     feePayer: { value: input.feePayer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -189,6 +201,10 @@ export function getExecuteSettingsTransactionInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -199,6 +215,7 @@ export function getExecuteSettingsTransactionInstruction<
       getAccountMeta(accounts.transaction),
       getAccountMeta(accounts.feePayer),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.program),
     ],
     data: getExecuteSettingsTransactionInstructionDataEncoder().encode({}),
     programAddress,
@@ -209,7 +226,8 @@ export function getExecuteSettingsTransactionInstruction<
     TAccountProposal,
     TAccountTransaction,
     TAccountFeePayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram
   >);
 }
 
@@ -234,6 +252,7 @@ export type ParsedExecuteSettingsTransactionInstruction<
     feePayer?: TAccountMetas[4] | undefined;
     /** We might need it in case reallocation is needed. */
     systemProgram?: TAccountMetas[5] | undefined;
+    program: TAccountMetas[6];
   };
   data: ExecuteSettingsTransactionInstructionData;
 };
@@ -246,7 +265,7 @@ export function parseExecuteSettingsTransactionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedExecuteSettingsTransactionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -271,6 +290,7 @@ export function parseExecuteSettingsTransactionInstruction<
       transaction: getNextAccount(),
       feePayer: getNextOptionalAccount(),
       systemProgram: getNextOptionalAccount(),
+      program: getNextAccount(),
     },
     data: getExecuteSettingsTransactionInstructionDataDecoder().decode(
       instruction.data
@@ -52,6 +52,10 @@ export type ExecuteTransactionInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountProgram extends
+    | string
+    | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
+  TAccountVaultPolicy extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -77,6 +81,12 @@ export type ExecuteTransactionInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountVaultPolicy extends string
+        ? ReadonlyAccount<TAccountVaultPolicy>
+        : TAccountVaultPolicy,
       ...TRemainingAccounts,
     ]
   >;
@@ -117,6 +127,8 @@ export type ExecuteTransactionInput<
   TAccountSigner extends string = string,
   TAccountFeePayer extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountProgram extends string = string,
+  TAccountVaultPolicy extends string = string,
 > = {
   settings: Address<TAccountSettings>;
   /** The proposal account associated with the transaction. */
@@ -126,6 +138,9 @@ export type ExecuteTransactionInput<
   signer: TransactionSigner<TAccountSigner>;
   feePayer: TransactionSigner<TAccountFeePayer>;
   systemProgram?: Address<TAccountSystemProgram>;
+  program?: Address<TAccountProgram>;
+  /** The policy of the smart account the transaction is for, required if it has one. */
+  vaultPolicy?: Address<TAccountVaultPolicy>;
 };
 
 export function getExecuteTransactionInstruction<
@@ -135,6 +150,8 @@ export function getExecuteTransactionInstruction<
   TAccountSigner extends string,
   TAccountFeePayer extends string,
   TAccountSystemProgram extends string,
+  TAccountProgram extends string,
+  TAccountVaultPolicy extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
@@ -144,7 +161,9 @@ export function getExecuteTransactionInstruction<
     TAccountTransaction,
     TAccountSigner,
     TAccountFeePayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram,
+    TAccountVaultPolicy
   >,
   config?: { programAddress?: TProgramAddress }
 ): ExecuteTransactionInstruction<
@@ -154,7 +173,9 @@ export function getExecuteTransactionInstruction<
   TAccountTransaction,
   TAccountSigner,
   TAccountFeePayer,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountProgram,
+  TAccountVaultPolicy
 > {
   // Program address.
   const programAddress =
@@ -168,6 +189,8 @@ export function getExecuteTransactionInstruction<
     signer: { value: input.signer ?? null, isWritable: false },
     feePayer: { value: input.feePayer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+    vaultPolicy: { value: input.vaultPolicy ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -179,6 +202,10 @@ export function getExecuteTransactionInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -189,6 +216,8 @@ export function getExecuteTransactionInstruction<
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.feePayer),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.vaultPolicy),
     ],
     data: getExecuteTransactionInstructionDataEncoder().encode({}),
     programAddress,
@@ -199,7 +228,9 @@ export function getExecuteTransactionInstruction<
     TAccountTransaction,
     TAccountSigner,
     TAccountFeePayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountProgram,
+    TAccountVaultPolicy
   >);
 }
 
@@ -217,6 +248,9 @@ export type ParsedExecuteTransactionInstruction<
     signer: TAccountMetas[3];
     feePayer: TAccountMetas[4];
     systemProgram: TAccountMetas[5];
+    program: TAccountMetas[6];
+    /** The policy of the smart account the transaction is for, required if it has one. */
+    vaultPolicy?: TAccountMetas[7] | undefined;
   };
   data: ExecuteTransactionInstructionData;
 };
@@ -229,7 +263,7 @@ export function parseExecuteTransactionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedExecuteTransactionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -239,6 +273,12 @@ export function parseExecuteTransactionInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -248,6 +288,8 @@ export function parseExecuteTransactionInstruction<
       signer: getNextAccount(),
       feePayer: getNextAccount(),
       systemProgram: getNextAccount(),
+      program: getNextAccount(),
+      vaultPolicy: getNextOptionalAccount(),
     },
     data: getExecuteTransactionInstructionDataDecoder().decode(
       instruction.data
@@ -49,6 +49,7 @@ export type ExecuteTransactionSyncInstruction<
   TAccountProgram extends
     | string
     | AccountMeta<string> = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
+  TAccountVaultPolicy extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -60,6 +61,9 @@ export type ExecuteTransactionSyncInstruction<
       TAccountProgram extends string
         ? ReadonlyAccount<TAccountProgram>
         : TAccountProgram,
+      TAccountVaultPolicy extends string
+        ? ReadonlyAccount<TAccountVaultPolicy>
+        : TAccountVaultPolicy,
       ...TRemainingAccounts,
     ]
   >;
@@ -123,9 +127,12 @@ export function getExecuteTransactionSyncInstructionDataCodec(): Codec<
 export type ExecuteTransactionSyncInput<
   TAccountSettings extends string = string,
   TAccountProgram extends string = string,
+  TAccountVaultPolicy extends string = string,
 > = {
   settings: Address<TAccountSettings>;
   program?: Address<TAccountProgram>;
+  /** The policy of the smart account at `args.account_index`, required if it has one. */
+  vaultPolicy?: Address<TAccountVaultPolicy>;
   accountIndex: ExecuteTransactionSyncInstructionDataArgs['accountIndex'];
   numSigners: ExecuteTransactionSyncInstructionDataArgs['numSigners'];
   instructions: ExecuteTransactionSyncInstructionDataArgs['instructions'];
@@ -134,15 +141,21 @@ export type ExecuteTransactionSyncInput<
 export function getExecuteTransactionSyncInstruction<
   TAccountSettings extends string,
   TAccountProgram extends string,
+  TAccountVaultPolicy extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
-  input: ExecuteTransactionSyncInput<TAccountSettings, TAccountProgram>,
+  input: ExecuteTransactionSyncInput<
+    TAccountSettings,
+    TAccountProgram,
+    TAccountVaultPolicy
+  >,
   config?: { programAddress?: TProgramAddress }
 ): ExecuteTransactionSyncInstruction<
   TProgramAddress,
   TAccountSettings,
-  TAccountProgram
+  TAccountProgram,
+  TAccountVaultPolicy
 > {
   // Program address.
   const programAddress =
@@ -152,6 +165,7 @@ export function getExecuteTransactionSyncInstruction<
   const originalAccounts = {
     settings: { value: input.settings ?? null, isWritable: false },
     program: { value: input.program ?? null, isWritable: false },
+    vaultPolicy: { value: input.vaultPolicy ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -172,6 +186,7 @@ export function getExecuteTransactionSyncInstruction<
     accounts: [
       getAccountMeta(accounts.settings),
       getAccountMeta(accounts.program),
+      getAccountMeta(accounts.vaultPolicy),
     ],
     data: getExecuteTransactionSyncInstructionDataEncoder().encode(
       args as ExecuteTransactionSyncInstructionDataArgs
@@ -180,7 +195,8 @@ export function getExecuteTransactionSyncInstruction<
   } as ExecuteTransactionSyncInstruction<
     TProgramAddress,
     TAccountSettings,
-    TAccountProgram
+    TAccountProgram,
+    TAccountVaultPolicy
   >);
 }
 
@@ -192,6 +208,8 @@ export type ParsedExecuteTransactionSyncInstruction<
   accounts: {
     settings: TAccountMetas[0];
     program: TAccountMetas[1];
+    /** The policy of the smart account at `args.account_index`, required if it has one. */
+    vaultPolicy?: TAccountMetas[2] | undefined;
   };
   data: ExecuteTransactionSyncInstructionData;
 };
@@ -204,7 +222,7 @@ export function parseExecuteTransactionSyncInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedExecuteTransactionSyncInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -214,9 +232,19 @@ export function parseExecuteTransactionSyncInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
-    accounts: { settings: getNextAccount(), program: getNextAccount() },
+    accounts: {
+      settings: getNextAccount(),
+      program: getNextAccount(),
+      vaultPolicy: getNextOptionalAccount(),
+    },
     data: getExecuteTransactionSyncInstructionDataDecoder().decode(
       instruction.data
     ),
@@ -6,37 +6,88 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptProgramConfigAuthority';
+export * from './acceptProgramConfigTreasury';
 export * from './activateProposal';
+export * from './addCreatorToAllowlist';
+export * from './addProgramFilterAsAuthority';
+export * from './addRestrictedSignerAsAuthority';
 export * from './addSignerAsAuthority';
 export * from './addTransactionToBatch';
+export * from './addTransactionToBatchFromBuffer';
 export * from './approveProposal';
+export * from './approveProposalWithPasskey';
+export * from './approveProposalWithSessionKey';
+export * from './approveRecovery';
+export * from './archiveSettings';
+export * from './cancelProgramConfigTransfer';
 export * from './cancelProposal';
+export * from './cancelProposalWithPasskey';
+export * from './cancelProposalWithSessionKey';
+export * from './cancelSignerRotation';
+export * from './changeSignerPermissionsAsAuthority';
 export * from './changeThresholdAsAuthority';
+export * from './closeAbandonedTransactionBuffer';
 export * from './closeBatch';
 export * from './closeBatchTransaction';
 export * from './closeSettingsTransaction';
 export * from './closeTransaction';
 export * from './closeTransactionBuffer';
 export * from './createBatch';
+export * from './createCreatorAllowlistPage';
+export * from './createPasskey';
 export * from './createProposal';
+export * from './createRecovery';
+export * from './createSessionKey';
 export * from './createSettingsTransaction';
+export * from './createSettingsTransactionFromBuffer';
 export * from './createSmartAccount';
 export * from './createTransaction';
 export * from './createTransactionBuffer';
 export * from './createTransactionFromBuffer';
+export * from './emergencyExit';
+export * from './executeBatchAtomic';
 export * from './executeBatchTransaction';
 export * from './executeSettingsTransaction';
 export * from './executeSettingsTransactionSync';
 export * from './executeTransaction';
 export * from './executeTransactionSync';
+export * from './expireProposal';
 export * from './extendTransactionBuffer';
+export * from './finalizeRecovery';
+export * from './finalizeSignerRotation';
 export * from './initializeProgramConfig';
 export * from './logEvent';
+export * from './migrateSettings';
+export * from './proposeProgramConfigAuthority';
+export * from './proposeProgramConfigTreasury';
 export * from './rejectProposal';
+export * from './rejectProposalWithPasskey';
+export * from './rejectProposalWithSessionKey';
+export * from './removeCreatorFromAllowlist';
+export * from './removeProgramFilterAsAuthority';
+export * from './removeRestrictedSignerAsAuthority';
 export * from './removeSignerAsAuthority';
+export * from './removeTargetTimeLockAsAuthority';
+export * from './removeVaultPolicyAsAuthority';
+export * from './requestSignerRotation';
+export * from './revokeSessionKey';
+export * from './rotateSignerAsAuthority';
 export * from './setArchivalAuthorityAsAuthority';
+export * from './setExecutionWindowAsAuthority';
 export * from './setNewSettingsAuthorityAsAuthority';
-export * from './setProgramConfigAuthority';
+export * from './setProgramConfigCreationMode';
+export * from './setProgramConfigCreationTokenFee';
+export * from './setProgramConfigReferralFee';
 export * from './setProgramConfigSmartAccountCreationFee';
-export * from './setProgramConfigTreasury';
+export * from './setProgramFilterModeAsAuthority';
+export * from './setRecoveryConfigAsAuthority';
+export * from './setRentCollectorAsAuthority';
+export * from './setSignerRotationDelayAsAuthority';
+export * from './setTargetTimeLockAsAuthority';
 export * from './setTimeLockAsAuthority';
+export * from './setVaultPolicyAsAuthority';
+export * from './skipBatchTransaction';
+export * from './unarchiveSettings';
+export * from './useSpendingLimit';
+export * from './vetoRecovery';
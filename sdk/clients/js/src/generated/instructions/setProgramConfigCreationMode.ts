@@ -10,8 +10,6 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
-  getAddressDecoder,
-  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getProgramDerivedAddress,
@@ -34,16 +32,24 @@ import {
 } from '@solana/kit';
 import { ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getSmartAccountCreationModeDecoder,
+  getSmartAccountCreationModeEncoder,
+  type SmartAccountCreationMode,
+  type SmartAccountCreationModeArgs,
+} from '../types';
 
-export const SET_PROGRAM_CONFIG_TREASURY_DISCRIMINATOR = new Uint8Array([4]);
+export const SET_PROGRAM_CONFIG_CREATION_MODE_DISCRIMINATOR = new Uint8Array([
+  78,
+]);
 
-export function getSetProgramConfigTreasuryDiscriminatorBytes() {
+export function getSetProgramConfigCreationModeDiscriminatorBytes() {
   return fixEncoderSize(getBytesEncoder(), 1).encode(
-    SET_PROGRAM_CONFIG_TREASURY_DISCRIMINATOR
+    SET_PROGRAM_CONFIG_CREATION_MODE_DISCRIMINATOR
   );
 }
 
-export type SetProgramConfigTreasuryInstruction<
+export type SetProgramConfigCreationModeInstruction<
   TProgram extends string = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
   TAccountProgramConfig extends string | AccountMeta<string> = string,
   TAccountAuthority extends string | AccountMeta<string> = string,
@@ -63,67 +69,67 @@ export type SetProgramConfigTreasuryInstruction<
     ]
   >;
 
-export type SetProgramConfigTreasuryInstructionData = {
+export type SetProgramConfigCreationModeInstructionData = {
   discriminator: ReadonlyUint8Array;
-  newTreasury: Address;
+  newSmartAccountCreationMode: SmartAccountCreationMode;
 };
 
-export type SetProgramConfigTreasuryInstructionDataArgs = {
-  newTreasury: Address;
+export type SetProgramConfigCreationModeInstructionDataArgs = {
+  newSmartAccountCreationMode: SmartAccountCreationModeArgs;
 };
 
-export function getSetProgramConfigTreasuryInstructionDataEncoder(): FixedSizeEncoder<SetProgramConfigTreasuryInstructionDataArgs> {
+export function getSetProgramConfigCreationModeInstructionDataEncoder(): FixedSizeEncoder<SetProgramConfigCreationModeInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 1)],
-      ['newTreasury', getAddressEncoder()],
+      ['newSmartAccountCreationMode', getSmartAccountCreationModeEncoder()],
     ]),
     (value) => ({
       ...value,
-      discriminator: SET_PROGRAM_CONFIG_TREASURY_DISCRIMINATOR,
+      discriminator: SET_PROGRAM_CONFIG_CREATION_MODE_DISCRIMINATOR,
     })
   );
 }
 
-export function getSetProgramConfigTreasuryInstructionDataDecoder(): FixedSizeDecoder<SetProgramConfigTreasuryInstructionData> {
+export function getSetProgramConfigCreationModeInstructionDataDecoder(): FixedSizeDecoder<SetProgramConfigCreationModeInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 1)],
-    ['newTreasury', getAddressDecoder()],
+    ['newSmartAccountCreationMode', getSmartAccountCreationModeDecoder()],
   ]);
 }
 
-export function getSetProgramConfigTreasuryInstructionDataCodec(): FixedSizeCodec<
-  SetProgramConfigTreasuryInstructionDataArgs,
-  SetProgramConfigTreasuryInstructionData
+export function getSetProgramConfigCreationModeInstructionDataCodec(): FixedSizeCodec<
+  SetProgramConfigCreationModeInstructionDataArgs,
+  SetProgramConfigCreationModeInstructionData
 > {
   return combineCodec(
-    getSetProgramConfigTreasuryInstructionDataEncoder(),
-    getSetProgramConfigTreasuryInstructionDataDecoder()
+    getSetProgramConfigCreationModeInstructionDataEncoder(),
+    getSetProgramConfigCreationModeInstructionDataDecoder()
   );
 }
 
-export type SetProgramConfigTreasuryAsyncInput<
+export type SetProgramConfigCreationModeAsyncInput<
   TAccountProgramConfig extends string = string,
   TAccountAuthority extends string = string,
 > = {
   programConfig?: Address<TAccountProgramConfig>;
   authority: TransactionSigner<TAccountAuthority>;
-  newTreasury: SetProgramConfigTreasuryInstructionDataArgs['newTreasury'];
+  newSmartAccountCreationMode: SetProgramConfigCreationModeInstructionDataArgs['newSmartAccountCreationMode'];
 };
 
-export async function getSetProgramConfigTreasuryInstructionAsync<
+export async function getSetProgramConfigCreationModeInstructionAsync<
   TAccountProgramConfig extends string,
   TAccountAuthority extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
-  input: SetProgramConfigTreasuryAsyncInput<
+  input: SetProgramConfigCreationModeAsyncInput<
     TAccountProgramConfig,
     TAccountAuthority
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
-  SetProgramConfigTreasuryInstruction<
+  SetProgramConfigCreationModeInstruction<
     TProgramAddress,
     TAccountProgramConfig,
     TAccountAuthority
@@ -171,38 +177,38 @@ export async function getSetProgramConfigTreasuryInstructionAsync<
       getAccountMeta(accounts.programConfig),
       getAccountMeta(accounts.authority),
     ],
-    data: getSetProgramConfigTreasuryInstructionDataEncoder().encode(
-      args as SetProgramConfigTreasuryInstructionDataArgs
+    data: getSetProgramConfigCreationModeInstructionDataEncoder().encode(
+      args as SetProgramConfigCreationModeInstructionDataArgs
     ),
     programAddress,
-  } as SetProgramConfigTreasuryInstruction<
+  } as SetProgramConfigCreationModeInstruction<
     TProgramAddress,
     TAccountProgramConfig,
     TAccountAuthority
   >);
 }
 
-export type SetProgramConfigTreasuryInput<
+export type SetProgramConfigCreationModeInput<
   TAccountProgramConfig extends string = string,
   TAccountAuthority extends string = string,
 > = {
   programConfig: Address<TAccountProgramConfig>;
   authority: TransactionSigner<TAccountAuthority>;
-  newTreasury: SetProgramConfigTreasuryInstructionDataArgs['newTreasury'];
+  newSmartAccountCreationMode: SetProgramConfigCreationModeInstructionDataArgs['newSmartAccountCreationMode'];
 };
 
-export function getSetProgramConfigTreasuryInstruction<
+export function getSetProgramConfigCreationModeInstruction<
   TAccountProgramConfig extends string,
   TAccountAuthority extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
-  input: SetProgramConfigTreasuryInput<
+  input: SetProgramConfigCreationModeInput<
     TAccountProgramConfig,
     TAccountAuthority
   >,
   config?: { programAddress?: TProgramAddress }
-): SetProgramConfigTreasuryInstruction<
+): SetProgramConfigCreationModeInstruction<
   TProgramAddress,
   TAccountProgramConfig,
   TAccountAuthority
@@ -230,18 +236,18 @@ export function getSetProgramConfigTreasuryInstruction<
       getAccountMeta(accounts.programConfig),
       getAccountMeta(accounts.authority),
     ],
-    data: getSetProgramConfigTreasuryInstructionDataEncoder().encode(
-      args as SetProgramConfigTreasuryInstructionDataArgs
+    data: getSetProgramConfigCreationModeInstructionDataEncoder().encode(
+      args as SetProgramConfigCreationModeInstructionDataArgs
     ),
     programAddress,
-  } as SetProgramConfigTreasuryInstruction<
+  } as SetProgramConfigCreationModeInstruction<
     TProgramAddress,
     TAccountProgramConfig,
     TAccountAuthority
   >);
 }
 
-export type ParsedSetProgramConfigTreasuryInstruction<
+export type ParsedSetProgramConfigCreationModeInstruction<
   TProgram extends string = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
   TAccountMetas extends readonly AccountMeta[] = readonly AccountMeta[],
 > = {
@@ -250,17 +256,17 @@ export type ParsedSetProgramConfigTreasuryInstruction<
     programConfig: TAccountMetas[0];
     authority: TAccountMetas[1];
   };
-  data: SetProgramConfigTreasuryInstructionData;
+  data: SetProgramConfigCreationModeInstructionData;
 };
 
-export function parseSetProgramConfigTreasuryInstruction<
+export function parseSetProgramConfigCreationModeInstruction<
   TProgram extends string,
   TAccountMetas extends readonly AccountMeta[],
 >(
   instruction: Instruction<TProgram> &
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
-): ParsedSetProgramConfigTreasuryInstruction<TProgram, TAccountMetas> {
+): ParsedSetProgramConfigCreationModeInstruction<TProgram, TAccountMetas> {
   if (instruction.accounts.length < 2) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
@@ -274,7 +280,7 @@ export function parseSetProgramConfigTreasuryInstruction<
   return {
     programAddress: instruction.programAddress,
     accounts: { programConfig: getNextAccount(), authority: getNextAccount() },
-    data: getSetProgramConfigTreasuryInstructionDataDecoder().decode(
+    data: getSetProgramConfigCreationModeInstructionDataDecoder().decode(
       instruction.data
     ),
   };
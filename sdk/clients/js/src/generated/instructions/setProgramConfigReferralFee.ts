@@ -10,13 +10,13 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
-  getAddressDecoder,
-  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -35,15 +35,17 @@ import {
 import { ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
-export const SET_PROGRAM_CONFIG_AUTHORITY_DISCRIMINATOR = new Uint8Array([2]);
+export const SET_PROGRAM_CONFIG_REFERRAL_FEE_DISCRIMINATOR = new Uint8Array([
+  77,
+]);
 
-export function getSetProgramConfigAuthorityDiscriminatorBytes() {
+export function getSetProgramConfigReferralFeeDiscriminatorBytes() {
   return fixEncoderSize(getBytesEncoder(), 1).encode(
-    SET_PROGRAM_CONFIG_AUTHORITY_DISCRIMINATOR
+    SET_PROGRAM_CONFIG_REFERRAL_FEE_DISCRIMINATOR
   );
 }
 
-export type SetProgramConfigAuthorityInstruction<
+export type SetProgramConfigReferralFeeInstruction<
   TProgram extends string = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
   TAccountProgramConfig extends string | AccountMeta<string> = string,
   TAccountAuthority extends string | AccountMeta<string> = string,
@@ -63,67 +65,67 @@ export type SetProgramConfigAuthorityInstruction<
     ]
   >;
 
-export type SetProgramConfigAuthorityInstructionData = {
+export type SetProgramConfigReferralFeeInstructionData = {
   discriminator: ReadonlyUint8Array;
-  newAuthority: Address;
+  newReferralFeeBps: number;
 };
 
-export type SetProgramConfigAuthorityInstructionDataArgs = {
-  newAuthority: Address;
+export type SetProgramConfigReferralFeeInstructionDataArgs = {
+  newReferralFeeBps: number;
 };
 
-export function getSetProgramConfigAuthorityInstructionDataEncoder(): FixedSizeEncoder<SetProgramConfigAuthorityInstructionDataArgs> {
+export function getSetProgramConfigReferralFeeInstructionDataEncoder(): FixedSizeEncoder<SetProgramConfigReferralFeeInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 1)],
-      ['newAuthority', getAddressEncoder()],
+      ['newReferralFeeBps', getU16Encoder()],
     ]),
     (value) => ({
       ...value,
-      discriminator: SET_PROGRAM_CONFIG_AUTHORITY_DISCRIMINATOR,
+      discriminator: SET_PROGRAM_CONFIG_REFERRAL_FEE_DISCRIMINATOR,
     })
   );
 }
 
-export function getSetProgramConfigAuthorityInstructionDataDecoder(): FixedSizeDecoder<SetProgramConfigAuthorityInstructionData> {
+export function getSetProgramConfigReferralFeeInstructionDataDecoder(): FixedSizeDecoder<SetProgramConfigReferralFeeInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 1)],
-    ['newAuthority', getAddressDecoder()],
+    ['newReferralFeeBps', getU16Decoder()],
   ]);
 }
 
-export function getSetProgramConfigAuthorityInstructionDataCodec(): FixedSizeCodec<
-  SetProgramConfigAuthorityInstructionDataArgs,
-  SetProgramConfigAuthorityInstructionData
+export function getSetProgramConfigReferralFeeInstructionDataCodec(): FixedSizeCodec<
+  SetProgramConfigReferralFeeInstructionDataArgs,
+  SetProgramConfigReferralFeeInstructionData
 > {
   return combineCodec(
-    getSetProgramConfigAuthorityInstructionDataEncoder(),
-    getSetProgramConfigAuthorityInstructionDataDecoder()
+    getSetProgramConfigReferralFeeInstructionDataEncoder(),
+    getSetProgramConfigReferralFeeInstructionDataDecoder()
   );
 }
 
-export type SetProgramConfigAuthorityAsyncInput<
+export type SetProgramConfigReferralFeeAsyncInput<
   TAccountProgramConfig extends string = string,
   TAccountAuthority extends string = string,
 > = {
   programConfig?: Address<TAccountProgramConfig>;
   authority: TransactionSigner<TAccountAuthority>;
-  newAuthority: SetProgramConfigAuthorityInstructionDataArgs['newAuthority'];
+  newReferralFeeBps: SetProgramConfigReferralFeeInstructionDataArgs['newReferralFeeBps'];
 };
 
-export async function getSetProgramConfigAuthorityInstructionAsync<
+export async function getSetProgramConfigReferralFeeInstructionAsync<
   TAccountProgramConfig extends string,
   TAccountAuthority extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
-  input: SetProgramConfigAuthorityAsyncInput<
+  input: SetProgramConfigReferralFeeAsyncInput<
     TAccountProgramConfig,
     TAccountAuthority
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
-  SetProgramConfigAuthorityInstruction<
+  SetProgramConfigReferralFeeInstruction<
     TProgramAddress,
     TAccountProgramConfig,
     TAccountAuthority
@@ -171,38 +173,38 @@ export async function getSetProgramConfigAuthorityInstructionAsync<
       getAccountMeta(accounts.programConfig),
       getAccountMeta(accounts.authority),
     ],
-    data: getSetProgramConfigAuthorityInstructionDataEncoder().encode(
-      args as SetProgramConfigAuthorityInstructionDataArgs
+    data: getSetProgramConfigReferralFeeInstructionDataEncoder().encode(
+      args as SetProgramConfigReferralFeeInstructionDataArgs
     ),
     programAddress,
-  } as SetProgramConfigAuthorityInstruction<
+  } as SetProgramConfigReferralFeeInstruction<
     TProgramAddress,
     TAccountProgramConfig,
     TAccountAuthority
   >);
 }
 
-export type SetProgramConfigAuthorityInput<
+export type SetProgramConfigReferralFeeInput<
   TAccountProgramConfig extends string = string,
   TAccountAuthority extends string = string,
 > = {
   programConfig: Address<TAccountProgramConfig>;
   authority: TransactionSigner<TAccountAuthority>;
-  newAuthority: SetProgramConfigAuthorityInstructionDataArgs['newAuthority'];
+  newReferralFeeBps: SetProgramConfigReferralFeeInstructionDataArgs['newReferralFeeBps'];
 };
 
-export function getSetProgramConfigAuthorityInstruction<
+export function getSetProgramConfigReferralFeeInstruction<
   TAccountProgramConfig extends string,
   TAccountAuthority extends string,
   TProgramAddress extends
     Address = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
 >(
-  input: SetProgramConfigAuthorityInput<
+  input: SetProgramConfigReferralFeeInput<
     TAccountProgramConfig,
     TAccountAuthority
   >,
   config?: { programAddress?: TProgramAddress }
-): SetProgramConfigAuthorityInstruction<
+): SetProgramConfigReferralFeeInstruction<
   TProgramAddress,
   TAccountProgramConfig,
   TAccountAuthority
@@ -230,18 +232,18 @@ export function getSetProgramConfigAuthorityInstruction<
       getAccountMeta(accounts.programConfig),
       getAccountMeta(accounts.authority),
     ],
-    data: getSetProgramConfigAuthorityInstructionDataEncoder().encode(
-      args as SetProgramConfigAuthorityInstructionDataArgs
+    data: getSetProgramConfigReferralFeeInstructionDataEncoder().encode(
+      args as SetProgramConfigReferralFeeInstructionDataArgs
     ),
     programAddress,
-  } as SetProgramConfigAuthorityInstruction<
+  } as SetProgramConfigReferralFeeInstruction<
     TProgramAddress,
     TAccountProgramConfig,
     TAccountAuthority
   >);
 }
 
-export type ParsedSetProgramConfigAuthorityInstruction<
+export type ParsedSetProgramConfigReferralFeeInstruction<
   TProgram extends string = typeof ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
   TAccountMetas extends readonly AccountMeta[] = readonly AccountMeta[],
 > = {
@@ -250,17 +252,17 @@ export type ParsedSetProgramConfigAuthorityInstruction<
     programConfig: TAccountMetas[0];
     authority: TAccountMetas[1];
   };
-  data: SetProgramConfigAuthorityInstructionData;
+  data: SetProgramConfigReferralFeeInstructionData;
 };
 
-export function parseSetProgramConfigAuthorityInstruction<
+export function parseSetProgramConfigReferralFeeInstruction<
   TProgram extends string,
   TAccountMetas extends readonly AccountMeta[],
 >(
   instruction: Instruction<TProgram> &
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
-): ParsedSetProgramConfigAuthorityInstruction<TProgram, TAccountMetas> {
+): ParsedSetProgramConfigReferralFeeInstruction<TProgram, TAccountMetas> {
   if (instruction.accounts.length < 2) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
@@ -274,7 +276,7 @@ export function parseSetProgramConfigAuthorityInstruction<
   return {
     programAddress: instruction.programAddress,
     accounts: { programConfig: getNextAccount(), authority: getNextAccount() },
-    data: getSetProgramConfigAuthorityInstructionDataDecoder().decode(
+    data: getSetProgramConfigReferralFeeInstructionDataDecoder().decode(
       instruction.data
     ),
   };
@@ -14,60 +14,131 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAcceptProgramConfigAuthorityInstruction,
+  type ParsedAcceptProgramConfigTreasuryInstruction,
   type ParsedActivateProposalInstruction,
+  type ParsedAddCreatorToAllowlistInstruction,
+  type ParsedAddProgramFilterAsAuthorityInstruction,
+  type ParsedAddRestrictedSignerAsAuthorityInstruction,
   type ParsedAddSignerAsAuthorityInstruction,
+  type ParsedAddTransactionToBatchFromBufferInstruction,
   type ParsedAddTransactionToBatchInstruction,
   type ParsedApproveProposalInstruction,
+  type ParsedApproveProposalWithPasskeyInstruction,
+  type ParsedApproveProposalWithSessionKeyInstruction,
+  type ParsedApproveRecoveryInstruction,
+  type ParsedArchiveSettingsInstruction,
+  type ParsedCancelProgramConfigTransferInstruction,
   type ParsedCancelProposalInstruction,
+  type ParsedCancelProposalWithPasskeyInstruction,
+  type ParsedCancelProposalWithSessionKeyInstruction,
+  type ParsedCancelSignerRotationInstruction,
+  type ParsedChangeSignerPermissionsAsAuthorityInstruction,
   type ParsedChangeThresholdAsAuthorityInstruction,
+  type ParsedCloseAbandonedTransactionBufferInstruction,
   type ParsedCloseBatchInstruction,
   type ParsedCloseBatchTransactionInstruction,
   type ParsedCloseSettingsTransactionInstruction,
   type ParsedCloseTransactionBufferInstruction,
   type ParsedCloseTransactionInstruction,
   type ParsedCreateBatchInstruction,
+  type ParsedCreateCreatorAllowlistPageInstruction,
+  type ParsedCreatePasskeyInstruction,
   type ParsedCreateProposalInstruction,
+  type ParsedCreateRecoveryInstruction,
+  type ParsedCreateSessionKeyInstruction,
+  type ParsedCreateSettingsTransactionFromBufferInstruction,
   type ParsedCreateSettingsTransactionInstruction,
   type ParsedCreateSmartAccountInstruction,
   type ParsedCreateTransactionBufferInstruction,
   type ParsedCreateTransactionFromBufferInstruction,
   type ParsedCreateTransactionInstruction,
+  type ParsedEmergencyExitInstruction,
+  type ParsedExecuteBatchAtomicInstruction,
   type ParsedExecuteBatchTransactionInstruction,
   type ParsedExecuteSettingsTransactionInstruction,
   type ParsedExecuteSettingsTransactionSyncInstruction,
   type ParsedExecuteTransactionInstruction,
   type ParsedExecuteTransactionSyncInstruction,
+  type ParsedExpireProposalInstruction,
   type ParsedExtendTransactionBufferInstruction,
+  type ParsedFinalizeRecoveryInstruction,
+  type ParsedFinalizeSignerRotationInstruction,
   type ParsedInitializeProgramConfigInstruction,
   type ParsedLogEventInstruction,
+  type ParsedMigrateSettingsInstruction,
+  type ParsedProposeProgramConfigAuthorityInstruction,
+  type ParsedProposeProgramConfigTreasuryInstruction,
   type ParsedRejectProposalInstruction,
+  type ParsedRejectProposalWithPasskeyInstruction,
+  type ParsedRejectProposalWithSessionKeyInstruction,
+  type ParsedRemoveCreatorFromAllowlistInstruction,
+  type ParsedRemoveProgramFilterAsAuthorityInstruction,
+  type ParsedRemoveRestrictedSignerAsAuthorityInstruction,
   type ParsedRemoveSignerAsAuthorityInstruction,
+  type ParsedRemoveTargetTimeLockAsAuthorityInstruction,
+  type ParsedRemoveVaultPolicyAsAuthorityInstruction,
+  type ParsedRequestSignerRotationInstruction,
+  type ParsedRevokeSessionKeyInstruction,
+  type ParsedRotateSignerAsAuthorityInstruction,
   type ParsedSetArchivalAuthorityAsAuthorityInstruction,
+  type ParsedSetExecutionWindowAsAuthorityInstruction,
   type ParsedSetNewSettingsAuthorityAsAuthorityInstruction,
-  type ParsedSetProgramConfigAuthorityInstruction,
+  type ParsedSetProgramConfigCreationModeInstruction,
+  type ParsedSetProgramConfigCreationTokenFeeInstruction,
+  type ParsedSetProgramConfigReferralFeeInstruction,
   type ParsedSetProgramConfigSmartAccountCreationFeeInstruction,
-  type ParsedSetProgramConfigTreasuryInstruction,
+  type ParsedSetProgramFilterModeAsAuthorityInstruction,
+  type ParsedSetRecoveryConfigAsAuthorityInstruction,
+  type ParsedSetRentCollectorAsAuthorityInstruction,
+  type ParsedSetSignerRotationDelayAsAuthorityInstruction,
+  type ParsedSetTargetTimeLockAsAuthorityInstruction,
   type ParsedSetTimeLockAsAuthorityInstruction,
+  type ParsedSetVaultPolicyAsAuthorityInstruction,
+  type ParsedSkipBatchTransactionInstruction,
+  type ParsedUnarchiveSettingsInstruction,
+  type ParsedUseSpendingLimitInstruction,
+  type ParsedVetoRecoveryInstruction,
 } from '../instructions';
 
 export const ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS =
   'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh' as Address<'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh'>;
 
 export enum AstrolabeSmartAccountAccount {
+  ArchivedSettings,
   Batch,
   BatchTransaction,
+  CreatorAllowlistPage,
+  Passkey,
   ProgramConfig,
+  ProgramConfigTransfer,
   Proposal,
+  Recovery,
+  SessionKey,
   Settings,
   SettingsTransaction,
+  SignerRotation,
+  SpendingLimit,
   Transaction,
   TransactionBuffer,
+  VaultPolicy,
 }
 
 export function identifyAstrolabeSmartAccountAccount(
   account: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): AstrolabeSmartAccountAccount {
   const data = 'data' in account ? account.data : account;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([65, 190, 183, 228, 74, 212, 137, 24])
+      ),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountAccount.ArchivedSettings;
+  }
   if (
     containsBytes(
       data,
@@ -90,6 +161,28 @@ export function identifyAstrolabeSmartAccountAccount(
   ) {
     return AstrolabeSmartAccountAccount.BatchTransaction;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([132, 1, 216, 102, 33, 56, 55, 121])
+      ),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountAccount.CreatorAllowlistPage;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([63, 187, 82, 162, 123, 174, 41, 68])
+      ),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountAccount.Passkey;
+  }
   if (
     containsBytes(
       data,
@@ -101,6 +194,17 @@ export function identifyAstrolabeSmartAccountAccount(
   ) {
     return AstrolabeSmartAccountAccount.ProgramConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([162, 101, 133, 247, 206, 36, 219, 140])
+      ),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountAccount.ProgramConfigTransfer;
+  }
   if (
     containsBytes(
       data,
@@ -112,6 +216,28 @@ export function identifyAstrolabeSmartAccountAccount(
   ) {
     return AstrolabeSmartAccountAccount.Proposal;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([242, 166, 34, 227, 71, 217, 155, 103])
+      ),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountAccount.Recovery;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([93, 186, 163, 139, 160, 255, 81, 112])
+      ),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountAccount.SessionKey;
+  }
   if (
     containsBytes(
       data,
@@ -134,6 +260,28 @@ export function identifyAstrolabeSmartAccountAccount(
   ) {
     return AstrolabeSmartAccountAccount.SettingsTransaction;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([156, 204, 26, 7, 134, 170, 230, 227])
+      ),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountAccount.SignerRotation;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([10, 201, 27, 160, 218, 195, 222, 152])
+      ),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountAccount.SpendingLimit;
+  }
   if (
     containsBytes(
       data,
@@ -156,52 +304,132 @@ export function identifyAstrolabeSmartAccountAccount(
   ) {
     return AstrolabeSmartAccountAccount.TransactionBuffer;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([114, 95, 33, 99, 228, 82, 116, 98])
+      ),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountAccount.VaultPolicy;
+  }
   throw new Error(
     'The provided account could not be identified as a astrolabeSmartAccount account.'
   );
 }
 
 export enum AstrolabeSmartAccountInstruction {
+  AcceptProgramConfigAuthority,
+  AcceptProgramConfigTreasury,
   ActivateProposal,
+  AddCreatorToAllowlist,
+  AddProgramFilterAsAuthority,
+  AddRestrictedSignerAsAuthority,
   AddSignerAsAuthority,
   AddTransactionToBatch,
+  AddTransactionToBatchFromBuffer,
   ApproveProposal,
+  ApproveProposalWithPasskey,
+  ApproveProposalWithSessionKey,
+  ApproveRecovery,
+  ArchiveSettings,
+  CancelProgramConfigTransfer,
   CancelProposal,
+  CancelProposalWithPasskey,
+  CancelProposalWithSessionKey,
+  CancelSignerRotation,
+  ChangeSignerPermissionsAsAuthority,
   ChangeThresholdAsAuthority,
+  CloseAbandonedTransactionBuffer,
   CloseBatch,
   CloseBatchTransaction,
   CloseSettingsTransaction,
   CloseTransaction,
   CloseTransactionBuffer,
   CreateBatch,
+  CreateCreatorAllowlistPage,
+  CreatePasskey,
   CreateProposal,
+  CreateRecovery,
+  CreateSessionKey,
   CreateSettingsTransaction,
+  CreateSettingsTransactionFromBuffer,
   CreateSmartAccount,
   CreateTransaction,
   CreateTransactionBuffer,
   CreateTransactionFromBuffer,
+  EmergencyExit,
+  ExecuteBatchAtomic,
   ExecuteBatchTransaction,
   ExecuteSettingsTransaction,
   ExecuteSettingsTransactionSync,
   ExecuteTransaction,
   ExecuteTransactionSync,
+  ExpireProposal,
   ExtendTransactionBuffer,
+  FinalizeRecovery,
+  FinalizeSignerRotation,
   InitializeProgramConfig,
   LogEvent,
+  MigrateSettings,
+  ProposeProgramConfigAuthority,
+  ProposeProgramConfigTreasury,
   RejectProposal,
+  RejectProposalWithPasskey,
+  RejectProposalWithSessionKey,
+  RemoveCreatorFromAllowlist,
+  RemoveProgramFilterAsAuthority,
+  RemoveRestrictedSignerAsAuthority,
   RemoveSignerAsAuthority,
+  RemoveTargetTimeLockAsAuthority,
+  RemoveVaultPolicyAsAuthority,
+  RequestSignerRotation,
+  RevokeSessionKey,
+  RotateSignerAsAuthority,
   SetArchivalAuthorityAsAuthority,
+  SetExecutionWindowAsAuthority,
   SetNewSettingsAuthorityAsAuthority,
-  SetProgramConfigAuthority,
+  SetProgramConfigCreationMode,
+  SetProgramConfigCreationTokenFee,
+  SetProgramConfigReferralFee,
   SetProgramConfigSmartAccountCreationFee,
-  SetProgramConfigTreasury,
+  SetProgramFilterModeAsAuthority,
+  SetRecoveryConfigAsAuthority,
+  SetRentCollectorAsAuthority,
+  SetSignerRotationDelayAsAuthority,
+  SetTargetTimeLockAsAuthority,
   SetTimeLockAsAuthority,
+  SetVaultPolicyAsAuthority,
+  SkipBatchTransaction,
+  UnarchiveSettings,
+  UseSpendingLimit,
+  VetoRecovery,
 }
 
 export function identifyAstrolabeSmartAccountInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): AstrolabeSmartAccountInstruction {
   const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([83])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.AcceptProgramConfigAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([85])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.AcceptProgramConfigTreasury;
+  }
   if (
     containsBytes(
       data,
@@ -211,6 +439,33 @@ export function identifyAstrolabeSmartAccountInstruction(
   ) {
     return AstrolabeSmartAccountInstruction.ActivateProposal;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([80])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.AddCreatorToAllowlist;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([69])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.AddProgramFilterAsAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([60])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.AddRestrictedSignerAsAuthority;
+  }
   if (
     containsBytes(
       data,
@@ -229,6 +484,15 @@ export function identifyAstrolabeSmartAccountInstruction(
   ) {
     return AstrolabeSmartAccountInstruction.AddTransactionToBatch;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([73])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.AddTransactionToBatchFromBuffer;
+  }
   if (
     containsBytes(
       data,
@@ -238,6 +502,51 @@ export function identifyAstrolabeSmartAccountInstruction(
   ) {
     return AstrolabeSmartAccountInstruction.ApproveProposal;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([40])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.ApproveProposalWithPasskey;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([46])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.ApproveProposalWithSessionKey;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([50])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.ApproveRecovery;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([37])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.ArchiveSettings;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([86])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.CancelProgramConfigTransfer;
+  }
   if (
     containsBytes(
       data,
@@ -247,6 +556,42 @@ export function identifyAstrolabeSmartAccountInstruction(
   ) {
     return AstrolabeSmartAccountInstruction.CancelProposal;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([42])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.CancelProposalWithPasskey;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([48])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.CancelProposalWithSessionKey;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([66])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.CancelSignerRotation;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([62])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.ChangeSignerPermissionsAsAuthority;
+  }
   if (
     containsBytes(
       data,
@@ -256,6 +601,15 @@ export function identifyAstrolabeSmartAccountInstruction(
   ) {
     return AstrolabeSmartAccountInstruction.ChangeThresholdAsAuthority;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([75])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.CloseAbandonedTransactionBuffer;
+  }
   if (
     containsBytes(
       data,
@@ -310,6 +664,24 @@ export function identifyAstrolabeSmartAccountInstruction(
   ) {
     return AstrolabeSmartAccountInstruction.CreateBatch;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([79])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.CreateCreatorAllowlistPage;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([39])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.CreatePasskey;
+  }
   if (
     containsBytes(
       data,
@@ -319,6 +691,24 @@ export function identifyAstrolabeSmartAccountInstruction(
   ) {
     return AstrolabeSmartAccountInstruction.CreateProposal;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([49])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.CreateRecovery;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([44])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.CreateSessionKey;
+  }
   if (
     containsBytes(
       data,
@@ -328,6 +718,15 @@ export function identifyAstrolabeSmartAccountInstruction(
   ) {
     return AstrolabeSmartAccountInstruction.CreateSettingsTransaction;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([74])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.CreateSettingsTransactionFromBuffer;
+  }
   if (
     containsBytes(
       data,
@@ -364,6 +763,24 @@ export function identifyAstrolabeSmartAccountInstruction(
   ) {
     return AstrolabeSmartAccountInstruction.CreateTransactionFromBuffer;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([36])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.EmergencyExit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([71])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.ExecuteBatchAtomic;
+  }
   if (
     containsBytes(
       data,
@@ -409,6 +826,15 @@ export function identifyAstrolabeSmartAccountInstruction(
   ) {
     return AstrolabeSmartAccountInstruction.ExecuteTransactionSync;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([57])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.ExpireProposal;
+  }
   if (
     containsBytes(
       data,
@@ -418,6 +844,24 @@ export function identifyAstrolabeSmartAccountInstruction(
   ) {
     return AstrolabeSmartAccountInstruction.ExtendTransactionBuffer;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([52])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.FinalizeRecovery;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([67])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.FinalizeSignerRotation;
+  }
   if (
     containsBytes(
       data,
@@ -436,6 +880,33 @@ export function identifyAstrolabeSmartAccountInstruction(
   ) {
     return AstrolabeSmartAccountInstruction.LogEvent;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([87])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.MigrateSettings;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([82])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.ProposeProgramConfigAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([84])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.ProposeProgramConfigTreasury;
+  }
   if (
     containsBytes(
       data,
@@ -445,6 +916,51 @@ export function identifyAstrolabeSmartAccountInstruction(
   ) {
     return AstrolabeSmartAccountInstruction.RejectProposal;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([41])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.RejectProposalWithPasskey;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([47])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.RejectProposalWithSessionKey;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([81])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.RemoveCreatorFromAllowlist;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([70])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.RemoveProgramFilterAsAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([61])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.RemoveRestrictedSignerAsAuthority;
+  }
   if (
     containsBytes(
       data,
@@ -454,6 +970,51 @@ export function identifyAstrolabeSmartAccountInstruction(
   ) {
     return AstrolabeSmartAccountInstruction.RemoveSignerAsAuthority;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([59])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.RemoveTargetTimeLockAsAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([55])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.RemoveVaultPolicyAsAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([65])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.RequestSignerRotation;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([45])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.RevokeSessionKey;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([63])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.RotateSignerAsAuthority;
+  }
   if (
     containsBytes(
       data,
@@ -463,6 +1024,15 @@ export function identifyAstrolabeSmartAccountInstruction(
   ) {
     return AstrolabeSmartAccountInstruction.SetArchivalAuthorityAsAuthority;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([56])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.SetExecutionWindowAsAuthority;
+  }
   if (
     containsBytes(
       data,
@@ -475,11 +1045,29 @@ export function identifyAstrolabeSmartAccountInstruction(
   if (
     containsBytes(
       data,
-      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([2])),
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([78])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.SetProgramConfigCreationMode;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([76])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.SetProgramConfigCreationTokenFee;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([77])),
       0
     )
   ) {
-    return AstrolabeSmartAccountInstruction.SetProgramConfigAuthority;
+    return AstrolabeSmartAccountInstruction.SetProgramConfigReferralFee;
   }
   if (
     containsBytes(
@@ -493,11 +1081,47 @@ export function identifyAstrolabeSmartAccountInstruction(
   if (
     containsBytes(
       data,
-      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([4])),
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([68])),
       0
     )
   ) {
-    return AstrolabeSmartAccountInstruction.SetProgramConfigTreasury;
+    return AstrolabeSmartAccountInstruction.SetProgramFilterModeAsAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([53])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.SetRecoveryConfigAsAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([43])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.SetRentCollectorAsAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([64])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.SetSignerRotationDelayAsAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([58])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.SetTargetTimeLockAsAuthority;
   }
   if (
     containsBytes(
@@ -508,6 +1132,51 @@ export function identifyAstrolabeSmartAccountInstruction(
   ) {
     return AstrolabeSmartAccountInstruction.SetTimeLockAsAuthority;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([54])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.SetVaultPolicyAsAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([72])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.SkipBatchTransaction;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([38])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.UnarchiveSettings;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([35])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.UseSpendingLimit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([51])),
+      0
+    )
+  ) {
+    return AstrolabeSmartAccountInstruction.VetoRecovery;
+  }
   throw new Error(
     'The provided instruction could not be identified as a astrolabeSmartAccount instruction.'
   );
@@ -516,24 +1185,72 @@ export function identifyAstrolabeSmartAccountInstruction(
 export type ParsedAstrolabeSmartAccountInstruction<
   TProgram extends string = 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh',
 > =
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.AcceptProgramConfigAuthority;
+    } & ParsedAcceptProgramConfigAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.AcceptProgramConfigTreasury;
+    } & ParsedAcceptProgramConfigTreasuryInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.ActivateProposal;
     } & ParsedActivateProposalInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.AddCreatorToAllowlist;
+    } & ParsedAddCreatorToAllowlistInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.AddProgramFilterAsAuthority;
+    } & ParsedAddProgramFilterAsAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.AddRestrictedSignerAsAuthority;
+    } & ParsedAddRestrictedSignerAsAuthorityInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.AddSignerAsAuthority;
     } & ParsedAddSignerAsAuthorityInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.AddTransactionToBatch;
     } & ParsedAddTransactionToBatchInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.AddTransactionToBatchFromBuffer;
+    } & ParsedAddTransactionToBatchFromBufferInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.ApproveProposal;
     } & ParsedApproveProposalInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.ApproveProposalWithPasskey;
+    } & ParsedApproveProposalWithPasskeyInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.ApproveProposalWithSessionKey;
+    } & ParsedApproveProposalWithSessionKeyInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.ApproveRecovery;
+    } & ParsedApproveRecoveryInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.ArchiveSettings;
+    } & ParsedArchiveSettingsInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.CancelProgramConfigTransfer;
+    } & ParsedCancelProgramConfigTransferInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.CancelProposal;
     } & ParsedCancelProposalInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.CancelProposalWithPasskey;
+    } & ParsedCancelProposalWithPasskeyInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.CancelProposalWithSessionKey;
+    } & ParsedCancelProposalWithSessionKeyInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.CancelSignerRotation;
+    } & ParsedCancelSignerRotationInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.ChangeSignerPermissionsAsAuthority;
+    } & ParsedChangeSignerPermissionsAsAuthorityInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.ChangeThresholdAsAuthority;
     } & ParsedChangeThresholdAsAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.CloseAbandonedTransactionBuffer;
+    } & ParsedCloseAbandonedTransactionBufferInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.CloseBatch;
     } & ParsedCloseBatchInstruction<TProgram>)
@@ -552,12 +1269,27 @@ export type ParsedAstrolabeSmartAccountInstruction<
   | ({
       instructionType: AstrolabeSmartAccountInstruction.CreateBatch;
     } & ParsedCreateBatchInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.CreateCreatorAllowlistPage;
+    } & ParsedCreateCreatorAllowlistPageInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.CreatePasskey;
+    } & ParsedCreatePasskeyInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.CreateProposal;
     } & ParsedCreateProposalInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.CreateRecovery;
+    } & ParsedCreateRecoveryInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.CreateSessionKey;
+    } & ParsedCreateSessionKeyInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.CreateSettingsTransaction;
     } & ParsedCreateSettingsTransactionInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.CreateSettingsTransactionFromBuffer;
+    } & ParsedCreateSettingsTransactionFromBufferInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.CreateSmartAccount;
     } & ParsedCreateSmartAccountInstruction<TProgram>)
@@ -570,6 +1302,12 @@ export type ParsedAstrolabeSmartAccountInstruction<
   | ({
       instructionType: AstrolabeSmartAccountInstruction.CreateTransactionFromBuffer;
     } & ParsedCreateTransactionFromBufferInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.EmergencyExit;
+    } & ParsedEmergencyExitInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.ExecuteBatchAtomic;
+    } & ParsedExecuteBatchAtomicInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.ExecuteBatchTransaction;
     } & ParsedExecuteBatchTransactionInstruction<TProgram>)
@@ -585,36 +1323,120 @@ export type ParsedAstrolabeSmartAccountInstruction<
   | ({
       instructionType: AstrolabeSmartAccountInstruction.ExecuteTransactionSync;
     } & ParsedExecuteTransactionSyncInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.ExpireProposal;
+    } & ParsedExpireProposalInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.ExtendTransactionBuffer;
     } & ParsedExtendTransactionBufferInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.FinalizeRecovery;
+    } & ParsedFinalizeRecoveryInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.FinalizeSignerRotation;
+    } & ParsedFinalizeSignerRotationInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.InitializeProgramConfig;
     } & ParsedInitializeProgramConfigInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.LogEvent;
     } & ParsedLogEventInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.MigrateSettings;
+    } & ParsedMigrateSettingsInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.ProposeProgramConfigAuthority;
+    } & ParsedProposeProgramConfigAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.ProposeProgramConfigTreasury;
+    } & ParsedProposeProgramConfigTreasuryInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.RejectProposal;
     } & ParsedRejectProposalInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.RejectProposalWithPasskey;
+    } & ParsedRejectProposalWithPasskeyInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.RejectProposalWithSessionKey;
+    } & ParsedRejectProposalWithSessionKeyInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.RemoveCreatorFromAllowlist;
+    } & ParsedRemoveCreatorFromAllowlistInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.RemoveProgramFilterAsAuthority;
+    } & ParsedRemoveProgramFilterAsAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.RemoveRestrictedSignerAsAuthority;
+    } & ParsedRemoveRestrictedSignerAsAuthorityInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.RemoveSignerAsAuthority;
     } & ParsedRemoveSignerAsAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.RemoveTargetTimeLockAsAuthority;
+    } & ParsedRemoveTargetTimeLockAsAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.RemoveVaultPolicyAsAuthority;
+    } & ParsedRemoveVaultPolicyAsAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.RequestSignerRotation;
+    } & ParsedRequestSignerRotationInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.RevokeSessionKey;
+    } & ParsedRevokeSessionKeyInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.RotateSignerAsAuthority;
+    } & ParsedRotateSignerAsAuthorityInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.SetArchivalAuthorityAsAuthority;
     } & ParsedSetArchivalAuthorityAsAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.SetExecutionWindowAsAuthority;
+    } & ParsedSetExecutionWindowAsAuthorityInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.SetNewSettingsAuthorityAsAuthority;
     } & ParsedSetNewSettingsAuthorityAsAuthorityInstruction<TProgram>)
   | ({
-      instructionType: AstrolabeSmartAccountInstruction.SetProgramConfigAuthority;
-    } & ParsedSetProgramConfigAuthorityInstruction<TProgram>)
+      instructionType: AstrolabeSmartAccountInstruction.SetProgramConfigCreationMode;
+    } & ParsedSetProgramConfigCreationModeInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.SetProgramConfigCreationTokenFee;
+    } & ParsedSetProgramConfigCreationTokenFeeInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.SetProgramConfigReferralFee;
+    } & ParsedSetProgramConfigReferralFeeInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.SetProgramConfigSmartAccountCreationFee;
     } & ParsedSetProgramConfigSmartAccountCreationFeeInstruction<TProgram>)
   | ({
-      instructionType: AstrolabeSmartAccountInstruction.SetProgramConfigTreasury;
-    } & ParsedSetProgramConfigTreasuryInstruction<TProgram>)
+      instructionType: AstrolabeSmartAccountInstruction.SetProgramFilterModeAsAuthority;
+    } & ParsedSetProgramFilterModeAsAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.SetRecoveryConfigAsAuthority;
+    } & ParsedSetRecoveryConfigAsAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.SetRentCollectorAsAuthority;
+    } & ParsedSetRentCollectorAsAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.SetSignerRotationDelayAsAuthority;
+    } & ParsedSetSignerRotationDelayAsAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.SetTargetTimeLockAsAuthority;
+    } & ParsedSetTargetTimeLockAsAuthorityInstruction<TProgram>)
   | ({
       instructionType: AstrolabeSmartAccountInstruction.SetTimeLockAsAuthority;
-    } & ParsedSetTimeLockAsAuthorityInstruction<TProgram>);
+    } & ParsedSetTimeLockAsAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.SetVaultPolicyAsAuthority;
+    } & ParsedSetVaultPolicyAsAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.SkipBatchTransaction;
+    } & ParsedSkipBatchTransactionInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.UnarchiveSettings;
+    } & ParsedUnarchiveSettingsInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.UseSpendingLimit;
+    } & ParsedUseSpendingLimitInstruction<TProgram>)
+  | ({
+      instructionType: AstrolabeSmartAccountInstruction.VetoRecovery;
+    } & ParsedVetoRecoveryInstruction<TProgram>);
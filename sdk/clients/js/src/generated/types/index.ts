@@ -6,14 +6,25 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './addTransactionToBatchArgs';
+export * from './createSettingsTransactionArgs';
 export * from './createTransactionArgs';
+export * from './creatorAllowlistChangeArgs';
+export * from './period';
 export * from './permissions';
+export * from './programConfigTransferKind';
+export * from './programFilter';
+export * from './programFilterMode';
 export * from './proposalStatus';
 export * from './restrictedPermissions';
 export * from './restrictedSmartAccountSigner';
+export * from './sessionExpiration';
 export * from './settingsAction';
 export * from './smartAccountCompiledInstruction';
+export * from './smartAccountCreationMode';
 export * from './smartAccountMessageAddressTableLookup';
 export * from './smartAccountSigner';
 export * from './smartAccountTransactionMessage';
+export * from './targetTimeLock';
+export * from './timeLockTarget';
 export * from './voteOnProposalArgs';
@@ -31,7 +31,8 @@ export type ProposalStatus =
   | { __kind: 'Rejected'; timestamp: bigint }
   | { __kind: 'Approved'; timestamp: bigint }
   | { __kind: 'Executed'; timestamp: bigint }
-  | { __kind: 'Cancelled'; timestamp: bigint };
+  | { __kind: 'Cancelled'; timestamp: bigint }
+  | { __kind: 'Expired'; timestamp: bigint };
 
 export type ProposalStatusArgs =
   | { __kind: 'Draft'; timestamp: number | bigint }
@@ -39,7 +40,8 @@ export type ProposalStatusArgs =
   | { __kind: 'Rejected'; timestamp: number | bigint }
   | { __kind: 'Approved'; timestamp: number | bigint }
   | { __kind: 'Executed'; timestamp: number | bigint }
-  | { __kind: 'Cancelled'; timestamp: number | bigint };
+  | { __kind: 'Cancelled'; timestamp: number | bigint }
+  | { __kind: 'Expired'; timestamp: number | bigint };
 
 export function getProposalStatusEncoder(): FixedSizeEncoder<ProposalStatusArgs> {
   return getDiscriminatedUnionEncoder([
@@ -49,6 +51,7 @@ export function getProposalStatusEncoder(): FixedSizeEncoder<ProposalStatusArgs>
     ['Approved', getStructEncoder([['timestamp', getI64Encoder()]])],
     ['Executed', getStructEncoder([['timestamp', getI64Encoder()]])],
     ['Cancelled', getStructEncoder([['timestamp', getI64Encoder()]])],
+    ['Expired', getStructEncoder([['timestamp', getI64Encoder()]])],
   ]) as FixedSizeEncoder<ProposalStatusArgs>;
 }
 
@@ -60,6 +63,7 @@ export function getProposalStatusDecoder(): FixedSizeDecoder<ProposalStatus> {
     ['Approved', getStructDecoder([['timestamp', getI64Decoder()]])],
     ['Executed', getStructDecoder([['timestamp', getI64Decoder()]])],
     ['Cancelled', getStructDecoder([['timestamp', getI64Decoder()]])],
+    ['Expired', getStructDecoder([['timestamp', getI64Decoder()]])],
   ]) as FixedSizeDecoder<ProposalStatus>;
 }
 
@@ -119,6 +123,14 @@ export function proposalStatus(
     'Cancelled'
   >
 ): GetDiscriminatedUnionVariant<ProposalStatusArgs, '__kind', 'Cancelled'>;
+export function proposalStatus(
+  kind: 'Expired',
+  data: GetDiscriminatedUnionVariantContent<
+    ProposalStatusArgs,
+    '__kind',
+    'Expired'
+  >
+): GetDiscriminatedUnionVariant<ProposalStatusArgs, '__kind', 'Expired'>;
 export function proposalStatus<K extends ProposalStatusArgs['__kind'], Data>(
   kind: K,
   data?: Data
@@ -27,17 +27,28 @@ import {
 export type RestrictedSmartAccountSigner = {
   key: Address;
   restrictedPermissions: RestrictedPermissions;
+  /**
+   * The pre-registered address that funds are swept to when this signer
+   * triggers an emergency exit.
+   */
+  exitDestination: Address;
 };
 
 export type RestrictedSmartAccountSignerArgs = {
   key: Address;
   restrictedPermissions: RestrictedPermissionsArgs;
+  /**
+   * The pre-registered address that funds are swept to when this signer
+   * triggers an emergency exit.
+   */
+  exitDestination: Address;
 };
 
 export function getRestrictedSmartAccountSignerEncoder(): FixedSizeEncoder<RestrictedSmartAccountSignerArgs> {
   return getStructEncoder([
     ['key', getAddressEncoder()],
     ['restrictedPermissions', getRestrictedPermissionsEncoder()],
+    ['exitDestination', getAddressEncoder()],
   ]);
 }
 
@@ -45,6 +56,7 @@ export function getRestrictedSmartAccountSignerDecoder(): FixedSizeDecoder<Restr
   return getStructDecoder([
     ['key', getAddressDecoder()],
     ['restrictedPermissions', getRestrictedPermissionsDecoder()],
+    ['exitDestination', getAddressDecoder()],
   ]);
 }
 
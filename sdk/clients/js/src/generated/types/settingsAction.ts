@@ -10,8 +10,12 @@ import {
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getDiscriminatedUnionDecoder,
   getDiscriminatedUnionEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
@@ -20,6 +24,10 @@ import {
   getU16Encoder,
   getU32Decoder,
   getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type Codec,
   type Decoder,
@@ -30,10 +38,34 @@ import {
   type OptionOrNullable,
 } from '@solana/kit';
 import {
+  getPeriodDecoder,
+  getPeriodEncoder,
+  getPermissionsDecoder,
+  getPermissionsEncoder,
+  getProgramFilterDecoder,
+  getProgramFilterEncoder,
+  getProgramFilterModeDecoder,
+  getProgramFilterModeEncoder,
+  getRestrictedSmartAccountSignerDecoder,
+  getRestrictedSmartAccountSignerEncoder,
   getSmartAccountSignerDecoder,
   getSmartAccountSignerEncoder,
+  getTimeLockTargetDecoder,
+  getTimeLockTargetEncoder,
+  type Period,
+  type PeriodArgs,
+  type Permissions,
+  type PermissionsArgs,
+  type ProgramFilter,
+  type ProgramFilterArgs,
+  type ProgramFilterMode,
+  type ProgramFilterModeArgs,
+  type RestrictedSmartAccountSigner,
+  type RestrictedSmartAccountSignerArgs,
   type SmartAccountSigner,
   type SmartAccountSignerArgs,
+  type TimeLockTarget,
+  type TimeLockTargetArgs,
 } from '.';
 
 export type SettingsAction =
@@ -41,7 +73,52 @@ export type SettingsAction =
   | { __kind: 'RemoveSigner'; oldSigner: Address }
   | { __kind: 'ChangeThreshold'; newThreshold: number }
   | { __kind: 'SetTimeLock'; newTimeLock: number }
-  | { __kind: 'SetArchivalAuthority'; newArchivalAuthority: Option<Address> };
+  | { __kind: 'SetArchivalAuthority'; newArchivalAuthority: Option<Address> }
+  | {
+      __kind: 'AddSpendingLimit';
+      seed: Address;
+      accountIndex: number;
+      mint: Address;
+      amount: bigint;
+      period: Period;
+      signers: Array<Address>;
+      destinations: Array<Address>;
+      expiration: bigint;
+    }
+  | { __kind: 'RemoveSpendingLimit'; spendingLimit: Address }
+  | { __kind: 'SetRentCollector'; newRentCollector: Option<Address> }
+  | {
+      __kind: 'SetRecoveryConfig';
+      newRecoveryThreshold: number;
+      newRecoveryDelay: number;
+    }
+  | { __kind: 'SetExecutionWindow'; newExecutionWindow: number }
+  | {
+      __kind: 'SetVaultPolicy';
+      accountIndex: number;
+      threshold: number;
+      timeLock: number;
+      signers: Array<Address>;
+    }
+  | { __kind: 'RemoveVaultPolicy'; accountIndex: number }
+  | { __kind: 'SetTargetTimeLock'; target: TimeLockTarget; timeLock: number }
+  | { __kind: 'RemoveTargetTimeLock'; target: TimeLockTarget }
+  | {
+      __kind: 'AddRestrictedSigner';
+      newRestrictedSigner: RestrictedSmartAccountSigner;
+    }
+  | { __kind: 'RemoveRestrictedSigner'; oldRestrictedSigner: Address }
+  | {
+      __kind: 'ChangeSignerPermissions';
+      signer: Address;
+      newPermissions: Permissions;
+    }
+  | { __kind: 'SetSettingsAuthority'; newSettingsAuthority: Address }
+  | { __kind: 'RotateSigner'; oldSigner: Address; newSigner: Address }
+  | { __kind: 'SetSignerRotationDelay'; newSignerRotationDelay: Option<number> }
+  | { __kind: 'SetProgramFilterMode'; mode: ProgramFilterMode }
+  | { __kind: 'AddProgramFilter'; filter: ProgramFilter }
+  | { __kind: 'RemoveProgramFilter'; filter: ProgramFilter };
 
 export type SettingsActionArgs =
   | { __kind: 'AddSigner'; newSigner: SmartAccountSignerArgs }
@@ -51,7 +128,59 @@ export type SettingsActionArgs =
   | {
       __kind: 'SetArchivalAuthority';
       newArchivalAuthority: OptionOrNullable<Address>;
-    };
+    }
+  | {
+      __kind: 'AddSpendingLimit';
+      seed: Address;
+      accountIndex: number;
+      mint: Address;
+      amount: number | bigint;
+      period: PeriodArgs;
+      signers: Array<Address>;
+      destinations: Array<Address>;
+      expiration: number | bigint;
+    }
+  | { __kind: 'RemoveSpendingLimit'; spendingLimit: Address }
+  | { __kind: 'SetRentCollector'; newRentCollector: OptionOrNullable<Address> }
+  | {
+      __kind: 'SetRecoveryConfig';
+      newRecoveryThreshold: number;
+      newRecoveryDelay: number;
+    }
+  | { __kind: 'SetExecutionWindow'; newExecutionWindow: number }
+  | {
+      __kind: 'SetVaultPolicy';
+      accountIndex: number;
+      threshold: number;
+      timeLock: number;
+      signers: Array<Address>;
+    }
+  | { __kind: 'RemoveVaultPolicy'; accountIndex: number }
+  | {
+      __kind: 'SetTargetTimeLock';
+      target: TimeLockTargetArgs;
+      timeLock: number;
+    }
+  | { __kind: 'RemoveTargetTimeLock'; target: TimeLockTargetArgs }
+  | {
+      __kind: 'AddRestrictedSigner';
+      newRestrictedSigner: RestrictedSmartAccountSignerArgs;
+    }
+  | { __kind: 'RemoveRestrictedSigner'; oldRestrictedSigner: Address }
+  | {
+      __kind: 'ChangeSignerPermissions';
+      signer: Address;
+      newPermissions: PermissionsArgs;
+    }
+  | { __kind: 'SetSettingsAuthority'; newSettingsAuthority: Address }
+  | { __kind: 'RotateSigner'; oldSigner: Address; newSigner: Address }
+  | {
+      __kind: 'SetSignerRotationDelay';
+      newSignerRotationDelay: OptionOrNullable<number>;
+    }
+  | { __kind: 'SetProgramFilterMode'; mode: ProgramFilterModeArgs }
+  | { __kind: 'AddProgramFilter'; filter: ProgramFilterArgs }
+  | { __kind: 'RemoveProgramFilter'; filter: ProgramFilterArgs };
 
 export function getSettingsActionEncoder(): Encoder<SettingsActionArgs> {
   return getDiscriminatedUnionEncoder([
@@ -68,6 +197,107 @@ export function getSettingsActionEncoder(): Encoder<SettingsActionArgs> {
         ['newArchivalAuthority', getOptionEncoder(getAddressEncoder())],
       ]),
     ],
+    [
+      'AddSpendingLimit',
+      getStructEncoder([
+        ['seed', getAddressEncoder()],
+        ['accountIndex', getU8Encoder()],
+        ['mint', getAddressEncoder()],
+        ['amount', getU64Encoder()],
+        ['period', getPeriodEncoder()],
+        ['signers', getArrayEncoder(getAddressEncoder())],
+        ['destinations', getArrayEncoder(getAddressEncoder())],
+        ['expiration', getI64Encoder()],
+      ]),
+    ],
+    [
+      'RemoveSpendingLimit',
+      getStructEncoder([['spendingLimit', getAddressEncoder()]]),
+    ],
+    [
+      'SetRentCollector',
+      getStructEncoder([
+        ['newRentCollector', getOptionEncoder(getAddressEncoder())],
+      ]),
+    ],
+    [
+      'SetRecoveryConfig',
+      getStructEncoder([
+        ['newRecoveryThreshold', getU16Encoder()],
+        ['newRecoveryDelay', getU32Encoder()],
+      ]),
+    ],
+    [
+      'SetExecutionWindow',
+      getStructEncoder([['newExecutionWindow', getU32Encoder()]]),
+    ],
+    [
+      'SetVaultPolicy',
+      getStructEncoder([
+        ['accountIndex', getU8Encoder()],
+        ['threshold', getU16Encoder()],
+        ['timeLock', getU32Encoder()],
+        ['signers', getArrayEncoder(getAddressEncoder())],
+      ]),
+    ],
+    ['RemoveVaultPolicy', getStructEncoder([['accountIndex', getU8Encoder()]])],
+    [
+      'SetTargetTimeLock',
+      getStructEncoder([
+        ['target', getTimeLockTargetEncoder()],
+        ['timeLock', getU32Encoder()],
+      ]),
+    ],
+    [
+      'RemoveTargetTimeLock',
+      getStructEncoder([['target', getTimeLockTargetEncoder()]]),
+    ],
+    [
+      'AddRestrictedSigner',
+      getStructEncoder([
+        ['newRestrictedSigner', getRestrictedSmartAccountSignerEncoder()],
+      ]),
+    ],
+    [
+      'RemoveRestrictedSigner',
+      getStructEncoder([['oldRestrictedSigner', getAddressEncoder()]]),
+    ],
+    [
+      'ChangeSignerPermissions',
+      getStructEncoder([
+        ['signer', getAddressEncoder()],
+        ['newPermissions', getPermissionsEncoder()],
+      ]),
+    ],
+    [
+      'SetSettingsAuthority',
+      getStructEncoder([['newSettingsAuthority', getAddressEncoder()]]),
+    ],
+    [
+      'RotateSigner',
+      getStructEncoder([
+        ['oldSigner', getAddressEncoder()],
+        ['newSigner', getAddressEncoder()],
+      ]),
+    ],
+    [
+      'SetSignerRotationDelay',
+      getStructEncoder([
+        ['newSignerRotationDelay', getOptionEncoder(getU32Encoder())],
+      ]),
+    ],
+    [
+      'SetProgramFilterMode',
+      getStructEncoder([['mode', getProgramFilterModeEncoder()]]),
+    ],
+    [
+      'AddProgramFilter',
+      getStructEncoder([['filter', getProgramFilterEncoder()]]),
+    ],
+    [
+      'RemoveProgramFilter',
+      getStructEncoder([['filter', getProgramFilterEncoder()]]),
+    ],
   ]);
 }
 
@@ -86,6 +316,107 @@ export function getSettingsActionDecoder(): Decoder<SettingsAction> {
         ['newArchivalAuthority', getOptionDecoder(getAddressDecoder())],
       ]),
     ],
+    [
+      'AddSpendingLimit',
+      getStructDecoder([
+        ['seed', getAddressDecoder()],
+        ['accountIndex', getU8Decoder()],
+        ['mint', getAddressDecoder()],
+        ['amount', getU64Decoder()],
+        ['period', getPeriodDecoder()],
+        ['signers', getArrayDecoder(getAddressDecoder())],
+        ['destinations', getArrayDecoder(getAddressDecoder())],
+        ['expiration', getI64Decoder()],
+      ]),
+    ],
+    [
+      'RemoveSpendingLimit',
+      getStructDecoder([['spendingLimit', getAddressDecoder()]]),
+    ],
+    [
+      'SetRentCollector',
+      getStructDecoder([
+        ['newRentCollector', getOptionDecoder(getAddressDecoder())],
+      ]),
+    ],
+    [
+      'SetRecoveryConfig',
+      getStructDecoder([
+        ['newRecoveryThreshold', getU16Decoder()],
+        ['newRecoveryDelay', getU32Decoder()],
+      ]),
+    ],
+    [
+      'SetExecutionWindow',
+      getStructDecoder([['newExecutionWindow', getU32Decoder()]]),
+    ],
+    [
+      'SetVaultPolicy',
+      getStructDecoder([
+        ['accountIndex', getU8Decoder()],
+        ['threshold', getU16Decoder()],
+        ['timeLock', getU32Decoder()],
+        ['signers', getArrayDecoder(getAddressDecoder())],
+      ]),
+    ],
+    ['RemoveVaultPolicy', getStructDecoder([['accountIndex', getU8Decoder()]])],
+    [
+      'SetTargetTimeLock',
+      getStructDecoder([
+        ['target', getTimeLockTargetDecoder()],
+        ['timeLock', getU32Decoder()],
+      ]),
+    ],
+    [
+      'RemoveTargetTimeLock',
+      getStructDecoder([['target', getTimeLockTargetDecoder()]]),
+    ],
+    [
+      'AddRestrictedSigner',
+      getStructDecoder([
+        ['newRestrictedSigner', getRestrictedSmartAccountSignerDecoder()],
+      ]),
+    ],
+    [
+      'RemoveRestrictedSigner',
+      getStructDecoder([['oldRestrictedSigner', getAddressDecoder()]]),
+    ],
+    [
+      'ChangeSignerPermissions',
+      getStructDecoder([
+        ['signer', getAddressDecoder()],
+        ['newPermissions', getPermissionsDecoder()],
+      ]),
+    ],
+    [
+      'SetSettingsAuthority',
+      getStructDecoder([['newSettingsAuthority', getAddressDecoder()]]),
+    ],
+    [
+      'RotateSigner',
+      getStructDecoder([
+        ['oldSigner', getAddressDecoder()],
+        ['newSigner', getAddressDecoder()],
+      ]),
+    ],
+    [
+      'SetSignerRotationDelay',
+      getStructDecoder([
+        ['newSignerRotationDelay', getOptionDecoder(getU32Decoder())],
+      ]),
+    ],
+    [
+      'SetProgramFilterMode',
+      getStructDecoder([['mode', getProgramFilterModeDecoder()]]),
+    ],
+    [
+      'AddProgramFilter',
+      getStructDecoder([['filter', getProgramFilterDecoder()]]),
+    ],
+    [
+      'RemoveProgramFilter',
+      getStructDecoder([['filter', getProgramFilterDecoder()]]),
+    ],
   ]);
 }
 
@@ -145,6 +476,214 @@ export function settingsAction(
   '__kind',
   'SetArchivalAuthority'
 >;
+export function settingsAction(
+  kind: 'AddSpendingLimit',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'AddSpendingLimit'
+  >
+): GetDiscriminatedUnionVariant<
+  SettingsActionArgs,
+  '__kind',
+  'AddSpendingLimit'
+>;
+export function settingsAction(
+  kind: 'RemoveSpendingLimit',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'RemoveSpendingLimit'
+  >
+): GetDiscriminatedUnionVariant<
+  SettingsActionArgs,
+  '__kind',
+  'RemoveSpendingLimit'
+>;
+export function settingsAction(
+  kind: 'SetRentCollector',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'SetRentCollector'
+  >
+): GetDiscriminatedUnionVariant<
+  SettingsActionArgs,
+  '__kind',
+  'SetRentCollector'
+>;
+export function settingsAction(
+  kind: 'SetRecoveryConfig',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'SetRecoveryConfig'
+  >
+): GetDiscriminatedUnionVariant<
+  SettingsActionArgs,
+  '__kind',
+  'SetRecoveryConfig'
+>;
+export function settingsAction(
+  kind: 'SetExecutionWindow',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'SetExecutionWindow'
+  >
+): GetDiscriminatedUnionVariant<
+  SettingsActionArgs,
+  '__kind',
+  'SetExecutionWindow'
+>;
+export function settingsAction(
+  kind: 'SetVaultPolicy',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'SetVaultPolicy'
+  >
+): GetDiscriminatedUnionVariant<SettingsActionArgs, '__kind', 'SetVaultPolicy'>;
+export function settingsAction(
+  kind: 'RemoveVaultPolicy',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'RemoveVaultPolicy'
+  >
+): GetDiscriminatedUnionVariant<
+  SettingsActionArgs,
+  '__kind',
+  'RemoveVaultPolicy'
+>;
+export function settingsAction(
+  kind: 'SetTargetTimeLock',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'SetTargetTimeLock'
+  >
+): GetDiscriminatedUnionVariant<
+  SettingsActionArgs,
+  '__kind',
+  'SetTargetTimeLock'
+>;
+export function settingsAction(
+  kind: 'RemoveTargetTimeLock',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'RemoveTargetTimeLock'
+  >
+): GetDiscriminatedUnionVariant<
+  SettingsActionArgs,
+  '__kind',
+  'RemoveTargetTimeLock'
+>;
+export function settingsAction(
+  kind: 'AddRestrictedSigner',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'AddRestrictedSigner'
+  >
+): GetDiscriminatedUnionVariant<
+  SettingsActionArgs,
+  '__kind',
+  'AddRestrictedSigner'
+>;
+export function settingsAction(
+  kind: 'RemoveRestrictedSigner',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'RemoveRestrictedSigner'
+  >
+): GetDiscriminatedUnionVariant<
+  SettingsActionArgs,
+  '__kind',
+  'RemoveRestrictedSigner'
+>;
+export function settingsAction(
+  kind: 'ChangeSignerPermissions',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'ChangeSignerPermissions'
+  >
+): GetDiscriminatedUnionVariant<
+  SettingsActionArgs,
+  '__kind',
+  'ChangeSignerPermissions'
+>;
+export function settingsAction(
+  kind: 'SetSettingsAuthority',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'SetSettingsAuthority'
+  >
+): GetDiscriminatedUnionVariant<
+  SettingsActionArgs,
+  '__kind',
+  'SetSettingsAuthority'
+>;
+export function settingsAction(
+  kind: 'RotateSigner',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'RotateSigner'
+  >
+): GetDiscriminatedUnionVariant<SettingsActionArgs, '__kind', 'RotateSigner'>;
+export function settingsAction(
+  kind: 'SetSignerRotationDelay',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'SetSignerRotationDelay'
+  >
+): GetDiscriminatedUnionVariant<
+  SettingsActionArgs,
+  '__kind',
+  'SetSignerRotationDelay'
+>;
+export function settingsAction(
+  kind: 'SetProgramFilterMode',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'SetProgramFilterMode'
+  >
+): GetDiscriminatedUnionVariant<
+  SettingsActionArgs,
+  '__kind',
+  'SetProgramFilterMode'
+>;
+export function settingsAction(
+  kind: 'AddProgramFilter',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'AddProgramFilter'
+  >
+): GetDiscriminatedUnionVariant<
+  SettingsActionArgs,
+  '__kind',
+  'AddProgramFilter'
+>;
+export function settingsAction(
+  kind: 'RemoveProgramFilter',
+  data: GetDiscriminatedUnionVariantContent<
+    SettingsActionArgs,
+    '__kind',
+    'RemoveProgramFilter'
+  >
+): GetDiscriminatedUnionVariant<
+  SettingsActionArgs,
+  '__kind',
+  'RemoveProgramFilter'
+>;
 export function settingsAction<K extends SettingsActionArgs['__kind'], Data>(
   kind: K,
   data?: Data
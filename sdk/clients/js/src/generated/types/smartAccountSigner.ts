@@ -12,6 +12,8 @@ import {
   getAddressEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -24,17 +26,25 @@ import {
   type PermissionsArgs,
 } from '.';
 
-export type SmartAccountSigner = { key: Address; permissions: Permissions };
+export type SmartAccountSigner = {
+  key: Address;
+  permissions: Permissions;
+  /** Voting weight of the signer, counted towards the `threshold` when it has the `Vote` permission. */
+  weight: number;
+};
 
 export type SmartAccountSignerArgs = {
   key: Address;
   permissions: PermissionsArgs;
+  /** Voting weight of the signer, counted towards the `threshold` when it has the `Vote` permission. */
+  weight: number;
 };
 
 export function getSmartAccountSignerEncoder(): FixedSizeEncoder<SmartAccountSignerArgs> {
   return getStructEncoder([
     ['key', getAddressEncoder()],
     ['permissions', getPermissionsEncoder()],
+    ['weight', getU16Encoder()],
   ]);
 }
 
@@ -42,6 +52,7 @@ export function getSmartAccountSignerDecoder(): FixedSizeDecoder<SmartAccountSig
   return getStructDecoder([
     ['key', getAddressDecoder()],
     ['permissions', getPermissionsDecoder()],
+    ['weight', getU16Decoder()],
   ]);
 }
 
@@ -0,0 +1,648 @@
+//! Decoding of the events the program logs through `log_event` inner instructions.
+//!
+//! Every event is wrapped in a [`SmartAccountEventEnvelope`] carrying its type and a
+//! length-prefixed payload, so events this crate doesn't know about can be skipped.
+//!
+//! ```ignore
+//! for data in log_event_inner_instructions {
+//!     let envelope = decode_log_event(&data)?;
+//!     match envelope.decode_event()? {
+//!         Some(SmartAccountEvent::ProposalEvent(event)) => { /* ... */ }
+//!         Some(_) => {}
+//!         None => {} // Emitted by a newer program version.
+//!     }
+//! }
+//! ```
+
+use std::io::{Read, Write};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_pubkey::Pubkey;
+use thiserror::Error;
+
+use crate::generated::accounts::{Settings, SETTINGS_DISCRIMINATOR};
+use crate::generated::instructions::{LogEventInstructionArgs, LOG_EVENT_DISCRIMINATOR};
+use crate::generated::types::{
+    ProgramConfigTransferKind, ProposalStatus, SettingsAction, SmartAccountCompiledInstruction,
+};
+
+/// The envelope version this crate decodes.
+pub const EVENT_SCHEMA_VERSION: u8 = 1;
+
+#[derive(Debug, Error)]
+pub enum EventDecodeError {
+    #[error("Instruction data is not a log_event instruction")]
+    NotLogEventInstruction,
+    #[error("Unsupported event schema version {0}")]
+    UnsupportedVersion(u8),
+    #[error("Failed to deserialize event: {0}")]
+    Deserialize(#[from] std::io::Error),
+}
+
+/// What the program logs through `log_event`.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SmartAccountEventEnvelope {
+    pub version: u8,
+    /// The [`SmartAccountEvent`] variant index of the payload.
+    pub event_type: u8,
+    /// The smart account settings, or the program config for program config events.
+    pub settings_pubkey: Pubkey,
+    pub slot: u64,
+    pub timestamp: i64,
+    /// The Borsh-serialized event, without the variant tag.
+    pub payload: Vec<u8>,
+}
+
+/// Decodes the envelope out of the data of a `log_event` instruction.
+pub fn decode_log_event(
+    instruction_data: &[u8],
+) -> Result<SmartAccountEventEnvelope, EventDecodeError> {
+    let mut data = instruction_data
+        .strip_prefix(LOG_EVENT_DISCRIMINATOR.as_slice())
+        .ok_or(EventDecodeError::NotLogEventInstruction)?;
+    let args = LogEventInstructionArgs::deserialize(&mut data)?;
+    Ok(SmartAccountEventEnvelope::try_from_slice(&args.event)?)
+}
+
+impl SmartAccountEventEnvelope {
+    /// Decodes the payload, or returns `None` if the event type is unknown to this crate.
+    ///
+    /// Trailing payload bytes are ignored, as newer program versions may append
+    /// fields to existing events. This doesn't hold for the events embedding the
+    /// settings (`CreateSmartAccountEvent`, `SynchronousSettingsTransactionEvent`,
+    /// `AuthoritySettingsEvent`, `AuthorityChangeEvent`, `ArchiveSettingsEvent` and
+    /// `UnarchiveSettingsEvent`):
+    /// they only decode with the `Settings` layout of the program that logged them.
+    pub fn decode_event(&self) -> Result<Option<SmartAccountEvent>, EventDecodeError> {
+        if self.version != EVENT_SCHEMA_VERSION {
+            return Err(EventDecodeError::UnsupportedVersion(self.version));
+        }
+        let mut payload = self.payload.as_slice();
+        let payload = &mut payload;
+        let event = match self.event_type {
+            0 => {
+                SmartAccountEvent::CreateSmartAccountEvent(BorshDeserialize::deserialize(payload)?)
+            }
+            1 => SmartAccountEvent::SynchronousTransactionEvent(BorshDeserialize::deserialize(
+                payload,
+            )?),
+            2 => SmartAccountEvent::SynchronousSettingsTransactionEvent(
+                BorshDeserialize::deserialize(payload)?,
+            ),
+            3 => SmartAccountEvent::AuthoritySettingsEvent(BorshDeserialize::deserialize(payload)?),
+            4 => SmartAccountEvent::AuthorityChangeEvent(BorshDeserialize::deserialize(payload)?),
+            5 => SmartAccountEvent::UseSpendingLimitEvent(BorshDeserialize::deserialize(payload)?),
+            6 => SmartAccountEvent::EmergencyExitEvent(BorshDeserialize::deserialize(payload)?),
+            7 => SmartAccountEvent::ArchiveSettingsEvent(BorshDeserialize::deserialize(payload)?),
+            8 => SmartAccountEvent::UnarchiveSettingsEvent(BorshDeserialize::deserialize(payload)?),
+            9 => SmartAccountEvent::TransactionEvent(BorshDeserialize::deserialize(payload)?),
+            10 => SmartAccountEvent::ProposalEvent(BorshDeserialize::deserialize(payload)?),
+            11 => SmartAccountEvent::ProgramConfigEvent(BorshDeserialize::deserialize(payload)?),
+            _ => return Ok(None),
+        };
+        Ok(Some(event))
+    }
+}
+
+/// Mirrors the program's `SmartAccountEvent`; the variant index is the envelope `event_type`.
+#[derive(BorshSerialize, Clone, Debug, Eq, PartialEq)]
+pub enum SmartAccountEvent {
+    CreateSmartAccountEvent(CreateSmartAccountEvent),
+    SynchronousTransactionEvent(SynchronousTransactionEvent),
+    SynchronousSettingsTransactionEvent(SynchronousSettingsTransactionEvent),
+    AuthoritySettingsEvent(AuthoritySettingsEvent),
+    AuthorityChangeEvent(AuthorityChangeEvent),
+    UseSpendingLimitEvent(UseSpendingLimitEvent),
+    EmergencyExitEvent(EmergencyExitEvent),
+    ArchiveSettingsEvent(ArchiveSettingsEvent),
+    UnarchiveSettingsEvent(UnarchiveSettingsEvent),
+    TransactionEvent(TransactionEvent),
+    ProposalEvent(ProposalEvent),
+    ProgramConfigEvent(ProgramConfigEvent),
+}
+
+/// Settings as embedded in events, which serialize them without the account discriminator.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventSettings(pub Settings);
+
+impl BorshDeserialize for EventSettings {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut reader = SETTINGS_DISCRIMINATOR.as_slice().chain(reader);
+        Ok(Self(Settings::deserialize_reader(&mut reader)?))
+    }
+}
+
+impl BorshSerialize for EventSettings {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let data = borsh::to_vec(&self.0)?;
+        writer.write_all(&data[SETTINGS_DISCRIMINATOR.len()..])
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct CreateSmartAccountEvent {
+    pub new_settings_pubkey: Pubkey,
+    pub new_settings_content: EventSettings,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SynchronousTransactionEvent {
+    pub settings_pubkey: Pubkey,
+    pub account_index: u8,
+    pub signers: Vec<Pubkey>,
+    pub instructions: Vec<SmartAccountCompiledInstruction>,
+    pub instruction_accounts: Vec<Pubkey>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SynchronousSettingsTransactionEvent {
+    pub settings_pubkey: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub settings: EventSettings,
+    pub changes: Vec<SettingsAction>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct AuthoritySettingsEvent {
+    pub settings: EventSettings,
+    pub settings_pubkey: Pubkey,
+    pub authority: Pubkey,
+    pub change: SettingsAction,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct AuthorityChangeEvent {
+    pub settings: EventSettings,
+    pub settings_pubkey: Pubkey,
+    pub authority: Pubkey,
+    pub new_authority: Option<Pubkey>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct UseSpendingLimitEvent {
+    pub settings_pubkey: Pubkey,
+    pub spending_limit_pubkey: Pubkey,
+    pub signer: Pubkey,
+    pub destination: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub remaining_amount: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyExitEvent {
+    pub settings_pubkey: Pubkey,
+    pub restricted_signer: Pubkey,
+    pub account_index: u8,
+    pub smart_account: Pubkey,
+    pub exit_destination: Pubkey,
+    pub lamports: u64,
+    pub token_transfers: Vec<EmergencyExitTokenTransfer>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyExitTokenTransfer {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ArchiveSettingsEvent {
+    pub settings_pubkey: Pubkey,
+    pub archival_authority: Pubkey,
+    pub archived_settings_pubkey: Pubkey,
+    pub settings_hash: [u8; 32],
+    pub settings: EventSettings,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct UnarchiveSettingsEvent {
+    pub settings_pubkey: Pubkey,
+    pub archived_settings_pubkey: Pubkey,
+    pub settings: EventSettings,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TransactionEvent {
+    pub event_type: TransactionEventType,
+    pub settings_pubkey: Pubkey,
+    pub transaction_pubkey: Pubkey,
+    /// Index of the transaction, or of the batch for batches and their transactions.
+    pub transaction_index: u64,
+    pub signer: Option<Pubkey>,
+    pub memo: Option<String>,
+    pub transaction_content: TransactionContent,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub enum TransactionEventType {
+    Create,
+    Execute,
+    Skip,
+    Close,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub enum TransactionContent {
+    Transaction {
+        account_index: u8,
+        instructions: Vec<InstructionSummary>,
+    },
+    SettingsTransaction {
+        actions: Vec<SettingsAction>,
+    },
+    Batch {
+        account_index: u8,
+        size: u32,
+    },
+    BatchTransaction {
+        batch_transaction_index: u32,
+        outcome: BatchTransactionOutcome,
+        instructions: Vec<InstructionSummary>,
+    },
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub enum BatchTransactionOutcome {
+    Pending,
+    Executed,
+    Skipped,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct InstructionSummary {
+    pub program_id: Pubkey,
+    pub num_accounts: u8,
+    /// The leading bytes of the instruction data.
+    pub discriminator: Vec<u8>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ProposalEvent {
+    pub event_type: ProposalEventType,
+    pub settings_pubkey: Pubkey,
+    pub proposal_pubkey: Pubkey,
+    pub transaction_index: u64,
+    pub signer: Option<Pubkey>,
+    pub memo: Option<String>,
+    /// The status of the proposal after the event.
+    pub status: ProposalStatus,
+    pub tally: VoteTally,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub enum ProposalEventType {
+    Create,
+    Activate,
+    Approve,
+    Reject,
+    Cancel,
+    Expire,
+    Execute,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct VoteTally {
+    pub approvals: u16,
+    pub rejections: u16,
+    pub cancellations: u16,
+    pub approval_weight: u32,
+    pub rejection_weight: u32,
+    pub cancellation_weight: u32,
+    pub threshold: u16,
+    pub cutoff: u32,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ProgramConfigEvent {
+    pub event_type: ProgramConfigEventType,
+    pub program_config_pubkey: Pubkey,
+    pub transfer_kind: ProgramConfigTransferKind,
+    /// The `authority` or `treasury` at the time of the event.
+    pub old_key: Pubkey,
+    pub new_key: Pubkey,
+    pub signer: Pubkey,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub enum ProgramConfigEventType {
+    ProposeTransfer,
+    AcceptTransfer,
+    CancelTransfer,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generated::types::{Permissions, ProgramFilterMode, SmartAccountSigner};
+
+    fn settings() -> EventSettings {
+        EventSettings(Settings {
+            discriminator: SETTINGS_DISCRIMINATOR,
+            seed: 1,
+            settings_authority: Pubkey::default(),
+            threshold: 1,
+            time_lock: 60,
+            transaction_index: 2,
+            stale_transaction_index: 1,
+            rent_collector: Some(Pubkey::new_unique()),
+            archival_authority: None,
+            archivable_after: 0,
+            recovery_threshold: 0,
+            recovery_delay: 0,
+            vault_policies: [0; 32],
+            execution_window: 0,
+            signer_rotation_delay: Some(3_600),
+            program_filter_mode: ProgramFilterMode::Disabled,
+            bump: 255,
+            signers: vec![SmartAccountSigner {
+                key: Pubkey::new_unique(),
+                permissions: Permissions { mask: 7 },
+                weight: 1,
+            }],
+            restricted_signers: vec![],
+            target_time_locks: vec![],
+            program_filters: vec![],
+            account_utilization: 0,
+            reserved1: 0,
+            reserved2: 0,
+        })
+    }
+
+    fn instructions() -> Vec<InstructionSummary> {
+        vec![InstructionSummary {
+            program_id: Pubkey::new_unique(),
+            num_accounts: 2,
+            discriminator: vec![2, 0, 0, 0],
+        }]
+    }
+
+    /// The data of the `log_event` instruction the program logs the event with,
+    /// see `SmartAccountEvent::to_envelope` in the program.
+    fn log_event_data(event: &SmartAccountEvent, trailing_bytes: &[u8]) -> Vec<u8> {
+        let mut payload = borsh::to_vec(event).unwrap();
+        let event_type = payload.remove(0);
+        payload.extend_from_slice(trailing_bytes);
+        let envelope = SmartAccountEventEnvelope {
+            version: EVENT_SCHEMA_VERSION,
+            event_type,
+            settings_pubkey: Pubkey::new_unique(),
+            slot: 100,
+            timestamp: 1_700_000_000,
+            payload,
+        };
+        let args = LogEventInstructionArgs {
+            account_seeds: vec![],
+            bump: 255,
+            event: borsh::to_vec(&envelope).unwrap(),
+        };
+        [
+            LOG_EVENT_DISCRIMINATOR.to_vec(),
+            borsh::to_vec(&args).unwrap(),
+        ]
+        .concat()
+    }
+
+    fn round_trip(event: SmartAccountEvent) {
+        let envelope = decode_log_event(&log_event_data(&event, &[])).unwrap();
+        assert_eq!(envelope.decode_event().unwrap(), Some(event));
+    }
+
+    #[test]
+    fn test_round_trip_create_smart_account_event() {
+        round_trip(SmartAccountEvent::CreateSmartAccountEvent(
+            CreateSmartAccountEvent {
+                new_settings_pubkey: Pubkey::new_unique(),
+                new_settings_content: settings(),
+            },
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_synchronous_transaction_event() {
+        round_trip(SmartAccountEvent::SynchronousTransactionEvent(
+            SynchronousTransactionEvent {
+                settings_pubkey: Pubkey::new_unique(),
+                account_index: 1,
+                signers: vec![Pubkey::new_unique()],
+                instructions: vec![SmartAccountCompiledInstruction {
+                    program_id_index: 2,
+                    account_indexes: vec![0, 1],
+                    data: vec![2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+                }],
+                instruction_accounts: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+            },
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_synchronous_settings_transaction_event() {
+        round_trip(SmartAccountEvent::SynchronousSettingsTransactionEvent(
+            SynchronousSettingsTransactionEvent {
+                settings_pubkey: Pubkey::new_unique(),
+                signers: vec![Pubkey::new_unique()],
+                settings: settings(),
+                changes: vec![SettingsAction::ChangeThreshold { new_threshold: 1 }],
+            },
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_authority_settings_event() {
+        round_trip(SmartAccountEvent::AuthoritySettingsEvent(
+            AuthoritySettingsEvent {
+                settings: settings(),
+                settings_pubkey: Pubkey::new_unique(),
+                authority: Pubkey::new_unique(),
+                change: SettingsAction::SetTimeLock { new_time_lock: 0 },
+            },
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_authority_change_event() {
+        round_trip(SmartAccountEvent::AuthorityChangeEvent(
+            AuthorityChangeEvent {
+                settings: settings(),
+                settings_pubkey: Pubkey::new_unique(),
+                authority: Pubkey::new_unique(),
+                new_authority: Some(Pubkey::new_unique()),
+            },
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_use_spending_limit_event() {
+        round_trip(SmartAccountEvent::UseSpendingLimitEvent(
+            UseSpendingLimitEvent {
+                settings_pubkey: Pubkey::new_unique(),
+                spending_limit_pubkey: Pubkey::new_unique(),
+                signer: Pubkey::new_unique(),
+                destination: Pubkey::new_unique(),
+                mint: Pubkey::default(),
+                amount: 10,
+                remaining_amount: 90,
+            },
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_emergency_exit_event() {
+        round_trip(SmartAccountEvent::EmergencyExitEvent(EmergencyExitEvent {
+            settings_pubkey: Pubkey::new_unique(),
+            restricted_signer: Pubkey::new_unique(),
+            account_index: 0,
+            smart_account: Pubkey::new_unique(),
+            exit_destination: Pubkey::new_unique(),
+            lamports: 1_000,
+            token_transfers: vec![EmergencyExitTokenTransfer {
+                mint: Pubkey::new_unique(),
+                amount: 5,
+            }],
+        }));
+    }
+
+    #[test]
+    fn test_round_trip_archive_settings_event() {
+        round_trip(SmartAccountEvent::ArchiveSettingsEvent(
+            ArchiveSettingsEvent {
+                settings_pubkey: Pubkey::new_unique(),
+                archival_authority: Pubkey::new_unique(),
+                archived_settings_pubkey: Pubkey::new_unique(),
+                settings_hash: [7; 32],
+                settings: settings(),
+            },
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_unarchive_settings_event() {
+        round_trip(SmartAccountEvent::UnarchiveSettingsEvent(
+            UnarchiveSettingsEvent {
+                settings_pubkey: Pubkey::new_unique(),
+                archived_settings_pubkey: Pubkey::new_unique(),
+                settings: settings(),
+            },
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_transaction_event() {
+        let transaction_event = |event_type, transaction_content| {
+            SmartAccountEvent::TransactionEvent(TransactionEvent {
+                event_type,
+                settings_pubkey: Pubkey::new_unique(),
+                transaction_pubkey: Pubkey::new_unique(),
+                transaction_index: 3,
+                signer: Some(Pubkey::new_unique()),
+                memo: Some("memo".to_string()),
+                transaction_content,
+            })
+        };
+        round_trip(transaction_event(
+            TransactionEventType::Create,
+            TransactionContent::Transaction {
+                account_index: 0,
+                instructions: instructions(),
+            },
+        ));
+        round_trip(transaction_event(
+            TransactionEventType::Execute,
+            TransactionContent::SettingsTransaction {
+                actions: vec![SettingsAction::SetRentCollector {
+                    new_rent_collector: None,
+                }],
+            },
+        ));
+        round_trip(transaction_event(
+            TransactionEventType::Close,
+            TransactionContent::Batch {
+                account_index: 0,
+                size: 2,
+            },
+        ));
+        round_trip(transaction_event(
+            TransactionEventType::Skip,
+            TransactionContent::BatchTransaction {
+                batch_transaction_index: 1,
+                outcome: BatchTransactionOutcome::Skipped,
+                instructions: instructions(),
+            },
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_proposal_event() {
+        round_trip(SmartAccountEvent::ProposalEvent(ProposalEvent {
+            event_type: ProposalEventType::Approve,
+            settings_pubkey: Pubkey::new_unique(),
+            proposal_pubkey: Pubkey::new_unique(),
+            transaction_index: 3,
+            signer: Some(Pubkey::new_unique()),
+            memo: None,
+            status: ProposalStatus::Approved {
+                timestamp: 1_700_000_000,
+            },
+            tally: VoteTally {
+                approvals: 2,
+                rejections: 0,
+                cancellations: 0,
+                approval_weight: 3,
+                rejection_weight: 0,
+                cancellation_weight: 0,
+                threshold: 3,
+                cutoff: 2,
+            },
+        }));
+    }
+
+    #[test]
+    fn test_round_trip_program_config_event() {
+        round_trip(SmartAccountEvent::ProgramConfigEvent(ProgramConfigEvent {
+            event_type: ProgramConfigEventType::AcceptTransfer,
+            program_config_pubkey: Pubkey::new_unique(),
+            transfer_kind: ProgramConfigTransferKind::Treasury,
+            old_key: Pubkey::new_unique(),
+            new_key: Pubkey::new_unique(),
+            signer: Pubkey::new_unique(),
+        }));
+    }
+
+    #[test]
+    fn test_decode_appended_fields() {
+        let event = SmartAccountEvent::UseSpendingLimitEvent(UseSpendingLimitEvent {
+            settings_pubkey: Pubkey::new_unique(),
+            spending_limit_pubkey: Pubkey::new_unique(),
+            signer: Pubkey::new_unique(),
+            destination: Pubkey::new_unique(),
+            mint: Pubkey::default(),
+            amount: 10,
+            remaining_amount: 90,
+        });
+        let envelope = decode_log_event(&log_event_data(&event, &[1, 2, 3])).unwrap();
+        assert_eq!(envelope.decode_event().unwrap(), Some(event));
+    }
+
+    #[test]
+    fn test_decode_unknown_event() {
+        let mut data = log_event_data(
+            &SmartAccountEvent::ArchiveSettingsEvent(ArchiveSettingsEvent {
+                settings_pubkey: Pubkey::new_unique(),
+                archival_authority: Pubkey::new_unique(),
+                archived_settings_pubkey: Pubkey::new_unique(),
+                settings_hash: [0; 32],
+                settings: settings(),
+            }),
+            &[],
+        );
+        let mut envelope = decode_log_event(&data).unwrap();
+        envelope.event_type = u8::MAX;
+        assert_eq!(envelope.decode_event().unwrap(), None);
+
+        data[0] = 0;
+        assert!(matches!(
+            decode_log_event(&data),
+            Err(EventDecodeError::NotLogEventInstruction)
+        ));
+    }
+}
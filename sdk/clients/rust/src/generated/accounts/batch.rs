@@ -26,6 +26,7 @@ pub struct Batch {
     )]
     pub creator: Pubkey,
     /// The rent collector for the batch account.
+    /// Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
@@ -41,16 +42,19 @@ pub struct Batch {
     pub account_bump: u8,
     /// Number of transactions in the batch.
     pub size: u32,
-    /// Index of the last executed transaction within the batch.
+    /// Index of the last executed or skipped transaction within the batch.
     /// 0 means that no transactions have been executed yet.
     pub executed_transaction_index: u32,
+    /// The longest time lock of the programs the transactions of the batch touch,
+    /// from the settings `target_time_locks` captured at the creation of each transaction.
+    pub time_lock: u32,
+    /// Indexes of the transactions within the batch that were skipped instead of executed, in ascending order.
+    pub skipped_transaction_indexes: Vec<u32>,
 }
 
 pub const BATCH_DISCRIMINATOR: [u8; 8] = [156, 194, 70, 44, 22, 88, 137, 44];
 
 impl Batch {
-    pub const LEN: usize = 123;
-
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
@@ -149,7 +153,7 @@ impl anchor_lang::AccountSerialize for Batch {}
 
 #[cfg(feature = "anchor")]
 impl anchor_lang::Owner for Batch {
-    fn owner() -> Pubkey {
+    fn owner() -> solana_pubkey::Pubkey {
         crate::ASTROLABE_SMART_ACCOUNT_ID
     }
 }
@@ -17,6 +17,7 @@ pub struct BatchTransaction {
     /// PDA bump.
     pub bump: u8,
     /// The rent collector for the batch transaction account.
+    /// Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
@@ -135,7 +136,7 @@ impl anchor_lang::AccountSerialize for BatchTransaction {}
 
 #[cfg(feature = "anchor")]
 impl anchor_lang::Owner for BatchTransaction {
-    fn owner() -> Pubkey {
+    fn owner() -> solana_pubkey::Pubkey {
         crate::ASTROLABE_SMART_ACCOUNT_ID
     }
 }
@@ -5,20 +5,38 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#archived_settings;
 pub(crate) mod r#batch;
 pub(crate) mod r#batch_transaction;
+pub(crate) mod r#creator_allowlist_page;
+pub(crate) mod r#passkey;
 pub(crate) mod r#program_config;
+pub(crate) mod r#program_config_transfer;
 pub(crate) mod r#proposal;
+pub(crate) mod r#recovery;
+pub(crate) mod r#session_key;
 pub(crate) mod r#settings;
 pub(crate) mod r#settings_transaction;
+pub(crate) mod r#signer_rotation;
+pub(crate) mod r#spending_limit;
 pub(crate) mod r#transaction;
 pub(crate) mod r#transaction_buffer;
+pub(crate) mod r#vault_policy;
 
+pub use self::r#archived_settings::*;
 pub use self::r#batch::*;
 pub use self::r#batch_transaction::*;
+pub use self::r#creator_allowlist_page::*;
+pub use self::r#passkey::*;
 pub use self::r#program_config::*;
+pub use self::r#program_config_transfer::*;
 pub use self::r#proposal::*;
+pub use self::r#recovery::*;
+pub use self::r#session_key::*;
 pub use self::r#settings::*;
 pub use self::r#settings_transaction::*;
+pub use self::r#signer_rotation::*;
+pub use self::r#spending_limit::*;
 pub use self::r#transaction::*;
 pub use self::r#transaction_buffer::*;
+pub use self::r#vault_policy::*;
@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::SmartAccountCreationMode;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_pubkey::Pubkey;
@@ -30,9 +31,23 @@ pub struct ProgramConfig {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub treasury: Pubkey,
+    /// The SPL mint the `smart_account_creation_token_fee` is charged in.
+    /// `Pubkey::default()` if no token fee is set.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub creation_fee_mint: Pubkey,
+    /// The amount of `creation_fee_mint` tokens charged for creating a new smart account,
+    /// on top of `smart_account_creation_fee`. This fee is sent to the `treasury` token account.
+    pub smart_account_creation_token_fee: u64,
+    /// The share of the creation fees, in basis points, that is sent to the referrer
+    /// of a new smart account instead of the `treasury`.
+    pub referral_fee_bps: u16,
+    /// Who can create new smart accounts.
+    pub smart_account_creation_mode: SmartAccountCreationMode,
     /// Reserved for future use.
-    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 64],
+    pub reserved: [u8; 21],
 }
 
 pub const PROGRAM_CONFIG_DISCRIMINATOR: [u8; 8] = [196, 210, 90, 231, 144, 149, 140, 63];
@@ -138,7 +153,7 @@ impl anchor_lang::AccountSerialize for ProgramConfig {}
 
 #[cfg(feature = "anchor")]
 impl anchor_lang::Owner for ProgramConfig {
-    fn owner() -> Pubkey {
+    fn owner() -> solana_pubkey::Pubkey {
         crate::ASTROLABE_SMART_ACCOUNT_ID
     }
 }
@@ -23,6 +23,7 @@ pub struct Proposal {
     /// Index of the smart account transaction this proposal is associated with.
     pub transaction_index: u64,
     /// The rent collector for the proposal account.
+    /// Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
@@ -30,6 +31,9 @@ pub struct Proposal {
     pub rent_collector: Pubkey,
     /// The status of the transaction.
     pub status: ProposalStatus,
+    /// Unix timestamp after which the proposal can no longer be voted on.
+    /// `None` means the proposal can be voted on until it's approved, rejected or becomes stale.
+    pub voting_deadline: Option<i64>,
     /// PDA bump.
     pub bump: u8,
     /// Keys that have approved/signed.
@@ -153,7 +157,7 @@ impl anchor_lang::AccountSerialize for Proposal {}
 
 #[cfg(feature = "anchor")]
 impl anchor_lang::Owner for Proposal {
-    fn owner() -> Pubkey {
+    fn owner() -> solana_pubkey::Pubkey {
         crate::ASTROLABE_SMART_ACCOUNT_ID
     }
 }
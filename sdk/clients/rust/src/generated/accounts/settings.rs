@@ -5,8 +5,11 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::ProgramFilter;
+use crate::generated::types::ProgramFilterMode;
 use crate::generated::types::RestrictedSmartAccountSigner;
 use crate::generated::types::SmartAccountSigner;
+use crate::generated::types::TargetTimeLock;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_pubkey::Pubkey;
@@ -20,7 +23,8 @@ pub struct Settings {
     /// to ensure uniqueness of each settings PDA without relying on user input.
     ///
     /// Note: As this represents a DOS vector in the current creation architecture,
-    /// account creation will be permissioned until compression is implemented.
+    /// account creation can be permissioned with the program config `smart_account_creation_mode`
+    /// until compression is implemented.
     pub seed: u128,
     /// The authority that can change the smart account settings.
     /// This is a very important parameter as this authority can change the signers and threshold.
@@ -36,7 +40,7 @@ pub struct Settings {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub settings_authority: Pubkey,
-    /// Threshold for signatures.
+    /// Threshold for signatures, as the voting weight of the signers that approved.
     pub threshold: u16,
     /// How many seconds must pass between transaction voting settlement and execution.
     pub time_lock: u32,
@@ -45,20 +49,45 @@ pub struct Settings {
     /// Last stale transaction index. All transactions up until this index are stale.
     /// This index is updated when smart account settings (signers/threshold/time_lock) change.
     pub stale_transaction_index: u64,
-    /// Field reserved for when archival/compression is implemented.
-    /// Will be set to Pubkey::default() to mark accounts that should
-    /// be eligible for archival before the feature is implemented.
+    /// The address where the rent for the accounts related to executed, rejected, or cancelled
+    /// transactions can be reclaimed. If set to `None`, the rent reclamation feature is turned off.
+    pub rent_collector: Option<Pubkey>,
+    /// The authority that can archive the settings account with `archive_settings` to reclaim its rent.
+    /// `None` and `Pubkey::default()` both mean that the smart account cannot be archived.
     pub archival_authority: Option<Pubkey>,
-    /// Field that will prevent a smart account from being archived immediately after unarchival.
+    /// Unix timestamp before which the smart account cannot be archived.
+    /// Set to `ARCHIVAL_COOLDOWN` seconds after the moment of unarchival.
     /// This is to prevent a DOS vector where the archival authority could
     /// constantly unarchive and archive the smart account to prevent it from
     /// being used.
     pub archivable_after: u64,
+    /// How many guardians (restricted signers with the `Recover` permission) must approve a recovery.
+    /// `0` means that recovery is disabled.
+    pub recovery_threshold: u16,
+    /// How many seconds the current signers have to veto a recovery once it's approved by the guardians.
+    pub recovery_delay: u32,
+    /// Bitmap of the account indices that have a `VaultPolicy`, bit `i % 8` of byte `i / 8` for account index `i`.
+    /// Used to require the `VaultPolicy` account whenever one exists for the smart account being used.
+    pub vault_policies: [u8; 32],
+    /// How many seconds an approved proposal can be executed for after its approval.
+    /// It includes the time lock, so it should be longer than the time lock of the smart accounts.
+    /// `0` means approved proposals can be executed at any time.
+    pub execution_window: u32,
+    /// How many seconds must pass between a signer requesting the rotation of its own key and the rotation,
+    /// giving the other signers time to cancel it. `None` means that signers can't rotate their own keys.
+    pub signer_rotation_delay: Option<u32>,
+    /// Whether `program_filters` are the only instructions the smart accounts can invoke, or the ones they can't.
+    pub program_filter_mode: ProgramFilterMode,
     /// Bump for the smart account PDA seed.
     pub bump: u8,
     /// Signers attached to the smart account
     pub signers: Vec<SmartAccountSigner>,
     pub restricted_signers: Vec<RestrictedSmartAccountSigner>,
+    /// Time locks of specific programs or settings actions, sorted by target.
+    /// A transaction is time locked for the longest of `time_lock` and the time locks of the targets it touches.
+    pub target_time_locks: Vec<TargetTimeLock>,
+    /// Program instructions the smart accounts are allowed or denied to invoke, sorted, see `program_filter_mode`.
+    pub program_filters: Vec<ProgramFilter>,
     /// Counter for how many sub accounts are in use (improves off-chain indexing)
     pub account_utilization: u8,
     pub reserved1: u8,
@@ -166,7 +195,7 @@ impl anchor_lang::AccountSerialize for Settings {}
 
 #[cfg(feature = "anchor")]
 impl anchor_lang::Owner for Settings {
-    fn owner() -> Pubkey {
+    fn owner() -> solana_pubkey::Pubkey {
         crate::ASTROLABE_SMART_ACCOUNT_ID
     }
 }
@@ -27,6 +27,7 @@ pub struct SettingsTransaction {
     )]
     pub creator: Pubkey,
     /// The rent collector for the settings transaction account.
+    /// Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
@@ -36,6 +37,9 @@ pub struct SettingsTransaction {
     pub index: u64,
     /// bump for the transaction seeds.
     pub bump: u8,
+    /// The longest time lock of the actions the transaction touches, from the settings `target_time_locks`.
+    /// Those can't change without making the transaction stale, so it's the same as at approval time.
+    pub time_lock: u32,
     /// Action to be performed on the settings.
     pub actions: Vec<SettingsAction>,
 }
@@ -141,7 +145,7 @@ impl anchor_lang::AccountSerialize for SettingsTransaction {}
 
 #[cfg(feature = "anchor")]
 impl anchor_lang::Owner for SettingsTransaction {
-    fn owner() -> Pubkey {
+    fn owner() -> solana_pubkey::Pubkey {
         crate::ASTROLABE_SMART_ACCOUNT_ID
     }
 }
@@ -27,6 +27,7 @@ pub struct Transaction {
     )]
     pub creator: Pubkey,
     /// The rent collector for the transaction account.
+    /// Set to the settings `rent_collector` on creation, or to the fee payer if rent reclamation is disabled.
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
@@ -40,6 +41,9 @@ pub struct Transaction {
     pub account_index: u8,
     /// Derivation bump of the smart account PDA this transaction belongs to.
     pub account_bump: u8,
+    /// The longest time lock of the programs the transaction touches,
+    /// from the settings `target_time_locks` captured at creation.
+    pub time_lock: u32,
     /// Derivation bumps for additional signers.
     /// Some transactions require multiple signers. Often these additional signers are "ephemeral" keypairs
     /// that are generated on the client with a sole purpose of signing the transaction and be discarded immediately after.
@@ -154,7 +158,7 @@ impl anchor_lang::AccountSerialize for Transaction {}
 
 #[cfg(feature = "anchor")]
 impl anchor_lang::Owner for Transaction {
-    fn owner() -> Pubkey {
+    fn owner() -> solana_pubkey::Pubkey {
         crate::ASTROLABE_SMART_ACCOUNT_ID
     }
 }
@@ -25,6 +25,16 @@ pub struct TransactionBuffer {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub creator: Pubkey,
+    /// The account that paid the rent for the TransactionBuffer, refunded when it is closed by others.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub rent_payer: Pubkey,
+    /// Timestamp when the TransactionBuffer was created.
+    pub created_at: i64,
+    /// `transaction_index` of the settings when the TransactionBuffer was created.
+    pub transaction_index: u64,
     /// Index to seed address derivation
     pub buffer_index: u8,
     /// Smart account index of the transaction this buffer belongs to.
@@ -138,7 +148,7 @@ impl anchor_lang::AccountSerialize for TransactionBuffer {}
 
 #[cfg(feature = "anchor")]
 impl anchor_lang::Owner for TransactionBuffer {
-    fn owner() -> Pubkey {
+    fn owner() -> solana_pubkey::Pubkey {
         crate::ASTROLABE_SMART_ACCOUNT_ID
     }
 }
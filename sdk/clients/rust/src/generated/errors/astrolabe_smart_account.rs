@@ -133,8 +133,8 @@ pub enum AstrolabeSmartAccountError {
     /// 6040 - Final message buffer hash doesnt match the expected hash
     #[error("Final message buffer hash doesnt match the expected hash")]
     FinalBufferHashMismatch = 0x1798,
-    /// 6041 - Final buffer size cannot exceed 4000 bytes
-    #[error("Final buffer size cannot exceed 4000 bytes")]
+    /// 6041 - Final buffer size cannot exceed 9216 bytes
+    #[error("Final buffer size cannot exceed 9216 bytes")]
     FinalBufferSizeExceeded = 0x1799,
     /// 6042 - Final buffer size mismatch
     #[error("Final buffer size mismatch")]
@@ -163,6 +163,144 @@ pub enum AstrolabeSmartAccountError {
     /// 6050 - Feature not implemented
     #[error("Feature not implemented")]
     NotImplemented = 0x17A2,
+    /// 6051 - Spending limit amount must be greater than 0
+    #[error("Spending limit amount must be greater than 0")]
+    SpendingLimitInvalidAmount = 0x17A3,
+    /// 6052 - Spending limit exceeded
+    #[error("Spending limit exceeded")]
+    SpendingLimitExceeded = 0x17A4,
+    /// 6053 - Spending limit is expired
+    #[error("Spending limit is expired")]
+    SpendingLimitExpired = 0x17A5,
+    /// 6054 - Signer is not a restricted signer with the required permission
+    #[error("Signer is not a restricted signer with the required permission")]
+    UnauthorizedRestrictedSigner = 0x17A6,
+    /// 6055 - Restricted signer with the EmergencyExit permission must have an exit destination
+    #[error("Restricted signer with the EmergencyExit permission must have an exit destination")]
+    MissingExitDestination = 0x17A7,
+    /// 6056 - Smart account cannot be archived before `archivable_after`
+    #[error("Smart account cannot be archived before `archivable_after`")]
+    ArchivalCooldownNotElapsed = 0x17A8,
+    /// 6057 - Supplied settings do not match the archived settings hash
+    #[error("Supplied settings do not match the archived settings hash")]
+    ArchivedSettingsHashMismatch = 0x17A9,
+    /// 6058 - Invalid compressed secp256r1 public key
+    #[error("Invalid compressed secp256r1 public key")]
+    InvalidPasskeyPublicKey = 0x17AA,
+    /// 6059 - No secp256r1 signature by the passkey found in the transaction
+    #[error("No secp256r1 signature by the passkey found in the transaction")]
+    MissingPasskeySignature = 0x17AB,
+    /// 6060 - Invalid WebAuthn authenticator data
+    #[error("Invalid WebAuthn authenticator data")]
+    InvalidWebAuthnAuthenticatorData = 0x17AC,
+    /// 6061 - Invalid WebAuthn client data
+    #[error("Invalid WebAuthn client data")]
+    InvalidWebAuthnClientData = 0x17AD,
+    /// 6062 - Session key is expired
+    #[error("Session key is expired")]
+    SessionKeyExpired = 0x17AE,
+    /// 6063 - Session key is not allowed to perform this action
+    #[error("Session key is not allowed to perform this action")]
+    SessionKeyOutOfScope = 0x17AF,
+    /// 6064 - Session key lamports budget exceeded
+    #[error("Session key lamports budget exceeded")]
+    SessionKeyBudgetExceeded = 0x17B0,
+    /// 6065 - Recovery threshold must not exceed the number of guardians
+    #[error("Recovery threshold must not exceed the number of guardians")]
+    InvalidRecoveryThreshold = 0x17B1,
+    /// 6066 - Recovery is disabled for this smart account
+    #[error("Recovery is disabled for this smart account")]
+    RecoveryDisabled = 0x17B2,
+    /// 6067 - Recovery has not been approved by enough guardians
+    #[error("Recovery has not been approved by enough guardians")]
+    RecoveryNotApproved = 0x17B3,
+    /// 6068 - Recovery veto period has not elapsed
+    #[error("Recovery veto period has not elapsed")]
+    RecoveryDelayNotElapsed = 0x17B4,
+    /// 6069 - Guardian has already approved the recovery
+    #[error("Guardian has already approved the recovery")]
+    RecoveryAlreadyApproved = 0x17B5,
+    /// 6070 - Vault policy account is required for this smart account
+    #[error("Vault policy account is required for this smart account")]
+    MissingVaultPolicy = 0x17B6,
+    /// 6071 - Transaction has not been approved by enough signers of the vault policy
+    #[error("Transaction has not been approved by enough signers of the vault policy")]
+    VaultPolicyThresholdNotReached = 0x17B7,
+    /// 6072 - Voting deadline must be in the future
+    #[error("Voting deadline must be in the future")]
+    InvalidVotingDeadline = 0x17B8,
+    /// 6073 - Proposal voting deadline or execution window has passed
+    #[error("Proposal voting deadline or execution window has passed")]
+    ProposalExpired = 0x17B9,
+    /// 6074 - Proposal has not expired
+    #[error("Proposal has not expired")]
+    ProposalNotExpired = 0x17BA,
+    /// 6075 - Execution window must be longer than the time lock
+    #[error("Execution window must be longer than the time lock")]
+    InvalidExecutionWindow = 0x17BB,
+    /// 6076 - Found multiple time locks for the same target
+    #[error("Found multiple time locks for the same target")]
+    DuplicateTargetTimeLock = 0x17BC,
+    /// 6077 - Target has no time lock
+    #[error("Target has no time lock")]
+    MissingTargetTimeLock = 0x17BD,
+    /// 6078 - Signer rotation is disabled for this smart account
+    #[error("Signer rotation is disabled for this smart account")]
+    SignerRotationDisabled = 0x17BE,
+    /// 6079 - Signer rotation delay has not elapsed yet
+    #[error("Signer rotation delay has not elapsed yet")]
+    SignerRotationDelayNotElapsed = 0x17BF,
+    /// 6080 - Program instruction is not allowed by the program filters of the smart account
+    #[error("Program instruction is not allowed by the program filters of the smart account")]
+    ProgramNotAllowed = 0x17C0,
+    /// 6081 - Found multiple program filters for the same instructions
+    #[error("Found multiple program filters for the same instructions")]
+    DuplicateProgramFilter = 0x17C1,
+    /// 6082 - Program filter not found
+    #[error("Program filter not found")]
+    MissingProgramFilter = 0x17C2,
+    /// 6083 - Program filter discriminator is too long
+    #[error("Program filter discriminator is too long")]
+    InvalidProgramFilter = 0x17C3,
+    /// 6084 - Transaction buffer has not timed out, gone stale or lost its creator
+    #[error("Transaction buffer has not timed out, gone stale or lost its creator")]
+    TransactionBufferNotAbandoned = 0x17C4,
+    /// 6085 - Referral fee can't exceed 10000 basis points
+    #[error("Referral fee can't exceed 10000 basis points")]
+    InvalidReferralFee = 0x17C5,
+    /// 6086 - Referrer account doesn't match the referrer of the smart account
+    #[error("Referrer account doesn't match the referrer of the smart account")]
+    InvalidReferrer = 0x17C6,
+    /// 6087 - Creator is not on the smart account creation allowlist
+    #[error("Creator is not on the smart account creation allowlist")]
+    CreatorNotAllowlisted = 0x17C7,
+    /// 6088 - Creator is already on the smart account creation allowlist
+    #[error("Creator is already on the smart account creation allowlist")]
+    CreatorAlreadyAllowlisted = 0x17C8,
+    /// 6089 - Creator allowlist page is full
+    #[error("Creator allowlist page is full")]
+    CreatorAllowlistPageFull = 0x17C9,
+    /// 6090 - Smart account creation must be cosigned by the program config authority
+    #[error("Smart account creation must be cosigned by the program config authority")]
+    CreationNotCosigned = 0x17CA,
+    /// 6091 - Program config transfer was proposed by a previous authority
+    #[error("Program config transfer was proposed by a previous authority")]
+    ProgramConfigTransferStale = 0x17CB,
+    /// 6092 - Program config transfer is for another parameter
+    #[error("Program config transfer is for another parameter")]
+    ProgramConfigTransferKindMismatch = 0x17CC,
+    /// 6093 - Transaction account can't grow by more than 10240 bytes within an instruction
+    #[error("Transaction account can't grow by more than 10240 bytes within an instruction")]
+    TransactionSizeExceeded = 0x17CD,
+    /// 6094 - Arithmetic overflow
+    #[error("Arithmetic overflow")]
+    Overflow = 0x17CE,
+    /// 6095 - Settings account is already in the current layout
+    #[error("Settings account is already in the current layout")]
+    SettingsAlreadyMigrated = 0x17CF,
+    /// 6096 - Settings are too large to be restored once archived
+    #[error("Settings are too large to be restored once archived")]
+    SettingsTooLargeToArchive = 0x17D0,
 }
 
 impl From<AstrolabeSmartAccountError> for solana_program_error::ProgramError {
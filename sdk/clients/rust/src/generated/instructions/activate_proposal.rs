@@ -18,6 +18,13 @@ pub struct ActivateProposal {
     pub signer: solana_pubkey::Pubkey,
 
     pub proposal: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    pub transaction: Option<solana_pubkey::Pubkey>,
+    /// The policy of the smart account the transaction is for, required if it has one.
+    pub vault_policy: Option<solana_pubkey::Pubkey>,
 }
 
 impl ActivateProposal {
@@ -30,13 +37,39 @@ impl ActivateProposal {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.settings,
             false,
         ));
         accounts.push(solana_instruction::AccountMeta::new(self.signer, true));
         accounts.push(solana_instruction::AccountMeta::new(self.proposal, false));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
+        if let Some(transaction) = self.transaction {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                transaction,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(vault_policy) = self.vault_policy {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                vault_policy,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = ActivateProposalInstructionData::new().try_to_vec().unwrap();
 
@@ -79,11 +112,17 @@ impl Default for ActivateProposalInstructionData {
 ///   0. `[]` settings
 ///   1. `[writable, signer]` signer
 ///   2. `[writable]` proposal
+///   3. `[optional]` program (default to `aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh`)
+///   4. `[optional]` transaction
+///   5. `[optional]` vault_policy
 #[derive(Clone, Debug, Default)]
 pub struct ActivateProposalBuilder {
     settings: Option<solana_pubkey::Pubkey>,
     signer: Option<solana_pubkey::Pubkey>,
     proposal: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
+    transaction: Option<solana_pubkey::Pubkey>,
+    vault_policy: Option<solana_pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -106,6 +145,27 @@ impl ActivateProposalBuilder {
         self.proposal = Some(proposal);
         self
     }
+    /// `[optional account, default to 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    /// `[optional account]`
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    #[inline(always)]
+    pub fn transaction(&mut self, transaction: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.transaction = transaction;
+        self
+    }
+    /// `[optional account]`
+    /// The policy of the smart account the transaction is for, required if it has one.
+    #[inline(always)]
+    pub fn vault_policy(&mut self, vault_policy: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.vault_policy = vault_policy;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -127,6 +187,11 @@ impl ActivateProposalBuilder {
             settings: self.settings.expect("settings is not set"),
             signer: self.signer.expect("signer is not set"),
             proposal: self.proposal.expect("proposal is not set"),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh"
+            )),
+            transaction: self.transaction,
+            vault_policy: self.vault_policy,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -140,6 +205,13 @@ pub struct ActivateProposalCpiAccounts<'a, 'b> {
     pub signer: &'b solana_account_info::AccountInfo<'a>,
 
     pub proposal: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    pub transaction: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The policy of the smart account the transaction is for, required if it has one.
+    pub vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `activate_proposal` CPI instruction.
@@ -152,6 +224,13 @@ pub struct ActivateProposalCpi<'a, 'b> {
     pub signer: &'b solana_account_info::AccountInfo<'a>,
 
     pub proposal: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    pub transaction: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The policy of the smart account the transaction is for, required if it has one.
+    pub vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> ActivateProposalCpi<'a, 'b> {
@@ -164,6 +243,9 @@ impl<'a, 'b> ActivateProposalCpi<'a, 'b> {
             settings: accounts.settings,
             signer: accounts.signer,
             proposal: accounts.proposal,
+            program: accounts.program,
+            transaction: accounts.transaction,
+            vault_policy: accounts.vault_policy,
         }
     }
     #[inline(always)]
@@ -189,7 +271,7 @@ impl<'a, 'b> ActivateProposalCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.settings.key,
             false,
@@ -199,6 +281,32 @@ impl<'a, 'b> ActivateProposalCpi<'a, 'b> {
             *self.proposal.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
+        if let Some(transaction) = self.transaction {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *transaction.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(vault_policy) = self.vault_policy {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *vault_policy.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -213,11 +321,18 @@ impl<'a, 'b> ActivateProposalCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.settings.clone());
         account_infos.push(self.signer.clone());
         account_infos.push(self.proposal.clone());
+        account_infos.push(self.program.clone());
+        if let Some(transaction) = self.transaction {
+            account_infos.push(transaction.clone());
+        }
+        if let Some(vault_policy) = self.vault_policy {
+            account_infos.push(vault_policy.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -237,6 +352,9 @@ impl<'a, 'b> ActivateProposalCpi<'a, 'b> {
 ///   0. `[]` settings
 ///   1. `[writable, signer]` signer
 ///   2. `[writable]` proposal
+///   3. `[]` program
+///   4. `[optional]` transaction
+///   5. `[optional]` vault_policy
 #[derive(Clone, Debug)]
 pub struct ActivateProposalCpiBuilder<'a, 'b> {
     instruction: Box<ActivateProposalCpiBuilderInstruction<'a, 'b>>,
@@ -249,6 +367,9 @@ impl<'a, 'b> ActivateProposalCpiBuilder<'a, 'b> {
             settings: None,
             signer: None,
             proposal: None,
+            program: None,
+            transaction: None,
+            vault_policy: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -268,6 +389,32 @@ impl<'a, 'b> ActivateProposalCpiBuilder<'a, 'b> {
         self.instruction.proposal = Some(proposal);
         self
     }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    /// `[optional account]`
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    #[inline(always)]
+    pub fn transaction(
+        &mut self,
+        transaction: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.transaction = transaction;
+        self
+    }
+    /// `[optional account]`
+    /// The policy of the smart account the transaction is for, required if it has one.
+    #[inline(always)]
+    pub fn vault_policy(
+        &mut self,
+        vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_policy = vault_policy;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -310,6 +457,12 @@ impl<'a, 'b> ActivateProposalCpiBuilder<'a, 'b> {
             signer: self.instruction.signer.expect("signer is not set"),
 
             proposal: self.instruction.proposal.expect("proposal is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
+
+            transaction: self.instruction.transaction,
+
+            vault_policy: self.instruction.vault_policy,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -324,6 +477,9 @@ struct ActivateProposalCpiBuilderInstruction<'a, 'b> {
     settings: Option<&'b solana_account_info::AccountInfo<'a>>,
     signer: Option<&'b solana_account_info::AccountInfo<'a>>,
     proposal: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    transaction: Option<&'b solana_account_info::AccountInfo<'a>>,
+    vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
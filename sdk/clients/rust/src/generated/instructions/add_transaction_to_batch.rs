@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::AddTransactionToBatchArgs;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -27,6 +28,8 @@ pub struct AddTransactionToBatch {
     pub fee_payer: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl AddTransactionToBatch {
@@ -43,7 +46,7 @@ impl AddTransactionToBatch {
         args: AddTransactionToBatchInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.settings,
             false,
@@ -66,6 +69,10 @@ impl AddTransactionToBatch {
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = AddTransactionToBatchInstructionData::new()
             .try_to_vec()
@@ -108,8 +115,7 @@ impl Default for AddTransactionToBatchInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddTransactionToBatchInstructionArgs {
-    pub ephemeral_signers: u8,
-    pub transaction_message: Vec<u8>,
+    pub args: AddTransactionToBatchArgs,
 }
 
 impl AddTransactionToBatchInstructionArgs {
@@ -129,6 +135,7 @@ impl AddTransactionToBatchInstructionArgs {
 ///   4. `[signer]` signer
 ///   5. `[writable, signer]` fee_payer
 ///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   7. `[optional]` program (default to `aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh`)
 #[derive(Clone, Debug, Default)]
 pub struct AddTransactionToBatchBuilder {
     settings: Option<solana_pubkey::Pubkey>,
@@ -138,8 +145,8 @@ pub struct AddTransactionToBatchBuilder {
     signer: Option<solana_pubkey::Pubkey>,
     fee_payer: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
-    ephemeral_signers: Option<u8>,
-    transaction_message: Option<Vec<u8>>,
+    program: Option<solana_pubkey::Pubkey>,
+    args: Option<AddTransactionToBatchArgs>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -188,14 +195,15 @@ impl AddTransactionToBatchBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account, default to 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh']`
     #[inline(always)]
-    pub fn ephemeral_signers(&mut self, ephemeral_signers: u8) -> &mut Self {
-        self.ephemeral_signers = Some(ephemeral_signers);
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
         self
     }
     #[inline(always)]
-    pub fn transaction_message(&mut self, transaction_message: Vec<u8>) -> &mut Self {
-        self.transaction_message = Some(transaction_message);
+    pub fn args(&mut self, args: AddTransactionToBatchArgs) -> &mut Self {
+        self.args = Some(args);
         self
     }
     /// Add an additional account to the instruction.
@@ -225,16 +233,12 @@ impl AddTransactionToBatchBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh"
+            )),
         };
         let args = AddTransactionToBatchInstructionArgs {
-            ephemeral_signers: self
-                .ephemeral_signers
-                .clone()
-                .expect("ephemeral_signers is not set"),
-            transaction_message: self
-                .transaction_message
-                .clone()
-                .expect("transaction_message is not set"),
+            args: self.args.clone().expect("args is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -257,6 +261,8 @@ pub struct AddTransactionToBatchCpiAccounts<'a, 'b> {
     pub fee_payer: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `add_transaction_to_batch` CPI instruction.
@@ -277,6 +283,8 @@ pub struct AddTransactionToBatchCpi<'a, 'b> {
     pub fee_payer: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: AddTransactionToBatchInstructionArgs,
 }
@@ -296,6 +304,7 @@ impl<'a, 'b> AddTransactionToBatchCpi<'a, 'b> {
             signer: accounts.signer,
             fee_payer: accounts.fee_payer,
             system_program: accounts.system_program,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -322,7 +331,7 @@ impl<'a, 'b> AddTransactionToBatchCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.settings.key,
             false,
@@ -348,6 +357,10 @@ impl<'a, 'b> AddTransactionToBatchCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -366,7 +379,7 @@ impl<'a, 'b> AddTransactionToBatchCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.settings.clone());
         account_infos.push(self.proposal.clone());
@@ -375,6 +388,7 @@ impl<'a, 'b> AddTransactionToBatchCpi<'a, 'b> {
         account_infos.push(self.signer.clone());
         account_infos.push(self.fee_payer.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -398,6 +412,7 @@ impl<'a, 'b> AddTransactionToBatchCpi<'a, 'b> {
 ///   4. `[signer]` signer
 ///   5. `[writable, signer]` fee_payer
 ///   6. `[]` system_program
+///   7. `[]` program
 #[derive(Clone, Debug)]
 pub struct AddTransactionToBatchCpiBuilder<'a, 'b> {
     instruction: Box<AddTransactionToBatchCpiBuilderInstruction<'a, 'b>>,
@@ -414,8 +429,8 @@ impl<'a, 'b> AddTransactionToBatchCpiBuilder<'a, 'b> {
             signer: None,
             fee_payer: None,
             system_program: None,
-            ephemeral_signers: None,
-            transaction_message: None,
+            program: None,
+            args: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -467,13 +482,13 @@ impl<'a, 'b> AddTransactionToBatchCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn ephemeral_signers(&mut self, ephemeral_signers: u8) -> &mut Self {
-        self.instruction.ephemeral_signers = Some(ephemeral_signers);
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
         self
     }
     #[inline(always)]
-    pub fn transaction_message(&mut self, transaction_message: Vec<u8>) -> &mut Self {
-        self.instruction.transaction_message = Some(transaction_message);
+    pub fn args(&mut self, args: AddTransactionToBatchArgs) -> &mut Self {
+        self.instruction.args = Some(args);
         self
     }
     /// Add an additional account to the instruction.
@@ -511,16 +526,7 @@ impl<'a, 'b> AddTransactionToBatchCpiBuilder<'a, 'b> {
     #[allow(clippy::vec_init_then_push)]
     pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
         let args = AddTransactionToBatchInstructionArgs {
-            ephemeral_signers: self
-                .instruction
-                .ephemeral_signers
-                .clone()
-                .expect("ephemeral_signers is not set"),
-            transaction_message: self
-                .instruction
-                .transaction_message
-                .clone()
-                .expect("transaction_message is not set"),
+            args: self.instruction.args.clone().expect("args is not set"),
         };
         let instruction = AddTransactionToBatchCpi {
             __program: self.instruction.__program,
@@ -544,6 +550,8 @@ impl<'a, 'b> AddTransactionToBatchCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -563,8 +571,8 @@ struct AddTransactionToBatchCpiBuilderInstruction<'a, 'b> {
     signer: Option<&'b solana_account_info::AccountInfo<'a>>,
     fee_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
-    ephemeral_signers: Option<u8>,
-    transaction_message: Option<Vec<u8>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    args: Option<AddTransactionToBatchArgs>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -21,6 +21,13 @@ pub struct CancelProposal {
     pub proposal: solana_pubkey::Pubkey,
 
     pub system_program: Option<solana_pubkey::Pubkey>,
+
+    pub program: solana_pubkey::Pubkey,
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    pub transaction: Option<solana_pubkey::Pubkey>,
+    /// The policy of the smart account the transaction is for, required if it has one.
+    pub vault_policy: Option<solana_pubkey::Pubkey>,
 }
 
 impl CancelProposal {
@@ -37,7 +44,7 @@ impl CancelProposal {
         args: CancelProposalInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.settings,
             false,
@@ -55,6 +62,32 @@ impl CancelProposal {
                 false,
             ));
         }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
+        if let Some(transaction) = self.transaction {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                transaction,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(vault_policy) = self.vault_policy {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                vault_policy,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = CancelProposalInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -112,12 +145,18 @@ impl CancelProposalInstructionArgs {
 ///   1. `[writable, signer]` signer
 ///   2. `[writable]` proposal
 ///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[optional]` program (default to `aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh`)
+///   5. `[optional]` transaction
+///   6. `[optional]` vault_policy
 #[derive(Clone, Debug, Default)]
 pub struct CancelProposalBuilder {
     settings: Option<solana_pubkey::Pubkey>,
     signer: Option<solana_pubkey::Pubkey>,
     proposal: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
+    transaction: Option<solana_pubkey::Pubkey>,
+    vault_policy: Option<solana_pubkey::Pubkey>,
     args: Option<VoteOnProposalArgs>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -147,6 +186,27 @@ impl CancelProposalBuilder {
         self.system_program = system_program;
         self
     }
+    /// `[optional account, default to 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    /// `[optional account]`
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    #[inline(always)]
+    pub fn transaction(&mut self, transaction: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.transaction = transaction;
+        self
+    }
+    /// `[optional account]`
+    /// The policy of the smart account the transaction is for, required if it has one.
+    #[inline(always)]
+    pub fn vault_policy(&mut self, vault_policy: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.vault_policy = vault_policy;
+        self
+    }
     #[inline(always)]
     pub fn args(&mut self, args: VoteOnProposalArgs) -> &mut Self {
         self.args = Some(args);
@@ -174,6 +234,11 @@ impl CancelProposalBuilder {
             signer: self.signer.expect("signer is not set"),
             proposal: self.proposal.expect("proposal is not set"),
             system_program: self.system_program,
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh"
+            )),
+            transaction: self.transaction,
+            vault_policy: self.vault_policy,
         };
         let args = CancelProposalInstructionArgs {
             args: self.args.clone().expect("args is not set"),
@@ -192,6 +257,13 @@ pub struct CancelProposalCpiAccounts<'a, 'b> {
     pub proposal: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    pub transaction: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The policy of the smart account the transaction is for, required if it has one.
+    pub vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `cancel_proposal` CPI instruction.
@@ -206,6 +278,13 @@ pub struct CancelProposalCpi<'a, 'b> {
     pub proposal: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    pub transaction: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The policy of the smart account the transaction is for, required if it has one.
+    pub vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: CancelProposalInstructionArgs,
 }
@@ -222,6 +301,9 @@ impl<'a, 'b> CancelProposalCpi<'a, 'b> {
             signer: accounts.signer,
             proposal: accounts.proposal,
             system_program: accounts.system_program,
+            program: accounts.program,
+            transaction: accounts.transaction,
+            vault_policy: accounts.vault_policy,
             __args: args,
         }
     }
@@ -248,7 +330,7 @@ impl<'a, 'b> CancelProposalCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.settings.key,
             false,
@@ -269,6 +351,32 @@ impl<'a, 'b> CancelProposalCpi<'a, 'b> {
                 false,
             ));
         }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
+        if let Some(transaction) = self.transaction {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *transaction.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(vault_policy) = self.vault_policy {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *vault_policy.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -285,7 +393,7 @@ impl<'a, 'b> CancelProposalCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.settings.clone());
         account_infos.push(self.signer.clone());
@@ -293,6 +401,13 @@ impl<'a, 'b> CancelProposalCpi<'a, 'b> {
         if let Some(system_program) = self.system_program {
             account_infos.push(system_program.clone());
         }
+        account_infos.push(self.program.clone());
+        if let Some(transaction) = self.transaction {
+            account_infos.push(transaction.clone());
+        }
+        if let Some(vault_policy) = self.vault_policy {
+            account_infos.push(vault_policy.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -313,6 +428,9 @@ impl<'a, 'b> CancelProposalCpi<'a, 'b> {
 ///   1. `[writable, signer]` signer
 ///   2. `[writable]` proposal
 ///   3. `[optional]` system_program
+///   4. `[]` program
+///   5. `[optional]` transaction
+///   6. `[optional]` vault_policy
 #[derive(Clone, Debug)]
 pub struct CancelProposalCpiBuilder<'a, 'b> {
     instruction: Box<CancelProposalCpiBuilderInstruction<'a, 'b>>,
@@ -326,6 +444,9 @@ impl<'a, 'b> CancelProposalCpiBuilder<'a, 'b> {
             signer: None,
             proposal: None,
             system_program: None,
+            program: None,
+            transaction: None,
+            vault_policy: None,
             args: None,
             __remaining_accounts: Vec::new(),
         });
@@ -356,6 +477,32 @@ impl<'a, 'b> CancelProposalCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    /// `[optional account]`
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    #[inline(always)]
+    pub fn transaction(
+        &mut self,
+        transaction: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.transaction = transaction;
+        self
+    }
+    /// `[optional account]`
+    /// The policy of the smart account the transaction is for, required if it has one.
+    #[inline(always)]
+    pub fn vault_policy(
+        &mut self,
+        vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_policy = vault_policy;
+        self
+    }
+    #[inline(always)]
     pub fn args(&mut self, args: VoteOnProposalArgs) -> &mut Self {
         self.instruction.args = Some(args);
         self
@@ -407,6 +554,12 @@ impl<'a, 'b> CancelProposalCpiBuilder<'a, 'b> {
             proposal: self.instruction.proposal.expect("proposal is not set"),
 
             system_program: self.instruction.system_program,
+
+            program: self.instruction.program.expect("program is not set"),
+
+            transaction: self.instruction.transaction,
+
+            vault_policy: self.instruction.vault_policy,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -423,6 +576,9 @@ struct CancelProposalCpiBuilderInstruction<'a, 'b> {
     signer: Option<&'b solana_account_info::AccountInfo<'a>>,
     proposal: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    transaction: Option<&'b solana_account_info::AccountInfo<'a>>,
+    vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
     args: Option<VoteOnProposalArgs>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
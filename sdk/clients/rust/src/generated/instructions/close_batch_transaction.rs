@@ -25,6 +25,8 @@ pub struct CloseBatchTransaction {
     pub transaction_rent_collector: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl CloseBatchTransaction {
@@ -37,7 +39,7 @@ impl CloseBatchTransaction {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.settings,
             false,
@@ -59,6 +61,10 @@ impl CloseBatchTransaction {
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = CloseBatchTransactionInstructionData::new()
             .try_to_vec()
@@ -106,6 +112,7 @@ impl Default for CloseBatchTransactionInstructionData {
 ///   3. `[writable]` transaction
 ///   4. `[writable]` transaction_rent_collector
 ///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[optional]` program (default to `aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh`)
 #[derive(Clone, Debug, Default)]
 pub struct CloseBatchTransactionBuilder {
     settings: Option<solana_pubkey::Pubkey>,
@@ -114,6 +121,7 @@ pub struct CloseBatchTransactionBuilder {
     transaction: Option<solana_pubkey::Pubkey>,
     transaction_rent_collector: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -159,6 +167,12 @@ impl CloseBatchTransactionBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account, default to 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -187,6 +201,9 @@ impl CloseBatchTransactionBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh"
+            )),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -207,6 +224,8 @@ pub struct CloseBatchTransactionCpiAccounts<'a, 'b> {
     pub transaction_rent_collector: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `close_batch_transaction` CPI instruction.
@@ -226,6 +245,8 @@ pub struct CloseBatchTransactionCpi<'a, 'b> {
     pub transaction_rent_collector: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> CloseBatchTransactionCpi<'a, 'b> {
@@ -241,6 +262,7 @@ impl<'a, 'b> CloseBatchTransactionCpi<'a, 'b> {
             transaction: accounts.transaction,
             transaction_rent_collector: accounts.transaction_rent_collector,
             system_program: accounts.system_program,
+            program: accounts.program,
         }
     }
     #[inline(always)]
@@ -266,7 +288,7 @@ impl<'a, 'b> CloseBatchTransactionCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.settings.key,
             false,
@@ -288,6 +310,10 @@ impl<'a, 'b> CloseBatchTransactionCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -304,7 +330,7 @@ impl<'a, 'b> CloseBatchTransactionCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.settings.clone());
         account_infos.push(self.proposal.clone());
@@ -312,6 +338,7 @@ impl<'a, 'b> CloseBatchTransactionCpi<'a, 'b> {
         account_infos.push(self.transaction.clone());
         account_infos.push(self.transaction_rent_collector.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -334,6 +361,7 @@ impl<'a, 'b> CloseBatchTransactionCpi<'a, 'b> {
 ///   3. `[writable]` transaction
 ///   4. `[writable]` transaction_rent_collector
 ///   5. `[]` system_program
+///   6. `[]` program
 #[derive(Clone, Debug)]
 pub struct CloseBatchTransactionCpiBuilder<'a, 'b> {
     instruction: Box<CloseBatchTransactionCpiBuilderInstruction<'a, 'b>>,
@@ -349,6 +377,7 @@ impl<'a, 'b> CloseBatchTransactionCpiBuilder<'a, 'b> {
             transaction: None,
             transaction_rent_collector: None,
             system_program: None,
+            program: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -396,6 +425,11 @@ impl<'a, 'b> CloseBatchTransactionCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -453,6 +487,8 @@ impl<'a, 'b> CloseBatchTransactionCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -470,6 +506,7 @@ struct CloseBatchTransactionCpiBuilderInstruction<'a, 'b> {
     transaction: Option<&'b solana_account_info::AccountInfo<'a>>,
     transaction_rent_collector: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
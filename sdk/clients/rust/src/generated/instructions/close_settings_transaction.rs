@@ -24,6 +24,8 @@ pub struct CloseSettingsTransaction {
     pub transaction_rent_collector: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl CloseSettingsTransaction {
@@ -36,7 +38,7 @@ impl CloseSettingsTransaction {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.settings,
             false,
@@ -58,6 +60,10 @@ impl CloseSettingsTransaction {
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = CloseSettingsTransactionInstructionData::new()
             .try_to_vec()
@@ -105,6 +111,7 @@ impl Default for CloseSettingsTransactionInstructionData {
 ///   3. `[writable]` proposal_rent_collector
 ///   4. `[writable]` transaction_rent_collector
 ///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[optional]` program (default to `aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh`)
 #[derive(Clone, Debug, Default)]
 pub struct CloseSettingsTransactionBuilder {
     settings: Option<solana_pubkey::Pubkey>,
@@ -113,6 +120,7 @@ pub struct CloseSettingsTransactionBuilder {
     proposal_rent_collector: Option<solana_pubkey::Pubkey>,
     transaction_rent_collector: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -161,6 +169,12 @@ impl CloseSettingsTransactionBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account, default to 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -191,6 +205,9 @@ impl CloseSettingsTransactionBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh"
+            )),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -210,6 +227,8 @@ pub struct CloseSettingsTransactionCpiAccounts<'a, 'b> {
     pub transaction_rent_collector: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `close_settings_transaction` CPI instruction.
@@ -228,6 +247,8 @@ pub struct CloseSettingsTransactionCpi<'a, 'b> {
     pub transaction_rent_collector: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> CloseSettingsTransactionCpi<'a, 'b> {
@@ -243,6 +264,7 @@ impl<'a, 'b> CloseSettingsTransactionCpi<'a, 'b> {
             proposal_rent_collector: accounts.proposal_rent_collector,
             transaction_rent_collector: accounts.transaction_rent_collector,
             system_program: accounts.system_program,
+            program: accounts.program,
         }
     }
     #[inline(always)]
@@ -268,7 +290,7 @@ impl<'a, 'b> CloseSettingsTransactionCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.settings.key,
             false,
@@ -293,6 +315,10 @@ impl<'a, 'b> CloseSettingsTransactionCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -309,7 +335,7 @@ impl<'a, 'b> CloseSettingsTransactionCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.settings.clone());
         account_infos.push(self.proposal.clone());
@@ -317,6 +343,7 @@ impl<'a, 'b> CloseSettingsTransactionCpi<'a, 'b> {
         account_infos.push(self.proposal_rent_collector.clone());
         account_infos.push(self.transaction_rent_collector.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -339,6 +366,7 @@ impl<'a, 'b> CloseSettingsTransactionCpi<'a, 'b> {
 ///   3. `[writable]` proposal_rent_collector
 ///   4. `[writable]` transaction_rent_collector
 ///   5. `[]` system_program
+///   6. `[]` program
 #[derive(Clone, Debug)]
 pub struct CloseSettingsTransactionCpiBuilder<'a, 'b> {
     instruction: Box<CloseSettingsTransactionCpiBuilderInstruction<'a, 'b>>,
@@ -354,6 +382,7 @@ impl<'a, 'b> CloseSettingsTransactionCpiBuilder<'a, 'b> {
             proposal_rent_collector: None,
             transaction_rent_collector: None,
             system_program: None,
+            program: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -404,6 +433,11 @@ impl<'a, 'b> CloseSettingsTransactionCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -464,6 +498,8 @@ impl<'a, 'b> CloseSettingsTransactionCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -481,6 +517,7 @@ struct CloseSettingsTransactionCpiBuilderInstruction<'a, 'b> {
     proposal_rent_collector: Option<&'b solana_account_info::AccountInfo<'a>>,
     transaction_rent_collector: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
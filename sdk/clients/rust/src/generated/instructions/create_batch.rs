@@ -22,6 +22,10 @@ pub struct CreateBatch {
     pub fee_payer: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    pub vault_policy: Option<solana_pubkey::Pubkey>,
 }
 
 impl CreateBatch {
@@ -35,7 +39,7 @@ impl CreateBatch {
         args: CreateBatchInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.settings, false));
         accounts.push(solana_instruction::AccountMeta::new(self.batch, false));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -47,6 +51,21 @@ impl CreateBatch {
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
+        if let Some(vault_policy) = self.vault_policy {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                vault_policy,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = CreateBatchInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -106,6 +125,8 @@ impl CreateBatchInstructionArgs {
 ///   2. `[signer]` creator
 ///   3. `[writable, signer]` fee_payer
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[optional]` program (default to `aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh`)
+///   6. `[optional]` vault_policy
 #[derive(Clone, Debug, Default)]
 pub struct CreateBatchBuilder {
     settings: Option<solana_pubkey::Pubkey>,
@@ -113,6 +134,8 @@ pub struct CreateBatchBuilder {
     creator: Option<solana_pubkey::Pubkey>,
     fee_payer: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
+    vault_policy: Option<solana_pubkey::Pubkey>,
     account_index: Option<u8>,
     memo: Option<String>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
@@ -150,6 +173,19 @@ impl CreateBatchBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account, default to 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    /// `[optional account]`
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    #[inline(always)]
+    pub fn vault_policy(&mut self, vault_policy: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.vault_policy = vault_policy;
+        self
+    }
     #[inline(always)]
     pub fn account_index(&mut self, account_index: u8) -> &mut Self {
         self.account_index = Some(account_index);
@@ -186,6 +222,10 @@ impl CreateBatchBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh"
+            )),
+            vault_policy: self.vault_policy,
         };
         let args = CreateBatchInstructionArgs {
             account_index: self
@@ -210,6 +250,10 @@ pub struct CreateBatchCpiAccounts<'a, 'b> {
     pub fee_payer: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    pub vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `create_batch` CPI instruction.
@@ -226,6 +270,10 @@ pub struct CreateBatchCpi<'a, 'b> {
     pub fee_payer: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    pub vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: CreateBatchInstructionArgs,
 }
@@ -243,6 +291,8 @@ impl<'a, 'b> CreateBatchCpi<'a, 'b> {
             creator: accounts.creator,
             fee_payer: accounts.fee_payer,
             system_program: accounts.system_program,
+            program: accounts.program,
+            vault_policy: accounts.vault_policy,
             __args: args,
         }
     }
@@ -269,7 +319,7 @@ impl<'a, 'b> CreateBatchCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.settings.key,
             false,
@@ -287,6 +337,21 @@ impl<'a, 'b> CreateBatchCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
+        if let Some(vault_policy) = self.vault_policy {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *vault_policy.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -303,13 +368,17 @@ impl<'a, 'b> CreateBatchCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.settings.clone());
         account_infos.push(self.batch.clone());
         account_infos.push(self.creator.clone());
         account_infos.push(self.fee_payer.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.program.clone());
+        if let Some(vault_policy) = self.vault_policy {
+            account_infos.push(vault_policy.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -331,6 +400,8 @@ impl<'a, 'b> CreateBatchCpi<'a, 'b> {
 ///   2. `[signer]` creator
 ///   3. `[writable, signer]` fee_payer
 ///   4. `[]` system_program
+///   5. `[]` program
+///   6. `[optional]` vault_policy
 #[derive(Clone, Debug)]
 pub struct CreateBatchCpiBuilder<'a, 'b> {
     instruction: Box<CreateBatchCpiBuilderInstruction<'a, 'b>>,
@@ -345,6 +416,8 @@ impl<'a, 'b> CreateBatchCpiBuilder<'a, 'b> {
             creator: None,
             fee_payer: None,
             system_program: None,
+            program: None,
+            vault_policy: None,
             account_index: None,
             memo: None,
             __remaining_accounts: Vec::new(),
@@ -382,6 +455,21 @@ impl<'a, 'b> CreateBatchCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    /// `[optional account]`
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    #[inline(always)]
+    pub fn vault_policy(
+        &mut self,
+        vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_policy = vault_policy;
+        self
+    }
+    #[inline(always)]
     pub fn account_index(&mut self, account_index: u8) -> &mut Self {
         self.instruction.account_index = Some(account_index);
         self
@@ -449,6 +537,10 @@ impl<'a, 'b> CreateBatchCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
+
+            vault_policy: self.instruction.vault_policy,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -466,6 +558,8 @@ struct CreateBatchCpiBuilderInstruction<'a, 'b> {
     creator: Option<&'b solana_account_info::AccountInfo<'a>>,
     fee_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
     account_index: Option<u8>,
     memo: Option<String>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -22,6 +22,13 @@ pub struct CreateProposal {
     pub fee_payer: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    pub transaction: Option<solana_pubkey::Pubkey>,
+    /// The policy of the smart account the transaction is for, required if it has one.
+    pub vault_policy: Option<solana_pubkey::Pubkey>,
 }
 
 impl CreateProposal {
@@ -38,7 +45,7 @@ impl CreateProposal {
         args: CreateProposalInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.settings,
             false,
@@ -53,6 +60,32 @@ impl CreateProposal {
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
+        if let Some(transaction) = self.transaction {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                transaction,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(vault_policy) = self.vault_policy {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                vault_policy,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = CreateProposalInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -95,6 +128,7 @@ impl Default for CreateProposalInstructionData {
 pub struct CreateProposalInstructionArgs {
     pub transaction_index: u64,
     pub draft: bool,
+    pub voting_deadline: Option<i64>,
 }
 
 impl CreateProposalInstructionArgs {
@@ -112,6 +146,9 @@ impl CreateProposalInstructionArgs {
 ///   2. `[signer]` creator
 ///   3. `[writable, signer]` fee_payer
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[optional]` program (default to `aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh`)
+///   6. `[optional]` transaction
+///   7. `[optional]` vault_policy
 #[derive(Clone, Debug, Default)]
 pub struct CreateProposalBuilder {
     settings: Option<solana_pubkey::Pubkey>,
@@ -119,8 +156,12 @@ pub struct CreateProposalBuilder {
     creator: Option<solana_pubkey::Pubkey>,
     fee_payer: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
+    transaction: Option<solana_pubkey::Pubkey>,
+    vault_policy: Option<solana_pubkey::Pubkey>,
     transaction_index: Option<u64>,
     draft: Option<bool>,
+    voting_deadline: Option<i64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -156,6 +197,27 @@ impl CreateProposalBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account, default to 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    /// `[optional account]`
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    #[inline(always)]
+    pub fn transaction(&mut self, transaction: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.transaction = transaction;
+        self
+    }
+    /// `[optional account]`
+    /// The policy of the smart account the transaction is for, required if it has one.
+    #[inline(always)]
+    pub fn vault_policy(&mut self, vault_policy: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.vault_policy = vault_policy;
+        self
+    }
     #[inline(always)]
     pub fn transaction_index(&mut self, transaction_index: u64) -> &mut Self {
         self.transaction_index = Some(transaction_index);
@@ -166,6 +228,12 @@ impl CreateProposalBuilder {
         self.draft = Some(draft);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn voting_deadline(&mut self, voting_deadline: i64) -> &mut Self {
+        self.voting_deadline = Some(voting_deadline);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -191,6 +259,11 @@ impl CreateProposalBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh"
+            )),
+            transaction: self.transaction,
+            vault_policy: self.vault_policy,
         };
         let args = CreateProposalInstructionArgs {
             transaction_index: self
@@ -198,6 +271,7 @@ impl CreateProposalBuilder {
                 .clone()
                 .expect("transaction_index is not set"),
             draft: self.draft.clone().expect("draft is not set"),
+            voting_deadline: self.voting_deadline.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -215,6 +289,13 @@ pub struct CreateProposalCpiAccounts<'a, 'b> {
     pub fee_payer: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    pub transaction: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The policy of the smart account the transaction is for, required if it has one.
+    pub vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `create_proposal` CPI instruction.
@@ -231,6 +312,13 @@ pub struct CreateProposalCpi<'a, 'b> {
     pub fee_payer: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    pub transaction: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The policy of the smart account the transaction is for, required if it has one.
+    pub vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: CreateProposalInstructionArgs,
 }
@@ -248,6 +336,9 @@ impl<'a, 'b> CreateProposalCpi<'a, 'b> {
             creator: accounts.creator,
             fee_payer: accounts.fee_payer,
             system_program: accounts.system_program,
+            program: accounts.program,
+            transaction: accounts.transaction,
+            vault_policy: accounts.vault_policy,
             __args: args,
         }
     }
@@ -274,7 +365,7 @@ impl<'a, 'b> CreateProposalCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.settings.key,
             false,
@@ -295,6 +386,32 @@ impl<'a, 'b> CreateProposalCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
+        if let Some(transaction) = self.transaction {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *transaction.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(vault_policy) = self.vault_policy {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *vault_policy.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -311,13 +428,20 @@ impl<'a, 'b> CreateProposalCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.settings.clone());
         account_infos.push(self.proposal.clone());
         account_infos.push(self.creator.clone());
         account_infos.push(self.fee_payer.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.program.clone());
+        if let Some(transaction) = self.transaction {
+            account_infos.push(transaction.clone());
+        }
+        if let Some(vault_policy) = self.vault_policy {
+            account_infos.push(vault_policy.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -339,6 +463,9 @@ impl<'a, 'b> CreateProposalCpi<'a, 'b> {
 ///   2. `[signer]` creator
 ///   3. `[writable, signer]` fee_payer
 ///   4. `[]` system_program
+///   5. `[]` program
+///   6. `[optional]` transaction
+///   7. `[optional]` vault_policy
 #[derive(Clone, Debug)]
 pub struct CreateProposalCpiBuilder<'a, 'b> {
     instruction: Box<CreateProposalCpiBuilderInstruction<'a, 'b>>,
@@ -353,8 +480,12 @@ impl<'a, 'b> CreateProposalCpiBuilder<'a, 'b> {
             creator: None,
             fee_payer: None,
             system_program: None,
+            program: None,
+            transaction: None,
+            vault_policy: None,
             transaction_index: None,
             draft: None,
+            voting_deadline: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -390,6 +521,32 @@ impl<'a, 'b> CreateProposalCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    /// `[optional account]`
+    /// The `Transaction` or `Batch` of the proposal, to find the smart account it's for.
+    /// Only required if the settings have a `VaultPolicy`.
+    #[inline(always)]
+    pub fn transaction(
+        &mut self,
+        transaction: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.transaction = transaction;
+        self
+    }
+    /// `[optional account]`
+    /// The policy of the smart account the transaction is for, required if it has one.
+    #[inline(always)]
+    pub fn vault_policy(
+        &mut self,
+        vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_policy = vault_policy;
+        self
+    }
+    #[inline(always)]
     pub fn transaction_index(&mut self, transaction_index: u64) -> &mut Self {
         self.instruction.transaction_index = Some(transaction_index);
         self
@@ -399,6 +556,12 @@ impl<'a, 'b> CreateProposalCpiBuilder<'a, 'b> {
         self.instruction.draft = Some(draft);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn voting_deadline(&mut self, voting_deadline: i64) -> &mut Self {
+        self.instruction.voting_deadline = Some(voting_deadline);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -440,6 +603,7 @@ impl<'a, 'b> CreateProposalCpiBuilder<'a, 'b> {
                 .clone()
                 .expect("transaction_index is not set"),
             draft: self.instruction.draft.clone().expect("draft is not set"),
+            voting_deadline: self.instruction.voting_deadline.clone(),
         };
         let instruction = CreateProposalCpi {
             __program: self.instruction.__program,
@@ -456,6 +620,12 @@ impl<'a, 'b> CreateProposalCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
+
+            transaction: self.instruction.transaction,
+
+            vault_policy: self.instruction.vault_policy,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -473,8 +643,12 @@ struct CreateProposalCpiBuilderInstruction<'a, 'b> {
     creator: Option<&'b solana_account_info::AccountInfo<'a>>,
     fee_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    transaction: Option<&'b solana_account_info::AccountInfo<'a>>,
+    vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
     transaction_index: Option<u64>,
     draft: Option<bool>,
+    voting_deadline: Option<i64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
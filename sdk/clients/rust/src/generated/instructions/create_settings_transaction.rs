@@ -5,7 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
-use crate::generated::types::SettingsAction;
+use crate::generated::types::CreateSettingsTransactionArgs;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -23,6 +23,8 @@ pub struct CreateSettingsTransaction {
     pub fee_payer: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl CreateSettingsTransaction {
@@ -39,7 +41,7 @@ impl CreateSettingsTransaction {
         args: CreateSettingsTransactionInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.settings, false));
         accounts.push(solana_instruction::AccountMeta::new(
             self.transaction,
@@ -54,6 +56,10 @@ impl CreateSettingsTransaction {
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = CreateSettingsTransactionInstructionData::new()
             .try_to_vec()
@@ -96,8 +102,7 @@ impl Default for CreateSettingsTransactionInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CreateSettingsTransactionInstructionArgs {
-    pub actions: Vec<SettingsAction>,
-    pub memo: Option<String>,
+    pub args: CreateSettingsTransactionArgs,
 }
 
 impl CreateSettingsTransactionInstructionArgs {
@@ -115,6 +120,7 @@ impl CreateSettingsTransactionInstructionArgs {
 ///   2. `[signer]` creator
 ///   3. `[writable, signer]` fee_payer
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[optional]` program (default to `aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh`)
 #[derive(Clone, Debug, Default)]
 pub struct CreateSettingsTransactionBuilder {
     settings: Option<solana_pubkey::Pubkey>,
@@ -122,8 +128,8 @@ pub struct CreateSettingsTransactionBuilder {
     creator: Option<solana_pubkey::Pubkey>,
     fee_payer: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
-    actions: Option<Vec<SettingsAction>>,
-    memo: Option<String>,
+    program: Option<solana_pubkey::Pubkey>,
+    args: Option<CreateSettingsTransactionArgs>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -159,15 +165,15 @@ impl CreateSettingsTransactionBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account, default to 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh']`
     #[inline(always)]
-    pub fn actions(&mut self, actions: Vec<SettingsAction>) -> &mut Self {
-        self.actions = Some(actions);
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
         self
     }
-    /// `[optional argument]`
     #[inline(always)]
-    pub fn memo(&mut self, memo: String) -> &mut Self {
-        self.memo = Some(memo);
+    pub fn args(&mut self, args: CreateSettingsTransactionArgs) -> &mut Self {
+        self.args = Some(args);
         self
     }
     /// Add an additional account to the instruction.
@@ -195,10 +201,12 @@ impl CreateSettingsTransactionBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh"
+            )),
         };
         let args = CreateSettingsTransactionInstructionArgs {
-            actions: self.actions.clone().expect("actions is not set"),
-            memo: self.memo.clone(),
+            args: self.args.clone().expect("args is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -216,6 +224,8 @@ pub struct CreateSettingsTransactionCpiAccounts<'a, 'b> {
     pub fee_payer: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `create_settings_transaction` CPI instruction.
@@ -232,6 +242,8 @@ pub struct CreateSettingsTransactionCpi<'a, 'b> {
     pub fee_payer: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: CreateSettingsTransactionInstructionArgs,
 }
@@ -249,6 +261,7 @@ impl<'a, 'b> CreateSettingsTransactionCpi<'a, 'b> {
             creator: accounts.creator,
             fee_payer: accounts.fee_payer,
             system_program: accounts.system_program,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -275,7 +288,7 @@ impl<'a, 'b> CreateSettingsTransactionCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.settings.key,
             false,
@@ -296,6 +309,10 @@ impl<'a, 'b> CreateSettingsTransactionCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -314,13 +331,14 @@ impl<'a, 'b> CreateSettingsTransactionCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.settings.clone());
         account_infos.push(self.transaction.clone());
         account_infos.push(self.creator.clone());
         account_infos.push(self.fee_payer.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -342,6 +360,7 @@ impl<'a, 'b> CreateSettingsTransactionCpi<'a, 'b> {
 ///   2. `[signer]` creator
 ///   3. `[writable, signer]` fee_payer
 ///   4. `[]` system_program
+///   5. `[]` program
 #[derive(Clone, Debug)]
 pub struct CreateSettingsTransactionCpiBuilder<'a, 'b> {
     instruction: Box<CreateSettingsTransactionCpiBuilderInstruction<'a, 'b>>,
@@ -356,8 +375,8 @@ impl<'a, 'b> CreateSettingsTransactionCpiBuilder<'a, 'b> {
             creator: None,
             fee_payer: None,
             system_program: None,
-            actions: None,
-            memo: None,
+            program: None,
+            args: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -396,14 +415,13 @@ impl<'a, 'b> CreateSettingsTransactionCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn actions(&mut self, actions: Vec<SettingsAction>) -> &mut Self {
-        self.instruction.actions = Some(actions);
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
         self
     }
-    /// `[optional argument]`
     #[inline(always)]
-    pub fn memo(&mut self, memo: String) -> &mut Self {
-        self.instruction.memo = Some(memo);
+    pub fn args(&mut self, args: CreateSettingsTransactionArgs) -> &mut Self {
+        self.instruction.args = Some(args);
         self
     }
     /// Add an additional account to the instruction.
@@ -441,12 +459,7 @@ impl<'a, 'b> CreateSettingsTransactionCpiBuilder<'a, 'b> {
     #[allow(clippy::vec_init_then_push)]
     pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
         let args = CreateSettingsTransactionInstructionArgs {
-            actions: self
-                .instruction
-                .actions
-                .clone()
-                .expect("actions is not set"),
-            memo: self.instruction.memo.clone(),
+            args: self.instruction.args.clone().expect("args is not set"),
         };
         let instruction = CreateSettingsTransactionCpi {
             __program: self.instruction.__program,
@@ -466,6 +479,8 @@ impl<'a, 'b> CreateSettingsTransactionCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -483,8 +498,8 @@ struct CreateSettingsTransactionCpiBuilderInstruction<'a, 'b> {
     creator: Option<&'b solana_account_info::AccountInfo<'a>>,
     fee_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
-    actions: Option<Vec<SettingsAction>>,
-    memo: Option<String>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    args: Option<CreateSettingsTransactionArgs>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -30,6 +30,23 @@ pub struct CreateSmartAccount {
     pub system_program: solana_pubkey::Pubkey,
 
     pub program: solana_pubkey::Pubkey,
+    /// The referrer the referral share of the creation fee is transferred to.
+    pub referrer_account: Option<solana_pubkey::Pubkey>,
+    /// The mint of the creation token fee in case `program_config.smart_account_creation_token_fee` is set.
+    pub creation_fee_mint: Option<solana_pubkey::Pubkey>,
+    /// The token account the creation token fee is paid from.
+    pub fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+    /// The treasury token account the creation token fee is transferred to.
+    pub treasury_token_account: Option<solana_pubkey::Pubkey>,
+    /// The referrer token account the referral share of the creation token fee is transferred to.
+    /// Its owner is validated in the `CreateSmartAccount::validate()` method.
+    pub referrer_token_account: Option<solana_pubkey::Pubkey>,
+    /// In case `program_config.smart_account_creation_token_fee` is set.
+    pub token_program: Option<solana_pubkey::Pubkey>,
+    /// The allowlist page the `creator` is on, in case `program_config.smart_account_creation_mode` is `Allowlist`.
+    pub creator_allowlist: Option<solana_pubkey::Pubkey>,
+    /// The program config authority, in case `program_config.smart_account_creation_mode` is `Cosigned`.
+    pub program_config_authority: Option<solana_pubkey::Pubkey>,
 }
 
 impl CreateSmartAccount {
@@ -46,7 +63,7 @@ impl CreateSmartAccount {
         args: CreateSmartAccountInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             self.program_config,
             false,
@@ -66,6 +83,94 @@ impl CreateSmartAccount {
             self.program,
             false,
         ));
+        if let Some(referrer_account) = self.referrer_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                referrer_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(creation_fee_mint) = self.creation_fee_mint {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                creation_fee_mint,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                fee_payer_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(treasury_token_account) = self.treasury_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                treasury_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(referrer_token_account) = self.referrer_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                referrer_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(creator_allowlist) = self.creator_allowlist {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                creator_allowlist,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(program_config_authority) = self.program_config_authority {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                program_config_authority,
+                true,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = CreateSmartAccountInstructionData::new()
             .try_to_vec()
@@ -113,6 +218,7 @@ pub struct CreateSmartAccountInstructionArgs {
     pub time_lock: u32,
     pub rent_collector: Option<Pubkey>,
     pub memo: Option<String>,
+    pub referrer: Option<Pubkey>,
 }
 
 impl CreateSmartAccountInstructionArgs {
@@ -132,6 +238,14 @@ impl CreateSmartAccountInstructionArgs {
 ///   4. `[writable, signer]` fee_payer
 ///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   6. `[optional]` program (default to `aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh`)
+///   7. `[writable, optional]` referrer_account
+///   8. `[optional]` creation_fee_mint
+///   9. `[writable, optional]` fee_payer_token_account
+///   10. `[writable, optional]` treasury_token_account
+///   11. `[writable, optional]` referrer_token_account
+///   12. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   13. `[optional]` creator_allowlist
+///   14. `[signer, optional]` program_config_authority
 #[derive(Clone, Debug, Default)]
 pub struct CreateSmartAccountBuilder {
     program_config: Option<solana_pubkey::Pubkey>,
@@ -141,6 +255,14 @@ pub struct CreateSmartAccountBuilder {
     fee_payer: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
     program: Option<solana_pubkey::Pubkey>,
+    referrer_account: Option<solana_pubkey::Pubkey>,
+    creation_fee_mint: Option<solana_pubkey::Pubkey>,
+    fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+    treasury_token_account: Option<solana_pubkey::Pubkey>,
+    referrer_token_account: Option<solana_pubkey::Pubkey>,
+    token_program: Option<solana_pubkey::Pubkey>,
+    creator_allowlist: Option<solana_pubkey::Pubkey>,
+    program_config_authority: Option<solana_pubkey::Pubkey>,
     settings_authority: Option<Pubkey>,
     threshold: Option<u16>,
     signers: Option<Vec<SmartAccountSigner>>,
@@ -148,6 +270,7 @@ pub struct CreateSmartAccountBuilder {
     time_lock: Option<u32>,
     rent_collector: Option<Pubkey>,
     memo: Option<String>,
+    referrer: Option<Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -197,6 +320,84 @@ impl CreateSmartAccountBuilder {
         self.program = Some(program);
         self
     }
+    /// `[optional account]`
+    /// The referrer the referral share of the creation fee is transferred to.
+    #[inline(always)]
+    pub fn referrer_account(
+        &mut self,
+        referrer_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.referrer_account = referrer_account;
+        self
+    }
+    /// `[optional account]`
+    /// The mint of the creation token fee in case `program_config.smart_account_creation_token_fee` is set.
+    #[inline(always)]
+    pub fn creation_fee_mint(
+        &mut self,
+        creation_fee_mint: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.creation_fee_mint = creation_fee_mint;
+        self
+    }
+    /// `[optional account]`
+    /// The token account the creation token fee is paid from.
+    #[inline(always)]
+    pub fn fee_payer_token_account(
+        &mut self,
+        fee_payer_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.fee_payer_token_account = fee_payer_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// The treasury token account the creation token fee is transferred to.
+    #[inline(always)]
+    pub fn treasury_token_account(
+        &mut self,
+        treasury_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.treasury_token_account = treasury_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// The referrer token account the referral share of the creation token fee is transferred to.
+    /// Its owner is validated in the `CreateSmartAccount::validate()` method.
+    #[inline(always)]
+    pub fn referrer_token_account(
+        &mut self,
+        referrer_token_account: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.referrer_token_account = referrer_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// In case `program_config.smart_account_creation_token_fee` is set.
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.token_program = token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The allowlist page the `creator` is on, in case `program_config.smart_account_creation_mode` is `Allowlist`.
+    #[inline(always)]
+    pub fn creator_allowlist(
+        &mut self,
+        creator_allowlist: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.creator_allowlist = creator_allowlist;
+        self
+    }
+    /// `[optional account]`
+    /// The program config authority, in case `program_config.smart_account_creation_mode` is `Cosigned`.
+    #[inline(always)]
+    pub fn program_config_authority(
+        &mut self,
+        program_config_authority: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.program_config_authority = program_config_authority;
+        self
+    }
     /// `[optional argument]`
     #[inline(always)]
     pub fn settings_authority(&mut self, settings_authority: Pubkey) -> &mut Self {
@@ -238,6 +439,12 @@ impl CreateSmartAccountBuilder {
         self.memo = Some(memo);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn referrer(&mut self, referrer: Pubkey) -> &mut Self {
+        self.referrer = Some(referrer);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -267,6 +474,14 @@ impl CreateSmartAccountBuilder {
             program: self.program.unwrap_or(solana_pubkey::pubkey!(
                 "aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh"
             )),
+            referrer_account: self.referrer_account,
+            creation_fee_mint: self.creation_fee_mint,
+            fee_payer_token_account: self.fee_payer_token_account,
+            treasury_token_account: self.treasury_token_account,
+            referrer_token_account: self.referrer_token_account,
+            token_program: self.token_program,
+            creator_allowlist: self.creator_allowlist,
+            program_config_authority: self.program_config_authority,
         };
         let args = CreateSmartAccountInstructionArgs {
             settings_authority: self.settings_authority.clone(),
@@ -279,6 +494,7 @@ impl CreateSmartAccountBuilder {
             time_lock: self.time_lock.clone().expect("time_lock is not set"),
             rent_collector: self.rent_collector.clone(),
             memo: self.memo.clone(),
+            referrer: self.referrer.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -301,6 +517,23 @@ pub struct CreateSmartAccountCpiAccounts<'a, 'b> {
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The referrer the referral share of the creation fee is transferred to.
+    pub referrer_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The mint of the creation token fee in case `program_config.smart_account_creation_token_fee` is set.
+    pub creation_fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The token account the creation token fee is paid from.
+    pub fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The treasury token account the creation token fee is transferred to.
+    pub treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The referrer token account the referral share of the creation token fee is transferred to.
+    /// Its owner is validated in the `CreateSmartAccount::validate()` method.
+    pub referrer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// In case `program_config.smart_account_creation_token_fee` is set.
+    pub token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The allowlist page the `creator` is on, in case `program_config.smart_account_creation_mode` is `Allowlist`.
+    pub creator_allowlist: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The program config authority, in case `program_config.smart_account_creation_mode` is `Cosigned`.
+    pub program_config_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `create_smart_account` CPI instruction.
@@ -321,6 +554,23 @@ pub struct CreateSmartAccountCpi<'a, 'b> {
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The referrer the referral share of the creation fee is transferred to.
+    pub referrer_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The mint of the creation token fee in case `program_config.smart_account_creation_token_fee` is set.
+    pub creation_fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The token account the creation token fee is paid from.
+    pub fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The treasury token account the creation token fee is transferred to.
+    pub treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The referrer token account the referral share of the creation token fee is transferred to.
+    /// Its owner is validated in the `CreateSmartAccount::validate()` method.
+    pub referrer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// In case `program_config.smart_account_creation_token_fee` is set.
+    pub token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The allowlist page the `creator` is on, in case `program_config.smart_account_creation_mode` is `Allowlist`.
+    pub creator_allowlist: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// The program config authority, in case `program_config.smart_account_creation_mode` is `Cosigned`.
+    pub program_config_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: CreateSmartAccountInstructionArgs,
 }
@@ -340,6 +590,14 @@ impl<'a, 'b> CreateSmartAccountCpi<'a, 'b> {
             fee_payer: accounts.fee_payer,
             system_program: accounts.system_program,
             program: accounts.program,
+            referrer_account: accounts.referrer_account,
+            creation_fee_mint: accounts.creation_fee_mint,
+            fee_payer_token_account: accounts.fee_payer_token_account,
+            treasury_token_account: accounts.treasury_token_account,
+            referrer_token_account: accounts.referrer_token_account,
+            token_program: accounts.token_program,
+            creator_allowlist: accounts.creator_allowlist,
+            program_config_authority: accounts.program_config_authority,
             __args: args,
         }
     }
@@ -366,7 +624,7 @@ impl<'a, 'b> CreateSmartAccountCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.program_config.key,
             false,
@@ -395,6 +653,94 @@ impl<'a, 'b> CreateSmartAccountCpi<'a, 'b> {
             *self.program.key,
             false,
         ));
+        if let Some(referrer_account) = self.referrer_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *referrer_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(creation_fee_mint) = self.creation_fee_mint {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *creation_fee_mint.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *fee_payer_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(treasury_token_account) = self.treasury_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *treasury_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(referrer_token_account) = self.referrer_token_account {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *referrer_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(creator_allowlist) = self.creator_allowlist {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *creator_allowlist.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
+        if let Some(program_config_authority) = self.program_config_authority {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *program_config_authority.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -413,7 +759,7 @@ impl<'a, 'b> CreateSmartAccountCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(16 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.program_config.clone());
         account_infos.push(self.settings.clone());
@@ -422,6 +768,30 @@ impl<'a, 'b> CreateSmartAccountCpi<'a, 'b> {
         account_infos.push(self.fee_payer.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.program.clone());
+        if let Some(referrer_account) = self.referrer_account {
+            account_infos.push(referrer_account.clone());
+        }
+        if let Some(creation_fee_mint) = self.creation_fee_mint {
+            account_infos.push(creation_fee_mint.clone());
+        }
+        if let Some(fee_payer_token_account) = self.fee_payer_token_account {
+            account_infos.push(fee_payer_token_account.clone());
+        }
+        if let Some(treasury_token_account) = self.treasury_token_account {
+            account_infos.push(treasury_token_account.clone());
+        }
+        if let Some(referrer_token_account) = self.referrer_token_account {
+            account_infos.push(referrer_token_account.clone());
+        }
+        if let Some(token_program) = self.token_program {
+            account_infos.push(token_program.clone());
+        }
+        if let Some(creator_allowlist) = self.creator_allowlist {
+            account_infos.push(creator_allowlist.clone());
+        }
+        if let Some(program_config_authority) = self.program_config_authority {
+            account_infos.push(program_config_authority.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -445,6 +815,14 @@ impl<'a, 'b> CreateSmartAccountCpi<'a, 'b> {
 ///   4. `[writable, signer]` fee_payer
 ///   5. `[]` system_program
 ///   6. `[]` program
+///   7. `[writable, optional]` referrer_account
+///   8. `[optional]` creation_fee_mint
+///   9. `[writable, optional]` fee_payer_token_account
+///   10. `[writable, optional]` treasury_token_account
+///   11. `[writable, optional]` referrer_token_account
+///   12. `[optional]` token_program
+///   13. `[optional]` creator_allowlist
+///   14. `[signer, optional]` program_config_authority
 #[derive(Clone, Debug)]
 pub struct CreateSmartAccountCpiBuilder<'a, 'b> {
     instruction: Box<CreateSmartAccountCpiBuilderInstruction<'a, 'b>>,
@@ -461,6 +839,14 @@ impl<'a, 'b> CreateSmartAccountCpiBuilder<'a, 'b> {
             fee_payer: None,
             system_program: None,
             program: None,
+            referrer_account: None,
+            creation_fee_mint: None,
+            fee_payer_token_account: None,
+            treasury_token_account: None,
+            referrer_token_account: None,
+            token_program: None,
+            creator_allowlist: None,
+            program_config_authority: None,
             settings_authority: None,
             threshold: None,
             signers: None,
@@ -468,6 +854,7 @@ impl<'a, 'b> CreateSmartAccountCpiBuilder<'a, 'b> {
             time_lock: None,
             rent_collector: None,
             memo: None,
+            referrer: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -518,6 +905,87 @@ impl<'a, 'b> CreateSmartAccountCpiBuilder<'a, 'b> {
         self.instruction.program = Some(program);
         self
     }
+    /// `[optional account]`
+    /// The referrer the referral share of the creation fee is transferred to.
+    #[inline(always)]
+    pub fn referrer_account(
+        &mut self,
+        referrer_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.referrer_account = referrer_account;
+        self
+    }
+    /// `[optional account]`
+    /// The mint of the creation token fee in case `program_config.smart_account_creation_token_fee` is set.
+    #[inline(always)]
+    pub fn creation_fee_mint(
+        &mut self,
+        creation_fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.creation_fee_mint = creation_fee_mint;
+        self
+    }
+    /// `[optional account]`
+    /// The token account the creation token fee is paid from.
+    #[inline(always)]
+    pub fn fee_payer_token_account(
+        &mut self,
+        fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_payer_token_account = fee_payer_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// The treasury token account the creation token fee is transferred to.
+    #[inline(always)]
+    pub fn treasury_token_account(
+        &mut self,
+        treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.treasury_token_account = treasury_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// The referrer token account the referral share of the creation token fee is transferred to.
+    /// Its owner is validated in the `CreateSmartAccount::validate()` method.
+    #[inline(always)]
+    pub fn referrer_token_account(
+        &mut self,
+        referrer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.referrer_token_account = referrer_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// In case `program_config.smart_account_creation_token_fee` is set.
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_program = token_program;
+        self
+    }
+    /// `[optional account]`
+    /// The allowlist page the `creator` is on, in case `program_config.smart_account_creation_mode` is `Allowlist`.
+    #[inline(always)]
+    pub fn creator_allowlist(
+        &mut self,
+        creator_allowlist: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.creator_allowlist = creator_allowlist;
+        self
+    }
+    /// `[optional account]`
+    /// The program config authority, in case `program_config.smart_account_creation_mode` is `Cosigned`.
+    #[inline(always)]
+    pub fn program_config_authority(
+        &mut self,
+        program_config_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.program_config_authority = program_config_authority;
+        self
+    }
     /// `[optional argument]`
     #[inline(always)]
     pub fn settings_authority(&mut self, settings_authority: Pubkey) -> &mut Self {
@@ -559,6 +1027,12 @@ impl<'a, 'b> CreateSmartAccountCpiBuilder<'a, 'b> {
         self.instruction.memo = Some(memo);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn referrer(&mut self, referrer: Pubkey) -> &mut Self {
+        self.instruction.referrer = Some(referrer);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -617,6 +1091,7 @@ impl<'a, 'b> CreateSmartAccountCpiBuilder<'a, 'b> {
                 .expect("time_lock is not set"),
             rent_collector: self.instruction.rent_collector.clone(),
             memo: self.instruction.memo.clone(),
+            referrer: self.instruction.referrer.clone(),
         };
         let instruction = CreateSmartAccountCpi {
             __program: self.instruction.__program,
@@ -640,6 +1115,22 @@ impl<'a, 'b> CreateSmartAccountCpiBuilder<'a, 'b> {
                 .expect("system_program is not set"),
 
             program: self.instruction.program.expect("program is not set"),
+
+            referrer_account: self.instruction.referrer_account,
+
+            creation_fee_mint: self.instruction.creation_fee_mint,
+
+            fee_payer_token_account: self.instruction.fee_payer_token_account,
+
+            treasury_token_account: self.instruction.treasury_token_account,
+
+            referrer_token_account: self.instruction.referrer_token_account,
+
+            token_program: self.instruction.token_program,
+
+            creator_allowlist: self.instruction.creator_allowlist,
+
+            program_config_authority: self.instruction.program_config_authority,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -659,6 +1150,14 @@ struct CreateSmartAccountCpiBuilderInstruction<'a, 'b> {
     fee_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    referrer_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    creation_fee_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+    fee_payer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    treasury_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    referrer_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    creator_allowlist: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program_config_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     settings_authority: Option<Pubkey>,
     threshold: Option<u16>,
     signers: Option<Vec<SmartAccountSigner>>,
@@ -666,6 +1165,7 @@ struct CreateSmartAccountCpiBuilderInstruction<'a, 'b> {
     time_lock: Option<u32>,
     rent_collector: Option<Pubkey>,
     memo: Option<String>,
+    referrer: Option<Pubkey>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -23,6 +23,10 @@ pub struct CreateTransaction {
     pub fee_payer: solana_pubkey::Pubkey,
 
     pub system_program: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    pub vault_policy: Option<solana_pubkey::Pubkey>,
 }
 
 impl CreateTransaction {
@@ -39,7 +43,7 @@ impl CreateTransaction {
         args: CreateTransactionInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.settings, false));
         accounts.push(solana_instruction::AccountMeta::new(
             self.transaction,
@@ -54,6 +58,21 @@ impl CreateTransaction {
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
+        if let Some(vault_policy) = self.vault_policy {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                vault_policy,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = CreateTransactionInstructionData::new()
             .try_to_vec()
@@ -114,6 +133,8 @@ impl CreateTransactionInstructionArgs {
 ///   2. `[signer]` creator
 ///   3. `[writable, signer]` fee_payer
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[optional]` program (default to `aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh`)
+///   6. `[optional]` vault_policy
 #[derive(Clone, Debug, Default)]
 pub struct CreateTransactionBuilder {
     settings: Option<solana_pubkey::Pubkey>,
@@ -121,6 +142,8 @@ pub struct CreateTransactionBuilder {
     creator: Option<solana_pubkey::Pubkey>,
     fee_payer: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
+    vault_policy: Option<solana_pubkey::Pubkey>,
     args: Option<CreateTransactionArgs>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -157,6 +180,19 @@ impl CreateTransactionBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account, default to 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    /// `[optional account]`
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    #[inline(always)]
+    pub fn vault_policy(&mut self, vault_policy: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.vault_policy = vault_policy;
+        self
+    }
     #[inline(always)]
     pub fn args(&mut self, args: CreateTransactionArgs) -> &mut Self {
         self.args = Some(args);
@@ -187,6 +223,10 @@ impl CreateTransactionBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh"
+            )),
+            vault_policy: self.vault_policy,
         };
         let args = CreateTransactionInstructionArgs {
             args: self.args.clone().expect("args is not set"),
@@ -207,6 +247,10 @@ pub struct CreateTransactionCpiAccounts<'a, 'b> {
     pub fee_payer: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    pub vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `create_transaction` CPI instruction.
@@ -223,6 +267,10 @@ pub struct CreateTransactionCpi<'a, 'b> {
     pub fee_payer: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    pub vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: CreateTransactionInstructionArgs,
 }
@@ -240,6 +288,8 @@ impl<'a, 'b> CreateTransactionCpi<'a, 'b> {
             creator: accounts.creator,
             fee_payer: accounts.fee_payer,
             system_program: accounts.system_program,
+            program: accounts.program,
+            vault_policy: accounts.vault_policy,
             __args: args,
         }
     }
@@ -266,7 +316,7 @@ impl<'a, 'b> CreateTransactionCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.settings.key,
             false,
@@ -287,6 +337,21 @@ impl<'a, 'b> CreateTransactionCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
+        if let Some(vault_policy) = self.vault_policy {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *vault_policy.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -305,13 +370,17 @@ impl<'a, 'b> CreateTransactionCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.settings.clone());
         account_infos.push(self.transaction.clone());
         account_infos.push(self.creator.clone());
         account_infos.push(self.fee_payer.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.program.clone());
+        if let Some(vault_policy) = self.vault_policy {
+            account_infos.push(vault_policy.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -333,6 +402,8 @@ impl<'a, 'b> CreateTransactionCpi<'a, 'b> {
 ///   2. `[signer]` creator
 ///   3. `[writable, signer]` fee_payer
 ///   4. `[]` system_program
+///   5. `[]` program
+///   6. `[optional]` vault_policy
 #[derive(Clone, Debug)]
 pub struct CreateTransactionCpiBuilder<'a, 'b> {
     instruction: Box<CreateTransactionCpiBuilderInstruction<'a, 'b>>,
@@ -347,6 +418,8 @@ impl<'a, 'b> CreateTransactionCpiBuilder<'a, 'b> {
             creator: None,
             fee_payer: None,
             system_program: None,
+            program: None,
+            vault_policy: None,
             args: None,
             __remaining_accounts: Vec::new(),
         });
@@ -386,6 +459,21 @@ impl<'a, 'b> CreateTransactionCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    /// `[optional account]`
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    #[inline(always)]
+    pub fn vault_policy(
+        &mut self,
+        vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_policy = vault_policy;
+        self
+    }
+    #[inline(always)]
     pub fn args(&mut self, args: CreateTransactionArgs) -> &mut Self {
         self.instruction.args = Some(args);
         self
@@ -445,6 +533,10 @@ impl<'a, 'b> CreateTransactionCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
+
+            vault_policy: self.instruction.vault_policy,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -462,6 +554,8 @@ struct CreateTransactionCpiBuilderInstruction<'a, 'b> {
     creator: Option<&'b solana_account_info::AccountInfo<'a>>,
     fee_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
     args: Option<CreateTransactionArgs>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
@@ -24,6 +24,10 @@ pub struct CreateTransactionFromBuffer {
 
     pub system_program: solana_pubkey::Pubkey,
 
+    pub program: solana_pubkey::Pubkey,
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    pub vault_policy: Option<solana_pubkey::Pubkey>,
+
     pub transaction_buffer: solana_pubkey::Pubkey,
 
     pub from_buffer_creator: solana_pubkey::Pubkey,
@@ -43,7 +47,7 @@ impl CreateTransactionFromBuffer {
         args: CreateTransactionFromBufferInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.settings, false));
         accounts.push(solana_instruction::AccountMeta::new(
             self.transaction,
@@ -58,6 +62,21 @@ impl CreateTransactionFromBuffer {
             self.system_program,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
+        if let Some(vault_policy) = self.vault_policy {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                vault_policy,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new(
             self.transaction_buffer,
             false,
@@ -126,8 +145,10 @@ impl CreateTransactionFromBufferInstructionArgs {
 ///   2. `[signer]` creator
 ///   3. `[writable, signer]` fee_payer
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   5. `[writable]` transaction_buffer
-///   6. `[writable, signer]` from_buffer_creator
+///   5. `[optional]` program (default to `aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh`)
+///   6. `[optional]` vault_policy
+///   7. `[writable]` transaction_buffer
+///   8. `[writable, signer]` from_buffer_creator
 #[derive(Clone, Debug, Default)]
 pub struct CreateTransactionFromBufferBuilder {
     settings: Option<solana_pubkey::Pubkey>,
@@ -135,6 +156,8 @@ pub struct CreateTransactionFromBufferBuilder {
     creator: Option<solana_pubkey::Pubkey>,
     fee_payer: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
+    vault_policy: Option<solana_pubkey::Pubkey>,
     transaction_buffer: Option<solana_pubkey::Pubkey>,
     from_buffer_creator: Option<solana_pubkey::Pubkey>,
     args: Option<CreateTransactionArgs>,
@@ -173,6 +196,19 @@ impl CreateTransactionFromBufferBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account, default to 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    /// `[optional account]`
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    #[inline(always)]
+    pub fn vault_policy(&mut self, vault_policy: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.vault_policy = vault_policy;
+        self
+    }
     #[inline(always)]
     pub fn transaction_buffer(&mut self, transaction_buffer: solana_pubkey::Pubkey) -> &mut Self {
         self.transaction_buffer = Some(transaction_buffer);
@@ -213,6 +249,10 @@ impl CreateTransactionFromBufferBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh"
+            )),
+            vault_policy: self.vault_policy,
             transaction_buffer: self
                 .transaction_buffer
                 .expect("transaction_buffer is not set"),
@@ -240,6 +280,10 @@ pub struct CreateTransactionFromBufferCpiAccounts<'a, 'b> {
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
+    pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    pub vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub transaction_buffer: &'b solana_account_info::AccountInfo<'a>,
 
     pub from_buffer_creator: &'b solana_account_info::AccountInfo<'a>,
@@ -260,6 +304,10 @@ pub struct CreateTransactionFromBufferCpi<'a, 'b> {
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
+    pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    pub vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub transaction_buffer: &'b solana_account_info::AccountInfo<'a>,
 
     pub from_buffer_creator: &'b solana_account_info::AccountInfo<'a>,
@@ -280,6 +328,8 @@ impl<'a, 'b> CreateTransactionFromBufferCpi<'a, 'b> {
             creator: accounts.creator,
             fee_payer: accounts.fee_payer,
             system_program: accounts.system_program,
+            program: accounts.program,
+            vault_policy: accounts.vault_policy,
             transaction_buffer: accounts.transaction_buffer,
             from_buffer_creator: accounts.from_buffer_creator,
             __args: args,
@@ -308,7 +358,7 @@ impl<'a, 'b> CreateTransactionFromBufferCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.settings.key,
             false,
@@ -329,6 +379,21 @@ impl<'a, 'b> CreateTransactionFromBufferCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
+        if let Some(vault_policy) = self.vault_policy {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *vault_policy.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new(
             *self.transaction_buffer.key,
             false,
@@ -355,13 +420,17 @@ impl<'a, 'b> CreateTransactionFromBufferCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.settings.clone());
         account_infos.push(self.transaction.clone());
         account_infos.push(self.creator.clone());
         account_infos.push(self.fee_payer.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.program.clone());
+        if let Some(vault_policy) = self.vault_policy {
+            account_infos.push(vault_policy.clone());
+        }
         account_infos.push(self.transaction_buffer.clone());
         account_infos.push(self.from_buffer_creator.clone());
         remaining_accounts
@@ -385,8 +454,10 @@ impl<'a, 'b> CreateTransactionFromBufferCpi<'a, 'b> {
 ///   2. `[signer]` creator
 ///   3. `[writable, signer]` fee_payer
 ///   4. `[]` system_program
-///   5. `[writable]` transaction_buffer
-///   6. `[writable, signer]` from_buffer_creator
+///   5. `[]` program
+///   6. `[optional]` vault_policy
+///   7. `[writable]` transaction_buffer
+///   8. `[writable, signer]` from_buffer_creator
 #[derive(Clone, Debug)]
 pub struct CreateTransactionFromBufferCpiBuilder<'a, 'b> {
     instruction: Box<CreateTransactionFromBufferCpiBuilderInstruction<'a, 'b>>,
@@ -401,6 +472,8 @@ impl<'a, 'b> CreateTransactionFromBufferCpiBuilder<'a, 'b> {
             creator: None,
             fee_payer: None,
             system_program: None,
+            program: None,
+            vault_policy: None,
             transaction_buffer: None,
             from_buffer_creator: None,
             args: None,
@@ -442,6 +515,21 @@ impl<'a, 'b> CreateTransactionFromBufferCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    /// `[optional account]`
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    #[inline(always)]
+    pub fn vault_policy(
+        &mut self,
+        vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_policy = vault_policy;
+        self
+    }
+    #[inline(always)]
     pub fn transaction_buffer(
         &mut self,
         transaction_buffer: &'b solana_account_info::AccountInfo<'a>,
@@ -518,6 +606,10 @@ impl<'a, 'b> CreateTransactionFromBufferCpiBuilder<'a, 'b> {
                 .system_program
                 .expect("system_program is not set"),
 
+            program: self.instruction.program.expect("program is not set"),
+
+            vault_policy: self.instruction.vault_policy,
+
             transaction_buffer: self
                 .instruction
                 .transaction_buffer
@@ -544,6 +636,8 @@ struct CreateTransactionFromBufferCpiBuilderInstruction<'a, 'b> {
     creator: Option<&'b solana_account_info::AccountInfo<'a>>,
     fee_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
     transaction_buffer: Option<&'b solana_account_info::AccountInfo<'a>>,
     from_buffer_creator: Option<&'b solana_account_info::AccountInfo<'a>>,
     args: Option<CreateTransactionArgs>,
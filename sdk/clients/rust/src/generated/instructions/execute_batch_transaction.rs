@@ -28,6 +28,10 @@ pub struct ExecuteBatchTransaction {
     pub batch: solana_pubkey::Pubkey,
     /// Batch transaction to execute.
     pub transaction: solana_pubkey::Pubkey,
+
+    pub program: solana_pubkey::Pubkey,
+    /// The policy of the smart account the batch is for, required if it has one.
+    pub vault_policy: Option<solana_pubkey::Pubkey>,
 }
 
 impl ExecuteBatchTransaction {
@@ -40,7 +44,7 @@ impl ExecuteBatchTransaction {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.settings,
             false,
@@ -60,6 +64,21 @@ impl ExecuteBatchTransaction {
             self.transaction,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
+        if let Some(vault_policy) = self.vault_policy {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                vault_policy,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = ExecuteBatchTransactionInstructionData::new()
             .try_to_vec()
@@ -108,6 +127,8 @@ impl Default for ExecuteBatchTransactionInstructionData {
 ///   4. `[writable]` proposal
 ///   5. `[writable]` batch
 ///   6. `[]` transaction
+///   7. `[optional]` program (default to `aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh`)
+///   8. `[optional]` vault_policy
 #[derive(Clone, Debug, Default)]
 pub struct ExecuteBatchTransactionBuilder {
     settings: Option<solana_pubkey::Pubkey>,
@@ -117,6 +138,8 @@ pub struct ExecuteBatchTransactionBuilder {
     proposal: Option<solana_pubkey::Pubkey>,
     batch: Option<solana_pubkey::Pubkey>,
     transaction: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
+    vault_policy: Option<solana_pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -165,6 +188,19 @@ impl ExecuteBatchTransactionBuilder {
         self.transaction = Some(transaction);
         self
     }
+    /// `[optional account, default to 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    /// `[optional account]`
+    /// The policy of the smart account the batch is for, required if it has one.
+    #[inline(always)]
+    pub fn vault_policy(&mut self, vault_policy: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.vault_policy = vault_policy;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -192,6 +228,10 @@ impl ExecuteBatchTransactionBuilder {
             proposal: self.proposal.expect("proposal is not set"),
             batch: self.batch.expect("batch is not set"),
             transaction: self.transaction.expect("transaction is not set"),
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh"
+            )),
+            vault_policy: self.vault_policy,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -215,6 +255,10 @@ pub struct ExecuteBatchTransactionCpiAccounts<'a, 'b> {
     pub batch: &'b solana_account_info::AccountInfo<'a>,
     /// Batch transaction to execute.
     pub transaction: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The policy of the smart account the batch is for, required if it has one.
+    pub vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `execute_batch_transaction` CPI instruction.
@@ -236,6 +280,10 @@ pub struct ExecuteBatchTransactionCpi<'a, 'b> {
     pub batch: &'b solana_account_info::AccountInfo<'a>,
     /// Batch transaction to execute.
     pub transaction: &'b solana_account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The policy of the smart account the batch is for, required if it has one.
+    pub vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> ExecuteBatchTransactionCpi<'a, 'b> {
@@ -252,6 +300,8 @@ impl<'a, 'b> ExecuteBatchTransactionCpi<'a, 'b> {
             proposal: accounts.proposal,
             batch: accounts.batch,
             transaction: accounts.transaction,
+            program: accounts.program,
+            vault_policy: accounts.vault_policy,
         }
     }
     #[inline(always)]
@@ -277,7 +327,7 @@ impl<'a, 'b> ExecuteBatchTransactionCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.settings.key,
             false,
@@ -303,6 +353,21 @@ impl<'a, 'b> ExecuteBatchTransactionCpi<'a, 'b> {
             *self.transaction.key,
             false,
         ));
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
+        if let Some(vault_policy) = self.vault_policy {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *vault_policy.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -319,7 +384,7 @@ impl<'a, 'b> ExecuteBatchTransactionCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.settings.clone());
         account_infos.push(self.signer.clone());
@@ -328,6 +393,10 @@ impl<'a, 'b> ExecuteBatchTransactionCpi<'a, 'b> {
         account_infos.push(self.proposal.clone());
         account_infos.push(self.batch.clone());
         account_infos.push(self.transaction.clone());
+        account_infos.push(self.program.clone());
+        if let Some(vault_policy) = self.vault_policy {
+            account_infos.push(vault_policy.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -351,6 +420,8 @@ impl<'a, 'b> ExecuteBatchTransactionCpi<'a, 'b> {
 ///   4. `[writable]` proposal
 ///   5. `[writable]` batch
 ///   6. `[]` transaction
+///   7. `[]` program
+///   8. `[optional]` vault_policy
 #[derive(Clone, Debug)]
 pub struct ExecuteBatchTransactionCpiBuilder<'a, 'b> {
     instruction: Box<ExecuteBatchTransactionCpiBuilderInstruction<'a, 'b>>,
@@ -367,6 +438,8 @@ impl<'a, 'b> ExecuteBatchTransactionCpiBuilder<'a, 'b> {
             proposal: None,
             batch: None,
             transaction: None,
+            program: None,
+            vault_policy: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -417,6 +490,21 @@ impl<'a, 'b> ExecuteBatchTransactionCpiBuilder<'a, 'b> {
         self.instruction.transaction = Some(transaction);
         self
     }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    /// `[optional account]`
+    /// The policy of the smart account the batch is for, required if it has one.
+    #[inline(always)]
+    pub fn vault_policy(
+        &mut self,
+        vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_policy = vault_policy;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -473,6 +561,10 @@ impl<'a, 'b> ExecuteBatchTransactionCpiBuilder<'a, 'b> {
                 .instruction
                 .transaction
                 .expect("transaction is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
+
+            vault_policy: self.instruction.vault_policy,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -491,6 +583,8 @@ struct ExecuteBatchTransactionCpiBuilderInstruction<'a, 'b> {
     proposal: Option<&'b solana_account_info::AccountInfo<'a>>,
     batch: Option<&'b solana_account_info::AccountInfo<'a>>,
     transaction: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
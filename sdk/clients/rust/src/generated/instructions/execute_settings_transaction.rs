@@ -26,6 +26,8 @@ pub struct ExecuteSettingsTransaction {
     pub fee_payer: Option<solana_pubkey::Pubkey>,
     /// We might need it in case reallocation is needed.
     pub system_program: Option<solana_pubkey::Pubkey>,
+
+    pub program: solana_pubkey::Pubkey,
 }
 
 impl ExecuteSettingsTransaction {
@@ -38,7 +40,7 @@ impl ExecuteSettingsTransaction {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.settings, false));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.signer,
@@ -68,6 +70,10 @@ impl ExecuteSettingsTransaction {
                 false,
             ));
         }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = ExecuteSettingsTransactionInstructionData::new()
             .try_to_vec()
@@ -115,6 +121,7 @@ impl Default for ExecuteSettingsTransactionInstructionData {
 ///   3. `[]` transaction
 ///   4. `[writable, signer, optional]` fee_payer
 ///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[optional]` program (default to `aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh`)
 #[derive(Clone, Debug, Default)]
 pub struct ExecuteSettingsTransactionBuilder {
     settings: Option<solana_pubkey::Pubkey>,
@@ -123,6 +130,7 @@ pub struct ExecuteSettingsTransactionBuilder {
     transaction: Option<solana_pubkey::Pubkey>,
     fee_payer: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    program: Option<solana_pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -169,6 +177,12 @@ impl ExecuteSettingsTransactionBuilder {
         self.system_program = system_program;
         self
     }
+    /// `[optional account, default to 'aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -193,6 +207,9 @@ impl ExecuteSettingsTransactionBuilder {
             transaction: self.transaction.expect("transaction is not set"),
             fee_payer: self.fee_payer,
             system_program: self.system_program,
+            program: self.program.unwrap_or(solana_pubkey::pubkey!(
+                "aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh"
+            )),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -214,6 +231,8 @@ pub struct ExecuteSettingsTransactionCpiAccounts<'a, 'b> {
     pub fee_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// We might need it in case reallocation is needed.
     pub system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 /// `execute_settings_transaction` CPI instruction.
@@ -233,6 +252,8 @@ pub struct ExecuteSettingsTransactionCpi<'a, 'b> {
     pub fee_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// We might need it in case reallocation is needed.
     pub system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub program: &'b solana_account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> ExecuteSettingsTransactionCpi<'a, 'b> {
@@ -248,6 +269,7 @@ impl<'a, 'b> ExecuteSettingsTransactionCpi<'a, 'b> {
             transaction: accounts.transaction,
             fee_payer: accounts.fee_payer,
             system_program: accounts.system_program,
+            program: accounts.program,
         }
     }
     #[inline(always)]
@@ -273,7 +295,7 @@ impl<'a, 'b> ExecuteSettingsTransactionCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.settings.key,
             false,
@@ -309,6 +331,10 @@ impl<'a, 'b> ExecuteSettingsTransactionCpi<'a, 'b> {
                 false,
             ));
         }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -325,7 +351,7 @@ impl<'a, 'b> ExecuteSettingsTransactionCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.settings.clone());
         account_infos.push(self.signer.clone());
@@ -337,6 +363,7 @@ impl<'a, 'b> ExecuteSettingsTransactionCpi<'a, 'b> {
         if let Some(system_program) = self.system_program {
             account_infos.push(system_program.clone());
         }
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -359,6 +386,7 @@ impl<'a, 'b> ExecuteSettingsTransactionCpi<'a, 'b> {
 ///   3. `[]` transaction
 ///   4. `[writable, signer, optional]` fee_payer
 ///   5. `[optional]` system_program
+///   6. `[]` program
 #[derive(Clone, Debug)]
 pub struct ExecuteSettingsTransactionCpiBuilder<'a, 'b> {
     instruction: Box<ExecuteSettingsTransactionCpiBuilderInstruction<'a, 'b>>,
@@ -374,6 +402,7 @@ impl<'a, 'b> ExecuteSettingsTransactionCpiBuilder<'a, 'b> {
             transaction: None,
             fee_payer: None,
             system_program: None,
+            program: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -426,6 +455,11 @@ impl<'a, 'b> ExecuteSettingsTransactionCpiBuilder<'a, 'b> {
         self.instruction.system_program = system_program;
         self
     }
+    #[inline(always)]
+    pub fn program(&mut self, program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -477,6 +511,8 @@ impl<'a, 'b> ExecuteSettingsTransactionCpiBuilder<'a, 'b> {
             fee_payer: self.instruction.fee_payer,
 
             system_program: self.instruction.system_program,
+
+            program: self.instruction.program.expect("program is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -494,6 +530,7 @@ struct ExecuteSettingsTransactionCpiBuilderInstruction<'a, 'b> {
     transaction: Option<&'b solana_account_info::AccountInfo<'a>>,
     fee_payer: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
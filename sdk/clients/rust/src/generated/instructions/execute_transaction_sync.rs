@@ -16,6 +16,8 @@ pub struct ExecuteTransactionSync {
     pub settings: solana_pubkey::Pubkey,
 
     pub program: solana_pubkey::Pubkey,
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    pub vault_policy: Option<solana_pubkey::Pubkey>,
 }
 
 impl ExecuteTransactionSync {
@@ -32,7 +34,7 @@ impl ExecuteTransactionSync {
         args: ExecuteTransactionSyncInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.settings,
             false,
@@ -41,6 +43,17 @@ impl ExecuteTransactionSync {
             self.program,
             false,
         ));
+        if let Some(vault_policy) = self.vault_policy {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                vault_policy,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = ExecuteTransactionSyncInstructionData::new()
             .try_to_vec()
@@ -100,10 +113,12 @@ impl ExecuteTransactionSyncInstructionArgs {
 ///
 ///   0. `[]` settings
 ///   1. `[optional]` program (default to `aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh`)
+///   2. `[optional]` vault_policy
 #[derive(Clone, Debug, Default)]
 pub struct ExecuteTransactionSyncBuilder {
     settings: Option<solana_pubkey::Pubkey>,
     program: Option<solana_pubkey::Pubkey>,
+    vault_policy: Option<solana_pubkey::Pubkey>,
     account_index: Option<u8>,
     num_signers: Option<u8>,
     instructions: Option<Vec<u8>>,
@@ -125,6 +140,13 @@ impl ExecuteTransactionSyncBuilder {
         self.program = Some(program);
         self
     }
+    /// `[optional account]`
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    #[inline(always)]
+    pub fn vault_policy(&mut self, vault_policy: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.vault_policy = vault_policy;
+        self
+    }
     #[inline(always)]
     pub fn account_index(&mut self, account_index: u8) -> &mut Self {
         self.account_index = Some(account_index);
@@ -162,6 +184,7 @@ impl ExecuteTransactionSyncBuilder {
             program: self.program.unwrap_or(solana_pubkey::pubkey!(
                 "aStRoeLaWJCg8wy8wcUGHYBJJaoSUVQrgoUZZdQcWRh"
             )),
+            vault_policy: self.vault_policy,
         };
         let args = ExecuteTransactionSyncInstructionArgs {
             account_index: self
@@ -181,6 +204,8 @@ pub struct ExecuteTransactionSyncCpiAccounts<'a, 'b> {
     pub settings: &'b solana_account_info::AccountInfo<'a>,
 
     pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    pub vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `execute_transaction_sync` CPI instruction.
@@ -191,6 +216,8 @@ pub struct ExecuteTransactionSyncCpi<'a, 'b> {
     pub settings: &'b solana_account_info::AccountInfo<'a>,
 
     pub program: &'b solana_account_info::AccountInfo<'a>,
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    pub vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: ExecuteTransactionSyncInstructionArgs,
 }
@@ -205,6 +232,7 @@ impl<'a, 'b> ExecuteTransactionSyncCpi<'a, 'b> {
             __program: program,
             settings: accounts.settings,
             program: accounts.program,
+            vault_policy: accounts.vault_policy,
             __args: args,
         }
     }
@@ -231,7 +259,7 @@ impl<'a, 'b> ExecuteTransactionSyncCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.settings.key,
             false,
@@ -240,6 +268,17 @@ impl<'a, 'b> ExecuteTransactionSyncCpi<'a, 'b> {
             *self.program.key,
             false,
         ));
+        if let Some(vault_policy) = self.vault_policy {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *vault_policy.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::ASTROLABE_SMART_ACCOUNT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -258,10 +297,13 @@ impl<'a, 'b> ExecuteTransactionSyncCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.settings.clone());
         account_infos.push(self.program.clone());
+        if let Some(vault_policy) = self.vault_policy {
+            account_infos.push(vault_policy.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -280,6 +322,7 @@ impl<'a, 'b> ExecuteTransactionSyncCpi<'a, 'b> {
 ///
 ///   0. `[]` settings
 ///   1. `[]` program
+///   2. `[optional]` vault_policy
 #[derive(Clone, Debug)]
 pub struct ExecuteTransactionSyncCpiBuilder<'a, 'b> {
     instruction: Box<ExecuteTransactionSyncCpiBuilderInstruction<'a, 'b>>,
@@ -291,6 +334,7 @@ impl<'a, 'b> ExecuteTransactionSyncCpiBuilder<'a, 'b> {
             __program: program,
             settings: None,
             program: None,
+            vault_policy: None,
             account_index: None,
             num_signers: None,
             instructions: None,
@@ -308,6 +352,16 @@ impl<'a, 'b> ExecuteTransactionSyncCpiBuilder<'a, 'b> {
         self.instruction.program = Some(program);
         self
     }
+    /// `[optional account]`
+    /// The policy of the smart account at `args.account_index`, required if it has one.
+    #[inline(always)]
+    pub fn vault_policy(
+        &mut self,
+        vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_policy = vault_policy;
+        self
+    }
     #[inline(always)]
     pub fn account_index(&mut self, account_index: u8) -> &mut Self {
         self.instruction.account_index = Some(account_index);
@@ -380,6 +434,8 @@ impl<'a, 'b> ExecuteTransactionSyncCpiBuilder<'a, 'b> {
             settings: self.instruction.settings.expect("settings is not set"),
 
             program: self.instruction.program.expect("program is not set"),
+
+            vault_policy: self.instruction.vault_policy,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -394,6 +450,7 @@ struct ExecuteTransactionSyncCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_account_info::AccountInfo<'a>,
     settings: Option<&'b solana_account_info::AccountInfo<'a>>,
     program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    vault_policy: Option<&'b solana_account_info::AccountInfo<'a>>,
     account_index: Option<u8>,
     num_signers: Option<u8>,
     instructions: Option<Vec<u8>>,
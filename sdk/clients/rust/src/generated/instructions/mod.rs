@@ -5,72 +5,174 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#accept_program_config_authority;
+pub(crate) mod r#accept_program_config_treasury;
 pub(crate) mod r#activate_proposal;
+pub(crate) mod r#add_creator_to_allowlist;
+pub(crate) mod r#add_program_filter_as_authority;
+pub(crate) mod r#add_restricted_signer_as_authority;
 pub(crate) mod r#add_signer_as_authority;
 pub(crate) mod r#add_transaction_to_batch;
+pub(crate) mod r#add_transaction_to_batch_from_buffer;
 pub(crate) mod r#approve_proposal;
+pub(crate) mod r#approve_proposal_with_passkey;
+pub(crate) mod r#approve_proposal_with_session_key;
+pub(crate) mod r#approve_recovery;
+pub(crate) mod r#archive_settings;
+pub(crate) mod r#cancel_program_config_transfer;
 pub(crate) mod r#cancel_proposal;
+pub(crate) mod r#cancel_proposal_with_passkey;
+pub(crate) mod r#cancel_proposal_with_session_key;
+pub(crate) mod r#cancel_signer_rotation;
+pub(crate) mod r#change_signer_permissions_as_authority;
 pub(crate) mod r#change_threshold_as_authority;
+pub(crate) mod r#close_abandoned_transaction_buffer;
 pub(crate) mod r#close_batch;
 pub(crate) mod r#close_batch_transaction;
 pub(crate) mod r#close_settings_transaction;
 pub(crate) mod r#close_transaction;
 pub(crate) mod r#close_transaction_buffer;
 pub(crate) mod r#create_batch;
+pub(crate) mod r#create_creator_allowlist_page;
+pub(crate) mod r#create_passkey;
 pub(crate) mod r#create_proposal;
+pub(crate) mod r#create_recovery;
+pub(crate) mod r#create_session_key;
 pub(crate) mod r#create_settings_transaction;
+pub(crate) mod r#create_settings_transaction_from_buffer;
 pub(crate) mod r#create_smart_account;
 pub(crate) mod r#create_transaction;
 pub(crate) mod r#create_transaction_buffer;
 pub(crate) mod r#create_transaction_from_buffer;
+pub(crate) mod r#emergency_exit;
+pub(crate) mod r#execute_batch_atomic;
 pub(crate) mod r#execute_batch_transaction;
 pub(crate) mod r#execute_settings_transaction;
 pub(crate) mod r#execute_settings_transaction_sync;
 pub(crate) mod r#execute_transaction;
 pub(crate) mod r#execute_transaction_sync;
+pub(crate) mod r#expire_proposal;
 pub(crate) mod r#extend_transaction_buffer;
+pub(crate) mod r#finalize_recovery;
+pub(crate) mod r#finalize_signer_rotation;
 pub(crate) mod r#initialize_program_config;
 pub(crate) mod r#log_event;
+pub(crate) mod r#migrate_settings;
+pub(crate) mod r#propose_program_config_authority;
+pub(crate) mod r#propose_program_config_treasury;
 pub(crate) mod r#reject_proposal;
+pub(crate) mod r#reject_proposal_with_passkey;
+pub(crate) mod r#reject_proposal_with_session_key;
+pub(crate) mod r#remove_creator_from_allowlist;
+pub(crate) mod r#remove_program_filter_as_authority;
+pub(crate) mod r#remove_restricted_signer_as_authority;
 pub(crate) mod r#remove_signer_as_authority;
+pub(crate) mod r#remove_target_time_lock_as_authority;
+pub(crate) mod r#remove_vault_policy_as_authority;
+pub(crate) mod r#request_signer_rotation;
+pub(crate) mod r#revoke_session_key;
+pub(crate) mod r#rotate_signer_as_authority;
 pub(crate) mod r#set_archival_authority_as_authority;
+pub(crate) mod r#set_execution_window_as_authority;
 pub(crate) mod r#set_new_settings_authority_as_authority;
-pub(crate) mod r#set_program_config_authority;
+pub(crate) mod r#set_program_config_creation_mode;
+pub(crate) mod r#set_program_config_creation_token_fee;
+pub(crate) mod r#set_program_config_referral_fee;
 pub(crate) mod r#set_program_config_smart_account_creation_fee;
-pub(crate) mod r#set_program_config_treasury;
+pub(crate) mod r#set_program_filter_mode_as_authority;
+pub(crate) mod r#set_recovery_config_as_authority;
+pub(crate) mod r#set_rent_collector_as_authority;
+pub(crate) mod r#set_signer_rotation_delay_as_authority;
+pub(crate) mod r#set_target_time_lock_as_authority;
 pub(crate) mod r#set_time_lock_as_authority;
+pub(crate) mod r#set_vault_policy_as_authority;
+pub(crate) mod r#skip_batch_transaction;
+pub(crate) mod r#unarchive_settings;
+pub(crate) mod r#use_spending_limit;
+pub(crate) mod r#veto_recovery;
 
+pub use self::r#accept_program_config_authority::*;
+pub use self::r#accept_program_config_treasury::*;
 pub use self::r#activate_proposal::*;
+pub use self::r#add_creator_to_allowlist::*;
+pub use self::r#add_program_filter_as_authority::*;
+pub use self::r#add_restricted_signer_as_authority::*;
 pub use self::r#add_signer_as_authority::*;
 pub use self::r#add_transaction_to_batch::*;
+pub use self::r#add_transaction_to_batch_from_buffer::*;
 pub use self::r#approve_proposal::*;
+pub use self::r#approve_proposal_with_passkey::*;
+pub use self::r#approve_proposal_with_session_key::*;
+pub use self::r#approve_recovery::*;
+pub use self::r#archive_settings::*;
+pub use self::r#cancel_program_config_transfer::*;
 pub use self::r#cancel_proposal::*;
+pub use self::r#cancel_proposal_with_passkey::*;
+pub use self::r#cancel_proposal_with_session_key::*;
+pub use self::r#cancel_signer_rotation::*;
+pub use self::r#change_signer_permissions_as_authority::*;
 pub use self::r#change_threshold_as_authority::*;
+pub use self::r#close_abandoned_transaction_buffer::*;
 pub use self::r#close_batch::*;
 pub use self::r#close_batch_transaction::*;
 pub use self::r#close_settings_transaction::*;
 pub use self::r#close_transaction::*;
 pub use self::r#close_transaction_buffer::*;
 pub use self::r#create_batch::*;
+pub use self::r#create_creator_allowlist_page::*;
+pub use self::r#create_passkey::*;
 pub use self::r#create_proposal::*;
+pub use self::r#create_recovery::*;
+pub use self::r#create_session_key::*;
 pub use self::r#create_settings_transaction::*;
+pub use self::r#create_settings_transaction_from_buffer::*;
 pub use self::r#create_smart_account::*;
 pub use self::r#create_transaction::*;
 pub use self::r#create_transaction_buffer::*;
 pub use self::r#create_transaction_from_buffer::*;
+pub use self::r#emergency_exit::*;
+pub use self::r#execute_batch_atomic::*;
 pub use self::r#execute_batch_transaction::*;
 pub use self::r#execute_settings_transaction::*;
 pub use self::r#execute_settings_transaction_sync::*;
 pub use self::r#execute_transaction::*;
 pub use self::r#execute_transaction_sync::*;
+pub use self::r#expire_proposal::*;
 pub use self::r#extend_transaction_buffer::*;
+pub use self::r#finalize_recovery::*;
+pub use self::r#finalize_signer_rotation::*;
 pub use self::r#initialize_program_config::*;
 pub use self::r#log_event::*;
+pub use self::r#migrate_settings::*;
+pub use self::r#propose_program_config_authority::*;
+pub use self::r#propose_program_config_treasury::*;
 pub use self::r#reject_proposal::*;
+pub use self::r#reject_proposal_with_passkey::*;
+pub use self::r#reject_proposal_with_session_key::*;
+pub use self::r#remove_creator_from_allowlist::*;
+pub use self::r#remove_program_filter_as_authority::*;
+pub use self::r#remove_restricted_signer_as_authority::*;
 pub use self::r#remove_signer_as_authority::*;
+pub use self::r#remove_target_time_lock_as_authority::*;
+pub use self::r#remove_vault_policy_as_authority::*;
+pub use self::r#request_signer_rotation::*;
+pub use self::r#revoke_session_key::*;
+pub use self::r#rotate_signer_as_authority::*;
 pub use self::r#set_archival_authority_as_authority::*;
+pub use self::r#set_execution_window_as_authority::*;
 pub use self::r#set_new_settings_authority_as_authority::*;
-pub use self::r#set_program_config_authority::*;
+pub use self::r#set_program_config_creation_mode::*;
+pub use self::r#set_program_config_creation_token_fee::*;
+pub use self::r#set_program_config_referral_fee::*;
 pub use self::r#set_program_config_smart_account_creation_fee::*;
-pub use self::r#set_program_config_treasury::*;
+pub use self::r#set_program_filter_mode_as_authority::*;
+pub use self::r#set_recovery_config_as_authority::*;
+pub use self::r#set_rent_collector_as_authority::*;
+pub use self::r#set_signer_rotation_delay_as_authority::*;
+pub use self::r#set_target_time_lock_as_authority::*;
 pub use self::r#set_time_lock_as_authority::*;
+pub use self::r#set_vault_policy_as_authority::*;
+pub use self::r#skip_batch_transaction::*;
+pub use self::r#unarchive_settings::*;
+pub use self::r#use_spending_limit::*;
+pub use self::r#veto_recovery::*;
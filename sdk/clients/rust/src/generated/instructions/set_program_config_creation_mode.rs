@@ -5,24 +5,24 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::SmartAccountCreationMode;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
-use solana_pubkey::Pubkey;
 
-pub const SET_PROGRAM_CONFIG_TREASURY_DISCRIMINATOR: [u8; 1] = [4];
+pub const SET_PROGRAM_CONFIG_CREATION_MODE_DISCRIMINATOR: [u8; 1] = [78];
 
 /// Accounts.
 #[derive(Debug)]
-pub struct SetProgramConfigTreasury {
+pub struct SetProgramConfigCreationMode {
     pub program_config: solana_pubkey::Pubkey,
 
     pub authority: solana_pubkey::Pubkey,
 }
 
-impl SetProgramConfigTreasury {
+impl SetProgramConfigCreationMode {
     pub fn instruction(
         &self,
-        args: SetProgramConfigTreasuryInstructionArgs,
+        args: SetProgramConfigCreationModeInstructionArgs,
     ) -> solana_instruction::Instruction {
         self.instruction_with_remaining_accounts(args, &[])
     }
@@ -30,7 +30,7 @@ impl SetProgramConfigTreasury {
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
-        args: SetProgramConfigTreasuryInstructionArgs,
+        args: SetProgramConfigCreationModeInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
         let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
@@ -43,7 +43,7 @@ impl SetProgramConfigTreasury {
             true,
         ));
         accounts.extend_from_slice(remaining_accounts);
-        let mut data = SetProgramConfigTreasuryInstructionData::new()
+        let mut data = SetProgramConfigCreationModeInstructionData::new()
             .try_to_vec()
             .unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -59,13 +59,15 @@ impl SetProgramConfigTreasury {
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct SetProgramConfigTreasuryInstructionData {
+pub struct SetProgramConfigCreationModeInstructionData {
     discriminator: [u8; 1],
 }
 
-impl SetProgramConfigTreasuryInstructionData {
+impl SetProgramConfigCreationModeInstructionData {
     pub fn new() -> Self {
-        Self { discriminator: [4] }
+        Self {
+            discriminator: [78],
+        }
     }
 
     pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
@@ -73,7 +75,7 @@ impl SetProgramConfigTreasuryInstructionData {
     }
 }
 
-impl Default for SetProgramConfigTreasuryInstructionData {
+impl Default for SetProgramConfigCreationModeInstructionData {
     fn default() -> Self {
         Self::new()
     }
@@ -81,31 +83,31 @@ impl Default for SetProgramConfigTreasuryInstructionData {
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct SetProgramConfigTreasuryInstructionArgs {
-    pub new_treasury: Pubkey,
+pub struct SetProgramConfigCreationModeInstructionArgs {
+    pub new_smart_account_creation_mode: SmartAccountCreationMode,
 }
 
-impl SetProgramConfigTreasuryInstructionArgs {
+impl SetProgramConfigCreationModeInstructionArgs {
     pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
         borsh::to_vec(self)
     }
 }
 
-/// Instruction builder for `SetProgramConfigTreasury`.
+/// Instruction builder for `SetProgramConfigCreationMode`.
 ///
 /// ### Accounts:
 ///
 ///   0. `[writable]` program_config
 ///   1. `[signer]` authority
 #[derive(Clone, Debug, Default)]
-pub struct SetProgramConfigTreasuryBuilder {
+pub struct SetProgramConfigCreationModeBuilder {
     program_config: Option<solana_pubkey::Pubkey>,
     authority: Option<solana_pubkey::Pubkey>,
-    new_treasury: Option<Pubkey>,
+    new_smart_account_creation_mode: Option<SmartAccountCreationMode>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
-impl SetProgramConfigTreasuryBuilder {
+impl SetProgramConfigCreationModeBuilder {
     pub fn new() -> Self {
         Self::default()
     }
@@ -120,8 +122,11 @@ impl SetProgramConfigTreasuryBuilder {
         self
     }
     #[inline(always)]
-    pub fn new_treasury(&mut self, new_treasury: Pubkey) -> &mut Self {
-        self.new_treasury = Some(new_treasury);
+    pub fn new_smart_account_creation_mode(
+        &mut self,
+        new_smart_account_creation_mode: SmartAccountCreationMode,
+    ) -> &mut Self {
+        self.new_smart_account_creation_mode = Some(new_smart_account_creation_mode);
         self
     }
     /// Add an additional account to the instruction.
@@ -141,27 +146,30 @@ impl SetProgramConfigTreasuryBuilder {
     }
     #[allow(clippy::clone_on_copy)]
     pub fn instruction(&self) -> solana_instruction::Instruction {
-        let accounts = SetProgramConfigTreasury {
+        let accounts = SetProgramConfigCreationMode {
             program_config: self.program_config.expect("program_config is not set"),
             authority: self.authority.expect("authority is not set"),
         };
-        let args = SetProgramConfigTreasuryInstructionArgs {
-            new_treasury: self.new_treasury.clone().expect("new_treasury is not set"),
+        let args = SetProgramConfigCreationModeInstructionArgs {
+            new_smart_account_creation_mode: self
+                .new_smart_account_creation_mode
+                .clone()
+                .expect("new_smart_account_creation_mode is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
-/// `set_program_config_treasury` CPI accounts.
-pub struct SetProgramConfigTreasuryCpiAccounts<'a, 'b> {
+/// `set_program_config_creation_mode` CPI accounts.
+pub struct SetProgramConfigCreationModeCpiAccounts<'a, 'b> {
     pub program_config: &'b solana_account_info::AccountInfo<'a>,
 
     pub authority: &'b solana_account_info::AccountInfo<'a>,
 }
 
-/// `set_program_config_treasury` CPI instruction.
-pub struct SetProgramConfigTreasuryCpi<'a, 'b> {
+/// `set_program_config_creation_mode` CPI instruction.
+pub struct SetProgramConfigCreationModeCpi<'a, 'b> {
     /// The program to invoke.
     pub __program: &'b solana_account_info::AccountInfo<'a>,
 
@@ -169,14 +177,14 @@ pub struct SetProgramConfigTreasuryCpi<'a, 'b> {
 
     pub authority: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
-    pub __args: SetProgramConfigTreasuryInstructionArgs,
+    pub __args: SetProgramConfigCreationModeInstructionArgs,
 }
 
-impl<'a, 'b> SetProgramConfigTreasuryCpi<'a, 'b> {
+impl<'a, 'b> SetProgramConfigCreationModeCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_account_info::AccountInfo<'a>,
-        accounts: SetProgramConfigTreasuryCpiAccounts<'a, 'b>,
-        args: SetProgramConfigTreasuryInstructionArgs,
+        accounts: SetProgramConfigCreationModeCpiAccounts<'a, 'b>,
+        args: SetProgramConfigCreationModeInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -224,7 +232,7 @@ impl<'a, 'b> SetProgramConfigTreasuryCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let mut data = SetProgramConfigTreasuryInstructionData::new()
+        let mut data = SetProgramConfigCreationModeInstructionData::new()
             .try_to_vec()
             .unwrap();
         let mut args = self.__args.try_to_vec().unwrap();
@@ -251,24 +259,24 @@ impl<'a, 'b> SetProgramConfigTreasuryCpi<'a, 'b> {
     }
 }
 
-/// Instruction builder for `SetProgramConfigTreasury` via CPI.
+/// Instruction builder for `SetProgramConfigCreationMode` via CPI.
 ///
 /// ### Accounts:
 ///
 ///   0. `[writable]` program_config
 ///   1. `[signer]` authority
 #[derive(Clone, Debug)]
-pub struct SetProgramConfigTreasuryCpiBuilder<'a, 'b> {
-    instruction: Box<SetProgramConfigTreasuryCpiBuilderInstruction<'a, 'b>>,
+pub struct SetProgramConfigCreationModeCpiBuilder<'a, 'b> {
+    instruction: Box<SetProgramConfigCreationModeCpiBuilderInstruction<'a, 'b>>,
 }
 
-impl<'a, 'b> SetProgramConfigTreasuryCpiBuilder<'a, 'b> {
+impl<'a, 'b> SetProgramConfigCreationModeCpiBuilder<'a, 'b> {
     pub fn new(program: &'b solana_account_info::AccountInfo<'a>) -> Self {
-        let instruction = Box::new(SetProgramConfigTreasuryCpiBuilderInstruction {
+        let instruction = Box::new(SetProgramConfigCreationModeCpiBuilderInstruction {
             __program: program,
             program_config: None,
             authority: None,
-            new_treasury: None,
+            new_smart_account_creation_mode: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -287,8 +295,11 @@ impl<'a, 'b> SetProgramConfigTreasuryCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn new_treasury(&mut self, new_treasury: Pubkey) -> &mut Self {
-        self.instruction.new_treasury = Some(new_treasury);
+    pub fn new_smart_account_creation_mode(
+        &mut self,
+        new_smart_account_creation_mode: SmartAccountCreationMode,
+    ) -> &mut Self {
+        self.instruction.new_smart_account_creation_mode = Some(new_smart_account_creation_mode);
         self
     }
     /// Add an additional account to the instruction.
@@ -325,14 +336,14 @@ impl<'a, 'b> SetProgramConfigTreasuryCpiBuilder<'a, 'b> {
     #[allow(clippy::clone_on_copy)]
     #[allow(clippy::vec_init_then_push)]
     pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
-        let args = SetProgramConfigTreasuryInstructionArgs {
-            new_treasury: self
+        let args = SetProgramConfigCreationModeInstructionArgs {
+            new_smart_account_creation_mode: self
                 .instruction
-                .new_treasury
+                .new_smart_account_creation_mode
                 .clone()
-                .expect("new_treasury is not set"),
+                .expect("new_smart_account_creation_mode is not set"),
         };
-        let instruction = SetProgramConfigTreasuryCpi {
+        let instruction = SetProgramConfigCreationModeCpi {
             __program: self.instruction.__program,
 
             program_config: self
@@ -351,11 +362,11 @@ impl<'a, 'b> SetProgramConfigTreasuryCpiBuilder<'a, 'b> {
 }
 
 #[derive(Clone, Debug)]
-struct SetProgramConfigTreasuryCpiBuilderInstruction<'a, 'b> {
+struct SetProgramConfigCreationModeCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_account_info::AccountInfo<'a>,
     program_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_account_info::AccountInfo<'a>>,
-    new_treasury: Option<Pubkey>,
+    new_smart_account_creation_mode: Option<SmartAccountCreationMode>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
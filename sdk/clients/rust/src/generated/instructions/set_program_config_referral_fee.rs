@@ -7,22 +7,21 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
-use solana_pubkey::Pubkey;
 
-pub const SET_PROGRAM_CONFIG_AUTHORITY_DISCRIMINATOR: [u8; 1] = [2];
+pub const SET_PROGRAM_CONFIG_REFERRAL_FEE_DISCRIMINATOR: [u8; 1] = [77];
 
 /// Accounts.
 #[derive(Debug)]
-pub struct SetProgramConfigAuthority {
+pub struct SetProgramConfigReferralFee {
     pub program_config: solana_pubkey::Pubkey,
 
     pub authority: solana_pubkey::Pubkey,
 }
 
-impl SetProgramConfigAuthority {
+impl SetProgramConfigReferralFee {
     pub fn instruction(
         &self,
-        args: SetProgramConfigAuthorityInstructionArgs,
+        args: SetProgramConfigReferralFeeInstructionArgs,
     ) -> solana_instruction::Instruction {
         self.instruction_with_remaining_accounts(args, &[])
     }
@@ -30,7 +29,7 @@ impl SetProgramConfigAuthority {
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
-        args: SetProgramConfigAuthorityInstructionArgs,
+        args: SetProgramConfigReferralFeeInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
         let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
@@ -43,7 +42,7 @@ impl SetProgramConfigAuthority {
             true,
         ));
         accounts.extend_from_slice(remaining_accounts);
-        let mut data = SetProgramConfigAuthorityInstructionData::new()
+        let mut data = SetProgramConfigReferralFeeInstructionData::new()
             .try_to_vec()
             .unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -59,13 +58,15 @@ impl SetProgramConfigAuthority {
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct SetProgramConfigAuthorityInstructionData {
+pub struct SetProgramConfigReferralFeeInstructionData {
     discriminator: [u8; 1],
 }
 
-impl SetProgramConfigAuthorityInstructionData {
+impl SetProgramConfigReferralFeeInstructionData {
     pub fn new() -> Self {
-        Self { discriminator: [2] }
+        Self {
+            discriminator: [77],
+        }
     }
 
     pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
@@ -73,7 +74,7 @@ impl SetProgramConfigAuthorityInstructionData {
     }
 }
 
-impl Default for SetProgramConfigAuthorityInstructionData {
+impl Default for SetProgramConfigReferralFeeInstructionData {
     fn default() -> Self {
         Self::new()
     }
@@ -81,31 +82,31 @@ impl Default for SetProgramConfigAuthorityInstructionData {
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct SetProgramConfigAuthorityInstructionArgs {
-    pub new_authority: Pubkey,
+pub struct SetProgramConfigReferralFeeInstructionArgs {
+    pub new_referral_fee_bps: u16,
 }
 
-impl SetProgramConfigAuthorityInstructionArgs {
+impl SetProgramConfigReferralFeeInstructionArgs {
     pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
         borsh::to_vec(self)
     }
 }
 
-/// Instruction builder for `SetProgramConfigAuthority`.
+/// Instruction builder for `SetProgramConfigReferralFee`.
 ///
 /// ### Accounts:
 ///
 ///   0. `[writable]` program_config
 ///   1. `[signer]` authority
 #[derive(Clone, Debug, Default)]
-pub struct SetProgramConfigAuthorityBuilder {
+pub struct SetProgramConfigReferralFeeBuilder {
     program_config: Option<solana_pubkey::Pubkey>,
     authority: Option<solana_pubkey::Pubkey>,
-    new_authority: Option<Pubkey>,
+    new_referral_fee_bps: Option<u16>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
-impl SetProgramConfigAuthorityBuilder {
+impl SetProgramConfigReferralFeeBuilder {
     pub fn new() -> Self {
         Self::default()
     }
@@ -120,8 +121,8 @@ impl SetProgramConfigAuthorityBuilder {
         self
     }
     #[inline(always)]
-    pub fn new_authority(&mut self, new_authority: Pubkey) -> &mut Self {
-        self.new_authority = Some(new_authority);
+    pub fn new_referral_fee_bps(&mut self, new_referral_fee_bps: u16) -> &mut Self {
+        self.new_referral_fee_bps = Some(new_referral_fee_bps);
         self
     }
     /// Add an additional account to the instruction.
@@ -141,30 +142,30 @@ impl SetProgramConfigAuthorityBuilder {
     }
     #[allow(clippy::clone_on_copy)]
     pub fn instruction(&self) -> solana_instruction::Instruction {
-        let accounts = SetProgramConfigAuthority {
+        let accounts = SetProgramConfigReferralFee {
             program_config: self.program_config.expect("program_config is not set"),
             authority: self.authority.expect("authority is not set"),
         };
-        let args = SetProgramConfigAuthorityInstructionArgs {
-            new_authority: self
-                .new_authority
+        let args = SetProgramConfigReferralFeeInstructionArgs {
+            new_referral_fee_bps: self
+                .new_referral_fee_bps
                 .clone()
-                .expect("new_authority is not set"),
+                .expect("new_referral_fee_bps is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
-/// `set_program_config_authority` CPI accounts.
-pub struct SetProgramConfigAuthorityCpiAccounts<'a, 'b> {
+/// `set_program_config_referral_fee` CPI accounts.
+pub struct SetProgramConfigReferralFeeCpiAccounts<'a, 'b> {
     pub program_config: &'b solana_account_info::AccountInfo<'a>,
 
     pub authority: &'b solana_account_info::AccountInfo<'a>,
 }
 
-/// `set_program_config_authority` CPI instruction.
-pub struct SetProgramConfigAuthorityCpi<'a, 'b> {
+/// `set_program_config_referral_fee` CPI instruction.
+pub struct SetProgramConfigReferralFeeCpi<'a, 'b> {
     /// The program to invoke.
     pub __program: &'b solana_account_info::AccountInfo<'a>,
 
@@ -172,14 +173,14 @@ pub struct SetProgramConfigAuthorityCpi<'a, 'b> {
 
     pub authority: &'b solana_account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
-    pub __args: SetProgramConfigAuthorityInstructionArgs,
+    pub __args: SetProgramConfigReferralFeeInstructionArgs,
 }
 
-impl<'a, 'b> SetProgramConfigAuthorityCpi<'a, 'b> {
+impl<'a, 'b> SetProgramConfigReferralFeeCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_account_info::AccountInfo<'a>,
-        accounts: SetProgramConfigAuthorityCpiAccounts<'a, 'b>,
-        args: SetProgramConfigAuthorityInstructionArgs,
+        accounts: SetProgramConfigReferralFeeCpiAccounts<'a, 'b>,
+        args: SetProgramConfigReferralFeeInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -227,7 +228,7 @@ impl<'a, 'b> SetProgramConfigAuthorityCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let mut data = SetProgramConfigAuthorityInstructionData::new()
+        let mut data = SetProgramConfigReferralFeeInstructionData::new()
             .try_to_vec()
             .unwrap();
         let mut args = self.__args.try_to_vec().unwrap();
@@ -254,24 +255,24 @@ impl<'a, 'b> SetProgramConfigAuthorityCpi<'a, 'b> {
     }
 }
 
-/// Instruction builder for `SetProgramConfigAuthority` via CPI.
+/// Instruction builder for `SetProgramConfigReferralFee` via CPI.
 ///
 /// ### Accounts:
 ///
 ///   0. `[writable]` program_config
 ///   1. `[signer]` authority
 #[derive(Clone, Debug)]
-pub struct SetProgramConfigAuthorityCpiBuilder<'a, 'b> {
-    instruction: Box<SetProgramConfigAuthorityCpiBuilderInstruction<'a, 'b>>,
+pub struct SetProgramConfigReferralFeeCpiBuilder<'a, 'b> {
+    instruction: Box<SetProgramConfigReferralFeeCpiBuilderInstruction<'a, 'b>>,
 }
 
-impl<'a, 'b> SetProgramConfigAuthorityCpiBuilder<'a, 'b> {
+impl<'a, 'b> SetProgramConfigReferralFeeCpiBuilder<'a, 'b> {
     pub fn new(program: &'b solana_account_info::AccountInfo<'a>) -> Self {
-        let instruction = Box::new(SetProgramConfigAuthorityCpiBuilderInstruction {
+        let instruction = Box::new(SetProgramConfigReferralFeeCpiBuilderInstruction {
             __program: program,
             program_config: None,
             authority: None,
-            new_authority: None,
+            new_referral_fee_bps: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -290,8 +291,8 @@ impl<'a, 'b> SetProgramConfigAuthorityCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn new_authority(&mut self, new_authority: Pubkey) -> &mut Self {
-        self.instruction.new_authority = Some(new_authority);
+    pub fn new_referral_fee_bps(&mut self, new_referral_fee_bps: u16) -> &mut Self {
+        self.instruction.new_referral_fee_bps = Some(new_referral_fee_bps);
         self
     }
     /// Add an additional account to the instruction.
@@ -328,14 +329,14 @@ impl<'a, 'b> SetProgramConfigAuthorityCpiBuilder<'a, 'b> {
     #[allow(clippy::clone_on_copy)]
     #[allow(clippy::vec_init_then_push)]
     pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
-        let args = SetProgramConfigAuthorityInstructionArgs {
-            new_authority: self
+        let args = SetProgramConfigReferralFeeInstructionArgs {
+            new_referral_fee_bps: self
                 .instruction
-                .new_authority
+                .new_referral_fee_bps
                 .clone()
-                .expect("new_authority is not set"),
+                .expect("new_referral_fee_bps is not set"),
         };
-        let instruction = SetProgramConfigAuthorityCpi {
+        let instruction = SetProgramConfigReferralFeeCpi {
             __program: self.instruction.__program,
 
             program_config: self
@@ -354,11 +355,11 @@ impl<'a, 'b> SetProgramConfigAuthorityCpiBuilder<'a, 'b> {
 }
 
 #[derive(Clone, Debug)]
-struct SetProgramConfigAuthorityCpiBuilderInstruction<'a, 'b> {
+struct SetProgramConfigReferralFeeCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_account_info::AccountInfo<'a>,
     program_config: Option<&'b solana_account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_account_info::AccountInfo<'a>>,
-    new_authority: Option<Pubkey>,
+    new_referral_fee_bps: Option<u16>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -5,26 +5,48 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#add_transaction_to_batch_args;
+pub(crate) mod r#create_settings_transaction_args;
 pub(crate) mod r#create_transaction_args;
+pub(crate) mod r#creator_allowlist_change_args;
+pub(crate) mod r#period;
 pub(crate) mod r#permissions;
+pub(crate) mod r#program_config_transfer_kind;
+pub(crate) mod r#program_filter;
+pub(crate) mod r#program_filter_mode;
 pub(crate) mod r#proposal_status;
 pub(crate) mod r#restricted_permissions;
 pub(crate) mod r#restricted_smart_account_signer;
+pub(crate) mod r#session_expiration;
 pub(crate) mod r#settings_action;
 pub(crate) mod r#smart_account_compiled_instruction;
+pub(crate) mod r#smart_account_creation_mode;
 pub(crate) mod r#smart_account_message_address_table_lookup;
 pub(crate) mod r#smart_account_signer;
 pub(crate) mod r#smart_account_transaction_message;
+pub(crate) mod r#target_time_lock;
+pub(crate) mod r#time_lock_target;
 pub(crate) mod r#vote_on_proposal_args;
 
+pub use self::r#add_transaction_to_batch_args::*;
+pub use self::r#create_settings_transaction_args::*;
 pub use self::r#create_transaction_args::*;
+pub use self::r#creator_allowlist_change_args::*;
+pub use self::r#period::*;
 pub use self::r#permissions::*;
+pub use self::r#program_config_transfer_kind::*;
+pub use self::r#program_filter::*;
+pub use self::r#program_filter_mode::*;
 pub use self::r#proposal_status::*;
 pub use self::r#restricted_permissions::*;
 pub use self::r#restricted_smart_account_signer::*;
+pub use self::r#session_expiration::*;
 pub use self::r#settings_action::*;
 pub use self::r#smart_account_compiled_instruction::*;
+pub use self::r#smart_account_creation_mode::*;
 pub use self::r#smart_account_message_address_table_lookup::*;
 pub use self::r#smart_account_signer::*;
 pub use self::r#smart_account_transaction_message::*;
+pub use self::r#target_time_lock::*;
+pub use self::r#time_lock_target::*;
 pub use self::r#vote_on_proposal_args::*;
@@ -19,4 +19,5 @@ pub enum ProposalStatus {
     Approved { timestamp: i64 },
     Executed { timestamp: i64 },
     Cancelled { timestamp: i64 },
+    Expired { timestamp: i64 },
 }
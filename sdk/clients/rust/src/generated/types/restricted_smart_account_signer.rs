@@ -19,4 +19,11 @@ pub struct RestrictedSmartAccountSigner {
     )]
     pub key: Pubkey,
     pub restricted_permissions: RestrictedPermissions,
+    /// The pre-registered address that funds are swept to when this signer
+    /// triggers an emergency exit.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub exit_destination: Pubkey,
 }
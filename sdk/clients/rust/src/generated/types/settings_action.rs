@@ -5,7 +5,13 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::Period;
+use crate::generated::types::Permissions;
+use crate::generated::types::ProgramFilter;
+use crate::generated::types::ProgramFilterMode;
+use crate::generated::types::RestrictedSmartAccountSigner;
 use crate::generated::types::SmartAccountSigner;
+use crate::generated::types::TimeLockTarget;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_pubkey::Pubkey;
@@ -32,4 +38,116 @@ pub enum SettingsAction {
     SetArchivalAuthority {
         new_archival_authority: Option<Pubkey>,
     },
+    AddSpendingLimit {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        seed: Pubkey,
+        account_index: u8,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        mint: Pubkey,
+        amount: u64,
+        period: Period,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+        )]
+        signers: Vec<Pubkey>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+        )]
+        destinations: Vec<Pubkey>,
+        expiration: i64,
+    },
+    RemoveSpendingLimit {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        spending_limit: Pubkey,
+    },
+    SetRentCollector {
+        new_rent_collector: Option<Pubkey>,
+    },
+    SetRecoveryConfig {
+        new_recovery_threshold: u16,
+        new_recovery_delay: u32,
+    },
+    SetExecutionWindow {
+        new_execution_window: u32,
+    },
+    SetVaultPolicy {
+        account_index: u8,
+        threshold: u16,
+        time_lock: u32,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+        )]
+        signers: Vec<Pubkey>,
+    },
+    RemoveVaultPolicy {
+        account_index: u8,
+    },
+    SetTargetTimeLock {
+        target: TimeLockTarget,
+        time_lock: u32,
+    },
+    RemoveTargetTimeLock {
+        target: TimeLockTarget,
+    },
+    AddRestrictedSigner {
+        new_restricted_signer: RestrictedSmartAccountSigner,
+    },
+    RemoveRestrictedSigner {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        old_restricted_signer: Pubkey,
+    },
+    ChangeSignerPermissions {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        signer: Pubkey,
+        new_permissions: Permissions,
+    },
+    SetSettingsAuthority {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        new_settings_authority: Pubkey,
+    },
+    RotateSigner {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        old_signer: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        new_signer: Pubkey,
+    },
+    SetSignerRotationDelay {
+        new_signer_rotation_delay: Option<u32>,
+    },
+    SetProgramFilterMode {
+        mode: ProgramFilterMode,
+    },
+    AddProgramFilter {
+        filter: ProgramFilter,
+    },
+    RemoveProgramFilter {
+        filter: ProgramFilter,
+    },
 }
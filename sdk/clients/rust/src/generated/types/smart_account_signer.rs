@@ -19,4 +19,6 @@ pub struct SmartAccountSigner {
     )]
     pub key: Pubkey,
     pub permissions: Permissions,
+    /// Voting weight of the signer, counted towards the `threshold` when it has the `Vote` permission.
+    pub weight: u16,
 }
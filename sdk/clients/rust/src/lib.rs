@@ -1,3 +1,4 @@
+pub mod events;
 pub mod generated;
 
 // Re-export program ID at crate root (required by generated instruction code)
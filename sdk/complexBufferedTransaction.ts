@@ -38,6 +38,7 @@ import {
   deriveBufferPda,
   decodeTransactionMessage,
   getTransactionMessageEncoder,
+  getVaultPolicyAddress,
 } from './utils/index';
 
 export interface BufferedTransactionParams {
@@ -94,6 +95,8 @@ export async function createComplexBufferedTransaction(params: BufferedTransacti
   // Derive PDAs and fetch settings
   const settings = await fetchSettings(rpc, smartAccountSettings);
   const nextIndex = settings.data.transactionIndex + BigInt(1) + BigInt(transactionIndexOffset);
+  // The vault policy of the smart account, if any, must be passed on creation, approval and execution.
+  const vaultPolicy = await getVaultPolicyAddress(smartAccountSettings, settings.data.vaultPolicies, accountIndex);
   console.log(`🔧 complexBufferedTransaction: Using transaction index ${nextIndex} (current: ${settings.data.transactionIndex}, offset: ${transactionIndexOffset})`);
   // Derive Transaction PDA: ["smart_account", settings, "transaction", u64 index]
   const transactionPda = await deriveTransactionPda(smartAccountSettings, nextIndex);
@@ -436,14 +439,16 @@ export async function createComplexBufferedTransaction(params: BufferedTransacti
     creator: signer,
     feePayer: feePayerSigner,
     systemProgram: address('11111111111111111111111111111111'),
+    vaultPolicy,
     transactionBuffer: transactionBufferPda,
     fromBufferCreator: signer,
     args: {
       accountIndex,
       accountBump: smartAccountPdaBump,
       ephemeralSigners: 0,
-      // Must pass full buffer size - Anchor needs this for account reallocation
-      transactionMessage: new Uint8Array(finalBufferSize),
+      // Empty placeholder - the program reallocates the transaction to fit the buffer,
+      // passing the full buffer size wouldn't fit in the transaction for large buffers
+      transactionMessage: new Uint8Array(0),
       memo: undefined,
     },
   });
@@ -456,6 +461,7 @@ export async function createComplexBufferedTransaction(params: BufferedTransacti
     systemProgram: address('11111111111111111111111111111111'),
     transactionIndex: nextIndex,
     draft: false,
+    votingDeadline: null,
   });
 
   const approveIx = getApproveProposalInstruction({
@@ -463,6 +469,9 @@ export async function createComplexBufferedTransaction(params: BufferedTransacti
     signer,
     proposal: proposalPda,
     systemProgram: address('11111111111111111111111111111111'),
+    // Voting on a proposal under a vault policy also needs its transaction, to find the smart account it's for.
+    transaction: vaultPolicy ? transactionPda : undefined,
+    vaultPolicy,
     args: { memo: undefined },
   });
 
@@ -496,6 +505,7 @@ export async function createComplexBufferedTransaction(params: BufferedTransacti
     transaction: transactionPda,
     signer,
     feePayer: feePayerSigner,
+    vaultPolicy,
   });
   // Note: We don't need to close the buffer - CreateTransactionFromBuffer already does that
   // with `close = from_buffer_creator` in the Rust code
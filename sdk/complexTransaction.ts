@@ -38,6 +38,7 @@ import {
   deriveTransactionPda,
   deriveProposalPda,
   fetchSmartAccountSettings,
+  getVaultPolicyAddress,
 } from './utils/index';
 
 export interface ComplexTransactionParams {
@@ -227,12 +228,16 @@ export async function createComplexTransaction(
   // 5. Create the transaction account instruction
   console.log('🔧 Creating CreateTransaction instruction with transactionMessage of', transactionMessageBytes.length, 'bytes');
   
+  // The vault policy of the smart account, if any, must be passed on creation, approval and execution.
+  const vaultPolicy = await getVaultPolicyAddress(smartAccountSettings, settingsAccount.vaultPolicies, 0);
+
   const createTransactionInstruction = getCreateTransactionInstruction({
     settings: smartAccountSettings,
     transaction: transactionPda,
     creator: signer,
     feePayer: createNoopSigner(feePayer), // Backend pays for transaction account rent
     systemProgram: address('11111111111111111111111111111111'),
+    vaultPolicy,
     args: {
       accountIndex: 0, // Use 0 for the primary smart account
       accountBump: smartAccountPdaBump,
@@ -251,6 +256,7 @@ export async function createComplexTransaction(
     systemProgram: address('11111111111111111111111111111111'),
     transactionIndex: transactionIndex,
     draft: false,
+    votingDeadline: null,
   });
 
   // 7. Create the approve proposal instruction
@@ -259,6 +265,9 @@ export async function createComplexTransaction(
     signer: signer,
     proposal: proposalPda,
     systemProgram: address('11111111111111111111111111111111'),
+    // Voting on a proposal under a vault policy also needs its transaction, to find the smart account it's for.
+    transaction: vaultPolicy ? transactionPda : undefined,
+    vaultPolicy,
     args: { memo: null },
   });
 
@@ -368,17 +377,22 @@ export async function createComplexTransaction(
     signer: signer,
     feePayer: createNoopSigner(feePayer), // Backend pays for transaction execution fees
     systemProgram: address('11111111111111111111111111111111'),
+    vaultPolicy,
   });
 
-  // Create close instruction to reclaim rent back to fee payer
-  const closeTransactionInstruction = getCloseTransactionInstruction({
-    settings: smartAccountSettings,
-    proposal: proposalPda,
-    transaction: transactionPda,
-    proposalRentCollector: feePayer, // Rent goes back to backend fee payer
-    transactionRentCollector: feePayer, // Rent goes back to backend fee payer
-    systemProgram: address('11111111111111111111111111111111'),
-  });
+  // Create close instruction to reclaim rent to the smart account rent collector.
+  // Rent reclamation is only possible if the smart account has a `rentCollector` set.
+  const closeTransactionInstruction = settingsAccount.rentCollector
+    ? getCloseTransactionInstruction({
+        settings: smartAccountSettings,
+        proposal: proposalPda,
+        transaction: transactionPda,
+        // The proposal and transaction are created above, with the rent collector of the smart account.
+        proposalRentCollector: settingsAccount.rentCollector,
+        transactionRentCollector: settingsAccount.rentCollector,
+        systemProgram: address('11111111111111111111111111111111'),
+      })
+    : null;
 
   // The smart contract expects manual ALT resolution via remaining accounts (message_account_infos).
   // We must pass:
@@ -395,9 +409,8 @@ export async function createComplexTransaction(
   console.log('🔍 Static accounts:', decodedMessage.staticAccounts?.length || 0);
   
   // Build remaining accounts precisely and in-order.
-  // ExecuteTransaction has: Settings, Proposal, Transaction, Signer, FeePayer, SystemProgram
-  const explicitParamsCount = 6; 
-  const explicitParams = executeTransactionInstruction.accounts.slice(0, explicitParamsCount);
+  // ExecuteTransaction has: Settings, Proposal, Transaction, Signer, FeePayer, SystemProgram, Program, VaultPolicy
+  const explicitParams = executeTransactionInstruction.accounts;
   const resultAccounts: { address: Address; role: AccountRole }[] = [];
 
   // Static accounts writability derived from header
@@ -488,7 +501,9 @@ export async function createComplexTransaction(
     console.error('Unique signers:', Array.from(uniqueSigners));
   }
 
-  const executeInstructions = [executeTransactionInstruction, closeTransactionInstruction];
+  const executeInstructions = closeTransactionInstruction
+    ? [executeTransactionInstruction, closeTransactionInstruction]
+    : [executeTransactionInstruction];
 
   let executeTransactionMessage = pipe(
     createTransactionMessage({ version: 0 }),
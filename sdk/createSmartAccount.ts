@@ -12,9 +12,11 @@ import {
   createSolanaRpc,
 } from '@solana/kit';
 import { Buffer } from 'buffer';
+import { findAssociatedTokenPda, TOKEN_PROGRAM_ADDRESS } from '@solana-program/token';
 import { fetchProgramConfig } from './clients/js/src/generated/accounts/programConfig';
 import { getCreateSmartAccountInstructionAsync } from './clients/js/src/generated/instructions';
 import { ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS } from './clients/js/src/generated/programs';
+import { deriveCreatorAllowlistPda } from './utils/index';
 import {
   type RestrictedSmartAccountSignerArgs,
   type SmartAccountSignerArgs,
@@ -53,6 +55,21 @@ export type CreateSmartAccountParams = {
   rentCollector?: Address | null;
   /** Optional: A memo for the transaction. Defaults to None. */
   memo?: string | null;
+  /**
+   * Optional: The partner that referred the creation, who gets the program config's referral share
+   * of the creation fees. Defaults to None.
+   */
+  referrer?: Address | null;
+  /**
+   * Optional: The page of the creator allowlist the `creator` is on.
+   * Required when the program config creation mode is `Allowlist`.
+   */
+  creatorAllowlistPage?: number;
+  /**
+   * Optional: The program config authority, which must also sign the transaction.
+   * Required when the program config creation mode is `Cosigned`.
+   */
+  programConfigAuthority?: Address;
 };
 
 /**
@@ -92,6 +109,9 @@ export async function createSmartAccountTransaction(
     timeLock = 0,
     rentCollector = null,
     memo = null,
+    referrer = null,
+    creatorAllowlistPage,
+    programConfigAuthority,
   } = params;
 
   // 1. Fetch program config PDA and treasury from on-chain, as seen in createAccountTest.ts
@@ -103,7 +123,8 @@ export async function createSmartAccountTransaction(
     ],
   });
   const programConfig = await fetchProgramConfig(rpc, programConfigPda);
-  const { treasury, smartAccountIndex } = programConfig.data;
+  const { treasury, smartAccountIndex, creationFeeMint, smartAccountCreationTokenFee } =
+    programConfig.data;
 
   // 2. Compute the seed for the new settings account using the next available index.
   const nextSmartAccountIndex = smartAccountIndex + BigInt(1);
@@ -135,7 +156,21 @@ export async function createSmartAccountTransaction(
     ],
   });
 
-  // 5. Build the create smart account instruction.
+  // 5. If the creation fee is also charged in tokens, pay it from and to the associated token accounts.
+  const tokenFeeAccount = async (owner: Address) =>
+    (await findAssociatedTokenPda({ mint: creationFeeMint, owner, tokenProgram: TOKEN_PROGRAM_ADDRESS }))[0];
+  const tokenFeeInput =
+    smartAccountCreationTokenFee > BigInt(0)
+      ? {
+          creationFeeMint,
+          feePayerTokenAccount: await tokenFeeAccount(feePayer),
+          treasuryTokenAccount: await tokenFeeAccount(treasury),
+          referrerTokenAccount: referrer ? await tokenFeeAccount(referrer) : undefined,
+          tokenProgram: TOKEN_PROGRAM_ADDRESS,
+        }
+      : {};
+
+  // 6. Build the create smart account instruction.
   // Both creator and fee_payer are represented as NoopSigners because the transaction
   // will be signed later by a backend.
   const createSmartAccountInstruction =
@@ -147,6 +182,11 @@ export async function createSmartAccountTransaction(
       feePayer: createNoopSigner(feePayer),
       systemProgram: address('11111111111111111111111111111111'),
       program: ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
+      referrerAccount: referrer ?? undefined,
+      ...tokenFeeInput,
+      creatorAllowlist:
+        creatorAllowlistPage === undefined ? undefined : await deriveCreatorAllowlistPda(creatorAllowlistPage),
+      programConfigAuthority: programConfigAuthority ? createNoopSigner(programConfigAuthority) : undefined,
       settingsAuthority,
       threshold,
       signers,
@@ -154,9 +194,10 @@ export async function createSmartAccountTransaction(
       timeLock,
       rentCollector,
       memo,
+      referrer,
     });
 
-  // 6. Build the base transaction message with fee_payer as the transaction fee payer.
+  // 7. Build the base transaction message with fee_payer as the transaction fee payer.
   const { value: latestBlockhash } = await rpc.getLatestBlockhash().send();
 
   const instructions = [createSmartAccountInstruction];
@@ -169,7 +210,7 @@ export async function createSmartAccountTransaction(
       appendTransactionMessageInstructions(instructions, tx)
   );
 
-  // 7. Compile the transaction to get the buffer to be sent to the backend
+  // 8. Compile the transaction to get the buffer to be sent to the backend
   const compiledTransaction = compileTransaction(baseTransactionMessage);
 
   return {
@@ -111,11 +111,18 @@ async function main() {
     program: address(ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS),
     settingsAuthority: null,
     threshold: 1,
-    signers: [{ key: creatorSigner.address, permissions: { mask: 7 } }],
-    restrictedSigners: [{ key: restrictedSignerSigner.address, restrictedPermissions: { mask: 1 } }],
+    signers: [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }],
+    restrictedSigners: [
+      {
+        key: restrictedSignerSigner.address,
+        restrictedPermissions: { mask: 1 },
+        exitDestination: creatorSigner.address,
+      },
+    ],
     timeLock: 0,
     rentCollector: null,
     memo: null,
+    referrer: null,
   });
 
   // 5. Build the transaction message
@@ -245,6 +245,7 @@ async function main() {
     systemProgram: address('11111111111111111111111111111111'),
     transactionIndex: transactionIndex,
     draft: false,
+    votingDeadline: null,
   });
 
   // 9. Build, sign, and send the proposal creation transaction
@@ -3,6 +3,7 @@ export * from './simpleTransaction';
 export * from './complexTransaction';
 export * from './complexBufferedTransaction';
 export * from './addPasskeyAuthority';
+export * from './passkey';
 export * from './utils/index';
 export * from './clients/js/src/generated/accounts/settings';
 export * from './clients/js/src/generated/programs/index';
@@ -0,0 +1,138 @@
+import {
+  type Address,
+  type Instruction,
+  address,
+  getAddressEncoder,
+  getProgramDerivedAddress,
+} from '@solana/kit';
+import { ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS } from './clients/js/src/generated/programs';
+
+/** The native secp256r1 signature verification program. */
+export const SECP256R1_PROGRAM_ADDRESS = address('Secp256r1SigVerify1111111111111111111111111');
+
+const SIGNATURE_OFFSETS_START = 2;
+const DATA_START = 16;
+const COMPRESSED_PUBKEY_SERIALIZED_SIZE = 33;
+const SIGNATURE_SERIALIZED_SIZE = 64;
+/** Tells the precompile that the data is in the secp256r1 instruction itself. */
+const CURRENT_INSTRUCTION_INDEX = 0xffff;
+
+async function sha256(data: Uint8Array): Promise<Uint8Array> {
+  return new Uint8Array(await crypto.subtle.digest('SHA-256', data));
+}
+
+/**
+ * Derives the address of the `Passkey` account for a passkey.
+ * This address is what gets added to a smart account as the `SmartAccountSigner.key`.
+ *
+ * @param publicKey - The compressed (33 bytes) secp256r1 public key of the passkey.
+ * @param rpIdHash - The SHA-256 hash of the WebAuthn relying party ID.
+ */
+export async function getPasskeyAddress(
+  publicKey: Uint8Array,
+  rpIdHash: Uint8Array
+): Promise<Address> {
+  const [passkeyAddress] = await getProgramDerivedAddress({
+    programAddress: ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
+    seeds: [
+      new TextEncoder().encode('smart_account'),
+      new TextEncoder().encode('passkey'),
+      publicKey.slice(0, 1),
+      publicKey.slice(1),
+      rpIdHash,
+    ],
+  });
+  return passkeyAddress;
+}
+
+/**
+ * Computes the WebAuthn challenge a passkey must sign to approve `instruction`.
+ *
+ * The challenge commits to the passkey, its current nonce and the exact instruction
+ * (program, accounts and data), so the approval cannot be replayed or reused.
+ *
+ * @param passkey - The address of the `Passkey` account.
+ * @param nonce - The current `nonce` of the `Passkey` account.
+ * @param instruction - The smart account instruction being approved.
+ */
+export async function getPasskeyChallenge(
+  passkey: Address,
+  nonce: bigint,
+  instruction: Instruction
+): Promise<Uint8Array> {
+  const addressEncoder = getAddressEncoder();
+  const nonceBytes = new Uint8Array(8);
+  new DataView(nonceBytes.buffer).setBigUint64(0, nonce, true);
+
+  const parts: Uint8Array[] = [
+    new Uint8Array(addressEncoder.encode(passkey)),
+    nonceBytes,
+    new Uint8Array(addressEncoder.encode(instruction.programAddress)),
+    ...(instruction.accounts ?? []).map((account) => new Uint8Array(addressEncoder.encode(account.address))),
+    new Uint8Array(instruction.data ?? []),
+  ];
+  const preimage = new Uint8Array(parts.reduce((len, part) => len + part.length, 0));
+  let offset = 0;
+  for (const part of parts) {
+    preimage.set(part, offset);
+    offset += part.length;
+  }
+  return sha256(preimage);
+}
+
+/**
+ * Builds the secp256r1 precompile instruction that verifies a WebAuthn assertion.
+ * It must be placed before the smart account instruction the passkey approves.
+ *
+ * The `clientDataJSON` is appended after the signed message so the smart account program
+ * can check the challenge in it.
+ *
+ * @param publicKey - The compressed (33 bytes) secp256r1 public key of the passkey.
+ * @param signature - The assertion signature as 64 bytes `r || s`, with a low `s`.
+ *   WebAuthn returns DER-encoded signatures which must be converted first.
+ * @param authenticatorData - The `authenticatorData` of the assertion.
+ * @param clientDataJSON - The `clientDataJSON` of the assertion.
+ */
+export async function getSecp256r1WebAuthnInstruction(params: {
+  publicKey: Uint8Array;
+  signature: Uint8Array;
+  authenticatorData: Uint8Array;
+  clientDataJSON: Uint8Array;
+}): Promise<Instruction> {
+  const { publicKey, signature, authenticatorData, clientDataJSON } = params;
+  if (publicKey.length !== COMPRESSED_PUBKEY_SERIALIZED_SIZE) {
+    throw new Error('Passkey public key must be compressed (33 bytes)');
+  }
+  if (signature.length !== SIGNATURE_SERIALIZED_SIZE) {
+    throw new Error('Signature must be 64 bytes (r || s)');
+  }
+
+  const clientDataHash = await sha256(clientDataJSON);
+  const publicKeyOffset = DATA_START;
+  const signatureOffset = publicKeyOffset + COMPRESSED_PUBKEY_SERIALIZED_SIZE;
+  const messageOffset = signatureOffset + SIGNATURE_SERIALIZED_SIZE;
+  const messageSize = authenticatorData.length + clientDataHash.length;
+  const clientDataOffset = messageOffset + messageSize;
+
+  const data = new Uint8Array(clientDataOffset + 2 + clientDataJSON.length);
+  const view = new DataView(data.buffer);
+  data[0] = 1; // number of signatures
+  data[1] = 0; // padding
+  [
+    signatureOffset,
+    CURRENT_INSTRUCTION_INDEX,
+    publicKeyOffset,
+    CURRENT_INSTRUCTION_INDEX,
+    messageOffset,
+    messageSize,
+    CURRENT_INSTRUCTION_INDEX,
+  ].forEach((value, i) => view.setUint16(SIGNATURE_OFFSETS_START + i * 2, value, true));
+  data.set(publicKey, publicKeyOffset);
+  data.set(signature, signatureOffset);
+  data.set(authenticatorData, messageOffset);
+  data.set(clientDataHash, messageOffset + authenticatorData.length);
+  view.setUint16(clientDataOffset, clientDataJSON.length, true);
+  data.set(clientDataJSON, clientDataOffset + 2);
+
+  return { programAddress: SECP256R1_PROGRAM_ADDRESS, data };
+}
@@ -15,7 +15,14 @@ import {
 } from '@solana/kit';
 import * as bs58 from 'bs58';
 import * as fs from 'fs';
-import { getCloseTransactionBufferInstruction } from '../clients/js/src/generated/instructions';
+import {
+  getCloseAbandonedTransactionBufferInstruction,
+  getCloseTransactionBufferInstruction,
+} from '../clients/js/src/generated/instructions';
+import {
+  getTransactionBufferDecoder,
+  TRANSACTION_BUFFER_DISCRIMINATOR,
+} from '../clients/js/src/generated/accounts/transactionBuffer';
 import { ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS } from '../clients/js/src/generated/programs';
 
 async function cleanBuffers() {
@@ -80,8 +87,7 @@ async function cleanBuffers() {
         const closeBufferIx = getCloseTransactionBufferInstruction({
           settings: smartAccountSettings,
           transactionBuffer: transactionBufferPda,
-          bufferCreator: creatorSigner,
-          systemProgram: address('11111111111111111111111111111111'),
+          creator: creatorSigner,
         });
 
         const closeBufferMsg = pipe(
@@ -106,6 +112,51 @@ async function cleanBuffers() {
     }
   }
 
+  // Buffers of other signers can be closed once they timed out, went stale after a settings
+  // change or their creator is no longer a signer. Rent is refunded to their original payer.
+  console.log('');
+  console.log('🔍 Checking buffers left open by other signers...');
+  const otherBuffers = await rpc
+    .getProgramAccounts(ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS, {
+      encoding: 'base64',
+      filters: [
+        { memcmp: { offset: 0n, bytes: bs58.encode(TRANSACTION_BUFFER_DISCRIMINATOR) as any, encoding: 'base58' } },
+        { memcmp: { offset: 8n, bytes: smartAccountSettings as any, encoding: 'base58' } },
+      ],
+    })
+    .send();
+
+  for (const { pubkey, account } of otherBuffers) {
+    const transactionBuffer = getTransactionBufferDecoder().decode(Buffer.from(account.data[0], 'base64'));
+    if (transactionBuffer.creator === creatorSigner.address) {
+      continue;
+    }
+
+    try {
+      const closeAbandonedBufferIx = getCloseAbandonedTransactionBufferInstruction({
+        settings: smartAccountSettings,
+        transactionBuffer: pubkey,
+        closer: creatorSigner,
+        rentPayer: transactionBuffer.rentPayer,
+      });
+
+      const closeAbandonedBufferMsg = pipe(
+        createTransactionMessage({ version: 0 }),
+        tx => setTransactionMessageFeePayerSigner(creatorSigner, tx),
+        tx => setTransactionMessageLifetimeUsingBlockhash(latestBlockhash, tx),
+        tx => appendTransactionMessageInstruction(closeAbandonedBufferIx, tx)
+      );
+
+      const signedCloseAbandonedBuffer = await signTransactionMessageWithSigners(closeAbandonedBufferMsg);
+      assertIsTransactionWithinSizeLimit(signedCloseAbandonedBuffer);
+
+      const closeSignature = await sendAndConfirm(signedCloseAbandonedBuffer, { commitment: 'confirmed' });
+      console.log(`  ✅ Buffer ${pubkey} of ${transactionBuffer.creator}: Closed successfully (${closeSignature})`);
+    } catch (error) {
+      console.log(`  ⚪ Buffer ${pubkey} of ${transactionBuffer.creator}: Not abandoned yet, skipping`);
+    }
+  }
+
   console.log('');
   console.log('🎉 Buffer cleanup completed!');
   console.log('💡 You can now run tests without "account exists" errors');
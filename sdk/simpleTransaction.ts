@@ -30,7 +30,7 @@ import {
 import { ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS } from './clients/js/src/generated/programs';
 import { getSmartAccountTransactionMessageEncoder } from './clients/js/src/generated/types/smartAccountTransactionMessage';
 import * as bs58 from 'bs58';
-import { deriveSmartAccountInfo, deriveProposalPda, deriveTransactionPda, fetchSmartAccountSettings } from './utils/index';
+import { deriveSmartAccountInfo, deriveProposalPda, deriveTransactionPda, fetchSmartAccountSettings, getVaultPolicyAddress } from './utils/index';
 
 type SolanaRpc = ReturnType<typeof createSolanaRpc>;
 
@@ -331,6 +331,9 @@ export async function createSimpleTransaction(
   console.log('📊 Encoded bytes (first 50):', Array.from(transactionMessageBytes.slice(0, 50)));
   console.log('📊 Encoded hex (first 100 chars):', Array.from(transactionMessageBytes.slice(0, 50)).map(b => b.toString(16).padStart(2, '0')).join(''));
 
+  // The vault policy of the smart account, if any, must be passed on creation, approval and execution.
+  const vaultPolicy = await getVaultPolicyAddress(smartAccountSettings, settingsData.vaultPolicies, 0);
+
   // 5. Create the transaction account instruction
   // IMPORTANT: The accountIndex should match the index used when creating the smart account
   // This matches the working example and the expected u8 type in the program
@@ -340,6 +343,7 @@ export async function createSimpleTransaction(
     creator: signer,
     feePayer: createNoopSigner(feePayer), // Backend pays for transaction account rent
     systemProgram: address('11111111111111111111111111111111'),
+    vaultPolicy,
     args: {
       accountIndex: 0, // Use 0 for the primary smart account (default)
       accountBump: smartAccountPdaBump,
@@ -358,6 +362,7 @@ export async function createSimpleTransaction(
     systemProgram: address('11111111111111111111111111111111'),
     transactionIndex: transactionIndex,
     draft: false,
+    votingDeadline: null,
   });
 
   // 7. Create the approve proposal instruction
@@ -366,6 +371,9 @@ export async function createSimpleTransaction(
     signer: signer,
     proposal: proposalPda,
     systemProgram: address('11111111111111111111111111111111'),
+    // Voting on a proposal under a vault policy also needs its transaction, to find the smart account it's for.
+    transaction: vaultPolicy ? transactionPda : undefined,
+    vaultPolicy,
     args: { memo: null },
   });
 
@@ -376,6 +384,7 @@ export async function createSimpleTransaction(
     transaction: transactionPda,
     signer: signer,
     feePayer: createNoopSigner(feePayer),
+    vaultPolicy,
   });
 
   // CLONE the instruction and its accounts array because the generated code returns a frozen object
@@ -436,15 +445,19 @@ export async function createSimpleTransaction(
     console.log(`  [${i}] Address: ${acc.address.toString()}, Role: ${acc.role}`);
   });
 
-  // 9. Create close instruction to reclaim rent back to fee payer
-  const closeTransactionInstruction = getCloseTransactionInstruction({
-    settings: smartAccountSettings,
-    proposal: proposalPda,
-    transaction: transactionPda,
-    proposalRentCollector: feePayer, // Rent goes back to backend fee payer
-    transactionRentCollector: feePayer, // Rent goes back to backend fee payer
-    systemProgram: address('11111111111111111111111111111111'),
-  });
+  // 9. Create close instruction to reclaim rent to the smart account rent collector.
+  // Rent reclamation is only possible if the smart account has a `rentCollector` set.
+  const closeTransactionInstruction = settingsData.rentCollector
+    ? getCloseTransactionInstruction({
+        settings: smartAccountSettings,
+        proposal: proposalPda,
+        transaction: transactionPda,
+        // The proposal and transaction are created above, with the rent collector of the smart account.
+        proposalRentCollector: settingsData.rentCollector,
+        transactionRentCollector: settingsData.rentCollector,
+        systemProgram: address('11111111111111111111111111111111'),
+      })
+    : null;
 
   // 10. Combine all instructions into a single transaction
   const allInstructions = [
@@ -452,7 +465,7 @@ export async function createSimpleTransaction(
     createProposalInstruction,
     approveProposalInstruction,
     executeTransactionInstruction,
-    closeTransactionInstruction, // Close accounts and reclaim rent
+    ...(closeTransactionInstruction ? [closeTransactionInstruction] : []), // Close accounts and reclaim rent
   ];
 
   // 11. Build the final transaction message
@@ -56,7 +56,7 @@ async function testCreateSmartAccount() {
       creator: creatorSigner.address,
       feePayer: backendFeePayerSigner.address, // Use backend fee payer
       threshold: 1,
-      signers: [{ key: creatorSigner.address, permissions: { mask: 7 } }],
+      signers: [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }],
       restrictedSigners: [],
       settingsAuthority: null,
       timeLock: 0,
@@ -93,11 +93,12 @@ async function testCreateSmartAccount() {
       systemProgram: address('11111111111111111111111111111111'),
       settingsAuthority: null,
       threshold: 1,
-      signers: [{ key: creatorSigner.address, permissions: { mask: 7 } }],
+      signers: [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }],
       restrictedSigners: [],
       timeLock: 0,
       rentCollector: null,
       memo: null,
+      referrer: null,
     });
     
     // Build and send the transaction
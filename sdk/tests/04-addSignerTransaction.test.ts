@@ -105,7 +105,8 @@ async function testAddSignerTransaction() {
           __kind: 'AddSigner',
           newSigner: {
             key: newSignerAddress,
-            permissions: { mask: 0x07 } // Full permissions (PROPOSE | VOTE | EXECUTE)
+            permissions: { mask: 0x07 }, // Full permissions (PROPOSE | VOTE | EXECUTE)
+            weight: 1,
           },
         }
       ],
@@ -0,0 +1,107 @@
+import {
+  generateKeyPairSigner,
+  lamports,
+} from '@solana/kit';
+import {
+  getEmergencyExitInstruction,
+} from '../clients/js/src/generated/instructions';
+import { fetchSettings } from '../clients/js/src/generated/accounts/settings';
+import { setupTestContext, createTestSmartAccount } from './utils';
+
+async function testEmergencyExit() {
+  console.log('Testing emergencyExit...');
+  console.log('This test creates a smart account with a restricted signer and sweeps its vault to the exit destination.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, sendInstructions } = context;
+
+  // The restricted signer and the destination it is allowed to exit to.
+  const restrictedSigner = await generateKeyPairSigner();
+  const exitDestination = (await generateKeyPairSigner()).address;
+  console.log('🔑 Restricted signer:', restrictedSigner.address);
+  console.log('🏁 Exit destination:', exitDestination);
+
+  try {
+    // Step 1: Create a smart account with the restricted signer
+    console.log('');
+    console.log('🏗️  Step 1: Creating smart account with a restricted signer...');
+    const restrictedSigners = [
+      {
+        key: restrictedSigner.address,
+        restrictedPermissions: { mask: 1 }, // EmergencyExit
+        exitDestination,
+      },
+    ];
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers: [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }],
+      restrictedSigners,
+    });
+
+    const settings = await fetchSettings(rpc, result.settingsAddress);
+    if (settings.data.restrictedSigners.length !== 1) {
+      throw new Error('❌ Restricted signer was not stored on the smart account');
+    }
+    console.log('✅ Smart account created:', result.settingsAddress);
+
+    // Step 2: Fund the smart account vault
+    console.log('');
+    console.log('💰 Step 2: Funding smart account vault...');
+    await rpc.requestAirdrop(result.smartAccountPda, lamports(500_000_000n), { commitment: 'confirmed' }).send();
+    const vaultBalanceBefore = (await rpc.getBalance(result.smartAccountPda, { commitment: 'confirmed' }).send()).value;
+    console.log('Vault balance:', vaultBalanceBefore.toString());
+
+    // Step 3: An address that is not a restricted signer must not be able to exit
+    console.log('');
+    console.log('🚫 Step 3: Emergency exit by an unauthorized signer must fail...');
+    const impostor = await generateKeyPairSigner();
+    try {
+      await sendInstructions([
+        getEmergencyExitInstruction({
+          settings: result.settingsAddress,
+          restrictedSigner: impostor,
+          smartAccount: result.smartAccountPda,
+          exitDestination: impostor.address,
+          accountIndex: 0,
+          memo: null,
+        }),
+      ]);
+      throw new Error('❌ Emergency exit by an unauthorized signer succeeded');
+    } catch (error) {
+      if (error instanceof Error && error.message.startsWith('❌')) throw error;
+      console.log('✅ Unauthorized emergency exit rejected');
+    }
+
+    // Step 4: The restricted signer sweeps the vault
+    console.log('');
+    console.log('🚨 Step 4: Executing emergency exit...');
+    const signature = await sendInstructions([
+      getEmergencyExitInstruction({
+        settings: result.settingsAddress,
+        restrictedSigner,
+        smartAccount: result.smartAccountPda,
+        exitDestination,
+        accountIndex: 0,
+        memo: 'Emergency exit test',
+      }),
+    ]);
+    console.log('Transaction signature:', signature);
+
+    const vaultBalanceAfter = (await rpc.getBalance(result.smartAccountPda, { commitment: 'confirmed' }).send()).value;
+    const destinationBalance = (await rpc.getBalance(exitDestination, { commitment: 'confirmed' }).send()).value;
+    if (vaultBalanceAfter !== 0n) {
+      throw new Error(`❌ Vault was not emptied, remaining balance: ${vaultBalanceAfter}`);
+    }
+    if (destinationBalance !== vaultBalanceBefore) {
+      throw new Error(`❌ Exit destination received ${destinationBalance}, expected ${vaultBalanceBefore}`);
+    }
+    console.log('✅ Vault swept to the exit destination');
+  } catch (error) {
+    console.error('❌ emergencyExit failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testEmergencyExit();
@@ -0,0 +1,151 @@
+import {
+  generateKeyPairSigner,
+  getAddressEncoder,
+  getProgramDerivedAddress,
+} from '@solana/kit';
+import { Buffer } from 'buffer';
+import {
+  getAddSignerAsAuthorityInstruction,
+  getArchiveSettingsInstructionAsync,
+  getSetArchivalAuthorityAsAuthorityInstruction,
+  getUnarchiveSettingsInstructionAsync,
+} from '../clients/js/src/generated/instructions';
+import { fetchMaybeArchivedSettings } from '../clients/js/src/generated/accounts/archivedSettings';
+import { fetchMaybeSettings, fetchSettings, getSettingsEncoder } from '../clients/js/src/generated/accounts/settings';
+import { ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS } from '../clients/js/src/generated/programs';
+import { setupTestContext, createTestSmartAccount, expectRejected } from './utils';
+
+async function testArchiveSettings() {
+  console.log('Testing archiveSettings and unarchiveSettings...');
+  console.log('This test creates a controlled smart account, archives its settings and restores them from the preimage.');
+  console.log('It then checks that settings too large to be restored can\'t be archived.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  try {
+    // Step 1: Create a controlled smart account, so the archival authority can be set right away
+    console.log('');
+    console.log('🏗️  Step 1: Creating a controlled smart account...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers: [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }],
+      settingsAuthority: creatorSigner.address,
+    });
+    console.log('✅ Smart account created:', result.settingsAddress);
+
+    // Step 2: Make the backend fee payer the archival authority
+    console.log('');
+    console.log('🗄️  Step 2: Setting the archival authority...');
+    await sendInstructions([
+      getSetArchivalAuthorityAsAuthorityInstruction({
+        settings: result.settingsAddress,
+        settingsAuthority: creatorSigner,
+        newArchivalAuthority: backendFeePayerSigner.address,
+        memo: null,
+      }),
+    ]);
+    const settingsBefore = await fetchSettings(rpc, result.settingsAddress);
+    console.log('✅ Archival authority set');
+
+    // Step 3: Archive the settings
+    console.log('');
+    console.log('📦 Step 3: Archiving the settings...');
+    await sendInstructions([
+      await getArchiveSettingsInstructionAsync({
+        settings: result.settingsAddress,
+        archivalAuthority: backendFeePayerSigner,
+        feePayer: backendFeePayerSigner,
+        memo: null,
+      }),
+    ]);
+    if ((await fetchMaybeSettings(rpc, result.settingsAddress)).exists) {
+      throw new Error('❌ Settings account still exists after archival');
+    }
+    console.log('✅ Settings archived');
+
+    // Step 4: Restore the settings from the preimage of the archived hash
+    console.log('');
+    console.log('📤 Step 4: Unarchiving the settings...');
+    // The preimage is the Borsh-serialized settings without the account discriminator.
+    const settingsData = getSettingsEncoder().encode(settingsBefore.data).slice(8);
+    await sendInstructions([
+      await getUnarchiveSettingsInstructionAsync({
+        settings: result.settingsAddress,
+        feePayer: backendFeePayerSigner,
+        settingsData,
+      }),
+    ]);
+    const settingsAfter = await fetchSettings(rpc, result.settingsAddress);
+    if (settingsAfter.data.seed !== settingsBefore.data.seed) {
+      throw new Error('❌ Restored settings do not match the archived settings');
+    }
+    if (settingsAfter.data.archivableAfter <= settingsBefore.data.archivableAfter) {
+      throw new Error('❌ Archival cooldown was not set on unarchival');
+    }
+    const [archivedSettingsPda] = await getProgramDerivedAddress({
+      programAddress: ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
+      seeds: [
+        new Uint8Array(Buffer.from('smart_account')),
+        getAddressEncoder().encode(result.settingsAddress),
+        new Uint8Array(Buffer.from('archived_settings')),
+      ],
+    });
+    if ((await fetchMaybeArchivedSettings(rpc, archivedSettingsPda)).exists) {
+      throw new Error('❌ Archived settings record was not closed on unarchival');
+    }
+    console.log('✅ Settings restored, archivable after:', settingsAfter.data.archivableAfter.toString());
+
+    // Step 5: Settings too large for an unarchiveSettings transaction can't be archived
+    console.log('');
+    console.log('🚫 Step 5: Archiving a smart account with 21 signers...');
+    const largeResult = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers: [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }],
+      settingsAuthority: creatorSigner.address,
+    });
+    await sendInstructions([
+      getSetArchivalAuthorityAsAuthorityInstruction({
+        settings: largeResult.settingsAddress,
+        settingsAuthority: creatorSigner,
+        newArchivalAuthority: backendFeePayerSigner.address,
+        memo: null,
+      }),
+    ]);
+    for (let batch = 0; batch < 4; batch++) {
+      const newSigners = await Promise.all([...Array(5)].map(() => generateKeyPairSigner()));
+      await sendInstructions(
+        newSigners.map((newSigner) =>
+          getAddSignerAsAuthorityInstruction({
+            settings: largeResult.settingsAddress,
+            settingsAuthority: creatorSigner,
+            feePayer: backendFeePayerSigner,
+            newSigner: { key: newSigner.address, permissions: { mask: 2 }, weight: 1 },
+            memo: null,
+          })
+        )
+      );
+    }
+    await expectRejected('Archiving settings too large to be restored', async () =>
+      sendInstructions([
+        await getArchiveSettingsInstructionAsync({
+          settings: largeResult.settingsAddress,
+          archivalAuthority: backendFeePayerSigner,
+          feePayer: backendFeePayerSigner,
+          memo: null,
+        }),
+      ])
+    );
+    if (!(await fetchMaybeSettings(rpc, largeResult.settingsAddress)).exists) {
+      throw new Error('❌ Settings too large to be restored were archived');
+    }
+    console.log('✅ Settings too large to be restored were not archived');
+  } catch (error) {
+    console.error('❌ archiveSettings failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testArchiveSettings();
@@ -0,0 +1,210 @@
+import {
+  createNoopSigner,
+  generateKeyPairSigner,
+  isNone,
+  isSome,
+  lamports,
+  type Address,
+} from '@solana/kit';
+import { getTransferSolInstruction } from '@solana-program/system';
+import {
+  getApproveProposalInstruction,
+  getCloseTransactionInstruction,
+  getCreateProposalInstruction,
+  getCreateTransactionInstruction,
+  getExecuteTransactionInstruction,
+  getSetRentCollectorAsAuthorityInstruction,
+} from '../clients/js/src/generated/instructions';
+import { fetchMaybeProposal, fetchProposal } from '../clients/js/src/generated/accounts/proposal';
+import { fetchMaybeTransaction, fetchTransaction } from '../clients/js/src/generated/accounts/transaction';
+import { fetchSettings } from '../clients/js/src/generated/accounts/settings';
+import { deriveProposalPda, deriveTransactionPda } from '../utils';
+import {
+  compileSmartAccountMessage,
+  createTestSmartAccount,
+  deriveSmartAccountPda,
+  expectRejected,
+  setupTestContext,
+  withRemainingAccounts,
+} from './utils';
+
+async function testRentCollector() {
+  console.log('Testing rentCollector...');
+  console.log('This test creates a smart account with a rent collector and changes it with setRentCollectorAsAuthority.');
+  console.log('It then closes an executed transaction, whose rent goes to the rent collector it was created with.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  // The address that receives the rent of closed transaction accounts.
+  const rentCollector = (await generateKeyPairSigner()).address;
+  console.log('🧾 Rent collector:', rentCollector);
+
+  try {
+    // Step 1: Create a controlled smart account with the rent collector set
+    console.log('');
+    console.log('🏗️  Step 1: Creating a controlled smart account with a rent collector...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers: [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }],
+      settingsAuthority: creatorSigner.address,
+      rentCollector,
+    });
+
+    const settings = await fetchSettings(rpc, result.settingsAddress);
+    if (!isSome(settings.data.rentCollector) || settings.data.rentCollector.value !== rentCollector) {
+      throw new Error('❌ Rent collector was not stored on the smart account');
+    }
+    console.log('✅ Smart account created:', result.settingsAddress);
+
+    // Step 2: Execute a transaction, which records the current rent collector
+    console.log('');
+    console.log('🚀 Step 2: Executing a transaction...');
+    const [smartAccountPda, smartAccountPdaBump] = await deriveSmartAccountPda(result.settingsAddress, 0);
+    await sendInstructions([
+      getTransferSolInstruction({
+        source: backendFeePayerSigner,
+        destination: smartAccountPda,
+        amount: lamports(10_000_000n),
+      }),
+    ]);
+    const { transactionMessage, remainingAccounts } = await compileSmartAccountMessage(rpc, smartAccountPda, [
+      getTransferSolInstruction({
+        source: createNoopSigner(smartAccountPda),
+        destination: (await generateKeyPairSigner()).address,
+        amount: lamports(1_000_000n),
+      }),
+    ]);
+    const transactionIndex = 1n;
+    const transactionPda = await deriveTransactionPda(result.settingsAddress, transactionIndex);
+    const proposalPda = await deriveProposalPda(result.settingsAddress, transactionIndex);
+    await sendInstructions([
+      getCreateTransactionInstruction({
+        settings: result.settingsAddress,
+        transaction: transactionPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        args: {
+          accountIndex: 0,
+          accountBump: smartAccountPdaBump,
+          ephemeralSigners: 0,
+          transactionMessage,
+          memo: null,
+        },
+      }),
+      getCreateProposalInstruction({
+        settings: result.settingsAddress,
+        proposal: proposalPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        transactionIndex,
+        draft: false,
+        votingDeadline: null,
+      }),
+      getApproveProposalInstruction({
+        settings: result.settingsAddress,
+        signer: creatorSigner,
+        proposal: proposalPda,
+        args: { memo: null },
+      }),
+    ]);
+    await sendInstructions([
+      withRemainingAccounts(
+        getExecuteTransactionInstruction({
+          settings: result.settingsAddress,
+          proposal: proposalPda,
+          transaction: transactionPda,
+          signer: creatorSigner,
+          feePayer: backendFeePayerSigner,
+        }),
+        remainingAccounts
+      ),
+    ]);
+    const transaction = await fetchTransaction(rpc, transactionPda);
+    const proposal = await fetchProposal(rpc, proposalPda);
+    if (transaction.data.rentCollector !== rentCollector || proposal.data.rentCollector !== rentCollector) {
+      throw new Error('❌ Transaction accounts do not record the rent collector of the smart account');
+    }
+    console.log('✅ Transaction executed:', transactionPda);
+
+    const closeTransaction = (proposalRentCollector: Address, transactionRentCollector: Address) =>
+      sendInstructions([
+        getCloseTransactionInstruction({
+          settings: result.settingsAddress,
+          proposal: proposalPda,
+          transaction: transactionPda,
+          proposalRentCollector,
+          transactionRentCollector,
+        }),
+      ]);
+
+    // Step 3: Disable rent reclamation
+    console.log('');
+    console.log('🚫 Step 3: Unsetting the rent collector...');
+    await sendInstructions([
+      getSetRentCollectorAsAuthorityInstruction({
+        settings: result.settingsAddress,
+        settingsAuthority: creatorSigner,
+        newRentCollector: null,
+        memo: null,
+      }),
+    ]);
+    const settingsAfterUnset = await fetchSettings(rpc, result.settingsAddress);
+    if (!isNone(settingsAfterUnset.data.rentCollector)) {
+      throw new Error('❌ Rent collector was not unset');
+    }
+    await expectRejected('Closing the transaction with rent reclamation disabled', () =>
+      closeTransaction(rentCollector, rentCollector)
+    );
+    console.log('✅ Rent reclamation disabled');
+
+    // Step 4: Re-enable rent reclamation with the fee payer as the rent collector
+    console.log('');
+    console.log('🧾 Step 4: Setting the fee payer as the rent collector...');
+    await sendInstructions([
+      getSetRentCollectorAsAuthorityInstruction({
+        settings: result.settingsAddress,
+        settingsAuthority: creatorSigner,
+        newRentCollector: backendFeePayerSigner.address,
+        memo: 'Rent collector test',
+      }),
+    ]);
+    const settingsAfterSet = await fetchSettings(rpc, result.settingsAddress);
+    if (
+      !isSome(settingsAfterSet.data.rentCollector) ||
+      settingsAfterSet.data.rentCollector.value !== backendFeePayerSigner.address
+    ) {
+      throw new Error('❌ Rent collector was not updated');
+    }
+    if (settingsAfterSet.data.staleTransactionIndex !== settings.data.staleTransactionIndex) {
+      throw new Error('❌ Changing the rent collector must not invalidate prior transactions');
+    }
+    console.log('✅ Rent collector updated');
+
+    // Step 5: Close the transaction, its rent goes to the rent collector it was created with
+    console.log('');
+    console.log('🗑️  Step 5: Closing the transaction...');
+    await expectRejected('Closing the transaction to the new rent collector', () =>
+      closeTransaction(backendFeePayerSigner.address, backendFeePayerSigner.address)
+    );
+    await closeTransaction(rentCollector, rentCollector);
+    if ((await fetchMaybeTransaction(rpc, transactionPda)).exists) {
+      throw new Error('❌ Transaction still exists after closing');
+    }
+    if ((await fetchMaybeProposal(rpc, proposalPda)).exists) {
+      throw new Error('❌ Proposal still exists after closing');
+    }
+    const { value: rentCollectorBalance } = await rpc.getBalance(rentCollector, { commitment: 'confirmed' }).send();
+    if (rentCollectorBalance === 0n) {
+      throw new Error('❌ Expected the rent of the transaction accounts to go to their rent collector');
+    }
+    console.log('✅ Transaction closed, rent collected:', rentCollectorBalance);
+  } catch (error) {
+    console.error('❌ rentCollector failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testRentCollector();
@@ -0,0 +1,110 @@
+import {
+  address,
+  generateKeyPairSigner,
+} from '@solana/kit';
+import {
+  getCreateSessionKeyInstruction,
+  getRevokeSessionKeyInstruction,
+} from '../clients/js/src/generated/instructions';
+import { fetchMaybeSessionKey, fetchSessionKey } from '../clients/js/src/generated/accounts/sessionKey';
+import { sessionExpiration } from '../clients/js/src/generated/types';
+import { deriveSessionKeyPda } from '../utils/index';
+import { setupTestContext, createTestSmartAccount } from './utils';
+
+async function testSessionKey() {
+  console.log('Testing createSessionKey and revokeSessionKey...');
+  console.log('This test grants a session key on behalf of a smart account signer and revokes it.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  // The short-lived key that acts on behalf of the creator.
+  const sessionSigner = await generateKeyPairSigner();
+  console.log('🔑 Session key:', sessionSigner.address);
+
+  try {
+    // Step 1: Create a smart account
+    console.log('');
+    console.log('🏗️  Step 1: Creating smart account...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers: [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }],
+    });
+    console.log('✅ Smart account created:', result.settingsAddress);
+
+    const sessionKeyPda = await deriveSessionKeyPda(result.settingsAddress, sessionSigner.address);
+    const expiresAt = BigInt(Math.floor(Date.now() / 1000) + 60 * 60); // 1 hour
+
+    // Step 2: An address that is not a signer must not be able to grant a session key
+    console.log('');
+    console.log('🚫 Step 2: Granting a session key by a non-signer must fail...');
+    const impostor = await generateKeyPairSigner();
+    try {
+      await sendInstructions([
+        getCreateSessionKeyInstruction({
+          settings: result.settingsAddress,
+          sessionKey: sessionKeyPda,
+          granter: impostor,
+          feePayer: backendFeePayerSigner,
+          key: sessionSigner.address,
+          permissions: { mask: 2 },
+          expiration: sessionExpiration('Timestamp', [expiresAt]),
+          allowedPrograms: [],
+          maxLamports: null,
+          memo: null,
+        }),
+      ]);
+      throw new Error('❌ Session key granted by a non-signer');
+    } catch (error) {
+      if (error instanceof Error && error.message.startsWith('❌')) throw error;
+      console.log('✅ Session key by a non-signer rejected');
+    }
+
+    // Step 3: Grant a vote-only session key limited to the System Program and 0.1 SOL
+    console.log('');
+    console.log('🎟️  Step 3: Granting a session key...');
+    await sendInstructions([
+      getCreateSessionKeyInstruction({
+        settings: result.settingsAddress,
+        sessionKey: sessionKeyPda,
+        granter: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        key: sessionSigner.address,
+        permissions: { mask: 2 }, // Vote
+        expiration: sessionExpiration('Timestamp', [expiresAt]),
+        allowedPrograms: [address('11111111111111111111111111111111')],
+        maxLamports: 100_000_000n,
+        memo: 'Session key test',
+      }),
+    ]);
+    const sessionKey = await fetchSessionKey(rpc, sessionKeyPda);
+    if (sessionKey.data.granter !== creatorSigner.address || sessionKey.data.key !== sessionSigner.address) {
+      throw new Error('❌ Session key was not stored correctly');
+    }
+    console.log('✅ Session key granted, expires at:', expiresAt.toString());
+
+    // Step 4: The session key revokes itself
+    console.log('');
+    console.log('🗑️  Step 4: Revoking the session key...');
+    await sendInstructions([
+      getRevokeSessionKeyInstruction({
+        settings: result.settingsAddress,
+        sessionKey: sessionKeyPda,
+        signer: sessionSigner,
+        rentCollector: backendFeePayerSigner.address,
+        memo: null,
+      }),
+    ]);
+    if ((await fetchMaybeSessionKey(rpc, sessionKeyPda)).exists) {
+      throw new Error('❌ Session key account still exists after revocation');
+    }
+    console.log('✅ Session key revoked');
+  } catch (error) {
+    console.error('❌ sessionKey failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testSessionKey();
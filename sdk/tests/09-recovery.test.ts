@@ -0,0 +1,127 @@
+import {
+  getProgramDerivedAddress,
+  address,
+  generateKeyPairSigner,
+  getAddressEncoder,
+} from '@solana/kit';
+import { Buffer } from 'buffer';
+import {
+  getCreateRecoveryInstructionAsync,
+  getFinalizeRecoveryInstructionAsync,
+  getSetRecoveryConfigAsAuthorityInstruction,
+  getVetoRecoveryInstructionAsync,
+} from '../clients/js/src/generated/instructions';
+import { fetchMaybeRecovery } from '../clients/js/src/generated/accounts/recovery';
+import { fetchSettings } from '../clients/js/src/generated/accounts/settings';
+import { ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS } from '../clients/js/src/generated/programs';
+import { setupTestContext, createTestSmartAccount } from './utils';
+
+async function testRecovery() {
+  console.log('Testing guardian recovery...');
+  console.log('This test starts a recovery by a guardian, vetoes it, then starts another one and finalizes it.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  // The guardian that can recover the smart account, and the signer it is recovered to.
+  const guardian = await generateKeyPairSigner();
+  const newOwner = await generateKeyPairSigner();
+  console.log('🛡️  Guardian:', guardian.address);
+  console.log('🔑 New owner:', newOwner.address);
+
+  try {
+    // Step 1: Create a controlled smart account with a guardian
+    console.log('');
+    console.log('🏗️  Step 1: Creating a controlled smart account with a guardian...');
+    const restrictedSigners = [
+      {
+        key: guardian.address,
+        restrictedPermissions: { mask: 2 }, // Recover
+        exitDestination: address('11111111111111111111111111111111'),
+      },
+    ];
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers: [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }],
+      restrictedSigners,
+      settingsAuthority: creatorSigner.address,
+    });
+    console.log('✅ Smart account created:', result.settingsAddress);
+
+    // Step 2: Enable recovery by a single guardian without a veto period
+    console.log('');
+    console.log('⚙️  Step 2: Setting the recovery config...');
+    await sendInstructions([
+      getSetRecoveryConfigAsAuthorityInstruction({
+        settings: result.settingsAddress,
+        settingsAuthority: creatorSigner,
+        newRecoveryThreshold: 1,
+        newRecoveryDelay: 0,
+        memo: null,
+      }),
+    ]);
+    console.log('✅ Recovery enabled');
+
+    const [recoveryPda] = await getProgramDerivedAddress({
+      programAddress: ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
+      seeds: [
+        new Uint8Array(Buffer.from('smart_account')),
+        getAddressEncoder().encode(result.settingsAddress),
+        new Uint8Array(Buffer.from('recovery')),
+      ],
+    });
+    const createRecovery = () =>
+      getCreateRecoveryInstructionAsync({
+        settings: result.settingsAddress,
+        guardian,
+        feePayer: backendFeePayerSigner,
+        newSigners: [{ key: newOwner.address, permissions: { mask: 7 }, weight: 1 }],
+        newThreshold: 1,
+        memo: null,
+      });
+
+    // Step 3: The guardian starts a recovery and the current signer vetoes it
+    console.log('');
+    console.log('🚫 Step 3: Starting a recovery and vetoing it...');
+    await sendInstructions([await createRecovery()]);
+    await sendInstructions([
+      await getVetoRecoveryInstructionAsync({
+        settings: result.settingsAddress,
+        signer: creatorSigner,
+        rentCollector: backendFeePayerSigner.address,
+        memo: null,
+      }),
+    ]);
+    if ((await fetchMaybeRecovery(rpc, recoveryPda)).exists) {
+      throw new Error('❌ Recovery account still exists after the veto');
+    }
+    console.log('✅ Recovery vetoed');
+
+    // Step 4: The guardian starts another recovery and anyone finalizes it
+    console.log('');
+    console.log('♻️  Step 4: Starting a recovery and finalizing it...');
+    await sendInstructions([await createRecovery()]);
+    await sendInstructions([
+      await getFinalizeRecoveryInstructionAsync({
+        settings: result.settingsAddress,
+        rentCollector: backendFeePayerSigner.address,
+        feePayer: backendFeePayerSigner,
+      }),
+    ]);
+    const settings = await fetchSettings(rpc, result.settingsAddress);
+    if (settings.data.signers.length !== 1 || settings.data.signers[0].key !== newOwner.address) {
+      throw new Error('❌ Signers were not replaced by the recovery');
+    }
+    if (settings.data.staleTransactionIndex !== settings.data.transactionIndex) {
+      throw new Error('❌ Prior transactions were not invalidated by the recovery');
+    }
+    console.log('✅ Smart account recovered to:', newOwner.address);
+  } catch (error) {
+    console.error('❌ recovery failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testRecovery();
@@ -0,0 +1,259 @@
+import {
+  address,
+  createNoopSigner,
+  generateKeyPairSigner,
+  lamports,
+  AccountRole,
+  type Instruction,
+} from '@solana/kit';
+import { getTransferSolInstruction } from '@solana-program/system';
+import {
+  getApproveProposalInstruction,
+  getCreateProposalInstruction,
+  getCreateTransactionInstruction,
+  getExecuteTransactionInstruction,
+  getRemoveVaultPolicyAsAuthorityInstruction,
+  getSetVaultPolicyAsAuthorityInstruction,
+} from '../clients/js/src/generated/instructions';
+import { fetchMaybeVaultPolicy, fetchVaultPolicy } from '../clients/js/src/generated/accounts/vaultPolicy';
+import { fetchProposal } from '../clients/js/src/generated/accounts/proposal';
+import { deriveProposalPda, deriveTransactionPda, deriveVaultPolicyPda, getVaultPolicyAddress } from '../utils/index';
+import { fetchSettings } from '../clients/js/src/generated/accounts/settings';
+import {
+  compileSmartAccountMessage,
+  createTestSmartAccount,
+  deriveSmartAccountPda,
+  expectRejected,
+  setupTestContext,
+  withRemainingAccounts,
+} from './utils';
+
+async function testVaultPolicy() {
+  console.log('Testing vaultPolicy...');
+  console.log('This test sets a policy on a smart account with setVaultPolicyAsAuthority and removes it with removeVaultPolicyAsAuthority.');
+  console.log('It then executes a transaction under a policy with a higher threshold than the settings.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  // The smart account the policy is for.
+  const accountIndex = 1;
+
+  try {
+    // Step 1: Create a controlled smart account
+    console.log('');
+    console.log('🏗️  Step 1: Creating a controlled smart account...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers: [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }],
+      settingsAuthority: creatorSigner.address,
+    });
+    console.log('✅ Smart account created:', result.settingsAddress);
+
+    // The VaultPolicy account is passed as a remaining account.
+    const vaultPolicyPda = await deriveVaultPolicyPda(result.settingsAddress, accountIndex);
+    const withVaultPolicy = (instruction: Instruction): Instruction => ({
+      ...instruction,
+      accounts: [...(instruction.accounts ?? []), { address: vaultPolicyPda, role: AccountRole.WRITABLE }],
+    });
+
+    // Step 2: Set a policy on the smart account
+    console.log('');
+    console.log('🔐 Step 2: Setting the vault policy of smart account', accountIndex, '...');
+    await sendInstructions([
+      withVaultPolicy(
+        getSetVaultPolicyAsAuthorityInstruction({
+          settings: result.settingsAddress,
+          settingsAuthority: creatorSigner,
+          feePayer: backendFeePayerSigner,
+          systemProgram: address('11111111111111111111111111111111'),
+          accountIndex,
+          threshold: 1,
+          timeLock: 60,
+          signers: [creatorSigner.address],
+          memo: null,
+        })
+      ),
+    ]);
+    const vaultPolicy = await fetchVaultPolicy(rpc, vaultPolicyPda);
+    if (vaultPolicy.data.timeLock !== 60 || vaultPolicy.data.signers[0] !== creatorSigner.address) {
+      throw new Error('❌ Vault policy does not match what was set');
+    }
+    let settings = await fetchSettings(rpc, result.settingsAddress);
+    if ((await getVaultPolicyAddress(result.settingsAddress, settings.data.vaultPolicies, accountIndex)) !== vaultPolicyPda) {
+      throw new Error('❌ Settings do not record the vault policy');
+    }
+    if ((await getVaultPolicyAddress(result.settingsAddress, settings.data.vaultPolicies, 0)) !== undefined) {
+      throw new Error('❌ Settings record a vault policy for the wrong smart account');
+    }
+    console.log('✅ Vault policy set:', vaultPolicyPda);
+
+    // Step 3: Remove the policy
+    console.log('');
+    console.log('🗑️  Step 3: Removing the vault policy...');
+    await sendInstructions([
+      withVaultPolicy(
+        getRemoveVaultPolicyAsAuthorityInstruction({
+          settings: result.settingsAddress,
+          settingsAuthority: creatorSigner,
+          feePayer: backendFeePayerSigner,
+          accountIndex,
+          memo: null,
+        })
+      ),
+    ]);
+    if ((await fetchMaybeVaultPolicy(rpc, vaultPolicyPda)).exists) {
+      throw new Error('❌ Vault policy account still exists after removal');
+    }
+    settings = await fetchSettings(rpc, result.settingsAddress);
+    if ((await getVaultPolicyAddress(result.settingsAddress, settings.data.vaultPolicies, accountIndex)) !== undefined) {
+      throw new Error('❌ Settings still record the removed vault policy');
+    }
+    console.log('✅ Vault policy removed');
+
+    // Step 4: Create a smart account with a threshold of 1 and a policy with a threshold of 2 on its vault
+    console.log('');
+    console.log('🏗️  Step 4: Creating a smart account with a threshold of 1 and a policy with a threshold of 2...');
+    const secondSigner = await generateKeyPairSigner();
+    const policyAccount = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers: [
+        { key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 },
+        { key: secondSigner.address, permissions: { mask: 7 }, weight: 1 },
+      ],
+      settingsAuthority: creatorSigner.address,
+    });
+    const policySettings = policyAccount.settingsAddress;
+    const policyVaultPda = await deriveVaultPolicyPda(policySettings, 0);
+    await sendInstructions([
+      withRemainingAccounts(
+        getSetVaultPolicyAsAuthorityInstruction({
+          settings: policySettings,
+          settingsAuthority: creatorSigner,
+          feePayer: backendFeePayerSigner,
+          systemProgram: address('11111111111111111111111111111111'),
+          accountIndex: 0,
+          threshold: 2,
+          timeLock: 0,
+          signers: [],
+          memo: null,
+        }),
+        [{ address: policyVaultPda, role: AccountRole.WRITABLE }]
+      ),
+    ]);
+    console.log('✅ Vault policy with a threshold of 2 set:', policyVaultPda);
+
+    // Step 5: Propose a transfer from the vault
+    console.log('');
+    console.log('📝 Step 5: Proposing a transfer from the vault...');
+    const [smartAccountPda, smartAccountPdaBump] = await deriveSmartAccountPda(policySettings, 0);
+    await sendInstructions([
+      getTransferSolInstruction({
+        source: backendFeePayerSigner,
+        destination: smartAccountPda,
+        amount: lamports(100_000_000n),
+      }),
+    ]);
+    const recipient = await generateKeyPairSigner();
+    const transferAmount = 10_000_000n;
+    const { transactionMessage, remainingAccounts } = await compileSmartAccountMessage(rpc, smartAccountPda, [
+      getTransferSolInstruction({
+        source: createNoopSigner(smartAccountPda),
+        destination: recipient.address,
+        amount: lamports(transferAmount),
+      }),
+    ]);
+    const transactionIndex = (await fetchSettings(rpc, policySettings)).data.transactionIndex + 1n;
+    const transactionPda = await deriveTransactionPda(policySettings, transactionIndex);
+    const proposalPda = await deriveProposalPda(policySettings, transactionIndex);
+    await sendInstructions([
+      getCreateTransactionInstruction({
+        settings: policySettings,
+        transaction: transactionPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        vaultPolicy: policyVaultPda,
+        args: {
+          accountIndex: 0,
+          accountBump: smartAccountPdaBump,
+          ephemeralSigners: 0,
+          transactionMessage,
+          memo: null,
+        },
+      }),
+      getCreateProposalInstruction({
+        settings: policySettings,
+        proposal: proposalPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        transactionIndex,
+        draft: false,
+        votingDeadline: null,
+        transaction: transactionPda,
+        vaultPolicy: policyVaultPda,
+      }),
+    ]);
+    console.log('✅ Transaction and proposal created:', transactionPda);
+
+    // Step 6: One approval reaches the settings threshold but not the policy one
+    console.log('');
+    console.log('🗳️  Step 6: Approving with the first signer...');
+    const approve = (signer: typeof creatorSigner) =>
+      sendInstructions([
+        getApproveProposalInstruction({
+          settings: policySettings,
+          signer,
+          proposal: proposalPda,
+          transaction: transactionPda,
+          vaultPolicy: policyVaultPda,
+          args: { memo: null },
+        }),
+      ]);
+    const execute = () =>
+      sendInstructions([
+        withRemainingAccounts(
+          getExecuteTransactionInstruction({
+            settings: policySettings,
+            proposal: proposalPda,
+            transaction: transactionPda,
+            signer: creatorSigner,
+            feePayer: backendFeePayerSigner,
+            vaultPolicy: policyVaultPda,
+          }),
+          remainingAccounts
+        ),
+      ]);
+    await approve(creatorSigner);
+    let proposal = await fetchProposal(rpc, proposalPda);
+    if (proposal.data.status.__kind !== 'Active') {
+      throw new Error(`❌ Expected the proposal to stay active below the policy threshold, got ${proposal.data.status.__kind}`);
+    }
+    await expectRejected('Executing below the policy threshold', execute);
+
+    // Step 7: The second approval reaches the policy threshold, and the transaction executes
+    console.log('');
+    console.log('🚀 Step 7: Approving with the second signer and executing...');
+    await approve(secondSigner);
+    proposal = await fetchProposal(rpc, proposalPda);
+    if (proposal.data.status.__kind !== 'Approved') {
+      throw new Error(`❌ Expected the proposal to be approved at the policy threshold, got ${proposal.data.status.__kind}`);
+    }
+    await execute();
+    proposal = await fetchProposal(rpc, proposalPda);
+    if (proposal.data.status.__kind !== 'Executed') {
+      throw new Error(`❌ Expected the proposal to be executed, got ${proposal.data.status.__kind}`);
+    }
+    const { value: recipientBalance } = await rpc.getBalance(recipient.address, { commitment: 'confirmed' }).send();
+    if (recipientBalance !== transferAmount) {
+      throw new Error(`❌ Expected the recipient to get ${transferAmount} lamports, got ${recipientBalance}`);
+    }
+    console.log('✅ Transaction executed under the vault policy');
+  } catch (error) {
+    console.error('❌ vaultPolicy failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testVaultPolicy();
@@ -0,0 +1,78 @@
+import {
+  generateKeyPairSigner,
+} from '@solana/kit';
+import {
+  getChangeThresholdAsAuthorityInstruction,
+} from '../clients/js/src/generated/instructions';
+import { fetchSettings } from '../clients/js/src/generated/accounts/settings';
+import { setupTestContext, createTestSmartAccount } from './utils';
+
+async function testWeightedVoting() {
+  console.log('Testing weighted voting...');
+  console.log('This test creates a smart account with weighted signers and checks that the threshold is bounded by the total voting weight.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, sendInstructions } = context;
+
+  // A second signer that can only vote, with a lower weight than the creator.
+  const voter = await generateKeyPairSigner();
+  console.log('🗳️  Voter:', voter.address);
+  const signers = [
+    { key: creatorSigner.address, permissions: { mask: 7 }, weight: 2 },
+    { key: voter.address, permissions: { mask: 2 }, weight: 1 },
+  ];
+
+  try {
+    // Step 1: Create a controlled smart account with weighted signers
+    console.log('');
+    console.log('🏗️  Step 1: Creating a controlled smart account with a total voting weight of 3...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 2,
+      signers,
+      settingsAuthority: creatorSigner.address,
+    });
+    const settings = await fetchSettings(rpc, result.settingsAddress);
+    const creatorEntry = settings.data.signers.find((s) => s.key === creatorSigner.address);
+    if (creatorEntry?.weight !== 2) {
+      throw new Error(`❌ Expected creator weight 2, but got ${creatorEntry?.weight}`);
+    }
+    console.log('✅ Smart account created:', result.settingsAddress);
+
+    const changeThreshold = (newThreshold: number) =>
+      sendInstructions([
+        getChangeThresholdAsAuthorityInstruction({
+          settings: result.settingsAddress,
+          settingsAuthority: creatorSigner,
+          newThreshold,
+          memo: null,
+        }),
+      ]);
+
+    // Step 2: The threshold can be raised up to the total voting weight
+    console.log('');
+    console.log('⚖️  Step 2: Raising the threshold to the total voting weight...');
+    await changeThreshold(3);
+    console.log('✅ Threshold raised to 3');
+
+    // Step 3: The threshold cannot exceed the total voting weight
+    console.log('');
+    console.log('🚫 Step 3: Raising the threshold above the total voting weight...');
+    let exceeded = false;
+    try {
+      await changeThreshold(4);
+      exceeded = true;
+    } catch {
+      console.log('✅ Threshold above the total voting weight was rejected');
+    }
+    if (exceeded) {
+      throw new Error('❌ Threshold above the total voting weight was accepted');
+    }
+  } catch (error) {
+    console.error('❌ weighted voting failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testWeightedVoting();
@@ -0,0 +1,99 @@
+import {
+  getCreateProposalInstruction,
+  getCreateSettingsTransactionInstruction,
+  getExpireProposalInstruction,
+} from '../clients/js/src/generated/instructions';
+import { fetchProposal } from '../clients/js/src/generated/accounts/proposal';
+import { deriveProposalPda, deriveTransactionPda } from '../utils';
+import { setupTestContext, createTestSmartAccount } from './utils';
+
+async function testProposalExpiry() {
+  console.log('Testing proposal expiry...');
+  console.log('This test creates a proposal with a voting deadline and expires it once the deadline has passed.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  const signers = [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }];
+
+  try {
+    // Step 1: Create an autonomous smart account, settings transactions need consensus
+    console.log('');
+    console.log('🏗️  Step 1: Creating an autonomous smart account...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers,
+    });
+    console.log('✅ Smart account created:', result.settingsAddress);
+
+    // Step 2: Create a settings transaction with a proposal that must be voted on within a few seconds
+    console.log('');
+    console.log('⏳ Step 2: Creating a proposal with a voting deadline...');
+    const transactionIndex = 1n;
+    const transactionPda = await deriveTransactionPda(result.settingsAddress, transactionIndex);
+    const proposalPda = await deriveProposalPda(result.settingsAddress, transactionIndex);
+    const votingDeadline = BigInt(Math.floor(Date.now() / 1000) + 5);
+    await sendInstructions([
+      getCreateSettingsTransactionInstruction({
+        settings: result.settingsAddress,
+        transaction: transactionPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        args: {
+          actions: [{ __kind: 'SetExecutionWindow', newExecutionWindow: 3600 }],
+          memo: null,
+        },
+      }),
+      getCreateProposalInstruction({
+        settings: result.settingsAddress,
+        proposal: proposalPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        transactionIndex,
+        draft: false,
+        votingDeadline,
+      }),
+    ]);
+    console.log('✅ Proposal created with voting deadline', votingDeadline.toString());
+
+    const expireProposal = () =>
+      sendInstructions([
+        getExpireProposalInstruction({
+          settings: result.settingsAddress,
+          proposal: proposalPda,
+        }),
+      ]);
+
+    // Step 3: The proposal cannot be expired before its deadline
+    console.log('');
+    console.log('🚫 Step 3: Expiring the proposal before its voting deadline...');
+    let expiredEarly = false;
+    try {
+      await expireProposal();
+      expiredEarly = true;
+    } catch {
+      console.log('✅ Expiring the proposal before its deadline was rejected');
+    }
+    if (expiredEarly) {
+      throw new Error('❌ Proposal was expired before its voting deadline');
+    }
+
+    // Step 4: Anyone can expire the proposal once the deadline has passed
+    console.log('');
+    console.log('⌛ Step 4: Waiting for the voting deadline and expiring the proposal...');
+    await new Promise((resolve) => setTimeout(resolve, 10_000));
+    await expireProposal();
+    const proposal = await fetchProposal(rpc, proposalPda);
+    if (proposal.data.status.__kind !== 'Expired') {
+      throw new Error(`❌ Expected proposal status Expired, but got ${proposal.data.status.__kind}`);
+    }
+    console.log('✅ Proposal expired');
+  } catch (error) {
+    console.error('❌ proposal expiry failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testProposalExpiry();
@@ -0,0 +1,126 @@
+import {
+  address,
+  generateKeyPairSigner,
+} from '@solana/kit';
+import {
+  getAddRestrictedSignerAsAuthorityInstruction,
+  getChangeSignerPermissionsAsAuthorityInstruction,
+  getRemoveRestrictedSignerAsAuthorityInstruction,
+} from '../clients/js/src/generated/instructions';
+import { fetchSettings } from '../clients/js/src/generated/accounts/settings';
+import { setupTestContext, createTestSmartAccount } from './utils';
+
+async function testSignerChanges() {
+  console.log('Testing restricted signer and permission changes...');
+  console.log('This test adds and removes a restricted signer and changes the permissions of a signer of a controlled smart account.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  // A second signer that starts with all permissions, and a guardian to add as a restricted signer.
+  const member = await generateKeyPairSigner();
+  const guardian = await generateKeyPairSigner();
+  console.log('👤 Member:', member.address);
+  console.log('🛡️  Guardian:', guardian.address);
+  const signers = [
+    { key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 },
+    { key: member.address, permissions: { mask: 7 }, weight: 1 },
+  ];
+
+  try {
+    // Step 1: Create a controlled smart account without a time lock
+    console.log('');
+    console.log('🏗️  Step 1: Creating a controlled smart account without a time lock...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers,
+      settingsAuthority: creatorSigner.address,
+    });
+    console.log('✅ Smart account created:', result.settingsAddress);
+
+    // Step 2: Add a guardian as a restricted signer
+    console.log('');
+    console.log('🛡️  Step 2: Adding a guardian as a restricted signer...');
+    await sendInstructions([
+      getAddRestrictedSignerAsAuthorityInstruction({
+        settings: result.settingsAddress,
+        settingsAuthority: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        systemProgram: address('11111111111111111111111111111111'),
+        newRestrictedSigner: {
+          key: guardian.address,
+          restrictedPermissions: { mask: 2 }, // Recover
+          exitDestination: address('11111111111111111111111111111111'),
+        },
+        memo: null,
+      }),
+    ]);
+    let settings = await fetchSettings(rpc, result.settingsAddress);
+    if (!settings.data.restrictedSigners.some((s) => s.key === guardian.address)) {
+      throw new Error('❌ Expected the guardian to be a restricted signer');
+    }
+    console.log('✅ Guardian added');
+
+    // Step 3: Make the member vote-only
+    console.log('');
+    console.log('🔑 Step 3: Changing the permissions of the member to vote only...');
+    await sendInstructions([
+      getChangeSignerPermissionsAsAuthorityInstruction({
+        settings: result.settingsAddress,
+        settingsAuthority: creatorSigner,
+        signer: member.address,
+        newPermissions: { mask: 2 }, // Vote
+        memo: null,
+      }),
+    ]);
+    settings = await fetchSettings(rpc, result.settingsAddress);
+    const memberEntry = settings.data.signers.find((s) => s.key === member.address);
+    if (memberEntry?.permissions.mask !== 2) {
+      throw new Error(`❌ Expected member permissions 2, but got ${memberEntry?.permissions.mask}`);
+    }
+    if (settings.data.staleTransactionIndex !== settings.data.transactionIndex) {
+      throw new Error('❌ Expected prior transactions to be invalidated');
+    }
+    console.log('✅ Member permissions changed');
+
+    // Step 4: Remove the guardian
+    console.log('');
+    console.log('🗑️  Step 4: Removing the guardian...');
+    const removeGuardian = () =>
+      sendInstructions([
+        getRemoveRestrictedSignerAsAuthorityInstruction({
+          settings: result.settingsAddress,
+          settingsAuthority: creatorSigner,
+          oldRestrictedSigner: guardian.address,
+          memo: null,
+        }),
+      ]);
+    await removeGuardian();
+    settings = await fetchSettings(rpc, result.settingsAddress);
+    if (settings.data.restrictedSigners.length !== 0) {
+      throw new Error('❌ Expected no restricted signers after removal');
+    }
+    console.log('✅ Guardian removed');
+
+    // Step 5: Removing a restricted signer that doesn't exist fails
+    console.log('');
+    console.log('🚫 Step 5: Removing the guardian again...');
+    let removedTwice = false;
+    try {
+      await removeGuardian();
+      removedTwice = true;
+    } catch {
+      console.log('✅ Removing a missing restricted signer was rejected');
+    }
+    if (removedTwice) {
+      throw new Error('❌ Removing a missing restricted signer was accepted');
+    }
+  } catch (error) {
+    console.error('❌ signer changes failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testSignerChanges();
@@ -0,0 +1,122 @@
+import {
+  address,
+} from '@solana/kit';
+import {
+  getAddProgramFilterAsAuthorityInstruction,
+  getRemoveProgramFilterAsAuthorityInstruction,
+  getSetProgramFilterModeAsAuthorityInstruction,
+} from '../clients/js/src/generated/instructions';
+import { ProgramFilterMode } from '../clients/js/src/generated/types';
+import { fetchSettings } from '../clients/js/src/generated/accounts/settings';
+import { setupTestContext, createTestSmartAccount } from './utils';
+
+async function testProgramFilter() {
+  console.log('Testing program filters...');
+  console.log('This test denylists the System program transfer instruction for a controlled smart account and removes it.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  const signers = [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }];
+  // The System program `Transfer` instruction, its discriminator is the u32 instruction index 2.
+  const transferFilter = {
+    programId: address('11111111111111111111111111111111'),
+    discriminator: new Uint8Array([2, 0, 0, 0]),
+  };
+
+  try {
+    // Step 1: Create a controlled smart account without a time lock
+    console.log('');
+    console.log('🏗️  Step 1: Creating a controlled smart account without a time lock...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers,
+      settingsAuthority: creatorSigner.address,
+    });
+    console.log('✅ Smart account created:', result.settingsAddress);
+
+    // Step 2: Denylist the System program transfers
+    console.log('');
+    console.log('🚫 Step 2: Denylisting the System program transfers...');
+    await sendInstructions([
+      getSetProgramFilterModeAsAuthorityInstruction({
+        settings: result.settingsAddress,
+        settingsAuthority: creatorSigner,
+        mode: ProgramFilterMode.Denylist,
+        memo: null,
+      }),
+      getAddProgramFilterAsAuthorityInstruction({
+        settings: result.settingsAddress,
+        settingsAuthority: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        systemProgram: address('11111111111111111111111111111111'),
+        filter: transferFilter,
+        memo: null,
+      }),
+    ]);
+    let settings = await fetchSettings(rpc, result.settingsAddress);
+    if (settings.data.programFilterMode !== ProgramFilterMode.Denylist) {
+      throw new Error(`❌ Expected the denylist mode, but got ${settings.data.programFilterMode}`);
+    }
+    if (settings.data.programFilters.length !== 1) {
+      throw new Error(`❌ Expected 1 program filter, but got ${settings.data.programFilters.length}`);
+    }
+    console.log('✅ System program transfers denylisted');
+
+    // Step 3: Adding the same filter twice fails
+    console.log('');
+    console.log('🚫 Step 3: Adding the same filter again...');
+    let addedTwice = false;
+    try {
+      await sendInstructions([
+        getAddProgramFilterAsAuthorityInstruction({
+          settings: result.settingsAddress,
+          settingsAuthority: creatorSigner,
+          feePayer: backendFeePayerSigner,
+          systemProgram: address('11111111111111111111111111111111'),
+          filter: transferFilter,
+          memo: null,
+        }),
+      ]);
+      addedTwice = true;
+    } catch {
+      console.log('✅ Adding a duplicate program filter was rejected');
+    }
+    if (addedTwice) {
+      throw new Error('❌ Adding a duplicate program filter was accepted');
+    }
+
+    // Step 4: Remove the filter and disable the program filters
+    console.log('');
+    console.log('🗑️  Step 4: Removing the filter and disabling the program filters...');
+    await sendInstructions([
+      getRemoveProgramFilterAsAuthorityInstruction({
+        settings: result.settingsAddress,
+        settingsAuthority: creatorSigner,
+        filter: transferFilter,
+        memo: null,
+      }),
+      getSetProgramFilterModeAsAuthorityInstruction({
+        settings: result.settingsAddress,
+        settingsAuthority: creatorSigner,
+        mode: ProgramFilterMode.Disabled,
+        memo: null,
+      }),
+    ]);
+    settings = await fetchSettings(rpc, result.settingsAddress);
+    if (settings.data.programFilters.length !== 0) {
+      throw new Error('❌ Expected no program filters after removal');
+    }
+    if (settings.data.programFilterMode !== ProgramFilterMode.Disabled) {
+      throw new Error(`❌ Expected the program filters to be disabled, but got ${settings.data.programFilterMode}`);
+    }
+    console.log('✅ Program filters removed and disabled');
+  } catch (error) {
+    console.error('❌ program filter failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testProgramFilter();
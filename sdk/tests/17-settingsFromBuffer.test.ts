@@ -0,0 +1,94 @@
+import {
+  getArrayEncoder,
+} from '@solana/kit';
+import {
+  getCreateSettingsTransactionFromBufferInstruction,
+  getCreateTransactionBufferInstruction,
+} from '../clients/js/src/generated/instructions';
+import { getSettingsActionEncoder } from '../clients/js/src/generated/types';
+import { fetchSettingsTransaction } from '../clients/js/src/generated/accounts/settingsTransaction';
+import { deriveBufferPda, deriveTransactionPda } from '../utils';
+import { setupTestContext, createTestSmartAccount } from './utils';
+
+async function testSettingsFromBuffer() {
+  console.log('Testing settings transactions from a buffer...');
+  console.log('This test writes settings actions to a transaction buffer and turns it into a settings transaction.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  const signers = [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }];
+
+  try {
+    // Step 1: Create an autonomous smart account, settings transactions need consensus
+    console.log('');
+    console.log('🏗️  Step 1: Creating an autonomous smart account...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers,
+    });
+    console.log('✅ Smart account created:', result.settingsAddress);
+
+    // Step 2: Write the serialized settings actions to a transaction buffer
+    console.log('');
+    console.log('📦 Step 2: Writing the settings actions to a transaction buffer...');
+    const actions = new Uint8Array(
+      getArrayEncoder(getSettingsActionEncoder()).encode([
+        { __kind: 'SetExecutionWindow', newExecutionWindow: 3600 },
+        { __kind: 'SetTimeLock', newTimeLock: 0 },
+      ])
+    );
+    const finalBufferHash = new Uint8Array(
+      await crypto.subtle.digest('SHA-256', actions as unknown as ArrayBuffer)
+    );
+    const bufferIndex = 0;
+    const transactionBufferPda = await deriveBufferPda(result.settingsAddress, creatorSigner.address, bufferIndex);
+    await sendInstructions([
+      getCreateTransactionBufferInstruction({
+        settings: result.settingsAddress,
+        transactionBuffer: transactionBufferPda,
+        bufferCreator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        bufferIndex,
+        accountIndex: 0,
+        finalBufferHash,
+        finalBufferSize: actions.length,
+        buffer: actions,
+      }),
+    ]);
+    console.log('✅ Transaction buffer created:', transactionBufferPda);
+
+    // Step 3: Create the settings transaction from the buffer
+    console.log('');
+    console.log('🛠️  Step 3: Creating the settings transaction from the buffer...');
+    const transactionIndex = 1n;
+    const transactionPda = await deriveTransactionPda(result.settingsAddress, transactionIndex);
+    await sendInstructions([
+      getCreateSettingsTransactionFromBufferInstruction({
+        settings: result.settingsAddress,
+        transaction: transactionPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        transactionBuffer: transactionBufferPda,
+        fromBufferCreator: creatorSigner,
+        args: { actions: [], memo: null },
+      }),
+    ]);
+    const settingsTransaction = await fetchSettingsTransaction(rpc, transactionPda);
+    if (settingsTransaction.data.actions.length !== 2) {
+      throw new Error(`❌ Expected 2 settings actions, but got ${settingsTransaction.data.actions.length}`);
+    }
+    const { value: bufferAccount } = await rpc.getAccountInfo(transactionBufferPda, { commitment: 'confirmed' }).send();
+    if (bufferAccount !== null) {
+      throw new Error('❌ Expected the transaction buffer to be closed');
+    }
+    console.log('✅ Settings transaction created and transaction buffer closed');
+  } catch (error) {
+    console.error('❌ settings transaction from buffer failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testSettingsFromBuffer();
@@ -0,0 +1,111 @@
+import {
+  generateKeyPairSigner,
+  lamports,
+  type Address,
+} from '@solana/kit';
+import {
+  getSetProgramConfigReferralFeeInstructionAsync,
+  getSetProgramConfigSmartAccountCreationFeeInstructionAsync,
+} from '../clients/js/src/generated/instructions';
+import { fetchProgramConfig } from '../clients/js/src/generated/accounts/programConfig';
+import {
+  createTestSmartAccount,
+  deriveProgramConfigPda,
+  expectRejected,
+  loadProgramConfigAuthority,
+  setupTestContext,
+} from './utils';
+
+async function testReferralFee() {
+  console.log('Testing the referral share of the smart account creation fee...');
+  console.log('This test sets a creation fee with a referral share and creates a smart account with a referrer.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  // The program config authority set by 00-setup.test.ts
+  const authoritySigner = await loadProgramConfigAuthority();
+
+  const getBalance = async (account: Address) =>
+    (await rpc.getBalance(account, { commitment: 'confirmed' }).send()).value;
+
+  const programConfigPda = await deriveProgramConfigPda();
+  const { treasury } = (await fetchProgramConfig(rpc, programConfigPda)).data;
+
+  const creationFee = 10_000_000n;
+  const referralFeeBps = 2_000;
+  const referralFee = (creationFee * BigInt(referralFeeBps)) / 10_000n;
+
+  try {
+    // Step 1: Set a creation fee with a referral share
+    console.log('');
+    console.log('💸 Step 1: Setting a creation fee with a', referralFeeBps, 'bps referral share...');
+    await sendInstructions([
+      await getSetProgramConfigSmartAccountCreationFeeInstructionAsync({
+        authority: authoritySigner,
+        newSmartAccountCreationFee: creationFee,
+      }),
+      await getSetProgramConfigReferralFeeInstructionAsync({
+        authority: authoritySigner,
+        newReferralFeeBps: referralFeeBps,
+      }),
+    ]);
+    console.log('✅ Creation fee and referral share set');
+
+    // Step 2: A referral share above the whole fee is rejected
+    console.log('');
+    console.log('🚫 Step 2: Setting a referral share above 10000 bps...');
+    await expectRejected('Referral share above the whole fee', async () =>
+      sendInstructions([
+        await getSetProgramConfigReferralFeeInstructionAsync({
+          authority: authoritySigner,
+          newReferralFeeBps: 10_001,
+        }),
+      ])
+    );
+
+    // Step 3: Create a smart account with a referrer
+    console.log('');
+    console.log('🏗️  Step 3: Creating a smart account with a referrer...');
+    const referrer = await generateKeyPairSigner();
+    const treasuryBalanceBefore = await getBalance(treasury);
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers: [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }],
+      settingsAuthority: creatorSigner.address,
+      referrer: referrer.address,
+    });
+    console.log('✅ Smart account created:', result.settingsAddress);
+
+    const referrerBalance = await getBalance(referrer.address);
+    const treasuryBalanceAfter = await getBalance(treasury);
+    if (referrerBalance !== referralFee) {
+      throw new Error(`❌ Expected the referrer to get ${referralFee} lamports, got ${referrerBalance}`);
+    }
+    if (treasuryBalanceAfter - treasuryBalanceBefore !== creationFee - referralFee) {
+      throw new Error(
+        `❌ Expected the treasury to get ${creationFee - referralFee} lamports, got ${treasuryBalanceAfter - treasuryBalanceBefore}`
+      );
+    }
+    console.log('✅ Creation fee split between the treasury and the referrer');
+  } catch (error) {
+    console.error('❌ referral fee failed:', error);
+    throw error; // Re-throw to properly fail the test
+  } finally {
+    // Reset the fees so the other tests create smart accounts for free
+    await sendInstructions([
+      await getSetProgramConfigSmartAccountCreationFeeInstructionAsync({
+        authority: authoritySigner,
+        newSmartAccountCreationFee: 0n,
+      }),
+      await getSetProgramConfigReferralFeeInstructionAsync({
+        authority: authoritySigner,
+        newReferralFeeBps: 0,
+      }),
+    ]);
+  }
+
+}
+
+testReferralFee();
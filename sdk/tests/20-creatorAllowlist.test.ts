@@ -0,0 +1,130 @@
+import {
+  generateKeyPairSigner,
+  type Address,
+  type TransactionSigner,
+} from '@solana/kit';
+import {
+  getAddCreatorToAllowlistInstructionAsync,
+  getCreateCreatorAllowlistPageInstructionAsync,
+  getRemoveCreatorFromAllowlistInstructionAsync,
+  getSetProgramConfigCreationModeInstructionAsync,
+} from '../clients/js/src/generated/instructions';
+import { SmartAccountCreationMode } from '../clients/js/src/generated/types';
+import { deriveCreatorAllowlistPda } from '../utils';
+import {
+  createTestSmartAccount,
+  expectRejected,
+  loadProgramConfigAuthority,
+  setupTestContext,
+} from './utils';
+
+async function testCreatorAllowlist() {
+  console.log('Testing the smart account creation modes...');
+  console.log('This test creates smart accounts with the allowlisted and cosigned creation modes.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { backendFeePayerSigner, sendInstructions } = context;
+
+  // The program config authority set by 00-setup.test.ts
+  const authoritySigner = await loadProgramConfigAuthority();
+
+  const setCreationMode = async (newSmartAccountCreationMode: SmartAccountCreationMode) =>
+    sendInstructions([
+      await getSetProgramConfigCreationModeInstructionAsync({
+        authority: authoritySigner,
+        newSmartAccountCreationMode,
+      }),
+    ]);
+
+  // Each creation derives the settings of the next smart account index, so it's rebuilt on every attempt.
+  const createSmartAccount = async (
+    creator: TransactionSigner,
+    accounts: { creatorAllowlist?: Address; programConfigAuthority?: TransactionSigner }
+  ) => {
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers: [{ key: creator.address, permissions: { mask: 7 }, weight: 1 }],
+      creator,
+      ...accounts,
+    });
+    return result.settingsAddress;
+  };
+
+  const creator = await generateKeyPairSigner();
+  const page = 0;
+  const creatorAllowlist = await deriveCreatorAllowlistPda(page);
+
+  try {
+    // Step 1: Switch to the allowlist creation mode
+    console.log('');
+    console.log('📋 Step 1: Switching to the allowlist creation mode...');
+    try {
+      await sendInstructions([
+        await getCreateCreatorAllowlistPageInstructionAsync({
+          creatorAllowlist,
+          authority: authoritySigner,
+          rentPayer: backendFeePayerSigner,
+          page,
+        }),
+      ]);
+      console.log('✅ Creator allowlist page created:', creatorAllowlist);
+    } catch (error: any) {
+      if (error.context?.logs?.some((log: string) => log.includes('already in use'))) {
+        console.log('✅ Creator allowlist page already created (expected on subsequent runs)');
+      } else {
+        throw error;
+      }
+    }
+    await setCreationMode(SmartAccountCreationMode.Allowlist);
+    console.log('✅ Creation mode set to allowlist');
+
+    // Step 2: Only allowlisted creators can create a smart account
+    console.log('');
+    console.log('🚫 Step 2: Creating a smart account before being allowlisted...');
+    await expectRejected('Creation by a creator off the allowlist', () =>
+      createSmartAccount(creator, { creatorAllowlist })
+    );
+
+    // Step 3: Allowlisted creators can, until they are removed
+    console.log('');
+    console.log('🏗️  Step 3: Allowlisting the creator and creating a smart account...');
+    await sendInstructions([
+      await getAddCreatorToAllowlistInstructionAsync({
+        creatorAllowlist,
+        authority: authoritySigner,
+        creator: creator.address,
+      }),
+    ]);
+    const allowlistedSettings = await createSmartAccount(creator, { creatorAllowlist });
+    console.log('✅ Smart account created by the allowlisted creator:', allowlistedSettings);
+
+    await sendInstructions([
+      await getRemoveCreatorFromAllowlistInstructionAsync({
+        creatorAllowlist,
+        authority: authoritySigner,
+        creator: creator.address,
+      }),
+    ]);
+    await expectRejected('Creation by a creator removed from the allowlist', () =>
+      createSmartAccount(creator, { creatorAllowlist })
+    );
+
+    // Step 4: Cosigned creations need the program config authority
+    console.log('');
+    console.log('✍️  Step 4: Switching to the cosigned creation mode...');
+    await setCreationMode(SmartAccountCreationMode.Cosigned);
+    await expectRejected('Creation without the program config authority', () => createSmartAccount(creator, {}));
+    const cosignedSettings = await createSmartAccount(creator, { programConfigAuthority: authoritySigner });
+    console.log('✅ Smart account created with the program config authority:', cosignedSettings);
+  } catch (error) {
+    console.error('❌ creator allowlist failed:', error);
+    throw error; // Re-throw to properly fail the test
+  } finally {
+    // Reopen creation for the other tests
+    await setCreationMode(SmartAccountCreationMode.Open);
+  }
+
+}
+
+testCreatorAllowlist();
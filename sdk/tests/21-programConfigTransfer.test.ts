@@ -0,0 +1,114 @@
+import {
+  generateKeyPairSigner,
+} from '@solana/kit';
+import {
+  getAcceptProgramConfigAuthorityInstructionAsync,
+  getCancelProgramConfigTransferInstructionAsync,
+  getProposeProgramConfigAuthorityInstructionAsync,
+  getProposeProgramConfigTreasuryInstructionAsync,
+} from '../clients/js/src/generated/instructions';
+import { fetchProgramConfig } from '../clients/js/src/generated/accounts/programConfig';
+import {
+  deriveProgramConfigPda,
+  expectRejected,
+  loadProgramConfigAuthority,
+  setupTestContext,
+} from './utils';
+
+async function testProgramConfigTransfer() {
+  console.log('Testing the two-step transfer of the program config authority and treasury...');
+  console.log('This test proposes, cancels and accepts program config transfers, then hands the authority back.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, backendFeePayerSigner, sendInstructions } = context;
+
+  // The program config authority set by 00-setup.test.ts
+  const authoritySigner = await loadProgramConfigAuthority();
+
+  const programConfigPda = await deriveProgramConfigPda();
+  const fetchAuthority = async () => (await fetchProgramConfig(rpc, programConfigPda)).data.authority;
+
+  const newAuthority = await generateKeyPairSigner();
+
+  try {
+    // Step 1: A proposed treasury doesn't take effect until accepted, and the authority can cancel it
+    console.log('');
+    console.log('🏦 Step 1: Proposing and cancelling a treasury transfer...');
+    const { treasury } = (await fetchProgramConfig(rpc, programConfigPda)).data;
+    const newTreasury = await generateKeyPairSigner();
+    await sendInstructions([
+      await getProposeProgramConfigTreasuryInstructionAsync({
+        authority: authoritySigner,
+        rentPayer: backendFeePayerSigner,
+        newTreasury: newTreasury.address,
+      }),
+    ]);
+    if ((await fetchProgramConfig(rpc, programConfigPda)).data.treasury !== treasury) {
+      throw new Error('❌ Expected the treasury to be unchanged until the transfer is accepted');
+    }
+    await sendInstructions([
+      await getCancelProgramConfigTransferInstructionAsync({
+        signer: authoritySigner,
+        rentCollector: backendFeePayerSigner.address,
+      }),
+    ]);
+    console.log('✅ Treasury transfer cancelled by the authority');
+
+    // Step 2: Only the proposed authority can accept the transfer
+    console.log('');
+    console.log('🔑 Step 2: Proposing a new authority...');
+    await sendInstructions([
+      await getProposeProgramConfigAuthorityInstructionAsync({
+        authority: authoritySigner,
+        rentPayer: backendFeePayerSigner,
+        newAuthority: newAuthority.address,
+      }),
+    ]);
+    await expectRejected('Accepting the transfer as another key', async () =>
+      sendInstructions([
+        await getAcceptProgramConfigAuthorityInstructionAsync({
+          newKey: backendFeePayerSigner,
+          rentCollector: backendFeePayerSigner.address,
+        }),
+      ])
+    );
+
+    // Step 3: The proposed authority accepts the transfer
+    console.log('');
+    console.log('🤝 Step 3: Accepting the transfer as the new authority...');
+    await sendInstructions([
+      await getAcceptProgramConfigAuthorityInstructionAsync({
+        newKey: newAuthority,
+        rentCollector: backendFeePayerSigner.address,
+      }),
+    ]);
+    if ((await fetchAuthority()) !== newAuthority.address) {
+      throw new Error('❌ Expected the new authority to be set');
+    }
+    console.log('✅ Authority transferred to', newAuthority.address);
+  } catch (error) {
+    console.error('❌ program config transfer failed:', error);
+    throw error; // Re-throw to properly fail the test
+  } finally {
+    // Hand the authority back so the other tests can manage the program config
+    if ((await fetchAuthority()) === newAuthority.address) {
+      await sendInstructions([
+        await getProposeProgramConfigAuthorityInstructionAsync({
+          authority: newAuthority,
+          rentPayer: backendFeePayerSigner,
+          newAuthority: authoritySigner.address,
+        }),
+      ]);
+      await sendInstructions([
+        await getAcceptProgramConfigAuthorityInstructionAsync({
+          newKey: authoritySigner,
+          rentCollector: backendFeePayerSigner.address,
+        }),
+      ]);
+    }
+  }
+
+}
+
+testProgramConfigTransfer();
@@ -0,0 +1,190 @@
+import {
+  createNoopSigner,
+  generateKeyPairSigner,
+  lamports,
+} from '@solana/kit';
+import { getSetComputeUnitLimitInstruction } from '@solana-program/compute-budget';
+import { getAddMemoInstruction } from '@solana-program/memo';
+import { getTransferSolInstruction } from '@solana-program/system';
+import {
+  getApproveProposalInstruction,
+  getCreateProposalInstruction,
+  getCreateTransactionBufferInstruction,
+  getCreateTransactionFromBufferInstruction,
+  getExecuteTransactionInstruction,
+  getExtendTransactionBufferInstruction,
+} from '../clients/js/src/generated/instructions';
+import { fetchProposal } from '../clients/js/src/generated/accounts/proposal';
+import { fetchTransaction } from '../clients/js/src/generated/accounts/transaction';
+import { deriveBufferPda, deriveProposalPda, deriveTransactionPda } from '../utils';
+import {
+  compileSmartAccountMessage,
+  createTestSmartAccount,
+  deriveSmartAccountPda,
+  setupTestContext,
+  withRemainingAccounts,
+} from './utils';
+
+// How much of the message is written to the buffer per instruction, to stay within the transaction size limit.
+const CHUNK_SIZE = 800;
+
+async function testLargeBufferedTransaction() {
+  console.log('Testing transactions from buffers larger than 4000 bytes...');
+  console.log('This test writes a transaction message over several buffer extensions, then creates and executes it.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  try {
+    // Step 1: Create an autonomous smart account and fund its vault
+    console.log('');
+    console.log('🏗️  Step 1: Creating an autonomous smart account...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers: [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }],
+    });
+    const [smartAccountPda, smartAccountPdaBump] = await deriveSmartAccountPda(result.settingsAddress, 0);
+    await sendInstructions([
+      getTransferSolInstruction({
+        source: backendFeePayerSigner,
+        destination: smartAccountPda,
+        amount: lamports(100_000_000n),
+      }),
+    ]);
+    console.log('✅ Smart account created:', result.settingsAddress);
+
+    // Step 2: Compile a message with a transfer and enough memos to go over 4000 bytes
+    console.log('');
+    console.log('📝 Step 2: Compiling a transaction message larger than 4000 bytes...');
+    const recipient = await generateKeyPairSigner();
+    const transferAmount = 10_000_000n;
+    const { transactionMessage, remainingAccounts } = await compileSmartAccountMessage(rpc, smartAccountPda, [
+      getTransferSolInstruction({
+        source: createNoopSigner(smartAccountPda),
+        destination: recipient.address,
+        amount: lamports(transferAmount),
+      }),
+      ...Array.from({ length: 8 }, (_, i) => getAddMemoInstruction({ memo: `${i}`.padEnd(550, '.') })),
+    ]);
+    if (transactionMessage.length <= 4000) {
+      throw new Error(`❌ Expected a message larger than 4000 bytes, but got ${transactionMessage.length}`);
+    }
+    console.log('✅ Transaction message compiled:', transactionMessage.length, 'bytes');
+
+    // Step 3: Write the message to a transaction buffer, one chunk per instruction
+    console.log('');
+    console.log('📦 Step 3: Writing the message to a transaction buffer...');
+    const finalBufferHash = new Uint8Array(
+      await crypto.subtle.digest('SHA-256', transactionMessage as unknown as ArrayBuffer)
+    );
+    const bufferIndex = 0;
+    const transactionBufferPda = await deriveBufferPda(result.settingsAddress, creatorSigner.address, bufferIndex);
+    await sendInstructions([
+      getCreateTransactionBufferInstruction({
+        settings: result.settingsAddress,
+        transactionBuffer: transactionBufferPda,
+        bufferCreator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        bufferIndex,
+        accountIndex: 0,
+        finalBufferHash,
+        finalBufferSize: transactionMessage.length,
+        buffer: transactionMessage.slice(0, CHUNK_SIZE),
+      }),
+    ]);
+    for (let offset = CHUNK_SIZE; offset < transactionMessage.length; offset += CHUNK_SIZE) {
+      await sendInstructions([
+        getExtendTransactionBufferInstruction({
+          settings: result.settingsAddress,
+          transactionBuffer: transactionBufferPda,
+          creator: creatorSigner,
+          buffer: transactionMessage.slice(offset, offset + CHUNK_SIZE),
+        }),
+      ]);
+    }
+    console.log('✅ Transaction buffer written:', transactionBufferPda);
+
+    // Step 4: Create the transaction from the buffer and its proposal
+    console.log('');
+    console.log('🛠️  Step 4: Creating the transaction from the buffer...');
+    const transactionIndex = 1n;
+    const transactionPda = await deriveTransactionPda(result.settingsAddress, transactionIndex);
+    const proposalPda = await deriveProposalPda(result.settingsAddress, transactionIndex);
+    await sendInstructions([
+      getCreateTransactionFromBufferInstruction({
+        settings: result.settingsAddress,
+        transaction: transactionPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        transactionBuffer: transactionBufferPda,
+        fromBufferCreator: creatorSigner,
+        args: {
+          accountIndex: 0,
+          accountBump: smartAccountPdaBump,
+          ephemeralSigners: 0,
+          transactionMessage: new Uint8Array(),
+          memo: null,
+        },
+      }),
+      getCreateProposalInstruction({
+        settings: result.settingsAddress,
+        proposal: proposalPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        transactionIndex,
+        draft: false,
+        votingDeadline: null,
+      }),
+    ]);
+    const transaction = await fetchTransaction(rpc, transactionPda);
+    if (transaction.data.message.instructions.length !== 9) {
+      throw new Error(`❌ Expected 9 instructions, but got ${transaction.data.message.instructions.length}`);
+    }
+    const { value: bufferAccount } = await rpc.getAccountInfo(transactionBufferPda, { commitment: 'confirmed' }).send();
+    if (bufferAccount !== null) {
+      throw new Error('❌ Expected the transaction buffer to be closed');
+    }
+    console.log('✅ Transaction created and transaction buffer closed:', transactionPda);
+
+    // Step 5: Approve and execute the transaction
+    console.log('');
+    console.log('🚀 Step 5: Approving and executing the transaction...');
+    await sendInstructions([
+      getApproveProposalInstruction({
+        settings: result.settingsAddress,
+        signer: creatorSigner,
+        proposal: proposalPda,
+        args: { memo: null },
+      }),
+    ]);
+    await sendInstructions([
+      getSetComputeUnitLimitInstruction({ units: 1_400_000 }),
+      withRemainingAccounts(
+        getExecuteTransactionInstruction({
+          settings: result.settingsAddress,
+          proposal: proposalPda,
+          transaction: transactionPda,
+          signer: creatorSigner,
+          feePayer: backendFeePayerSigner,
+        }),
+        remainingAccounts
+      ),
+    ]);
+    const proposal = await fetchProposal(rpc, proposalPda);
+    if (proposal.data.status.__kind !== 'Executed') {
+      throw new Error(`❌ Expected the proposal to be executed, got ${proposal.data.status.__kind}`);
+    }
+    const { value: recipientBalance } = await rpc.getBalance(recipient.address, { commitment: 'confirmed' }).send();
+    if (recipientBalance !== transferAmount) {
+      throw new Error(`❌ Expected the recipient to get ${transferAmount} lamports, got ${recipientBalance}`);
+    }
+    console.log('✅ Transaction from a large buffer executed');
+  } catch (error) {
+    console.error('❌ large buffered transaction failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testLargeBufferedTransaction();
@@ -0,0 +1,189 @@
+import {
+  createNoopSigner,
+  generateKeyPairSigner,
+  lamports,
+} from '@solana/kit';
+import { getTransferSolInstruction } from '@solana-program/system';
+import {
+  getActivateProposalInstruction,
+  getAddTransactionToBatchFromBufferInstruction,
+  getApproveProposalInstruction,
+  getCreateBatchInstruction,
+  getCreateProposalInstruction,
+  getCreateTransactionBufferInstruction,
+  getExecuteBatchTransactionInstruction,
+} from '../clients/js/src/generated/instructions';
+import { fetchProposal } from '../clients/js/src/generated/accounts/proposal';
+import { fetchBatchTransaction } from '../clients/js/src/generated/accounts/batchTransaction';
+import { deriveBatchTransactionPda, deriveBufferPda, deriveProposalPda, deriveTransactionPda } from '../utils';
+import {
+  compileSmartAccountMessage,
+  createTestAddressLookupTable,
+  createTestSmartAccount,
+  deriveSmartAccountPda,
+  setupTestContext,
+  withRemainingAccounts,
+} from './utils';
+
+async function testBatchLookupTable() {
+  console.log('Testing batch transactions with address lookup tables...');
+  console.log('This test adds a transaction loading its recipient from an address lookup table to a batch from a buffer, and executes it.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  try {
+    // Step 1: Create an autonomous smart account and fund its vault
+    console.log('');
+    console.log('🏗️  Step 1: Creating an autonomous smart account...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers: [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }],
+    });
+    const [smartAccountPda] = await deriveSmartAccountPda(result.settingsAddress, 0);
+    await sendInstructions([
+      getTransferSolInstruction({
+        source: backendFeePayerSigner,
+        destination: smartAccountPda,
+        amount: lamports(100_000_000n),
+      }),
+    ]);
+    console.log('✅ Smart account created:', result.settingsAddress);
+
+    // Step 2: Put the recipient in an address lookup table and compile a transfer to it
+    console.log('');
+    console.log('📇 Step 2: Creating an address lookup table for the recipient...');
+    const recipient = await generateKeyPairSigner();
+    const transferAmount = 10_000_000n;
+    const lookupTable = await createTestAddressLookupTable(context, [recipient.address]);
+    const { transactionMessage, remainingAccounts } = await compileSmartAccountMessage(
+      rpc,
+      smartAccountPda,
+      [
+        getTransferSolInstruction({
+          source: createNoopSigner(smartAccountPda),
+          destination: recipient.address,
+          amount: lamports(transferAmount),
+        }),
+      ],
+      { [lookupTable]: [recipient.address] }
+    );
+    console.log('✅ Address lookup table created:', lookupTable);
+
+    // Step 3: Write the message to a transaction buffer
+    console.log('');
+    console.log('📦 Step 3: Writing the message to a transaction buffer...');
+    const finalBufferHash = new Uint8Array(
+      await crypto.subtle.digest('SHA-256', transactionMessage as unknown as ArrayBuffer)
+    );
+    const bufferIndex = 0;
+    const transactionBufferPda = await deriveBufferPda(result.settingsAddress, creatorSigner.address, bufferIndex);
+    await sendInstructions([
+      getCreateTransactionBufferInstruction({
+        settings: result.settingsAddress,
+        transactionBuffer: transactionBufferPda,
+        bufferCreator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        bufferIndex,
+        accountIndex: 0,
+        finalBufferHash,
+        finalBufferSize: transactionMessage.length,
+        buffer: transactionMessage,
+      }),
+    ]);
+    console.log('✅ Transaction buffer created:', transactionBufferPda);
+
+    // Step 4: Create a batch with a draft proposal, add the buffered transaction and activate the proposal
+    console.log('');
+    console.log('🧺 Step 4: Adding the buffered transaction to a batch...');
+    const batchIndex = 1n;
+    const batchPda = await deriveTransactionPda(result.settingsAddress, batchIndex);
+    const proposalPda = await deriveProposalPda(result.settingsAddress, batchIndex);
+    const batchTransactionPda = await deriveBatchTransactionPda(result.settingsAddress, batchIndex, 1);
+    await sendInstructions([
+      getCreateBatchInstruction({
+        settings: result.settingsAddress,
+        batch: batchPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        accountIndex: 0,
+        memo: null,
+      }),
+      getCreateProposalInstruction({
+        settings: result.settingsAddress,
+        proposal: proposalPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        transactionIndex: batchIndex,
+        draft: true,
+        votingDeadline: null,
+      }),
+    ]);
+    await sendInstructions([
+      getAddTransactionToBatchFromBufferInstruction({
+        settings: result.settingsAddress,
+        proposal: proposalPda,
+        batch: batchPda,
+        transaction: batchTransactionPda,
+        signer: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        transactionBuffer: transactionBufferPda,
+        fromBufferCreator: creatorSigner,
+        args: { ephemeralSigners: 0, transactionMessage: new Uint8Array() },
+      }),
+      getActivateProposalInstruction({
+        settings: result.settingsAddress,
+        signer: creatorSigner,
+        proposal: proposalPda,
+      }),
+    ]);
+    const batchTransaction = await fetchBatchTransaction(rpc, batchTransactionPda);
+    if (batchTransaction.data.message.addressTableLookups.length !== 1) {
+      throw new Error(
+        `❌ Expected 1 address table lookup, but got ${batchTransaction.data.message.addressTableLookups.length}`
+      );
+    }
+    console.log('✅ Batch transaction added:', batchTransactionPda);
+
+    // Step 5: Approve the batch and execute its transaction
+    console.log('');
+    console.log('🚀 Step 5: Approving the batch and executing its transaction...');
+    await sendInstructions([
+      getApproveProposalInstruction({
+        settings: result.settingsAddress,
+        signer: creatorSigner,
+        proposal: proposalPda,
+        args: { memo: null },
+      }),
+    ]);
+    await sendInstructions([
+      withRemainingAccounts(
+        getExecuteBatchTransactionInstruction({
+          settings: result.settingsAddress,
+          signer: creatorSigner,
+          feePayer: backendFeePayerSigner,
+          proposal: proposalPda,
+          batch: batchPda,
+          transaction: batchTransactionPda,
+        }),
+        remainingAccounts
+      ),
+    ]);
+    const proposal = await fetchProposal(rpc, proposalPda);
+    if (proposal.data.status.__kind !== 'Executed') {
+      throw new Error(`❌ Expected the proposal to be executed, got ${proposal.data.status.__kind}`);
+    }
+    const { value: recipientBalance } = await rpc.getBalance(recipient.address, { commitment: 'confirmed' }).send();
+    if (recipientBalance !== transferAmount) {
+      throw new Error(`❌ Expected the recipient to get ${transferAmount} lamports, got ${recipientBalance}`);
+    }
+    console.log('✅ Batch transaction loading accounts from an address lookup table executed');
+  } catch (error) {
+    console.error('❌ batch with address lookup table failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testBatchLookupTable();
@@ -0,0 +1,266 @@
+import {
+  address,
+  generateKeyPairSigner,
+  lamports,
+  AccountRole,
+  type AccountSignerMeta,
+  type Address,
+  type KeyPairSigner,
+} from '@solana/kit';
+import { getCreateAccountInstruction, getTransferSolInstruction } from '@solana-program/system';
+import {
+  findAssociatedTokenPda,
+  getCreateAssociatedTokenIdempotentInstructionAsync,
+  getInitializeMint2Instruction,
+  getMintSize,
+  TOKEN_PROGRAM_ADDRESS,
+} from '@solana-program/token';
+import {
+  getExecuteSettingsTransactionSyncInstruction,
+  getUseSpendingLimitInstruction,
+} from '../clients/js/src/generated/instructions';
+import { fetchMaybeSpendingLimit, fetchSpendingLimit } from '../clients/js/src/generated/accounts/spendingLimit';
+import { Period, type SettingsActionArgs } from '../clients/js/src/generated/types';
+import { deriveSpendingLimitPda, deriveVaultPolicyPda } from '../utils/index';
+import {
+  createTestSmartAccount,
+  deriveSmartAccountPda,
+  expectRejected,
+  setupTestContext,
+  withRemainingAccounts,
+  type TestContext,
+} from './utils';
+
+const SYSTEM_PROGRAM_ADDRESS = address('11111111111111111111111111111111');
+
+async function testSpendingLimit() {
+  console.log('Testing spending limits...');
+  console.log('This test adds spending limits with a synchronous settings transaction, uses them within and beyond');
+  console.log('their restrictions, checks them against a vault policy and removes them.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  const spender = await generateKeyPairSigner();
+  const rentCollector = await generateKeyPairSigner();
+  const recipient = await generateKeyPairSigner();
+  const otherRecipient = await generateKeyPairSigner();
+
+  try {
+    // Step 1: Create an autonomous smart account with a rent collector and fund its vault
+    console.log('');
+    console.log('🏗️  Step 1: Creating an autonomous smart account with a rent collector...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers: [
+        { key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 },
+        { key: spender.address, permissions: { mask: 7 }, weight: 1 },
+      ],
+      rentCollector: rentCollector.address,
+    });
+    const settings = result.settingsAddress;
+    const [smartAccountPda] = await deriveSmartAccountPda(settings, 0);
+    await sendInstructions([
+      getTransferSolInstruction({
+        source: backendFeePayerSigner,
+        destination: smartAccountPda,
+        amount: lamports(100_000_000n),
+      }),
+    ]);
+    console.log('✅ Smart account created:', settings);
+
+    // Settings changes are made synchronously, signed by the creator.
+    const creatorMeta: AccountSignerMeta = {
+      address: creatorSigner.address,
+      role: AccountRole.READONLY_SIGNER,
+      signer: creatorSigner,
+    };
+    const syncSettings = (actions: SettingsActionArgs[], accounts: Address[]) =>
+      sendInstructions([
+        withRemainingAccounts(
+          getExecuteSettingsTransactionSyncInstruction({
+            settings,
+            feePayer: backendFeePayerSigner,
+            systemProgram: SYSTEM_PROGRAM_ADDRESS,
+            numSigners: 1,
+            actions,
+            memo: null,
+          }),
+          [creatorMeta, ...accounts.map((account) => ({ address: account, role: AccountRole.WRITABLE }))]
+        ),
+      ]);
+
+    // Step 2: Add a daily SOL spending limit to the recipient, and one for a mint the vault doesn't hold
+    console.log('');
+    console.log('➕ Step 2: Adding the spending limits...');
+    const solSeed = (await generateKeyPairSigner()).address;
+    const tokenSeed = (await generateKeyPairSigner()).address;
+    const solSpendingLimit = await deriveSpendingLimitPda(settings, solSeed);
+    const tokenSpendingLimit = await deriveSpendingLimitPda(settings, tokenSeed);
+    const limitedMint = (await generateKeyPairSigner()).address;
+    const addSpendingLimit = (seed: Address, mint: Address): SettingsActionArgs => ({
+      __kind: 'AddSpendingLimit',
+      seed,
+      accountIndex: 0,
+      mint,
+      amount: 30_000_000n,
+      period: Period.Day,
+      signers: [creatorSigner.address, spender.address],
+      destinations: [recipient.address],
+      expiration: 9223372036854775807n, // i64::MAX, never expires
+    });
+    await syncSettings(
+      [addSpendingLimit(solSeed, SYSTEM_PROGRAM_ADDRESS), addSpendingLimit(tokenSeed, limitedMint)],
+      [solSpendingLimit, tokenSpendingLimit]
+    );
+    console.log('✅ Spending limits added:', solSpendingLimit, tokenSpendingLimit);
+
+    // Step 3: Spend part of the SOL spending limit
+    console.log('');
+    console.log('💸 Step 3: Spending 20_000_000 lamports of the 30_000_000 limit...');
+    const useSolSpendingLimit = (signer: KeyPairSigner, destination: Address, amount: bigint, vaultPolicy?: Address) =>
+      sendInstructions([
+        getUseSpendingLimitInstruction({
+          settings,
+          signer,
+          spendingLimit: solSpendingLimit,
+          smartAccount: smartAccountPda,
+          destination,
+          systemProgram: SYSTEM_PROGRAM_ADDRESS,
+          vaultPolicy,
+          amount,
+          decimals: 9,
+          memo: null,
+        }),
+      ]);
+    await useSolSpendingLimit(creatorSigner, recipient.address, 20_000_000n);
+    const spendingLimit = await fetchSpendingLimit(rpc, solSpendingLimit);
+    if (spendingLimit.data.remainingAmount !== 10_000_000n) {
+      throw new Error(`❌ Expected 10_000_000 lamports remaining, got ${spendingLimit.data.remainingAmount}`);
+    }
+    // The period resets on the schedule set at creation, which a test can't wait for,
+    // `SpendingLimit::reset_if_needed` is covered by the program unit tests.
+    if (spendingLimit.data.period !== Period.Day) {
+      throw new Error(`❌ Expected a daily period, got ${spendingLimit.data.period}`);
+    }
+    console.log('✅ Spent, 10_000_000 lamports remaining for the period');
+
+    // Step 4: The spending limit restrictions are enforced
+    console.log('');
+    console.log('🚫 Step 4: Exceeding the amount, sending elsewhere and using another mint...');
+    await expectRejected('Spending more than the remaining amount', () =>
+      useSolSpendingLimit(creatorSigner, recipient.address, 20_000_000n)
+    );
+    await expectRejected('Spending to a destination not allowed by the spending limit', () =>
+      useSolSpendingLimit(creatorSigner, otherRecipient.address, 1_000_000n)
+    );
+    const mint = await createMint(context);
+    const [smartAccountTokenAccount] = await findAssociatedTokenPda({
+      mint,
+      owner: smartAccountPda,
+      tokenProgram: TOKEN_PROGRAM_ADDRESS,
+    });
+    const [recipientTokenAccount] = await findAssociatedTokenPda({
+      mint,
+      owner: recipient.address,
+      tokenProgram: TOKEN_PROGRAM_ADDRESS,
+    });
+    await sendInstructions([
+      await getCreateAssociatedTokenIdempotentInstructionAsync({ payer: backendFeePayerSigner, owner: smartAccountPda, mint }),
+      await getCreateAssociatedTokenIdempotentInstructionAsync({ payer: backendFeePayerSigner, owner: recipient.address, mint }),
+    ]);
+    await expectRejected('Spending a mint other than the one of the spending limit', () =>
+      sendInstructions([
+        getUseSpendingLimitInstruction({
+          settings,
+          signer: creatorSigner,
+          spendingLimit: tokenSpendingLimit,
+          smartAccount: smartAccountPda,
+          destination: recipient.address,
+          mint,
+          smartAccountTokenAccount,
+          destinationTokenAccount: recipientTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ADDRESS,
+          amount: 0n,
+          decimals: 6,
+          memo: null,
+        }),
+      ])
+    );
+
+    // Step 5: A vault policy restricts which signers of the spending limit can use it
+    console.log('');
+    console.log('🔐 Step 5: Setting a vault policy only allowing the creator...');
+    const vaultPolicyPda = await deriveVaultPolicyPda(settings, 0);
+    await syncSettings(
+      [{ __kind: 'SetVaultPolicy', accountIndex: 0, threshold: 1, timeLock: 0, signers: [creatorSigner.address] }],
+      [vaultPolicyPda]
+    );
+    await expectRejected('Using the spending limit without the vault policy', () =>
+      useSolSpendingLimit(creatorSigner, recipient.address, 1_000_000n)
+    );
+    await expectRejected('Using the spending limit as a signer the vault policy does not allow', () =>
+      useSolSpendingLimit(spender, recipient.address, 1_000_000n, vaultPolicyPda)
+    );
+    await useSolSpendingLimit(creatorSigner, recipient.address, 1_000_000n, vaultPolicyPda);
+    const { value: recipientBalance } = await rpc.getBalance(recipient.address, { commitment: 'confirmed' }).send();
+    if (recipientBalance !== 21_000_000n) {
+      throw new Error(`❌ Expected the recipient to get 21_000_000 lamports, got ${recipientBalance}`);
+    }
+    console.log('✅ Only the signers allowed by the vault policy can use the spending limit');
+
+    // Step 6: Remove the spending limits, their rent goes to the rent collector
+    console.log('');
+    console.log('🗑️  Step 6: Removing the spending limits...');
+    await syncSettings(
+      [
+        { __kind: 'RemoveSpendingLimit', spendingLimit: solSpendingLimit },
+        { __kind: 'RemoveSpendingLimit', spendingLimit: tokenSpendingLimit },
+      ],
+      [solSpendingLimit, tokenSpendingLimit, rentCollector.address]
+    );
+    if ((await fetchMaybeSpendingLimit(rpc, solSpendingLimit)).exists) {
+      throw new Error('❌ SOL spending limit still exists after removal');
+    }
+    if ((await fetchMaybeSpendingLimit(rpc, tokenSpendingLimit)).exists) {
+      throw new Error('❌ Token spending limit still exists after removal');
+    }
+    const { value: rentCollectorBalance } = await rpc.getBalance(rentCollector.address, { commitment: 'confirmed' }).send();
+    if (rentCollectorBalance === 0n) {
+      throw new Error('❌ Expected the rent of the spending limits to go to the rent collector');
+    }
+    console.log('✅ Spending limits removed, rent collected:', rentCollectorBalance);
+  } catch (error) {
+    console.error('❌ spending limit failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+/**
+ * Creates a mint with 6 decimals, with the backend fee payer as its authority.
+ */
+async function createMint(context: TestContext): Promise<Address> {
+  const { rpc, backendFeePayerSigner, sendInstructions } = context;
+  const mint = await generateKeyPairSigner();
+  const space = BigInt(getMintSize());
+  const rent = await rpc.getMinimumBalanceForRentExemption(space).send();
+  await sendInstructions([
+    getCreateAccountInstruction({
+      payer: backendFeePayerSigner,
+      newAccount: mint,
+      lamports: rent,
+      space,
+      programAddress: TOKEN_PROGRAM_ADDRESS,
+    }),
+    getInitializeMint2Instruction({
+      mint: mint.address,
+      decimals: 6,
+      mintAuthority: backendFeePayerSigner.address,
+    }),
+  ]);
+  return mint.address;
+}
+
+testSpendingLimit();
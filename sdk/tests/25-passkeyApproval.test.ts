@@ -0,0 +1,245 @@
+import {
+  createNoopSigner,
+  generateKeyPairSigner,
+  lamports,
+  type Instruction,
+} from '@solana/kit';
+import { getSetComputeUnitLimitInstruction } from '@solana-program/compute-budget';
+import { getTransferSolInstruction } from '@solana-program/system';
+import {
+  getApproveProposalInstruction,
+  getApproveProposalWithPasskeyInstruction,
+  getCreatePasskeyInstruction,
+  getCreateProposalInstruction,
+  getCreateTransactionInstruction,
+  getExecuteTransactionInstruction,
+} from '../clients/js/src/generated/instructions';
+import { fetchPasskey } from '../clients/js/src/generated/accounts/passkey';
+import { fetchProposal } from '../clients/js/src/generated/accounts/proposal';
+import { getPasskeyAddress, getPasskeyChallenge, getSecp256r1WebAuthnInstruction } from '../passkey';
+import { deriveProposalPda, deriveTransactionPda } from '../utils';
+import {
+  compileSmartAccountMessage,
+  createTestSmartAccount,
+  deriveSmartAccountPda,
+  expectRejected,
+  setupTestContext,
+  withRemainingAccounts,
+} from './utils';
+
+const RP_ID = 'example.com';
+// Order of the P-256 curve, to normalize signatures to the low `s` the precompile requires.
+const P256_ORDER = 0xffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551n;
+
+async function sha256(data: Uint8Array): Promise<Uint8Array> {
+  return new Uint8Array(await crypto.subtle.digest('SHA-256', data as unknown as ArrayBuffer));
+}
+
+async function testPasskeyApproval() {
+  console.log('Testing passkey approvals...');
+  console.log('This test approves a proposal with a passkey signer, whose WebAuthn assertion is verified by the');
+  console.log('secp256r1 precompile, and checks that assertions for another site, ceremony or nonce are rejected.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  // A software passkey: WebCrypto signs the same `authenticatorData || sha256(clientDataJSON)` message an authenticator does.
+  const keyPair = await crypto.subtle.generateKey({ name: 'ECDSA', namedCurve: 'P-256' }, true, ['sign', 'verify']);
+  const uncompressedPublicKey = new Uint8Array(await crypto.subtle.exportKey('raw', keyPair.publicKey));
+  const publicKey = new Uint8Array(33);
+  publicKey[0] = 0x02 | (uncompressedPublicKey[64] & 1);
+  publicKey.set(uncompressedPublicKey.slice(1, 33), 1);
+  const rpIdHash = await sha256(new TextEncoder().encode(RP_ID));
+
+  /**
+   * Signs a WebAuthn assertion of `challenge` and returns the secp256r1 precompile instruction verifying it.
+   */
+  const signAssertion = async (
+    challenge: Uint8Array,
+    { rpId = RP_ID, type = 'webauthn.get' }: { rpId?: string; type?: string } = {}
+  ): Promise<Instruction> => {
+    const authenticatorData = new Uint8Array(37);
+    authenticatorData.set(await sha256(new TextEncoder().encode(rpId)));
+    authenticatorData[32] = 0x05; // user present and verified
+    const clientDataJSON = new TextEncoder().encode(
+      JSON.stringify({
+        type,
+        challenge: Buffer.from(challenge).toString('base64url'),
+        origin: `https://${rpId}`,
+        crossOrigin: false,
+      })
+    );
+    const message = new Uint8Array([...authenticatorData, ...(await sha256(clientDataJSON))]);
+    const signature = new Uint8Array(
+      await crypto.subtle.sign({ name: 'ECDSA', hash: 'SHA-256' }, keyPair.privateKey, message as unknown as ArrayBuffer)
+    );
+    const s = BigInt(`0x${Buffer.from(signature.slice(32)).toString('hex')}`);
+    if (s > P256_ORDER / 2n) {
+      signature.set(Buffer.from((P256_ORDER - s).toString(16).padStart(64, '0'), 'hex'), 32);
+    }
+    return getSecp256r1WebAuthnInstruction({ publicKey, signature, authenticatorData, clientDataJSON });
+  };
+
+  try {
+    // Step 1: Create the passkey account
+    console.log('');
+    console.log('🔑 Step 1: Creating the passkey account...');
+    const passkeyAddress = await getPasskeyAddress(publicKey, rpIdHash);
+    await sendInstructions([
+      getCreatePasskeyInstruction({
+        passkey: passkeyAddress,
+        feePayer: backendFeePayerSigner,
+        publicKey,
+        rpIdHash,
+      }),
+    ]);
+    console.log('✅ Passkey created:', passkeyAddress);
+
+    // Step 2: Create a smart account with a threshold of 2, that the passkey can vote on
+    console.log('');
+    console.log('🏗️  Step 2: Creating a smart account with the creator and the passkey as signers...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 2,
+      signers: [
+        { key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 },
+        { key: passkeyAddress, permissions: { mask: 2 }, weight: 1 },
+      ],
+    });
+    const settings = result.settingsAddress;
+    const [smartAccountPda, smartAccountPdaBump] = await deriveSmartAccountPda(settings, 0);
+    await sendInstructions([
+      getTransferSolInstruction({
+        source: backendFeePayerSigner,
+        destination: smartAccountPda,
+        amount: lamports(100_000_000n),
+      }),
+    ]);
+    console.log('✅ Smart account created:', settings);
+
+    // Step 3: Propose a transfer from the vault, approved by the creator
+    console.log('');
+    console.log('📝 Step 3: Proposing a transfer from the vault...');
+    const recipient = await generateKeyPairSigner();
+    const transferAmount = 10_000_000n;
+    const { transactionMessage, remainingAccounts } = await compileSmartAccountMessage(rpc, smartAccountPda, [
+      getTransferSolInstruction({
+        source: createNoopSigner(smartAccountPda),
+        destination: recipient.address,
+        amount: lamports(transferAmount),
+      }),
+    ]);
+    const transactionIndex = 1n;
+    const transactionPda = await deriveTransactionPda(settings, transactionIndex);
+    const proposalPda = await deriveProposalPda(settings, transactionIndex);
+    await sendInstructions([
+      getCreateTransactionInstruction({
+        settings,
+        transaction: transactionPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        args: {
+          accountIndex: 0,
+          accountBump: smartAccountPdaBump,
+          ephemeralSigners: 0,
+          transactionMessage,
+          memo: null,
+        },
+      }),
+      getCreateProposalInstruction({
+        settings,
+        proposal: proposalPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        transactionIndex,
+        draft: false,
+        votingDeadline: null,
+      }),
+      getApproveProposalInstruction({
+        settings,
+        signer: creatorSigner,
+        proposal: proposalPda,
+        args: { memo: null },
+      }),
+    ]);
+    console.log('✅ Transaction and proposal created and approved by the creator:', proposalPda);
+
+    // Step 4: Assertions for another site, ceremony or nonce are rejected
+    console.log('');
+    console.log('🚫 Step 4: Approving with invalid assertions...');
+    const approveInstruction = getApproveProposalWithPasskeyInstruction({
+      settings,
+      passkey: passkeyAddress,
+      proposal: proposalPda,
+      args: { memo: null },
+    });
+    const approveWithPasskey = (precompileInstruction: Instruction) =>
+      sendInstructions([precompileInstruction, approveInstruction]);
+    const challenge = await getPasskeyChallenge(passkeyAddress, 0n, approveInstruction);
+    await expectRejected('Approving with an assertion made for another site', async () =>
+      approveWithPasskey(await signAssertion(challenge, { rpId: 'evil.example' }))
+    );
+    await expectRejected('Approving with a registration instead of an assertion', async () =>
+      approveWithPasskey(await signAssertion(challenge, { type: 'webauthn.create' }))
+    );
+    await expectRejected('Approving with an assertion of the challenge for the next nonce', async () =>
+      approveWithPasskey(
+        await signAssertion(await getPasskeyChallenge(passkeyAddress, 1n, approveInstruction))
+      )
+    );
+    let proposal = await fetchProposal(rpc, proposalPda);
+    if (proposal.data.status.__kind !== 'Active') {
+      throw new Error(`❌ Expected the proposal to stay active, got ${proposal.data.status.__kind}`);
+    }
+
+    // Step 5: A valid assertion approves the proposal and consumes the nonce
+    console.log('');
+    console.log('✍️  Step 5: Approving with a valid assertion...');
+    const assertion = await signAssertion(challenge);
+    await approveWithPasskey(assertion);
+    proposal = await fetchProposal(rpc, proposalPda);
+    if (proposal.data.status.__kind !== 'Approved') {
+      throw new Error(`❌ Expected the proposal to be approved, got ${proposal.data.status.__kind}`);
+    }
+    const passkey = await fetchPasskey(rpc, passkeyAddress);
+    if (passkey.data.nonce !== 1n) {
+      throw new Error(`❌ Expected the passkey nonce to be 1, got ${passkey.data.nonce}`);
+    }
+    // Not the same transaction as before, so that it is processed again.
+    await expectRejected('Replaying the assertion', () =>
+      sendInstructions([getSetComputeUnitLimitInstruction({ units: 400_000 }), assertion, approveInstruction])
+    );
+    console.log('✅ Proposal approved by the passkey');
+
+    // Step 6: Execute the transaction
+    console.log('');
+    console.log('🚀 Step 6: Executing the transaction...');
+    await sendInstructions([
+      withRemainingAccounts(
+        getExecuteTransactionInstruction({
+          settings,
+          proposal: proposalPda,
+          transaction: transactionPda,
+          signer: creatorSigner,
+          feePayer: backendFeePayerSigner,
+        }),
+        remainingAccounts
+      ),
+    ]);
+    proposal = await fetchProposal(rpc, proposalPda);
+    if (proposal.data.status.__kind !== 'Executed') {
+      throw new Error(`❌ Expected the proposal to be executed, got ${proposal.data.status.__kind}`);
+    }
+    const { value: recipientBalance } = await rpc.getBalance(recipient.address, { commitment: 'confirmed' }).send();
+    if (recipientBalance !== transferAmount) {
+      throw new Error(`❌ Expected the recipient to get ${transferAmount} lamports, got ${recipientBalance}`);
+    }
+    console.log('✅ Transaction approved by a passkey executed');
+  } catch (error) {
+    console.error('❌ passkey approval failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testPasskeyApproval();
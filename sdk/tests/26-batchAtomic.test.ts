@@ -0,0 +1,186 @@
+import {
+  createNoopSigner,
+  generateKeyPairSigner,
+  lamports,
+  AccountRole,
+  type AccountMeta,
+  type Address,
+} from '@solana/kit';
+import { getTransferSolInstruction } from '@solana-program/system';
+import {
+  getActivateProposalInstruction,
+  getAddTransactionToBatchInstruction,
+  getApproveProposalInstruction,
+  getCreateBatchInstruction,
+  getCreateProposalInstruction,
+  getExecuteBatchAtomicInstruction,
+} from '../clients/js/src/generated/instructions';
+import { fetchBatch } from '../clients/js/src/generated/accounts/batch';
+import { fetchProposal } from '../clients/js/src/generated/accounts/proposal';
+import { deriveBatchTransactionPda, deriveProposalPda, deriveTransactionPda } from '../utils';
+import {
+  compileSmartAccountMessage,
+  createTestSmartAccount,
+  deriveSmartAccountPda,
+  expectRejected,
+  setupTestContext,
+  withRemainingAccounts,
+} from './utils';
+
+async function testBatchAtomic() {
+  console.log('Testing atomic batch execution...');
+  console.log('This test executes a batch of two transfers with executeBatchAtomic. The second transfer fails first,');
+  console.log('which rolls back the first one, and both succeed once the vault can pay for them.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  try {
+    // Step 1: Create an autonomous smart account with a vault that can only pay for the first transfer
+    console.log('');
+    console.log('🏗️  Step 1: Creating an autonomous smart account...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers: [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }],
+    });
+    const settings = result.settingsAddress;
+    const [smartAccountPda] = await deriveSmartAccountPda(settings, 0);
+    const fundVault = (amount: bigint) =>
+      sendInstructions([
+        getTransferSolInstruction({
+          source: backendFeePayerSigner,
+          destination: smartAccountPda,
+          amount: lamports(amount),
+        }),
+      ]);
+    await fundVault(15_000_000n);
+    console.log('✅ Smart account created:', settings);
+
+    // Step 2: Create a batch of two transfers of 10_000_000 lamports and approve it
+    console.log('');
+    console.log('🧺 Step 2: Creating and approving a batch of two transfers...');
+    const transferAmount = 10_000_000n;
+    const recipients = [(await generateKeyPairSigner()).address, (await generateKeyPairSigner()).address];
+    const batchIndex = 1n;
+    const batchPda = await deriveTransactionPda(settings, batchIndex);
+    const proposalPda = await deriveProposalPda(settings, batchIndex);
+    await sendInstructions([
+      getCreateBatchInstruction({
+        settings,
+        batch: batchPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        accountIndex: 0,
+        memo: null,
+      }),
+      getCreateProposalInstruction({
+        settings,
+        proposal: proposalPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        transactionIndex: batchIndex,
+        draft: true,
+        votingDeadline: null,
+      }),
+    ]);
+    // The remaining accounts of `executeBatchAtomic`, grouped per batch transaction.
+    const remainingAccounts: AccountMeta[] = [];
+    for (const [index, recipient] of recipients.entries()) {
+      const batchTransactionPda = await deriveBatchTransactionPda(settings, batchIndex, index + 1);
+      const compiled = await compileSmartAccountMessage(rpc, smartAccountPda, [
+        getTransferSolInstruction({
+          source: createNoopSigner(smartAccountPda),
+          destination: recipient,
+          amount: lamports(transferAmount),
+        }),
+      ]);
+      await sendInstructions([
+        getAddTransactionToBatchInstruction({
+          settings,
+          proposal: proposalPda,
+          batch: batchPda,
+          transaction: batchTransactionPda,
+          signer: creatorSigner,
+          feePayer: backendFeePayerSigner,
+          args: { ephemeralSigners: 0, transactionMessage: compiled.transactionMessage },
+        }),
+      ]);
+      remainingAccounts.push({ address: batchTransactionPda, role: AccountRole.READONLY }, ...compiled.remainingAccounts);
+    }
+    await sendInstructions([
+      getActivateProposalInstruction({
+        settings,
+        signer: creatorSigner,
+        proposal: proposalPda,
+      }),
+      getApproveProposalInstruction({
+        settings,
+        signer: creatorSigner,
+        proposal: proposalPda,
+        args: { memo: null },
+      }),
+    ]);
+    console.log('✅ Batch approved:', batchPda);
+
+    const executeBatchAtomic = () =>
+      sendInstructions([
+        withRemainingAccounts(
+          getExecuteBatchAtomicInstruction({
+            settings,
+            signer: creatorSigner,
+            feePayer: backendFeePayerSigner,
+            proposal: proposalPda,
+            batch: batchPda,
+          }),
+          remainingAccounts
+        ),
+      ]);
+    const getBalance = async (account: Address) =>
+      (await rpc.getBalance(account, { commitment: 'confirmed' }).send()).value;
+
+    // Step 3: The second transfer fails, which rolls back the first one
+    console.log('');
+    console.log('🚫 Step 3: Executing the batch while the vault can only pay for the first transfer...');
+    await expectRejected('Executing a batch whose second transaction fails', executeBatchAtomic);
+    if ((await getBalance(recipients[0])) !== 0n) {
+      throw new Error('❌ Expected the first transfer to be rolled back');
+    }
+    let batch = await fetchBatch(rpc, batchPda);
+    if (batch.data.executedTransactionIndex !== 0) {
+      throw new Error(`❌ Expected no executed batch transaction, got ${batch.data.executedTransactionIndex}`);
+    }
+    let proposal = await fetchProposal(rpc, proposalPda);
+    if (proposal.data.status.__kind !== 'Approved') {
+      throw new Error(`❌ Expected the proposal to stay approved, got ${proposal.data.status.__kind}`);
+    }
+    console.log('✅ Nothing was executed, the proposal is still approved');
+
+    // Step 4: Both transfers succeed once the vault can pay for them
+    console.log('');
+    console.log('🚀 Step 4: Funding the vault and executing the batch again...');
+    await fundVault(transferAmount);
+    await executeBatchAtomic();
+    for (const recipient of recipients) {
+      const balance = await getBalance(recipient);
+      if (balance !== transferAmount) {
+        throw new Error(`❌ Expected ${recipient} to get ${transferAmount} lamports, got ${balance}`);
+      }
+    }
+    batch = await fetchBatch(rpc, batchPda);
+    if (batch.data.executedTransactionIndex !== 2) {
+      throw new Error(`❌ Expected both batch transactions to be executed, got ${batch.data.executedTransactionIndex}`);
+    }
+    proposal = await fetchProposal(rpc, proposalPda);
+    if (proposal.data.status.__kind !== 'Executed') {
+      throw new Error(`❌ Expected the proposal to be executed, got ${proposal.data.status.__kind}`);
+    }
+    console.log('✅ Batch executed atomically');
+  } catch (error) {
+    console.error('❌ atomic batch execution failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testBatchAtomic();
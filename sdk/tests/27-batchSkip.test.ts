@@ -0,0 +1,212 @@
+import {
+  createNoopSigner,
+  generateKeyPairSigner,
+  lamports,
+  AccountRole,
+  type AccountSignerMeta,
+  type Address,
+} from '@solana/kit';
+import { getTransferSolInstruction } from '@solana-program/system';
+import {
+  getActivateProposalInstruction,
+  getAddTransactionToBatchInstruction,
+  getApproveProposalInstruction,
+  getCreateBatchInstruction,
+  getCreateProposalInstruction,
+  getExecuteBatchTransactionInstruction,
+  getSkipBatchTransactionInstruction,
+} from '../clients/js/src/generated/instructions';
+import { fetchBatch } from '../clients/js/src/generated/accounts/batch';
+import { fetchProposal } from '../clients/js/src/generated/accounts/proposal';
+import { deriveBatchTransactionPda, deriveProposalPda, deriveTransactionPda } from '../utils';
+import {
+  compileSmartAccountMessage,
+  createTestSmartAccount,
+  deriveSmartAccountPda,
+  expectRejected,
+  setupTestContext,
+  withRemainingAccounts,
+  type CompiledSmartAccountMessage,
+} from './utils';
+
+async function testBatchSkip() {
+  console.log('Testing skipping batch transactions...');
+  console.log('This test skips the transactions of a batch the vault cannot pay for with skipBatchTransaction,');
+  console.log('executes the one in between, and checks the batch records the skipped ones.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  try {
+    // Step 1: Create an autonomous smart account and fund its vault
+    console.log('');
+    console.log('🏗️  Step 1: Creating an autonomous smart account...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers: [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }],
+    });
+    const settings = result.settingsAddress;
+    const [smartAccountPda] = await deriveSmartAccountPda(settings, 0);
+    await sendInstructions([
+      getTransferSolInstruction({
+        source: backendFeePayerSigner,
+        destination: smartAccountPda,
+        amount: lamports(100_000_000n),
+      }),
+    ]);
+    console.log('✅ Smart account created:', settings);
+
+    // Step 2: Create a batch where only the second transfer can be paid for by the vault, and approve it
+    console.log('');
+    console.log('🧺 Step 2: Creating and approving a batch of three transfers...');
+    const amounts = [1_000_000_000n, 10_000_000n, 1_000_000_000n];
+    const recipients = await Promise.all(amounts.map(async () => (await generateKeyPairSigner()).address));
+    const batchIndex = 1n;
+    const batchPda = await deriveTransactionPda(settings, batchIndex);
+    const proposalPda = await deriveProposalPda(settings, batchIndex);
+    await sendInstructions([
+      getCreateBatchInstruction({
+        settings,
+        batch: batchPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        accountIndex: 0,
+        memo: null,
+      }),
+      getCreateProposalInstruction({
+        settings,
+        proposal: proposalPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        transactionIndex: batchIndex,
+        draft: true,
+        votingDeadline: null,
+      }),
+    ]);
+    const batchTransactions: { address: Address; compiled: CompiledSmartAccountMessage }[] = [];
+    for (const [index, amount] of amounts.entries()) {
+      const batchTransactionPda = await deriveBatchTransactionPda(settings, batchIndex, index + 1);
+      const compiled = await compileSmartAccountMessage(rpc, smartAccountPda, [
+        getTransferSolInstruction({
+          source: createNoopSigner(smartAccountPda),
+          destination: recipients[index],
+          amount: lamports(amount),
+        }),
+      ]);
+      await sendInstructions([
+        getAddTransactionToBatchInstruction({
+          settings,
+          proposal: proposalPda,
+          batch: batchPda,
+          transaction: batchTransactionPda,
+          signer: creatorSigner,
+          feePayer: backendFeePayerSigner,
+          args: { ephemeralSigners: 0, transactionMessage: compiled.transactionMessage },
+        }),
+      ]);
+      batchTransactions.push({ address: batchTransactionPda, compiled });
+    }
+    await sendInstructions([
+      getActivateProposalInstruction({
+        settings,
+        signer: creatorSigner,
+        proposal: proposalPda,
+      }),
+      getApproveProposalInstruction({
+        settings,
+        signer: creatorSigner,
+        proposal: proposalPda,
+        args: { memo: null },
+      }),
+    ]);
+    console.log('✅ Batch approved:', batchPda);
+
+    const executeBatchTransaction = (index: number) =>
+      sendInstructions([
+        withRemainingAccounts(
+          getExecuteBatchTransactionInstruction({
+            settings,
+            signer: creatorSigner,
+            feePayer: backendFeePayerSigner,
+            proposal: proposalPda,
+            batch: batchPda,
+            transaction: batchTransactions[index].address,
+          }),
+          batchTransactions[index].compiled.remainingAccounts
+        ),
+      ]);
+    // The creator reaches the threshold on their own.
+    const creatorMeta: AccountSignerMeta = {
+      address: creatorSigner.address,
+      role: AccountRole.READONLY_SIGNER,
+      signer: creatorSigner,
+    };
+    const skipBatchTransaction = (memo: string | null = null) =>
+      sendInstructions([
+        withRemainingAccounts(
+          getSkipBatchTransactionInstruction({
+            settings,
+            proposal: proposalPda,
+            batch: batchPda,
+            feePayer: backendFeePayerSigner,
+            numSigners: 1,
+            memo,
+          }),
+          [creatorMeta]
+        ),
+      ]);
+    const expectBatch = async (executedTransactionIndex: number, skippedTransactionIndexes: number[], status: string) => {
+      const batch = await fetchBatch(rpc, batchPda);
+      if (batch.data.executedTransactionIndex !== executedTransactionIndex) {
+        throw new Error(
+          `❌ Expected the executed transaction index to be ${executedTransactionIndex}, got ${batch.data.executedTransactionIndex}`
+        );
+      }
+      if (batch.data.skippedTransactionIndexes.join() !== skippedTransactionIndexes.join()) {
+        throw new Error(
+          `❌ Expected the skipped transactions to be [${skippedTransactionIndexes}], got [${batch.data.skippedTransactionIndexes}]`
+        );
+      }
+      const proposal = await fetchProposal(rpc, proposalPda);
+      if (proposal.data.status.__kind !== status) {
+        throw new Error(`❌ Expected the proposal to be ${status}, got ${proposal.data.status.__kind}`);
+      }
+    };
+
+    // Step 3: The first transfer can't be executed, so it's skipped
+    console.log('');
+    console.log('⏭️  Step 3: Skipping the first transfer...');
+    await expectRejected('Executing a transfer the vault cannot pay for', () => executeBatchTransaction(0));
+    await skipBatchTransaction();
+    await expectBatch(1, [1], 'Approved');
+    await expectRejected('Executing the skipped transaction', () => executeBatchTransaction(0));
+    console.log('✅ First transfer skipped, the proposal is still approved');
+
+    // Step 4: The transfer after the skipped one can be executed
+    console.log('');
+    console.log('🚀 Step 4: Executing the second transfer...');
+    await executeBatchTransaction(1);
+    await expectBatch(2, [1], 'Approved');
+    const { value: recipientBalance } = await rpc.getBalance(recipients[1], { commitment: 'confirmed' }).send();
+    if (recipientBalance !== amounts[1]) {
+      throw new Error(`❌ Expected the recipient to get ${amounts[1]} lamports, got ${recipientBalance}`);
+    }
+    console.log('✅ Second transfer executed');
+
+    // Step 5: Skipping the last transfer completes the batch
+    console.log('');
+    console.log('⏭️  Step 5: Skipping the last transfer...');
+    await skipBatchTransaction();
+    await expectBatch(3, [1, 3], 'Executed');
+    // Not the same transaction as before, so that it is processed again.
+    await expectRejected('Skipping a transaction of a completed batch', () => skipBatchTransaction('retry'));
+    console.log('✅ Last transfer skipped, the proposal is executed');
+  } catch (error) {
+    console.error('❌ batch transaction skip failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testBatchSkip();
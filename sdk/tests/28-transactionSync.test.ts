@@ -0,0 +1,136 @@
+import {
+  address,
+  createNoopSigner,
+  generateKeyPairSigner,
+  lamports,
+  AccountRole,
+  type AccountMeta,
+  type AccountSignerMeta,
+  type Address,
+  type KeyPairSigner,
+} from '@solana/kit';
+import { getTransferSolInstruction } from '@solana-program/system';
+import { getExecuteTransactionSyncInstruction } from '../clients/js/src/generated/instructions';
+import { getSmartAccountCompiledInstructionEncoder } from '../clients/js/src/generated/types';
+import {
+  createTestSmartAccount,
+  deriveSmartAccountPda,
+  expectRejected,
+  setupTestContext,
+  withRemainingAccounts,
+} from './utils';
+
+const SYSTEM_PROGRAM_ADDRESS = address('11111111111111111111111111111111');
+
+async function testTransactionSync() {
+  console.log('Testing synchronous transactions...');
+  console.log('This test executes a transfer from the vault with executeTransactionSync, signed by weighted signers,');
+  console.log('and checks that the instruction accounts are indexed after the signers and that the threshold is enforced.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  // A second signer that can only vote, with a lower weight than the creator.
+  const voter = await generateKeyPairSigner();
+  const recipient = await generateKeyPairSigner();
+
+  try {
+    // Step 1: Create an autonomous smart account that needs both signers to reach its threshold
+    console.log('');
+    console.log('🏗️  Step 1: Creating an autonomous smart account with a threshold of 3...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 3,
+      signers: [
+        { key: creatorSigner.address, permissions: { mask: 7 }, weight: 2 },
+        { key: voter.address, permissions: { mask: 2 }, weight: 1 },
+      ],
+    });
+    const settings = result.settingsAddress;
+    const [smartAccountPda] = await deriveSmartAccountPda(settings, 0);
+    await sendInstructions([
+      getTransferSolInstruction({
+        source: backendFeePayerSigner,
+        destination: smartAccountPda,
+        amount: lamports(100_000_000n),
+      }),
+    ]);
+    console.log('✅ Smart account created:', settings);
+
+    // The instruction accounts follow the signers, and the compiled instruction indexes into them only.
+    // Indexes 0 and 1 are also those of the signers, so a transfer between them would run if they weren't kept apart.
+    const transferAmount = 10_000_000n;
+    const instructionAccounts: AccountMeta[] = [
+      { address: smartAccountPda, role: AccountRole.WRITABLE },
+      { address: recipient.address, role: AccountRole.WRITABLE },
+      { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+    ];
+    const transfer = getTransferSolInstruction({
+      source: createNoopSigner(smartAccountPda),
+      destination: recipient.address,
+      amount: lamports(transferAmount),
+    });
+    const instructions = new Uint8Array([
+      1, // number of instructions, as a u8
+      ...getSmartAccountCompiledInstructionEncoder().encode({
+        programIdIndex: 2,
+        accountIndexes: new Uint8Array([0, 1]),
+        data: transfer.data,
+      }),
+    ]);
+    const signerMeta = (signer: KeyPairSigner): AccountSignerMeta => ({
+      address: signer.address,
+      role: AccountRole.READONLY_SIGNER,
+      signer,
+    });
+    const executeTransactionSync = (signers: KeyPairSigner[]) =>
+      sendInstructions([
+        withRemainingAccounts(
+          getExecuteTransactionSyncInstruction({
+            settings,
+            accountIndex: 0,
+            numSigners: signers.length,
+            instructions,
+          }),
+          [...signers.map(signerMeta), ...instructionAccounts]
+        ),
+      ]);
+    const getBalance = async (account: Address) =>
+      (await rpc.getBalance(account, { commitment: 'confirmed' }).send()).value;
+
+    // Step 2: The creator alone doesn't reach the threshold
+    console.log('');
+    console.log('🚫 Step 2: Executing with signers below the threshold...');
+    await expectRejected('Executing with a voting weight of 2 out of 3', () => executeTransactionSync([creatorSigner]));
+    if ((await getBalance(recipient.address)) !== 0n) {
+      throw new Error('❌ Expected no transfer below the threshold');
+    }
+    console.log('✅ Synchronous transaction below the threshold rejected');
+
+    // Step 3: Both signers reach the threshold, and the transfer runs from the vault
+    console.log('');
+    console.log('🚀 Step 3: Executing with both signers...');
+    const creatorBalance = await getBalance(creatorSigner.address);
+    const voterBalance = await getBalance(voter.address);
+    const vaultBalance = await getBalance(smartAccountPda);
+    await executeTransactionSync([creatorSigner, voter]);
+    const recipientBalance = await getBalance(recipient.address);
+    if (recipientBalance !== transferAmount) {
+      throw new Error(`❌ Expected the recipient to get ${transferAmount} lamports, got ${recipientBalance}`);
+    }
+    if ((await getBalance(smartAccountPda)) !== vaultBalance - transferAmount) {
+      throw new Error('❌ Expected the transfer to be paid by the vault');
+    }
+    // The backend fee payer pays for the transaction, so the signers' balances don't change.
+    if ((await getBalance(creatorSigner.address)) !== creatorBalance || (await getBalance(voter.address)) !== voterBalance) {
+      throw new Error('❌ Expected the signers not to take part in the transfer');
+    }
+    console.log('✅ Synchronous transaction executed from the vault');
+  } catch (error) {
+    console.error('❌ synchronous transaction failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+testTransactionSync();
@@ -0,0 +1,243 @@
+import {
+  addDecoderSizePrefix,
+  createNoopSigner,
+  generateKeyPairSigner,
+  getAddressDecoder,
+  getBase58Encoder,
+  getBytesDecoder,
+  getI64Decoder,
+  getOptionDecoder,
+  getStructDecoder,
+  getU16Decoder,
+  getU32Decoder,
+  getU64Decoder,
+  getU8Decoder,
+  getUtf8Decoder,
+  isSome,
+  lamports,
+  type Address,
+} from '@solana/kit';
+import { getTransferSolInstruction } from '@solana-program/system';
+import {
+  getApproveProposalInstruction,
+  getCreateProposalInstruction,
+  getCreateTransactionInstruction,
+  getExecuteTransactionInstruction,
+  getLogEventInstructionDataDecoder,
+  LOG_EVENT_DISCRIMINATOR,
+} from '../clients/js/src/generated/instructions';
+import { ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS } from '../clients/js/src/generated/programs';
+import { getProposalStatusDecoder } from '../clients/js/src/generated/types';
+import { deriveProposalPda, deriveTransactionPda } from '../utils';
+import {
+  compileSmartAccountMessage,
+  createTestSmartAccount,
+  deriveSmartAccountPda,
+  setupTestContext,
+  withRemainingAccounts,
+  type TestContext,
+} from './utils';
+
+// The `SmartAccountEvent` variant index of `ProposalEvent`, the envelope `eventType`.
+const PROPOSAL_EVENT_TYPE = 10;
+// `ProposalEventType` variant indexes.
+const PROPOSAL_EVENT_CREATE = 0;
+const PROPOSAL_EVENT_APPROVE = 2;
+const PROPOSAL_EVENT_EXECUTE = 6;
+
+const eventEnvelopeDecoder = getStructDecoder([
+  ['version', getU8Decoder()],
+  ['eventType', getU8Decoder()],
+  ['settingsPubkey', getAddressDecoder()],
+  ['slot', getU64Decoder()],
+  ['timestamp', getI64Decoder()],
+  ['payload', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+]);
+
+const proposalEventDecoder = getStructDecoder([
+  ['eventType', getU8Decoder()],
+  ['settingsPubkey', getAddressDecoder()],
+  ['proposalPubkey', getAddressDecoder()],
+  ['transactionIndex', getU64Decoder()],
+  ['signer', getOptionDecoder(getAddressDecoder())],
+  ['memo', getOptionDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder()))],
+  ['status', getProposalStatusDecoder()],
+  [
+    'tally',
+    getStructDecoder([
+      ['approvals', getU16Decoder()],
+      ['rejections', getU16Decoder()],
+      ['cancellations', getU16Decoder()],
+      ['approvalWeight', getU32Decoder()],
+      ['rejectionWeight', getU32Decoder()],
+      ['cancellationWeight', getU32Decoder()],
+      ['threshold', getU16Decoder()],
+      ['cutoff', getU32Decoder()],
+    ]),
+  ],
+]);
+
+async function testProposalEvents() {
+  console.log('Testing proposal events...');
+  console.log('This test creates, approves and executes a proposal, and decodes the ProposalEvents the program logs');
+  console.log('through its log_event inner instructions.');
+  console.log('');
+
+  const context = await setupTestContext();
+  const { rpc, creatorSigner, backendFeePayerSigner, sendInstructions } = context;
+
+  try {
+    // Step 1: Create an autonomous smart account and fund its vault
+    console.log('');
+    console.log('🏗️  Step 1: Creating an autonomous smart account...');
+    const result = await createTestSmartAccount(context, {
+      threshold: 1,
+      signers: [{ key: creatorSigner.address, permissions: { mask: 7 }, weight: 1 }],
+    });
+    const settings = result.settingsAddress;
+    const [smartAccountPda, smartAccountPdaBump] = await deriveSmartAccountPda(settings, 0);
+    await sendInstructions([
+      getTransferSolInstruction({
+        source: backendFeePayerSigner,
+        destination: smartAccountPda,
+        amount: lamports(10_000_000n),
+      }),
+    ]);
+    console.log('✅ Smart account created:', settings);
+
+    // Step 2: Create, approve and execute a proposal, in separate transactions
+    console.log('');
+    console.log('🚀 Step 2: Creating, approving and executing a proposal...');
+    const { transactionMessage, remainingAccounts } = await compileSmartAccountMessage(rpc, smartAccountPda, [
+      getTransferSolInstruction({
+        source: createNoopSigner(smartAccountPda),
+        destination: (await generateKeyPairSigner()).address,
+        amount: lamports(1_000_000n),
+      }),
+    ]);
+    const transactionIndex = 1n;
+    const transactionPda = await deriveTransactionPda(settings, transactionIndex);
+    const proposalPda = await deriveProposalPda(settings, transactionIndex);
+    await sendInstructions([
+      getCreateTransactionInstruction({
+        settings,
+        transaction: transactionPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        args: {
+          accountIndex: 0,
+          accountBump: smartAccountPdaBump,
+          ephemeralSigners: 0,
+          transactionMessage,
+          memo: null,
+        },
+      }),
+      getCreateProposalInstruction({
+        settings,
+        proposal: proposalPda,
+        creator: creatorSigner,
+        feePayer: backendFeePayerSigner,
+        transactionIndex,
+        draft: false,
+        votingDeadline: null,
+      }),
+    ]);
+    await sendInstructions([
+      getApproveProposalInstruction({
+        settings,
+        signer: creatorSigner,
+        proposal: proposalPda,
+        args: { memo: 'approve' },
+      }),
+    ]);
+    await sendInstructions([
+      withRemainingAccounts(
+        getExecuteTransactionInstruction({
+          settings,
+          proposal: proposalPda,
+          transaction: transactionPda,
+          signer: creatorSigner,
+          feePayer: backendFeePayerSigner,
+        }),
+        remainingAccounts
+      ),
+    ]);
+    console.log('✅ Proposal executed:', proposalPda);
+
+    // Step 3: Decode the proposal events logged by the three transactions
+    console.log('');
+    console.log('🔎 Step 3: Decoding the logged proposal events...');
+    const events = await fetchProposalEvents(context, proposalPda);
+    const expected = [
+      { eventType: PROPOSAL_EVENT_CREATE, status: 'Active', approvals: 0, memo: null },
+      { eventType: PROPOSAL_EVENT_APPROVE, status: 'Approved', approvals: 1, memo: 'approve' },
+      { eventType: PROPOSAL_EVENT_EXECUTE, status: 'Executed', approvals: 1, memo: null },
+    ];
+    if (events.length !== expected.length) {
+      throw new Error(`❌ Expected ${expected.length} proposal events, got ${events.length}`);
+    }
+    for (const [index, { envelopeSettings, event }] of events.entries()) {
+      const { eventType, status, approvals, memo } = expected[index];
+      if (envelopeSettings !== settings || event.settingsPubkey !== settings || event.proposalPubkey !== proposalPda) {
+        throw new Error(`❌ Event ${index} is not for the proposal`);
+      }
+      if (event.eventType !== eventType || event.transactionIndex !== transactionIndex) {
+        throw new Error(`❌ Expected event ${index} to be of type ${eventType}, got ${event.eventType}`);
+      }
+      if (!isSome(event.signer) || event.signer.value !== creatorSigner.address) {
+        throw new Error(`❌ Expected event ${index} to be signed by the creator`);
+      }
+      if ((isSome(event.memo) ? event.memo.value : null) !== memo) {
+        throw new Error(`❌ Expected event ${index} to have the memo ${memo}`);
+      }
+      if (event.status.__kind !== status) {
+        throw new Error(`❌ Expected event ${index} to report the status ${status}, got ${event.status.__kind}`);
+      }
+      if (
+        event.tally.approvals !== approvals ||
+        event.tally.approvalWeight !== approvals ||
+        event.tally.threshold !== 1
+      ) {
+        throw new Error(`❌ Unexpected tally in event ${index}: ${JSON.stringify(event.tally)}`);
+      }
+    }
+    console.log('✅ Create, approve and execute proposal events decoded');
+  } catch (error) {
+    console.error('❌ proposal events failed:', error);
+    throw error; // Re-throw to properly fail the test
+  }
+
+}
+
+/**
+ * Decodes the `ProposalEvent`s logged through `log_event` inner instructions by the transactions
+ * touching `proposal`, oldest first.
+ */
+async function fetchProposalEvents(context: TestContext, proposal: Address) {
+  const { rpc } = context;
+  const signatures = await rpc.getSignaturesForAddress(proposal, { commitment: 'confirmed' }).send();
+  const events = [];
+  for (const { signature } of signatures.reverse()) {
+    const transaction = await rpc
+      .getTransaction(signature, { commitment: 'confirmed', encoding: 'json', maxSupportedTransactionVersion: 0 })
+      .send();
+    const accountKeys = transaction?.transaction.message.accountKeys ?? [];
+    for (const { instructions } of transaction?.meta?.innerInstructions ?? []) {
+      for (const instruction of instructions) {
+        const data = getBase58Encoder().encode(instruction.data);
+        const programAddress = accountKeys[instruction.programIdIndex];
+        if (programAddress !== ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS || data[0] !== LOG_EVENT_DISCRIMINATOR[0]) {
+          continue;
+        }
+        const envelope = eventEnvelopeDecoder.decode(getLogEventInstructionDataDecoder().decode(data).event);
+        if (envelope.eventType !== PROPOSAL_EVENT_TYPE) {
+          continue;
+        }
+        events.push({ envelopeSettings: envelope.settingsPubkey, event: proposalEventDecoder.decode(envelope.payload) });
+      }
+    }
+  }
+  return events;
+}
+
+testProposalEvents();
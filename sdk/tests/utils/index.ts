@@ -0,0 +1,352 @@
+import {
+  createSolanaRpc,
+  createSolanaRpcSubscriptions,
+  createKeyPairFromBytes,
+  createSignerFromKeyPair,
+  sendAndConfirmTransactionFactory,
+  signTransactionMessageWithSigners,
+  createTransactionMessage,
+  setTransactionMessageFeePayerSigner,
+  setTransactionMessageLifetimeUsingBlockhash,
+  appendTransactionMessageInstructions,
+  compileTransactionMessage,
+  compressTransactionMessageUsingAddressLookupTables,
+  setTransactionMessageFeePayer,
+  pipe,
+  getProgramDerivedAddress,
+  lamports,
+  address,
+  getAddressEncoder,
+  getArrayEncoder,
+  getStructEncoder,
+  getU32Encoder,
+  getU64Encoder,
+  getU8Encoder,
+  AccountRole,
+  type AccountMeta,
+  type AccountSignerMeta,
+  type Address,
+  type Instruction,
+  type KeyPairSigner,
+  type TransactionSigner,
+} from '@solana/kit';
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { Buffer } from 'buffer';
+import * as bs58 from 'bs58';
+import { createSmartAccountTransaction, type CreateSmartAccountResult } from '../../createSmartAccount';
+import { getCreateSmartAccountInstructionAsync } from '../../clients/js/src/generated/instructions';
+import { fetchProgramConfig } from '../../clients/js/src/generated/accounts/programConfig';
+import { ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS } from '../../clients/js/src/generated/programs';
+import {
+  getSmartAccountTransactionMessageEncoder,
+  type RestrictedSmartAccountSignerArgs,
+  type SmartAccountSignerArgs,
+} from '../../clients/js/src/generated/types';
+
+type SolanaRpc = ReturnType<typeof createSolanaRpc>;
+
+/**
+ * What every test needs to talk to the local validator.
+ */
+export type TestContext = {
+  rpc: SolanaRpc;
+  /** The local Solana CLI keypair, which creates the smart accounts. */
+  creatorSigner: KeyPairSigner;
+  /** The backend fee payer, funded by `setupTestContext`. */
+  backendFeePayerSigner: KeyPairSigner;
+  /** Sends the instructions in one transaction paid for by the backend fee payer. */
+  sendInstructions: (instructions: Instruction[]) => Promise<void>;
+};
+
+/**
+ * Loads a signer from a Solana CLI keypair file.
+ */
+export async function loadKeypairSigner(file: string): Promise<KeyPairSigner> {
+  const keypairBytes = new Uint8Array(JSON.parse(fs.readFileSync(file).toString()));
+  return createSignerFromKeyPair(await createKeyPairFromBytes(keypairBytes));
+}
+
+/**
+ * Connects to the local validator, loads the creator and the backend fee payer, and funds the fee payer.
+ * The creator is the `SOLANA_KEYPAIR` keypair, or the Solana CLI default one.
+ */
+export async function setupTestContext(): Promise<TestContext> {
+  const rpc = createSolanaRpc('http://localhost:8899');
+  const rpcSubscriptions = createSolanaRpcSubscriptions('ws://localhost:8900');
+  const sendAndConfirm = sendAndConfirmTransactionFactory({ rpc, rpcSubscriptions });
+
+  const creatorSigner = await loadKeypairSigner(
+    process.env.SOLANA_KEYPAIR ?? path.join(os.homedir(), '.config/solana/id.json')
+  );
+
+  const backendFeePayerSigner = await loadKeypairSigner(path.join(__dirname, '../backend-fee-payer-keypair.json'));
+  console.log('📝 Backend Fee Payer:', backendFeePayerSigner.address);
+
+  console.log('💰 Funding Backend Fee Payer...');
+  await rpc.requestAirdrop(backendFeePayerSigner.address, lamports(1_000_000_000n), { commitment: 'confirmed' }).send();
+
+  const sendInstructions = async (instructions: Instruction[]) => {
+    const { value: latestBlockhash } = await rpc.getLatestBlockhash().send();
+    const transactionMessage = pipe(
+      createTransactionMessage({ version: 0 }),
+      (tx) => setTransactionMessageFeePayerSigner(backendFeePayerSigner, tx),
+      (tx) => setTransactionMessageLifetimeUsingBlockhash(latestBlockhash, tx),
+      (tx) => appendTransactionMessageInstructions(instructions, tx)
+    );
+    const signedTransaction = await signTransactionMessageWithSigners(transactionMessage);
+    return sendAndConfirm(signedTransaction, { commitment: 'confirmed' });
+  };
+
+  return { rpc, creatorSigner, backendFeePayerSigner, sendInstructions };
+}
+
+/**
+ * Loads the program config authority set by 00-setup.test.ts.
+ */
+export async function loadProgramConfigAuthority(): Promise<KeyPairSigner> {
+  return loadKeypairSigner(path.join(__dirname, '../../test-program-config-initializer-keypair.json'));
+}
+
+export async function deriveProgramConfigPda(): Promise<Address> {
+  const [programConfigPda] = await getProgramDerivedAddress({
+    programAddress: ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
+    seeds: [
+      new Uint8Array(Buffer.from('smart_account')),
+      new Uint8Array(Buffer.from('program_config')),
+    ],
+  });
+  return programConfigPda;
+}
+
+export type CreateTestSmartAccountParams = {
+  threshold: number;
+  signers: SmartAccountSignerArgs[];
+  restrictedSigners?: RestrictedSmartAccountSignerArgs[];
+  settingsAuthority?: Address | null;
+  timeLock?: number;
+  rentCollector?: Address | null;
+  referrer?: Address | null;
+  /** Defaults to the context creator. */
+  creator?: TransactionSigner;
+  /** Required when the program config creation mode is `Allowlist`. */
+  creatorAllowlist?: Address;
+  /** Required when the program config creation mode is `Cosigned`. */
+  programConfigAuthority?: TransactionSigner;
+};
+
+/**
+ * Creates a smart account signed by its creator, with the backend fee payer paying for it.
+ */
+export async function createTestSmartAccount(
+  context: TestContext,
+  params: CreateTestSmartAccountParams
+): Promise<CreateSmartAccountResult> {
+  const { rpc, backendFeePayerSigner, sendInstructions } = context;
+  const {
+    threshold,
+    signers,
+    restrictedSigners = [],
+    settingsAuthority = null,
+    timeLock = 0,
+    rentCollector = null,
+    referrer = null,
+    creator = context.creatorSigner,
+    creatorAllowlist,
+    programConfigAuthority,
+  } = params;
+
+  const result = await createSmartAccountTransaction({
+    rpc,
+    creator: creator.address,
+    feePayer: backendFeePayerSigner.address,
+    threshold,
+    signers,
+    restrictedSigners,
+    settingsAuthority,
+    timeLock,
+    rentCollector,
+    referrer,
+  });
+  const { treasury } = (await fetchProgramConfig(rpc, await deriveProgramConfigPda())).data;
+  await sendInstructions([
+    await getCreateSmartAccountInstructionAsync({
+      settings: result.settingsAddress,
+      treasury,
+      creator,
+      feePayer: backendFeePayerSigner,
+      referrerAccount: referrer ?? undefined,
+      creatorAllowlist,
+      programConfigAuthority,
+      settingsAuthority,
+      threshold,
+      signers,
+      restrictedSigners,
+      timeLock,
+      rentCollector,
+      memo: null,
+      referrer,
+    }),
+  ]);
+  return result;
+}
+
+/**
+ * Runs `attempt` and fails the test if it succeeds.
+ */
+export async function expectRejected(description: string, attempt: () => Promise<unknown>): Promise<void> {
+  let accepted = false;
+  try {
+    await attempt();
+    accepted = true;
+  } catch {
+    console.log(`✅ ${description} was rejected`);
+  }
+  if (accepted) {
+    throw new Error(`❌ ${description} was accepted`);
+  }
+}
+
+/**
+ * Derives the PDA of the smart account at `accountIndex` and its bump.
+ */
+export async function deriveSmartAccountPda(settingsAddress: Address, accountIndex: number): Promise<[Address, number]> {
+  const [smartAccountPda, smartAccountPdaBump] = await getProgramDerivedAddress({
+    programAddress: ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
+    seeds: [
+      new Uint8Array(Buffer.from('smart_account')),
+      bs58.decode(settingsAddress),
+      new Uint8Array(Buffer.from('smart_account')),
+      new Uint8Array([accountIndex]),
+    ],
+  });
+  return [smartAccountPda, smartAccountPdaBump];
+}
+
+/**
+ * A smart account transaction message, with the accounts needed to execute it.
+ */
+export type CompiledSmartAccountMessage = {
+  /** The encoded `SmartAccountTransactionMessage`. */
+  transactionMessage: Uint8Array;
+  /** The address lookup tables, then the message accounts, as expected by the execute instructions. */
+  remainingAccounts: AccountMeta[];
+};
+
+/**
+ * Compiles `instructions` into a transaction message for the smart account at `smartAccountPda`,
+ * loading the accounts found in `addressLookupTables` from them.
+ */
+export async function compileSmartAccountMessage(
+  rpc: SolanaRpc,
+  smartAccountPda: Address,
+  instructions: Instruction[],
+  addressLookupTables: Record<Address, Address[]> = {}
+): Promise<CompiledSmartAccountMessage> {
+  const { value: latestBlockhash } = await rpc.getLatestBlockhash().send();
+  const message = pipe(
+    createTransactionMessage({ version: 0 }),
+    (tx) => setTransactionMessageFeePayer(smartAccountPda, tx),
+    (tx) => setTransactionMessageLifetimeUsingBlockhash(latestBlockhash, tx),
+    (tx) => appendTransactionMessageInstructions(instructions, tx),
+    (tx) => compressTransactionMessageUsingAddressLookupTables(tx, addressLookupTables)
+  );
+  const compiled = compileTransactionMessage(message);
+  const { header, staticAccounts } = compiled;
+  const addressTableLookups = compiled.addressTableLookups ?? [];
+
+  const numSigners = header.numSignerAccounts;
+  const numWritableSigners = numSigners - header.numReadonlySignerAccounts;
+  const numWritableNonSigners = staticAccounts.length - numSigners - header.numReadonlyNonSignerAccounts;
+  const transactionMessage = getSmartAccountTransactionMessageEncoder().encode({
+    numSigners,
+    numWritableSigners,
+    numWritableNonSigners,
+    accountKeys: staticAccounts,
+    instructions: compiled.instructions.map((ix) => ({
+      programIdIndex: ix.programAddressIndex,
+      accountIndexes: new Uint8Array(ix.accountIndices ?? []),
+      data: ix.data ?? new Uint8Array(),
+    })),
+    addressTableLookups: addressTableLookups.map((lookup) => ({
+      accountKey: lookup.lookupTableAddress,
+      writableIndexes: new Uint8Array(lookup.writableIndexes),
+      readonlyIndexes: new Uint8Array(lookup.readonlyIndexes),
+    })),
+  });
+
+  // The smart account signs through the program, so none of the accounts sign the outer transaction.
+  const isWritable = (index: number) =>
+    index < numSigners ? index < numWritableSigners : index - numSigners < numWritableNonSigners;
+  const remainingAccounts: AccountMeta[] = [
+    ...addressTableLookups.map((lookup) => ({ address: lookup.lookupTableAddress, role: AccountRole.READONLY })),
+    ...staticAccounts.map((account, index) => ({
+      address: account,
+      role: isWritable(index) ? AccountRole.WRITABLE : AccountRole.READONLY,
+    })),
+    ...addressTableLookups.flatMap((lookup) => [
+      ...lookup.writableIndexes.map((i) => ({
+        address: addressLookupTables[lookup.lookupTableAddress][i],
+        role: AccountRole.WRITABLE,
+      })),
+      ...lookup.readonlyIndexes.map((i) => ({
+        address: addressLookupTables[lookup.lookupTableAddress][i],
+        role: AccountRole.READONLY,
+      })),
+    ]),
+  ];
+
+  return { transactionMessage: new Uint8Array(transactionMessage), remainingAccounts };
+}
+
+/**
+ * Appends `accounts` to the accounts of `instruction`.
+ */
+export function withRemainingAccounts(instruction: Instruction, accounts: (AccountMeta | AccountSignerMeta)[]): Instruction {
+  return { ...instruction, accounts: [...(instruction.accounts ?? []), ...accounts] };
+}
+
+const ADDRESS_LOOKUP_TABLE_PROGRAM_ADDRESS = address('AddressLookupTab1e1111111111111111111111111');
+const SYSTEM_PROGRAM_ADDRESS = address('11111111111111111111111111111111');
+
+/**
+ * Creates an address lookup table holding `addresses`, owned by the backend fee payer,
+ * and waits until they can be loaded from it.
+ */
+export async function createTestAddressLookupTable(context: TestContext, addresses: Address[]): Promise<Address> {
+  const { rpc, backendFeePayerSigner, sendInstructions } = context;
+
+  const recentSlot = await rpc.getSlot({ commitment: 'finalized' }).send();
+  const [lookupTable, bump] = await getProgramDerivedAddress({
+    programAddress: ADDRESS_LOOKUP_TABLE_PROGRAM_ADDRESS,
+    seeds: [getAddressEncoder().encode(backendFeePayerSigner.address), getU64Encoder().encode(recentSlot)],
+  });
+  const accounts: AccountMeta[] = [
+    { address: lookupTable, role: AccountRole.WRITABLE },
+    { address: backendFeePayerSigner.address, role: AccountRole.READONLY_SIGNER },
+    { address: backendFeePayerSigner.address, role: AccountRole.WRITABLE_SIGNER },
+    { address: SYSTEM_PROGRAM_ADDRESS, role: AccountRole.READONLY },
+  ];
+  // The address lookup table program instructions are bincode encoded, with a u32 variant and u64 vector lengths.
+  const createData = getStructEncoder([
+    ['instruction', getU32Encoder()],
+    ['recentSlot', getU64Encoder()],
+    ['bump', getU8Encoder()],
+  ]).encode({ instruction: 0, recentSlot, bump });
+  const extendData = getStructEncoder([
+    ['instruction', getU32Encoder()],
+    ['addresses', getArrayEncoder(getAddressEncoder(), { size: getU64Encoder() })],
+  ]).encode({ instruction: 2, addresses });
+  await sendInstructions([
+    { programAddress: ADDRESS_LOOKUP_TABLE_PROGRAM_ADDRESS, accounts, data: createData },
+    { programAddress: ADDRESS_LOOKUP_TABLE_PROGRAM_ADDRESS, accounts, data: extendData },
+  ]);
+
+  // Addresses can only be loaded from the slot after the one they were added in.
+  const extendedSlot = await rpc.getSlot({ commitment: 'confirmed' }).send();
+  while ((await rpc.getSlot({ commitment: 'confirmed' }).send()) <= extendedSlot) {
+    await new Promise((resolve) => setTimeout(resolve, 200));
+  }
+  return lookupTable;
+}
@@ -11,6 +11,8 @@ import {
     addEncoderSizePrefix,
     getBytesEncoder,
     getU32Encoder,
+    unwrapOption,
+    type ReadonlyUint8Array,
 } from '@solana/kit';
 import { Buffer } from 'buffer';
 import * as bs58 from 'bs58';
@@ -62,6 +64,30 @@ export async function deriveProposalPda(
   return proposalPda;
 }
 
+/**
+ * Derives the PDA of the transaction at `transactionIndex`, starting from 1, in the batch at `batchIndex`
+ */
+export async function deriveBatchTransactionPda(
+  settingsAddress: Address,
+  batchIndex: bigint,
+  transactionIndex: number
+): Promise<Address> {
+  const transactionIndexBytes = new Uint8Array(4);
+  new DataView(transactionIndexBytes.buffer).setUint32(0, transactionIndex, true);
+  const [batchTransactionPda] = await getProgramDerivedAddress({
+    programAddress: ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
+    seeds: [
+      new Uint8Array(Buffer.from('smart_account')),
+      bs58.decode(settingsAddress),
+      new Uint8Array(Buffer.from('transaction')),
+      new Uint8Array(new BigUint64Array([batchIndex]).buffer),
+      new Uint8Array(Buffer.from('batch_transaction')),
+      transactionIndexBytes,
+    ],
+  });
+  return batchTransactionPda;
+}
+
 /**
  Derives the transaction buffer PDA for a given settings address, creator, and buffer index
 */
@@ -83,6 +109,93 @@ export async function deriveBufferPda(
   return bufferPda;
 }
 
+/**
+ * Derives the session key PDA for a given settings address and session key
+ */
+export async function deriveSessionKeyPda(
+  settingsAddress: Address,
+  sessionKeyAddress: Address
+): Promise<Address> {
+  const [sessionKeyPda] = await getProgramDerivedAddress({
+    programAddress: ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
+    seeds: [
+      new Uint8Array(Buffer.from('smart_account')),
+      bs58.decode(settingsAddress),
+      new Uint8Array(Buffer.from('session_key')),
+      bs58.decode(sessionKeyAddress),
+    ],
+  });
+  return sessionKeyPda;
+}
+
+/**
+ * Derives the SpendingLimit PDA for a given settings address and spending limit seed
+ */
+export async function deriveSpendingLimitPda(
+  settingsAddress: Address,
+  seed: Address
+): Promise<Address> {
+  const [spendingLimitPda] = await getProgramDerivedAddress({
+    programAddress: ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
+    seeds: [
+      new Uint8Array(Buffer.from('smart_account')),
+      bs58.decode(settingsAddress),
+      new Uint8Array(Buffer.from('spending_limit')),
+      bs58.decode(seed),
+    ],
+  });
+  return spendingLimitPda;
+}
+
+/**
+ * Derives the VaultPolicy PDA of the smart account at `accountIndex`
+ */
+export async function deriveVaultPolicyPda(
+  settingsAddress: Address,
+  accountIndex: number
+): Promise<Address> {
+  const [vaultPolicyPda] = await getProgramDerivedAddress({
+    programAddress: ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
+    seeds: [
+      new Uint8Array(Buffer.from('smart_account')),
+      bs58.decode(settingsAddress),
+      new Uint8Array(Buffer.from('vault_policy')),
+      new Uint8Array([accountIndex]),
+    ],
+  });
+  return vaultPolicyPda;
+}
+
+/**
+ * Derives the PDA of the smart account creation allowlist page at `page`
+ */
+export async function deriveCreatorAllowlistPda(page: number): Promise<Address> {
+  const pageBytes = new Uint8Array(4);
+  new DataView(pageBytes.buffer).setUint32(0, page, true);
+  const [creatorAllowlistPda] = await getProgramDerivedAddress({
+    programAddress: ASTROLABE_SMART_ACCOUNT_PROGRAM_ADDRESS,
+    seeds: [
+      new Uint8Array(Buffer.from('smart_account')),
+      new Uint8Array(Buffer.from('creator_allowlist')),
+      pageBytes,
+    ],
+  });
+  return creatorAllowlistPda;
+}
+
+/**
+ * Returns the VaultPolicy PDA of the smart account at `accountIndex` if the settings have one,
+ * it must then be passed to the instructions creating, voting on and executing transactions for that smart account.
+ */
+export async function getVaultPolicyAddress(
+  settingsAddress: Address,
+  vaultPolicies: ReadonlyUint8Array,
+  accountIndex: number
+): Promise<Address | undefined> {
+  const hasVaultPolicy = (vaultPolicies[accountIndex >> 3] & (1 << (accountIndex & 7))) !== 0;
+  return hasVaultPolicy ? deriveVaultPolicyPda(settingsAddress, accountIndex) : undefined;
+}
+
 /**
  * Fetches smart account settings and returns current and next transaction indices
  */
@@ -91,7 +204,11 @@ export async function fetchSmartAccountSettings(rpc: SolanaRpc, settingsAddress:
   return {
     currentTransactionIndex: settings.data.transactionIndex,
     nextTransactionIndex: settings.data.transactionIndex + BigInt(1),
-    threshold: settings.data.threshold
+    threshold: settings.data.threshold,
+    // `null` when rent reclamation is disabled for the smart account.
+    rentCollector: unwrapOption(settings.data.rentCollector),
+    // Bitmap of the account indices that have a vault policy, see `getVaultPolicyAddress`.
+    vaultPolicies: settings.data.vaultPolicies
   };
 }
   